@@ -1,8 +1,2586 @@
 import test from 'ava'
 
-import { AABB, Vector3f } from '../index.js'
+import { AABB, AngleUnit, AttributeMath, Audio, Axis, AxisAngle, Basis, Beam, BlockFace, Boat, BoatStatus, Camera, Celestial, Chain, ChunkLoadStatus, Climbing, Cluster, CollisionResolver, CoordinateMode, Coordinates, CubicSpline, Damage, Difficulty, DifficultyMath, Dimension, DoorSweep, DynamicAabbTree, EffectMath, EntityPush, EntityStateBuffer, Euler, EulerOrder, Experience, Explosion, EyeRay, Falloff, FalloffKind, FitMode, FixedTimestepAccumulator, Flight, FluidMotion, FontMetrics, Formation, FormationKind, Frustum, GrowthMath, HeatGrid, Hunger, HungerAction, InterestManager, Interp, JavaRandom, KeyframeInterpolation, Knockback, Layout, LineOfFire, LineOfSight, MathConfig, Matrix3, Matrix4, MatrixStack, Minecart, ModifierOperation, MoveDelta, Movement, NoiseDebug, NoiseWorm, NumberTrack, ObstructionKind, Optimizer, Pathfinder, Picking, Plane, Platform, PlaybackMode, Points, Portal, Pose, PositionFilter, PotentiallyVisibleSet, Quaternion, QuaternionTrack, RailPath, RailShape, RaycastCache, Raycaster, Rect, Rot2, Rotation, SafeSpot, Seed, Selection, Sensor, ShapeGen, SpatialIndex, Sphere, Spline, StructureBounds, SweepAndPrune, SweepOrder, TickSchedule, TickTime, TicketGraph, TrailMap, Triangle, Vector2f, Vector3f, Vector3i, VectorBuffer, VectorPool, VectorTrack, Vibration, VolumeTransform, VoxelShape, Weather, Xoroshiro128PlusPlus } from '../index.js'
+
+const near = (actual, expected, epsilon = 1e-9) => Math.abs(actual - expected) < epsilon
 
 test('sum from native', (t) => {
   const x = new AABB(new Vector3f(0,0,0), new Vector3f(1,1,1))
   t.assert(x.min.x == 0)
 })
+
+test('vector arguments accept plain objects and arrays', (t) => {
+  const v = new Vector3f(1, 2, 3)
+
+  const fromObject = v.add({ x: 1, y: 1, z: 1 })
+  const fromArray = v.add([1, 1, 1])
+  t.deepEqual([fromObject.x, fromObject.y, fromObject.z], [2, 3, 4])
+  t.deepEqual([fromArray.x, fromArray.y, fromArray.z], [2, 3, 4])
+  t.is(v.dot([1, 0, 0]), 1)
+
+  const box = new AABB({ x: 0, y: 0, z: 0 }, [1, 1, 1])
+  t.assert(box.contains([0.5, 0.5, 0.5]))
+
+  t.is(new Camera([1, 2, 3], 0, 0).position.y, 2)
+  t.is(ShapeGen.circle({ x: 0, y: 0, z: 0 }, 2, 1).length, ShapeGen.circle(new Vector3f(0, 0, 0), 2, 1).length)
+  t.is(Knockback.combineAll([[1, 0, 0], { x: 0, y: 0.4, z: 0 }]).y, 0.4)
+  t.throws(() => v.add([1, 2]))
+})
+
+test('matrix elements reject the wrong length', (t) => {
+  const matrix = new Matrix4()
+
+  t.throws(() => { matrix.elements = [1, 2, 3] })
+  t.is(matrix.elements.length, 16)
+  t.is(matrix.multiply(matrix).elements[0], 1)
+})
+
+test('small scale matrices can be inverted', (t) => {
+  const scale = new Matrix4([0.001, 0, 0, 0, 0, 0.001, 0, 0, 0, 0, 0.001, 0, 0, 0, 0, 1])
+  const inverse = scale.inverse()
+
+  t.assert(inverse != null)
+  t.assert(Math.abs(inverse.elements[0] - 1000) < 1e-9)
+  t.is(new Matrix4([1, 2, 3, 4, 2, 4, 6, 8, 0, 0, 1, 0, 0, 0, 0, 1]).inverse(), null)
+})
+
+test('translations at world scale coordinates can be inverted', (t) => {
+  for (const x of [20000, 30000000]) {
+    const inverse = Matrix4.translation([x, 64, -x]).inverse()
+
+    t.assert(inverse != null)
+    t.deepEqual(inverse.elements.slice(12, 15), [-x, -64, x])
+  }
+
+  const scaled = Matrix4.compose([5000, 64, 5000], Quaternion.identity(), [0.5, 0.5, 0.5]).inverse()
+  const local = scaled.transformPoint([5000.25, 64, 5000])
+  t.is(local.x, 0.5)
+})
+
+test('screen rays stay correct far from the origin', (t) => {
+  const viewport = { width: 800, height: 600 }
+
+  for (const [x, z] of [[100, 100], [100000, 100000]]) {
+    const ray = new Camera([x, 64, z], 0, 0).screenToRay(400, 300, viewport)
+
+    t.assert(Math.abs(ray.origin.x - x) < 1e-6)
+    t.assert(Math.abs(ray.origin.z - (z + 0.05)) < 1e-6)
+    t.assert(Math.abs(ray.direction.z - 1) < 1e-9)
+  }
+
+  t.is(new Camera([0, 64, 0], 0, 0, 70, 0, 0).screenToRay(400, 300, viewport), null)
+})
+
+test('scaled entities are picked at any distance from the origin', (t) => {
+  const scaled = Matrix4.compose([5000, 64, 5000], Quaternion.identity(), [0.5, 0.5, 0.5])
+  const transforms = new Float64Array([...Matrix4.translation([10, 64, 0]).elements, ...scaled.elements])
+  const extents = new Float64Array([0.5, 0.5, 0.5, 0.5, 0.5, 0.5])
+
+  const hit = Picking.raycastEntities([4990, 64, 5000], [5010, 64, 5000], transforms, extents)
+  t.is(hit.index, 1)
+  t.is(hit.position.x, 4999.75)
+
+  const singular = new Float64Array([...Matrix4.scaling([0, 1, 1]).elements])
+  t.throws(() => Picking.raycastEntities([0, 0, 0], [1, 0, 0], singular, new Float64Array([0.5, 0.5, 0.5])))
+})
+
+test('wrapped words are measured with the formatting before them', (t) => {
+  const lines = FontMetrics.wrap('§lAAAA BBBB', 58)
+
+  t.deepEqual(lines, ['§lAAAA', '§lBBBB'])
+  t.assert(lines.every((line) => FontMetrics.measure(line) <= 58))
+})
+
+test('text seeds keep 64-bit precision and parse 0 like vanilla', (t) => {
+  t.is(Seed.fromString('9223372036854775807'), '9223372036854775807')
+  t.is(Seed.fromString('0'), '0')
+  t.is(Seed.fromString('hello'), '99162322')
+  t.is(Seed.hashCode64('the quick brown fox'), '-4892001691968205117')
+  t.is(Seed.fromUuid('7fffffff-ffff-ffff-0000-000000000000'), '9223372036854775807')
+})
+
+test('block traversal starts from the position within the first block', (t) => {
+  const visit = (start, end) => {
+    const blocks = []
+    Raycaster.transverseBlocks(start, end, (block) => {
+      blocks.push([block.x, block.y, block.z])
+      return false
+    })
+    return blocks
+  }
+
+  t.deepEqual(visit(new Vector3f(0.9, 0.5, 0.5), new Vector3f(2.2, 0.5, 0.5)), [[0, 0, 0], [1, 0, 0], [2, 0, 0]])
+  t.deepEqual(visit(new Vector3f(0.2, 0.5, 0.5), new Vector3f(0.7, 0.5, 0.5)), [[0, 0, 0]])
+})
+
+test('intercept distance is measured from the start of the ray', (t) => {
+  const hit = AABB.Intercept(new AABB([0, 0, 0], [1, 1, 1]), [-2, 0.5, 0.5], [5, 0.5, 0.5])
+
+  t.is(hit.distance, 2)
+})
+
+test('mining fatigue uses the vanilla multipliers', (t) => {
+  t.is(EffectMath.miningSpeedMultiplier(0, 3), 0.0027)
+  t.is(EffectMath.miningSpeedMultiplier(0, 4), 0.00081)
+  t.is(EffectMath.miningSpeedMultiplier(0, 10), 0.00081)
+})
+
+test('releasing a scoped vector does not pool it twice', (t) => {
+  const pool = new VectorPool()
+  pool.scoped(() => {
+    pool.release(pool.acquire())
+  })
+
+  t.is(pool.size(), 1)
+  t.not(pool.acquire(), pool.acquire())
+})
+
+test('a vector returned from a scope stays with the caller', (t) => {
+  const pool = new VectorPool()
+  const kept = pool.scoped(() => {
+    pool.acquire()
+    return pool.acquire()
+  })
+
+  t.is(pool.size(), 1)
+  t.not(pool.acquire(), kept)
+})
+
+test('paddled boats reach the vanilla top speed on blue ice', (t) => {
+  const boat = new Boat([0, 0, 0])
+  for (let tick = 0; tick < 2000; tick++) boat.step(BoatStatus.OnLand, { forward: true }, null, 0.989)
+
+  // 0.04F / (1 - 0.989) blocks per tick, the well known 72.7 m/s
+  t.assert(Math.abs(boat.velocity.z - Math.fround(0.04) / (1 - 0.989)) < 1e-9)
+})
+
+test('boats use vanilla float constants widened to double', (t) => {
+  const paddled = new Boat([0, 0, 0])
+  paddled.paddle({ forward: true })
+  t.is(paddled.velocity.z, 0.03999999910593033)
+
+  const falling = new Boat([0, 10, 0])
+  falling.step(BoatStatus.InAir)
+  t.is(falling.velocity.y, -0.03999999910593033)
+
+  const submerged = new Boat([0, 0, 0])
+  submerged.velocity = new Vector3f(1, 0, 0)
+  submerged.step(BoatStatus.UnderWater)
+  t.is(submerged.velocity.x, 0.44999998807907104)
+  t.is(submerged.velocity.y, (-0.03999999910593033 + 0.009999999776482582 * 0.0615384615957737) * 0.75)
+})
+
+test('random generators take 64-bit seeds as strings and reject bad bounds', (t) => {
+  t.is(new JavaRandom(42).nextInt(), -1170105035)
+  t.is(new JavaRandom('-4892001691968205117').nextInt(), 1430074675)
+  t.is(new Xoroshiro128PlusPlus('42').nextLong(), new Xoroshiro128PlusPlus(42).nextLong())
+
+  t.throws(() => new JavaRandom(1).nextInt(0))
+  t.throws(() => new Xoroshiro128PlusPlus(1).nextInt(-1))
+  t.throws(() => new JavaRandom('not a seed'))
+})
+
+test('move deltas keep the last sent position on unsent axes', (t) => {
+  const rotations = new Float64Array(3)
+  let sent = new Float64Array([0, 0, 0])
+
+  for (let tick = 1; tick <= 3; tick++) {
+    const batch = MoveDelta.compute(sent, new Float64Array([0.04 * tick, 0, 0]), rotations, rotations, null, 0.1)
+    t.is(MoveDelta.hasChanges(batch.flags[0]), tick == 3)
+    sent = batch.positions
+  }
+
+  t.is(sent[0], Math.fround(0.12))
+  t.throws(() => MoveDelta.compute(new Float64Array(3), new Float64Array(4), rotations, rotations))
+})
+
+test('flight uses vanilla float constants widened to double', (t) => {
+  t.is(Flight.riptideLift(), 1.1999999284744263)
+
+  const v = Flight.glide([1, 0, 0], 0, -90, 0)
+  t.is(v.x, 0.9900000095367432)
+})
+
+test('curves reject points that are not packed in triplets', (t) => {
+  t.is(ShapeGen.curve(new Float64Array([0, 0, 0, 3, 0, 0])).length, 4)
+  t.throws(() => ShapeGen.curve(new Float64Array([0, 0, 0, 3, 0])))
+})
+
+test('helices too long to sample are rejected', (t) => {
+  t.assert(ShapeGen.helix([0, 0, 0], 3, 10, 2).length > 0)
+  t.throws(() => ShapeGen.helix([0, 0, 0], 1e12, 0, 1e6))
+  t.throws(() => ShapeGen.helix([0, 0, 0], Infinity, 0, 1))
+})
+
+test('entity buffers too large to allocate are rejected', (t) => {
+  const buffer = new EntityStateBuffer(2)
+  t.throws(() => buffer.resize(4294967295))
+  t.is(buffer.size(), 2)
+  t.throws(() => new EntityStateBuffer(4294967295))
+})
+
+test('noise images with an unusable resolution are rejected', (t) => {
+  const region = new Rect(0, 0, 1, 1)
+
+  t.is(NoiseDebug.renderWith(() => 0, region, new Vector2f(2, 3)).length, 6)
+  t.throws(() => NoiseDebug.renderWith(() => 0, region, new Vector2f(4e9, 4e9)))
+  t.throws(() => NoiseDebug.renderWith(() => 0, region, new Vector2f(NaN, 1)))
+  t.throws(() => NoiseDebug.renderWith(() => 0, region, new Vector2f(0, 1)))
+})
+
+test('sampling draws the same indices without allocating the whole range', (t) => {
+  const random = new JavaRandom(42)
+
+  t.deepEqual(random.sample(10, 4), [0, 4, 7, 6])
+  t.deepEqual(random.sample(1000000, 5), [969970, 89549, 22697, 801478, 261239])
+  t.is(new JavaRandom(1).sample(2147483647, 2).length, 2)
+  t.deepEqual(new JavaRandom(1).sample(5, 9).sort(), [0, 1, 2, 3, 4])
+  t.throws(() => new JavaRandom(1).sample(3000000000, 2))
+})
+
+test('the 1D minimizer never evaluates outside the interval', (t) => {
+  const f = (x) => {
+    if (x < 0 || x > 1) throw new Error(`evaluated at ${x}`)
+    return x
+  }
+
+  t.assert(Optimizer.minimize1D(f, 0, 1).x < 1e-6)
+  t.assert(Math.abs(Optimizer.minimize1D((x) => (x - 0.3) ** 2, 0, 1).x - 0.3) < 1e-6)
+})
+
+test('lightning rolls consume the same values as vanilla nextInt(100000)', (t) => {
+  const weather = new JavaRandom(7)
+  const vanilla = new JavaRandom(7)
+  let strikes = 0
+
+  for (let tick = 0; tick < 300000; tick++) {
+    const strike = Weather.shouldStrike(weather, 1, 1)
+    t.is(strike, vanilla.nextInt(100000) == 0)
+    if (strike) strikes++
+  }
+
+  t.assert(strikes > 0)
+  t.is(weather.nextInt(), vanilla.nextInt())
+})
+
+test('large and small 3x3 matrices can be inverted', (t) => {
+  for (const scale of [1e-3, 1e8]) {
+    const inverse = new Matrix3([scale, 0, 0, 0, 1, 0, 0, 0, 1]).inverse()
+
+    t.assert(inverse != null)
+    t.is(inverse.elements[0], 1 / scale)
+  }
+
+  t.is(new Matrix3([1, 2, 3, 2, 4, 6, 0, 0, 1]).inverse(), null)
+})
+
+test('matrix elements outside of the matrix are rejected', (t) => {
+  const matrix = new Matrix3([1, 2, 3, 4, 5, 6, 7, 8, 9])
+
+  t.is(matrix.get(0, 1), 4)
+  t.is(matrix.get(2, 2), 9)
+  t.throws(() => matrix.get(3, 0))
+  t.throws(() => matrix.get(0, 3))
+})
+
+test('the global config sets the angle unit and up axis', (t) => {
+  t.is(Rotation.directionFromRotation(90, 0).x, -1)
+
+  MathConfig.setGlobal(new MathConfig(null, AngleUnit.Radians, Axis.Z))
+  try {
+    t.is(Math.round(Rotation.directionFromRotation(Math.PI / 2, 0).x), -1)
+    t.is(Rotation.rotationFromDirection([-1, 0, 0]).yaw, Math.PI / 2)
+    t.assert(Math.abs(Rotation.difference(0, 1.5 * Math.PI) + Math.PI / 2) < 1e-12)
+    t.assert(ShapeGen.helix([0, 0, 0], 3, 10, 1).every((block) => block.z >= 0 && block.z <= 10))
+    t.is(MathConfig.global().upAxis, Axis.Z)
+  } finally {
+    MathConfig.resetGlobal()
+  }
+
+  t.is(MathConfig.global().angleUnit, AngleUnit.Degrees)
+  t.is(Rotation.rotationFromDirection([-1, 0, 0]).yaw, 90)
+  t.throws(() => new MathConfig(-1))
+})
+
+test('circles too large to generate are rejected', (t) => {
+  t.is(ShapeGen.circle([0, 0, 0], 2, Axis.Y, 1, true).length, 21)
+  t.throws(() => ShapeGen.circle([0, 0, 0], 1e9, Axis.Y))
+})
+
+test('curves too thick or too long to generate are rejected', (t) => {
+  t.is(ShapeGen.curve(new Float64Array([0, 0, 0]), 3).length, 19)
+  t.throws(() => ShapeGen.curve(new Float64Array([0, 0, 0]), 1e7))
+  t.throws(() => ShapeGen.helix([0, 0, 0], 3, 10, 2, null, 1e7))
+  t.throws(() => ShapeGen.curve(new Float64Array([0, 0, 0, 1e12, 0, 0])))
+})
+
+test('raycast cache settings are validated and results expire oldest first', (t) => {
+  const cache = new RaycastCache(2)
+  const solid = () => true
+
+  t.throws(() => { cache.precision = 0 })
+  t.throws(() => { cache.precision = NaN })
+  t.is(cache.precision, 0.25)
+
+  cache.firstBlock([0, 0, 0], [5, 0, 0], solid)
+  cache.advance()
+  cache.firstBlock([0, 3, 0], [5, 3, 0], solid)
+  cache.firstBlock([0, 6, 0], [5, 6, 0], solid)
+  t.is(cache.size(), 2)
+
+  // The first ray was evicted, the second one is still cached
+  cache.firstBlock([0, 3, 0], [5, 3, 0], solid)
+  t.is(cache.hits(), 1)
+  cache.firstBlock([0, 0, 0], [5, 0, 0], solid)
+  t.is(cache.misses(), 4)
+
+  cache.ttl = 0
+  cache.advance()
+  t.is(cache.size(), 0)
+
+  cache.firstBlock([0, 0, 0], [5, 0, 0], solid)
+  cache.precision = 1
+  t.is(cache.size(), 0)
+})
+
+test('raycast caches trace the first solid block and reject lines they cannot trace', (t) => {
+  const cache = new RaycastCache(8, 0.5)
+  const wall = (position) => position.x === 3
+  const block = cache.firstBlock([0.5, 0.5, 0.5], [6.5, 0.5, 0.5], wall)
+  t.deepEqual([block.x, block.y, block.z], [3, 0, 0])
+  t.false(cache.lineOfSight([0.6, 0.6, 0.6], [6.6, 0.6, 0.6], () => false))
+  t.is(cache.hits(), 1)
+  t.true(cache.lineOfSight([0.5, 0.5, 0.5], [2.5, 0.5, 0.5], wall))
+  t.is(cache.firstBlock([0.5, 0.5, 0.5], [2.5, 0.5, 0.5], wall), null)
+
+  t.throws(() => new RaycastCache(8, NaN))
+  t.throws(() => new RaycastCache(8, Infinity))
+  t.throws(() => new RaycastCache(8, 0))
+  t.throws(() => cache.firstBlock([NaN, 0, 0], [1, 0, 0], wall))
+  t.throws(() => cache.lineOfSight([0, 0, 0], [Infinity, 0, 0], wall))
+  t.throws(() => cache.lineOfSight([0, 0, 0], [1e300, 0, 0], wall))
+  t.deepEqual([cache.hits(), cache.misses()], [2, 2])
+})
+
+test('heat grid cell sizes are validated', (t) => {
+  const grid = new HeatGrid()
+  grid.add([20, 0, 20], 1)
+
+  t.throws(() => { grid.cellSize = 0 })
+  t.throws(() => { grid.cellSize = -16 })
+  t.is(grid.sample([17, 0, 17]), 1)
+
+  grid.cellSize = 8
+  t.is(grid.size(), 0)
+  t.is(new HeatGrid(Infinity).cellSize, 16)
+})
+
+test('trail map settings are validated', (t) => {
+  const trail = new TrailMap()
+
+  t.throws(() => { trail.decayFactor = 1.5 })
+  t.throws(() => { trail.decayFactor = NaN })
+  t.throws(() => { trail.threshold = -1 })
+  t.is(trail.decayFactor, 0.95)
+
+  trail.decayFactor = 0.5
+  trail.deposit([0, 0, 0], 1)
+  trail.decay()
+  t.is(trail.sample([0, 0, 0]), 0.5)
+  t.is(new TrailMap(NaN).decayFactor, 0.95)
+})
+
+const randomBoxes = (seed, count, spread) => {
+  const random = new JavaRandom(seed)
+  return Array.from({ length: count }, () => {
+    const min = [random.nextDouble() * spread, random.nextDouble() * spread, random.nextDouble() * spread]
+    return new AABB(min, min.map((value) => value + 0.5 + random.nextDouble() * 2))
+  })
+}
+
+const overlapping = (a, b) => a.min.x < b.max.x && b.min.x < a.max.x && a.min.y < b.max.y && b.min.y < a.max.y && a.min.z < b.max.z && b.min.z < a.max.z
+
+test('the AABB tree answers queries like a brute force scan through inserts, moves and removals', (t) => {
+  const tree = new DynamicAabbTree()
+  const boxes = randomBoxes(1, 60, 20)
+  const ids = boxes.map((box) => tree.insert(box))
+  const live = new Map(ids.map((id, index) => [id, boxes[index]]))
+
+  const check = () => {
+    for (const query of randomBoxes(2, 20, 20)) {
+      const expected = [...live].filter(([, box]) => overlapping(box, query)).map(([id]) => id).sort((a, b) => a - b)
+      t.deepEqual(tree.query(query).sort((a, b) => a - b), expected)
+    }
+    t.is(tree.size(), live.size)
+  }
+
+  check()
+
+  // A move within the fat box keeps the leaf in place, a long move reinserts it
+  t.false(tree.update(ids[0], new AABB(boxes[0].min.add([0.05, 0, 0]), boxes[0].max.add([0.05, 0, 0]))))
+  live.set(ids[0], tree.get(ids[0]))
+  t.true(tree.update(ids[1], new AABB([40, 40, 40], [41, 41, 41])))
+  live.set(ids[1], tree.get(ids[1]))
+  check()
+
+  for (const id of ids.slice(10, 30)) {
+    t.true(tree.remove(id))
+    live.delete(id)
+  }
+  t.false(tree.remove(ids[10]))
+  t.is(tree.get(ids[10]), null)
+  check()
+
+  const pairs = tree.overlappingPairs().map(({ a, b }) => [a, b])
+  const expected = []
+  for (const [a, first] of live) for (const [b, second] of live) if (a < b && overlapping(first, second)) expected.push([a, b])
+  t.deepEqual(pairs, expected.sort((x, y) => x[0] - y[0] || x[1] - y[1]))
+})
+
+test('the AABB tree margin is validated', (t) => {
+  const tree = new DynamicAabbTree()
+
+  t.throws(() => { tree.margin = -0.5 })
+  t.throws(() => { tree.margin = Infinity })
+  tree.margin = 1
+  t.is(tree.margin, 1)
+  t.throws(() => new DynamicAabbTree(-0.5))
+  t.throws(() => new DynamicAabbTree(NaN))
+  t.throws(() => new DynamicAabbTree(Infinity))
+  t.is(new DynamicAabbTree(0).margin, 0)
+  t.is(new DynamicAabbTree().margin, 0.1)
+})
+
+test('the AABB tree rejects NaN boxes and ignores non-finite displacements', (t) => {
+  const tree = new DynamicAabbTree()
+  const id = tree.insert(new AABB([0, 0, 0], [1, 1, 1]))
+  t.throws(() => tree.insert(new AABB([NaN, 0, 0], [1, 1, 1])))
+  t.throws(() => tree.update(id, new AABB([0, 0, 0], [1, NaN, 1])))
+  t.is(tree.size(), 1)
+
+  t.true(tree.update(id, new AABB([10, 0, 0], [11, 1, 1]), [NaN, Infinity, 0]))
+  t.deepEqual(tree.query(new AABB([10.5, 0.5, 0.5], [12, 2, 2])), [id])
+  t.deepEqual(tree.query(new AABB([0, 0, 0], [1, 1, 1])), [])
+  const far = tree.insert(new AABB([-Infinity, 5, 5], [Infinity, 6, 6]))
+  t.deepEqual(tree.query(new AABB([1e300, 5.5, 5.5], [1e300, 5.5, 5.5])), [far])
+})
+
+test('sweep and prune reports the same pairs as a brute force scan', (t) => {
+  const pack = (boxes) => new Float64Array(boxes.flatMap((box) => [box.min.x, box.min.y, box.min.z, box.max.x, box.max.y, box.max.z]))
+  const bruteForce = (boxes) => {
+    const pairs = []
+    boxes.forEach((a, i) => boxes.forEach((b, j) => { if (i < j && overlapping(a, b)) pairs.push({ a: i, b: j }) }))
+    return pairs
+  }
+
+  const broadPhase = new SweepAndPrune()
+  let boxes = randomBoxes(3, 200, 30)
+  t.deepEqual(broadPhase.update(pack(boxes)), bruteForce(boxes))
+
+  // Small moves reuse the sorted endpoints
+  boxes = boxes.map((box, index) => new AABB(box.min.add([index % 3 * 0.2, 0, 0]), box.max.add([index % 3 * 0.2, 0, 0])))
+  t.deepEqual(broadPhase.update(pack(boxes)), bruteForce(boxes))
+
+  broadPhase.axis = Axis.Z
+  t.deepEqual(broadPhase.update(pack(boxes)), bruteForce(boxes))
+
+  boxes = randomBoxes(4, 50, 10)
+  t.deepEqual(broadPhase.update(pack(boxes)), bruteForce(boxes))
+  t.is(broadPhase.size(), 50)
+
+  t.deepEqual(broadPhase.update(new Float64Array([0, 0, 0, 1, 1, 1, 1, 0, 0, 2, 1, 1])), [])
+  t.throws(() => broadPhase.update(new Float64Array(7)))
+})
+
+test('path finding rejects negative influence weights', (t) => {
+  const walkable = (position) => position.y == 0
+  const heat = new HeatGrid(1)
+  heat.add([1, 0, 0], 5)
+
+  t.is(Pathfinder.find([0, 0, 0], [2, 0, 0], walkable, heat).length, 5)
+  t.is(Pathfinder.find([0, 0, 0], [2, 0, 0], walkable, heat, { influenceWeight: 0 }).length, 3)
+  t.throws(() => Pathfinder.find([0, 0, 0], [2, 0, 0], walkable, heat, { influenceWeight: -1 }))
+})
+
+test('there is no launch velocity towards the launch position', (t) => {
+  t.is(LineOfFire.launchVelocity([1, 64, 1], [1, 64, 1], 3), null)
+  t.is(LineOfFire.launchVelocity([1, 64, 1], [1, 70, 1], 3).y, 3)
+  t.assert(LineOfFire.launchVelocity([0, 64, 0], [10, 64, 0], 3).x > 0)
+})
+
+test('rooms see each other only through lines passing every portal between them', (t) => {
+  const rooms = [0, 1, 2, 3].map((i) => new AABB([i * 10, 0, 0], [i * 10 + 10, 10, 10]))
+  const doorway = (x, z) => new AABB([x, 0, z], [x, 2, z + 1])
+
+  const aligned = PotentiallyVisibleSet.compute(rooms, [doorway(10, 8), doorway(20, 8), doorway(30, 8)])
+  t.deepEqual(aligned.visibleFrom(0), [0, 1, 2, 3])
+
+  const offset = PotentiallyVisibleSet.compute(rooms, [doorway(10, 8), doorway(20, 0), doorway(30, 8)])
+  t.deepEqual(offset.visibleFrom(0), [0, 1, 2])
+  t.deepEqual(offset.visibleFrom(1), [0, 1, 2, 3])
+  t.false(offset.isVisible(0, 3))
+  t.false(offset.isVisible(0, 9))
+
+  const limited = PotentiallyVisibleSet.compute(rooms, [doorway(10, 8), doorway(20, 8), doorway(30, 8)], { maxDepth: 2 })
+  t.deepEqual(limited.visibleFrom(0), [0, 1, 2])
+})
+
+test('a dense grid of rooms sees its neighbours', (t) => {
+  const size = 6
+  const rooms = []
+  const portals = []
+
+  for (let x = 0; x < size; x++) {
+    for (let z = 0; z < size; z++) {
+      rooms.push(new AABB([x * 10, 0, z * 10], [x * 10 + 10, 10, z * 10 + 10]))
+      if (x + 1 < size) portals.push(new AABB([x * 10 + 10, 0, z * 10 + 1], [x * 10 + 10, 3, z * 10 + 9]))
+      if (z + 1 < size) portals.push(new AABB([x * 10 + 1, 0, z * 10 + 10], [x * 10 + 9, 3, z * 10 + 10]))
+    }
+  }
+
+  const pvs = PotentiallyVisibleSet.compute(rooms, portals, { samples: 2, maxDepth: 10 })
+  t.is(pvs.size(), size * size)
+  t.true(pvs.isVisible(0, 1))
+  t.true(pvs.isVisible(0, size))
+})
+
+test('rotations approach their target by at most a step', (t) => {
+  t.is(Rotation.approach(0, 10, 4), 4)
+  t.is(Rotation.approach(0, 10, -4), 4)
+  t.is(Rotation.approach(170, -170, 15), 185)
+  t.is(Rotation.approach(0, 10, 20), 10)
+  t.is(Rotation.approach(0, 10, NaN), 0)
+})
+
+test('weather strengths move at the default rate unless given a finite one', (t) => {
+  t.is(Weather.step(0, 1, 0.25), 0.25)
+  t.is(Weather.step(0, 1), 0.01)
+  t.is(Weather.step(0, 1, NaN), 0.01)
+  t.is(Weather.step(0, 1, Infinity), 0.01)
+  t.is(Weather.transitionTicks(0, 1, NaN), 100)
+  t.is(Weather.transitionTicks(0, 1, 0), 0)
+})
+
+test('portal destinations are scaled inside a valid world border', (t) => {
+  const nether = Portal.scale([100, 64, -100], Dimension.Overworld, Dimension.Nether)
+  t.deepEqual([nether.x, nether.y, nether.z], [12, 64, -13])
+
+  const clamped = Portal.scale([100, 64, -100], Dimension.Nether, Dimension.Overworld, 10)
+  t.deepEqual([clamped.x, clamped.z], [9, -10])
+  t.is(Portal.scale([0.5, 0, 0.5], Dimension.Overworld, Dimension.Nether, 1).x, 0)
+
+  t.throws(() => Portal.scale([8, 64, 8], Dimension.Overworld, Dimension.Nether, 0))
+  t.throws(() => Portal.scale([8, 64, 8], Dimension.Overworld, Dimension.Nether, -5))
+  t.throws(() => Portal.scale([8, 64, 8], Dimension.Overworld, Dimension.Nether, NaN))
+})
+
+test('structure selections are limited by a valid size limit', (t) => {
+  t.true(StructureBounds.fits([64, 384, 64]))
+  t.false(StructureBounds.fits([65, 1, 1]))
+
+  const region = StructureBounds.clamp({ origin: new Vector3f(1, 2, 3), size: new Vector3f(100, 0, 10) }, [32, 32, 32])
+  t.deepEqual([region.size.x, region.size.y, region.size.z], [32, 1, 10])
+  t.is(region.origin.z, 3)
+
+  const selection = StructureBounds.validate([0, 0, 0], [-3, 5, 1])
+  t.deepEqual([selection.origin.x, selection.size.x, selection.size.y, selection.size.z], [-3, 4, 6, 2])
+  t.throws(() => StructureBounds.validate([0, 0, 0], [64, 0, 0]))
+
+  for (const limit of [[0, 10, 10], [10, 0.5, 10], [10, 10, NaN]]) {
+    t.throws(() => StructureBounds.clamp({ origin: new Vector3f(0, 0, 0), size: new Vector3f(2, 2, 2) }, limit))
+    t.throws(() => StructureBounds.fits([1, 1, 1], limit))
+    t.throws(() => StructureBounds.validate([0, 0, 0], [0, 0, 0], limit))
+  }
+})
+
+test('splines are followed at constant speed and reject unusable input', (t) => {
+  const spline = new Spline(new Float64Array([0, 0, 0, 10, 0, 0]))
+  t.assert(Math.abs(spline.totalLength() - 10) < 1e-6)
+  t.assert(Math.abs(spline.tAtDistance(5) - 0.5) < 1e-6)
+  t.is(spline.tAtDistance(-1), 0)
+  t.is(spline.tAtDistance(20), 1)
+
+  const samples = spline.sampleEvenly(3)
+  t.is(samples.length, 9)
+  t.assert(Math.abs(samples[3] - 5) < 1e-6)
+
+  t.throws(() => new Spline(new Float64Array([0, 0, 0, Infinity, 0, 0])))
+  t.throws(() => new Spline(new Float64Array([0, 0, 0, NaN, 0, 0])))
+  t.throws(() => new Spline(new Float64Array([0, 0, 0])))
+  t.throws(() => spline.sampleEvenly(2 ** 32 - 1))
+})
+
+test('noise worms are reproducible and reject unusable options', (t) => {
+  const worm = NoiseWorm.generate(42, [0, 64, 0], { length: 16 })
+  t.is(worm.points.length, 48)
+  t.is(worm.radii.length, 16)
+  t.deepEqual([...worm.points.slice(0, 3)], [0, 64, 0])
+  t.deepEqual([...NoiseWorm.generate(42, [0, 64, 0], { length: 16 }).points], [...worm.points])
+
+  const flat = NoiseWorm.generate(42, [0, 64, 0], { length: 16, maxPitch: 0 })
+  t.true([...flat.points].every((value, i) => i % 3 != 1 || value == 64))
+
+  const fallback = NoiseWorm.generate(42, [0, 64, 0], { length: 16, maxPitch: NaN })
+  t.deepEqual([...fallback.points], [...worm.points])
+
+  t.throws(() => NoiseWorm.generate(42, [0, 64, 0], { length: 2 ** 32 - 1 }))
+})
+
+test('beams hit boxes and tolerate inverted or NaN bounds', (t) => {
+  const hit = Beam.intercept(new AABB([0, 0, 0], [1, 1, 1]), [-5, 0.5, 0.5], [5, 0.5, 0.5], 0.5)
+  t.assert(Math.abs(hit.distance - 4.5) < 1e-9)
+  t.assert(Math.abs(hit.position.x + 0.5) < 1e-9)
+  t.is(hit.normal.x, -1)
+
+  t.notThrows(() => Beam.intercept(new AABB([1, 1, 1], [0, 0, 0]), [-5, 0.5, 0.5], [5, 0.5, 0.5], 0.5))
+  t.notThrows(() => Beam.intercept(new AABB([0, NaN, 0], [1, 1, NaN]), [-5, 0.5, 0.5], [5, 0.5, 0.5], 0.5))
+  t.notThrows(() => Beam.pierce([-5, 0.5, 0.5], [5, 0.5, 0.5], 0.5, [new AABB([1, 1, 1], [0, 0, 0])]))
+})
+
+test('spheres overlap boxes and tolerate inverted or NaN bounds', (t) => {
+  const sphere = new Sphere([0, 0, 0], 1)
+  t.true(sphere.intersectsAabb(new AABB([0.5, 0.5, 0.5], [2, 2, 2])))
+  t.false(sphere.intersectsAabb(new AABB([0.8, 0.8, 0.8], [2, 2, 2])))
+
+  t.notThrows(() => sphere.intersectsAabb(new AABB([2, 2, 2], [0.5, 0.5, 0.5])))
+  t.notThrows(() => sphere.intersectsAabb(new AABB([NaN, 0, 0], [1, NaN, 1])))
+})
+
+test('tick schedules spread tasks across the period', (t) => {
+  const golden = TickSchedule.goldenRatioOffsets(20, 20)
+  t.is(golden.length, 20)
+  t.true(golden.every((offset) => offset < 20))
+  t.deepEqual(TickSchedule.goldenRatioOffsets(3, 0), [0, 0, 0])
+  t.deepEqual(TickSchedule.goldenRatioOffsets(5, 20, 2 ** 32 - 3).length, 5)
+
+  t.deepEqual(TickSchedule.stratifiedOffsets(4, 20), [2, 7, 12, 17])
+  t.deepEqual(TickSchedule.stratifiedOffsets(2, 0), [0, 0])
+  t.deepEqual(TickSchedule.stratifiedOffsets(4, 20, 7), TickSchedule.stratifiedOffsets(4, 20, 7))
+
+  t.deepEqual(TickSchedule.load(TickSchedule.stratifiedOffsets(40, 20), 20), new Array(20).fill(2))
+  t.deepEqual(TickSchedule.load([1, 21, 3], 20).slice(0, 4), [0, 2, 0, 1])
+  t.deepEqual(TickSchedule.load([1, 2], 0), [])
+})
+
+test('visible sets with too many rooms for the room to room matrix are rejected', (t) => {
+  const room = new AABB([0, 0, 0], [1, 1, 1])
+  t.throws(() => PotentiallyVisibleSet.compute(new Array(65536).fill(room), []))
+  t.is(PotentiallyVisibleSet.compute([room], []).size(), 1)
+})
+
+test('a throwing scope releases its vectors and does not capture later ones', (t) => {
+  const pool = new VectorPool()
+  t.throws(() => pool.scoped(() => {
+    pool.acquire()
+    throw new Error('failed')
+  }))
+  t.is(pool.size(), 1)
+
+  const outside = pool.acquire()
+  pool.scoped(() => undefined)
+  t.is(pool.size(), 0)
+  pool.release(outside)
+  t.is(pool.size(), 1)
+})
+
+test('hunger drains saturation before food and starves by difficulty', (t) => {
+  const hunger = new Hunger(20, 1)
+  hunger.exhaust(HungerAction.Regenerate)
+  t.is(hunger.exhaustion, 6)
+  hunger.tick(20, 20)
+  t.is(hunger.saturation, 0)
+  t.is(hunger.food, 20)
+
+  hunger.addExhaustion(4)
+  hunger.tick(20, 20, Difficulty.Peaceful)
+  t.is(hunger.food, 20)
+  t.is(hunger.exhaustion, 2)
+  hunger.addExhaustion(4)
+  hunger.tick(20, 20, Difficulty.Easy)
+  t.is(hunger.food, 19)
+
+  const starve = (health, difficulty) => {
+    const starving = new Hunger(0, 0)
+    let damage = 0
+    for (let i = 0; i < 80; i++) damage += starving.tick(health, 20, difficulty).damage
+    return damage
+  }
+
+  t.is(starve(10, Difficulty.Easy), 0)
+  t.is(starve(11, Difficulty.Easy), 1)
+  t.is(starve(1, Difficulty.Normal), 0)
+  t.is(starve(1, Difficulty.Hard), 1)
+  t.is(starve(20, Difficulty.Peaceful), 0)
+})
+
+test('layouts anchor elements, split 9-slices and fit content', (t) => {
+  const parent = new Rect(0, 0, 200, 100)
+  const centered = Layout.anchor(parent, new Vector2f(20, 10), new Vector2f(0.5, 0.5), new Vector2f(0.5, 0.5))
+  t.deepEqual([centered.x, centered.y, centered.width, centered.height], [90, 45, 20, 10])
+
+  const corner = Layout.anchor(parent, new Vector2f(20, 10), new Vector2f(1, 1), new Vector2f(1, 1), new Vector2f(-5, -5))
+  t.deepEqual([corner.x, corner.y], [175, 85])
+
+  const slices = Layout.nineSlice(new Rect(0, 0, 30, 30), 5, 5, 5, 5)
+  t.is(slices.length, 9)
+  t.deepEqual([slices[4].x, slices[4].y, slices[4].width, slices[4].height], [5, 5, 20, 20])
+
+  const scaled = Layout.nineSlice(new Rect(0, 0, 10, 10), 10, 0, 10, NaN)
+  t.deepEqual([slices[8].x, scaled[0].width, scaled[1].width, scaled[2].width, scaled[6].height], [25, 5, 0, 5, 0])
+
+  const contained = Layout.fit(new Vector2f(2, 1), parent, FitMode.Contain)
+  t.deepEqual([contained.x, contained.y, contained.width, contained.height], [0, 0, 200, 100])
+  const covered = Layout.fit(new Vector2f(1, 1), parent, FitMode.Cover)
+  t.deepEqual([covered.x, covered.y, covered.width, covered.height], [0, -50, 200, 200])
+  const stretched = Layout.fit(new Vector2f(1, 1), parent, FitMode.Stretch)
+  t.deepEqual([stretched.width, stretched.height], [200, 100])
+  t.is(Layout.fit(new Vector2f(0, 1), parent, FitMode.Contain).width, 0)
+})
+
+test('volumes rotate clockwise from above and mirror along an axis', (t) => {
+  const data = new Uint32Array([1, 2, 3, 4, 5, 6])
+  const size = [2, 1, 3]
+
+  const east = VolumeTransform.rotate90(new Uint32Array([1, 2]), [2, 1, 1], 1)
+  t.deepEqual([east.size.x, east.size.y, east.size.z], [1, 1, 2])
+  t.deepEqual([...east.data], [1, 2])
+
+  const quarter = VolumeTransform.rotate90(data, size, 1)
+  t.deepEqual([quarter.size.x, quarter.size.z], [3, 2])
+  t.deepEqual([...VolumeTransform.rotate90(quarter.data, quarter.size, -1).data], [...data])
+  t.deepEqual([...VolumeTransform.rotate90(data, size, 4).data], [...data])
+  t.deepEqual([...VolumeTransform.rotate90(data, size, 2).data], [6, 5, 4, 3, 2, 1])
+
+  t.deepEqual([...VolumeTransform.mirror(data, size, Axis.X).data], [4, 5, 6, 1, 2, 3])
+  t.deepEqual([...VolumeTransform.mirror(data, size, Axis.Z).data], [3, 2, 1, 6, 5, 4])
+  t.deepEqual([...VolumeTransform.mirror(data, size, Axis.Y).data], [...data])
+
+  t.throws(() => VolumeTransform.rotate90(data, [2, 2, 3], 1))
+  t.throws(() => VolumeTransform.mirror(data, [-2, 1, -3], Axis.X))
+  t.is(VolumeTransform.index(size, [1, 0, 2]), 5)
+  t.is(VolumeTransform.index(size, [2, 0, 0]), null)
+})
+
+test('volumes scale up by repeating blocks and down by the most common block', (t) => {
+  const up = VolumeTransform.upscale(new Uint32Array([1, 2]), [2, 1, 1], 2)
+  t.deepEqual([up.size.x, up.size.y, up.size.z], [4, 2, 2])
+  t.deepEqual([...up.data], [1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2])
+
+  const down = VolumeTransform.downsample(up.data, up.size, 2)
+  t.deepEqual([down.size.x, down.size.y, down.size.z], [2, 1, 1])
+  t.deepEqual([...down.data], [1, 2])
+
+  const mixed = new Uint32Array([0, 0, 0, 7, 5, 5])
+  t.deepEqual([...VolumeTransform.downsample(mixed, [6, 1, 1], 6).data], [0])
+  t.deepEqual([...VolumeTransform.downsample(mixed, [6, 1, 1], 6, 0).data], [5])
+  t.deepEqual([...VolumeTransform.downsample(new Uint32Array([0, 0]), [2, 1, 1], 2, 0).data], [0])
+
+  const partial = VolumeTransform.downsample(new Uint32Array([1, 1, 3]), [3, 1, 1], 2)
+  t.deepEqual([partial.size.x, ...partial.data], [2, 1, 3])
+
+  t.throws(() => VolumeTransform.upscale(new Uint32Array([1]), [1, 1, 1], 0))
+  t.throws(() => VolumeTransform.downsample(new Uint32Array([1]), [1, 1, 1], 0))
+  t.throws(() => VolumeTransform.upscale(new Uint32Array([1]), [1, 1, 1], 2 ** 16))
+})
+
+test('movement input converts between facing relative and world space', (t) => {
+  const south = Movement.compose({ forward: 1, strafe: 0 }, 0)
+  t.deepEqual([south.x, south.y, south.z], [0, 0, 1])
+  const left = Movement.compose({ forward: 0, strafe: 1 }, 0)
+  t.deepEqual([left.x, left.z], [1, 0])
+
+  const west = Movement.compose({ forward: 1, strafe: 0 }, 90)
+  t.true(near(west.x, -1) && near(west.z, 0))
+
+  const input = Movement.decompose([0.3, 5, -0.7], 37)
+  const back = Movement.compose(input, 37)
+  t.true(near(back.x, 0.3) && near(back.y, 0) && near(back.z, -0.7))
+
+  const diagonal = Movement.relative({ forward: 1, strafe: 1 }, 0, 0.1)
+  t.true(near(Math.hypot(diagonal.x, diagonal.z), 0.1))
+  const slow = Movement.relative({ forward: 0.5, strafe: 0 }, 0, 0.1)
+  t.true(near(slow.z, 0.05))
+  t.is(Movement.relative({ forward: 0, strafe: 0 }, 0, 0.1).z, 0)
+
+  t.is(Movement.yawDifference(170, -170), 20)
+  t.is(Movement.yawDifference(-170, 170), -20)
+  t.is(Movement.yawDifference(0, 180), -180)
+})
+
+test('rail paths run between the exits of a rail shape', (t) => {
+  const exits = RailPath.exits(RailShape.AscendingEast)
+  t.deepEqual(exits.map((exit) => [exit.x, exit.y, exit.z]), [[-1, -1, 0], [1, 0, 0]])
+
+  const middle = RailPath.evaluate([10.7, 64, -3.2], RailShape.NorthSouth, 0.5)
+  t.deepEqual([middle.position.x, middle.position.y, middle.position.z], [10.5, 64, -3.5])
+  t.deepEqual([middle.direction.x, middle.direction.z], [0, 1])
+
+  const ramp = RailPath.evaluate([0, 0, 0], RailShape.AscendingEast, 1)
+  t.deepEqual([ramp.position.x, ramp.position.y], [1, 1])
+  t.is(RailPath.evaluate([0, 0, 0], RailShape.AscendingEast, 2).position.x, 1)
+
+  const arc = RailPath.evaluate([0, 0, 0], RailShape.SouthEast, 0.5, true)
+  t.true(near(Math.hypot(arc.position.x - 1, arc.position.z - 1), 0.5))
+  t.true(near(Math.hypot(arc.direction.x, arc.direction.z), 1))
+
+  t.true(near(RailPath.length(RailShape.NorthSouth), 1))
+  t.true(near(RailPath.length(RailShape.SouthEast), Math.SQRT1_2))
+  t.true(near(RailPath.length(RailShape.SouthEast, true), Math.PI / 4))
+
+  t.is(RailPath.project([0, 0, 0], RailShape.EastWest, [0.25, 5, 0.9]), 0.25)
+  t.is(RailPath.project([0, 0, 0], RailShape.EastWest, [3, 0, 0]), 1)
+  t.true(RailPath.isAscending(RailShape.AscendingNorth))
+  t.false(RailPath.isCurved(RailShape.EastWest))
+})
+
+test('fall and impact damage follow the vanilla formulas', (t) => {
+  t.is(Damage.fall(10), 7)
+  t.is(Damage.fall(3.5), 1)
+  t.is(Damage.fall(2), 0)
+  t.is(Damage.fall(5, { jumpBoost: 2 }), 0)
+  t.is(Damage.fall(10, { multiplier: 0.5 }), 4)
+  t.is(Damage.fall(10, { multiplier: 0 }), 0)
+  t.is(Damage.fall(100, { slowFalling: true }), 0)
+  t.is(Damage.fall(10, { safeDistance: 0 }), 10)
+
+  t.is(Damage.fallDistanceFromVelocity(0.5), 0)
+  t.true(near(Damage.fallDistanceFromVelocity(-0.0784), 0.0784))
+  t.true(near(Damage.fallDistanceFromVelocity(-Math.fround(0.1552), false), 0.0784 + 0.1552, 1e-6))
+  t.true(Number.isFinite(Damage.fallDistanceFromVelocity(-Infinity)))
+  t.is(Damage.fallDistanceFromVelocity(NaN), 0)
+  t.true(Damage.fallDistanceFromVelocity(-0.5, true) > Damage.fallDistanceFromVelocity(-0.4))
+
+  t.is(Damage.impact(-0.0784), 0)
+  t.is(Damage.impact(-3, { slowFalling: true }), 0)
+  t.true(Damage.impact(-3) > 20)
+
+  t.is(Damage.kinetic(1, 0.5), 2)
+  t.is(Damage.kinetic(0.2, 0), 0)
+})
+
+test('attribute modifiers stack in vanilla order and damage reductions are capped', (t) => {
+  const modifiers = [
+    { amount: 0.5, operation: ModifierOperation.MultiplyTotal },
+    { amount: 2, operation: ModifierOperation.Addition },
+    { amount: 0.5, operation: ModifierOperation.MultiplyBase },
+    { amount: 0.5, operation: ModifierOperation.MultiplyBase }
+  ]
+  t.is(AttributeMath.apply(2, modifiers), 12)
+  t.is(AttributeMath.apply(2, [...modifiers, { amount: 10, operation: ModifierOperation.Cap }]), 10)
+  t.is(AttributeMath.apply(2, modifiers, 0, 8), 8)
+  t.is(AttributeMath.apply(2, [{ amount: -5, operation: ModifierOperation.Addition }], 0), 0)
+
+  t.is(AttributeMath.protectionFactor(4, 2, 1), 11)
+  t.is(AttributeMath.protectionFactor(2 ** 32 - 1, 2 ** 32 - 1), 2 ** 32 - 1)
+  t.is(AttributeMath.enchantmentReduction(10, 5), 8)
+  t.true(near(AttributeMath.enchantmentReduction(10, 100), 2))
+  t.is(AttributeMath.enchantmentReduction(10, -5), 10)
+
+  t.is(AttributeMath.armorReduction(10, 0), 10)
+  t.true(near(AttributeMath.armorReduction(10, 20, 8), 3))
+  t.true(near(AttributeMath.armorReduction(100, 20), 84))
+  t.is(AttributeMath.resistanceReduction(10, 2), 6)
+  t.is(AttributeMath.resistanceReduction(10, 7), 0)
+})
+
+test('experience levels follow the vanilla curve', (t) => {
+  t.deepEqual([0, 15, 16, 30, 31].map((level) => Experience.xpForNextLevel(level)), [7, 37, 42, 112, 121])
+  t.deepEqual([0, 16, 17, 30, 32].map((level) => Experience.levelToTotalXp(level)), [0, 352, 394, 1395, 1628])
+  t.is(Experience.levelToTotalXp(2 ** 32 - 1), 2 ** 32 - 1)
+
+  for (const level of [0, 1, 16, 17, 31, 32, 100, 1000]) {
+    const total = Experience.levelToTotalXp(level)
+    t.deepEqual(Experience.xpToLevel(total), { level, progress: 0, remaining: Experience.xpForNextLevel(level) })
+    t.is(Experience.xpToLevel(total + 1).level, level)
+    if (level > 0) t.is(Experience.xpToLevel(total - 1).level, level - 1)
+  }
+
+  const halfway = Experience.xpToLevel(352 + 21)
+  t.deepEqual(halfway, { level: 16, progress: 0.5, remaining: 21 })
+  t.true(Experience.xpToLevel(2 ** 32 - 1).level > 30000)
+
+  t.deepEqual(Experience.splitOrbs(10), [7, 3])
+  t.deepEqual(Experience.splitOrbs(0), [])
+  t.is(Experience.splitOrbs(5000).reduce((sum, orb) => sum + orb, 0), 5000)
+  t.is(Experience.orbSize(2500), 2477)
+})
+
+test('effect levels scale like vanilla', (t) => {
+  t.is(EffectMath.speedMultiplier(2), 1.4)
+  t.true(near(EffectMath.speedMultiplier(0, 2), 0.7))
+  t.is(EffectMath.speedMultiplier(0, 10), 0)
+
+  t.is(EffectMath.jumpVelocity(0), 0.42)
+  t.true(near(EffectMath.jumpHeight(0), 1.2522, 1e-4))
+  t.true(EffectMath.jumpHeight(2) > EffectMath.jumpHeight(1))
+  t.true(Number.isFinite(EffectMath.jumpHeight(2 ** 32 - 1)))
+  t.is(EffectMath.gravity(true), 0.01)
+
+  t.true(near(EffectMath.levitationVelocity(0, 1), 0.01))
+  t.true(near(EffectMath.levitationVelocity(0.05, 1), 0.05))
+  t.is(EffectMath.bonusHealth(2), 8)
+  t.is(EffectMath.attackBonus(2, 1), 2)
+
+  t.deepEqual([1, 2, 3].map((level) => EffectMath.regenerationInterval(level)), [50, 25, 12])
+  t.deepEqual([0, 1, 2].map((level) => EffectMath.poisonInterval(level)), [25, 25, 12])
+  t.is(EffectMath.witherInterval(2 ** 32 - 1), 1)
+})
+
+test('random tick and growth chances follow the vanilla odds', (t) => {
+  t.true(near(GrowthMath.randomTickChance(3), 1 - (4095 / 4096) ** 3))
+  t.is(GrowthMath.randomTickChance(0), 0)
+  t.is(GrowthMath.randomTickChance(1, 0), 1)
+  const saturated = GrowthMath.randomTickChance(2 ** 32 - 1)
+  t.true(saturated >= 0 && saturated <= 1)
+
+  t.is(GrowthMath.cropGrowthChance(10), 1 / 3)
+  t.is(GrowthMath.cropGrowthChance(1), 1 / 26)
+  t.is(GrowthMath.cropGrowthChance(0), 0)
+
+  t.true(near(GrowthMath.expectedTicks(1), 4096, 1e-6))
+  t.is(GrowthMath.expectedTicks(0), Infinity)
+  t.true(near(GrowthMath.expectedTicksToMature(7, 1, 10), 7 * 4096 * 3, 1e-6))
+
+  t.true(near(GrowthMath.probabilityWithin(0.5, 2), 0.75))
+  t.is(GrowthMath.probabilityWithin(2, 1), 1)
+  t.true(near(GrowthMath.ticksForProbability(0.5, 0.75), 2))
+  t.is(GrowthMath.ticksForProbability(0, 0.5), Infinity)
+  t.is(GrowthMath.ticksForProbability(1, 0.5), 1)
+
+  t.is(GrowthMath.feedBaby(-24000), -21600)
+  t.is(GrowthMath.feedBaby(-10), -10)
+  t.is(GrowthMath.feedBaby(100), 100)
+  t.true(GrowthMath.feedBaby(-(2 ** 31)) > -(2 ** 31))
+})
+
+test('local difficulty and mob caps follow the vanilla formulas', (t) => {
+  t.deepEqual(DifficultyMath.local(Difficulty.Normal, 0, 0, 0), { effective: 1.5, clamped: 0 })
+  t.deepEqual(DifficultyMath.local(Difficulty.Easy, 0, 0, 0), { effective: 0.75, clamped: 0 })
+  t.deepEqual(DifficultyMath.local(Difficulty.Peaceful, 1e9, 1e9, 0), { effective: 0, clamped: 0 })
+  t.deepEqual(DifficultyMath.local(Difficulty.Hard, 1e9, 1e9, 0), { effective: 6.75, clamped: 1 })
+  t.true(DifficultyMath.local(Difficulty.Hard, 1e9, 1e9, 4).effective < 6.75)
+  t.is(DifficultyMath.local(Difficulty.Hard, -1e9, -1e9, 0).effective, 2.25)
+
+  t.is(DifficultyMath.clamp(3), 0.5)
+  t.is(DifficultyMath.clamp(1), 0)
+  t.is(DifficultyMath.clamp(5), 1)
+
+  t.is(DifficultyMath.mobCap(70, 289), 70)
+  t.is(DifficultyMath.mobCap(70, 0), 0)
+  t.true(DifficultyMath.canSpawn(69, 70, 289))
+  t.false(DifficultyMath.canSpawn(70, 70, 289))
+})
+
+test('the day cycle follows the vanilla celestial angle', (t) => {
+  t.is(Celestial.angle(6000), 0)
+  t.is(Celestial.angle(18000), 0.5)
+  t.is(Celestial.angle(30000), 0)
+  t.true(near(Celestial.angle(-6000), 0.5))
+
+  t.is(Celestial.moonPhase(9), 1)
+  t.is(Celestial.moonPhase(-1), 7)
+  t.is(Celestial.moonPhase(24000 * 3 + 100, true), 3)
+  t.is(Celestial.moonPhase(-1, true), 7)
+  t.deepEqual([0, 4, 6, 12].map((phase) => Celestial.moonBrightness(phase)), [1, 0, 0.5, 0])
+
+  t.is(Celestial.skyDarkness(0), 0)
+  t.is(Celestial.skyDarkness(0.5), 11)
+  t.is(Celestial.skyDarkness(0, 1, 1), 5)
+  t.true(Celestial.isDay(6000))
+  t.false(Celestial.isDay(18000))
+
+  t.false(Celestial.canSleep(6000))
+  t.true(Celestial.canSleep(12542))
+  t.true(Celestial.canSleep(-6000))
+  t.true(Celestial.canSleep(6000, true))
+})
+
+test('sounds fall off linearly and are placed in the listener space', (t) => {
+  t.is(Audio.range(1), 16)
+  t.is(Audio.range(0.5), 16)
+  t.is(Audio.range(4), 64)
+
+  t.is(Audio.volumeAt([0, 0, 0], [8, 0, 0], 1), 0.5)
+  t.is(Audio.volumeAt([0, 0, 0], [32, 0, 0], 4), 0.5)
+  t.is(Audio.volumeAt([0, 0, 0], [20, 0, 0], 1), 0)
+  t.is(Audio.volumeAt([0, 0, 0], [1, 0, 0], 1, 0), 0)
+  t.true(Audio.isAudible([0, 0, 0], [15, 0, 0], 1))
+  t.false(Audio.isAudible([0, 0, 0], [16, 0, 0], 1))
+
+  const ahead = Audio.relativePosition([0, 0, 0], 0, 0, [0, 0, 5])
+  t.true(near(ahead.x, 0) && near(ahead.y, 0) && near(ahead.z, 5))
+  const above = Audio.relativePosition([0, 0, 0], 90, 0, [0, 3, 0])
+  t.true(near(above.y, 3))
+
+  t.true(near(Audio.pan([0, 0, 0], 0, [-5, 0, 0]), 1))
+  t.true(near(Audio.pan([0, 0, 0], 0, [5, 0, 0]), -1))
+  t.true(near(Audio.pan([0, 0, 0], 0, [0, 0, 5]), 0))
+  t.is(Audio.pan([0, 0, 0], 0, [0, 5, 0]), 0)
+})
+
+test('block picking selects the first outline the eye ray hits', (t) => {
+  const ground = (position, liquids) => {
+    if (position.y == 0 && position.x == 2) return liquids ? [new AABB([0, 0, 0], [1, 0.875, 1])] : []
+    return position.y < 0 ? [new AABB([0, 0, 0], [1, 1, 1])] : []
+  }
+
+  const down = Selection.pickBlock([0.5, 1.62, 0.5], new Vector2f(90, 0), 5, false, ground)
+  t.deepEqual([down.block.x, down.block.y, down.block.z], [0, -1, 0])
+  t.is(down.face, BlockFace.Up)
+  t.true(near(down.distance, 1.62))
+
+  const slab = (position) => position.x == 1 && position.y == 0 ? [new AABB([0, 0, 0], [1, 0.5, 1])] : []
+  t.is(Selection.pickBlock([0.5, 0.75, 0.5], new Vector2f(0, -90), 5, false, slab), null)
+  const lower = Selection.pickBlock([0.5, 0.25, 0.5], new Vector2f(0, -90), 5, false, slab)
+  t.deepEqual([lower.block.x, lower.face], [1, BlockFace.West])
+
+  t.is(Selection.pickBlock([2.5, 1.62, 0.5], new Vector2f(90, 0), 1, false, ground), null)
+  t.is(Selection.pickBlock([2.5, 1.62, 0.5], new Vector2f(90, 0), 1, true, ground).block.y, 0)
+  t.is(Selection.pickBlock([0.5, 1.62, 0.5], new Vector2f(90, 0), 1, false, ground), null)
+
+  t.throws(() => Selection.pickBlock([0.5, 1.62, 0.5], new Vector2f(90, 0), Infinity, false, ground))
+  t.throws(() => Selection.pickBlock([0.5, 1.62, 0.5], new Vector2f(90, 0), NaN, false, ground))
+  t.throws(() => Selection.pickBlock([0.5, 1.62, 0.5], new Vector2f(90, 0), -1, false, ground))
+})
+
+test('piercing rays report every box they pass through by distance', (t) => {
+  const boxes = [
+    new AABB([6, 0, 0], [7, 1, 1]),
+    new AABB([2, 0, 0], [3, 1, 1]),
+    new AABB([4, 5, 0], [5, 6, 1]),
+    new AABB([4, 0, 0], [5, 1, 1])
+  ]
+
+  const hits = Raycaster.pierce([0, 0.5, 0.5], [10, 0.5, 0.5], boxes)
+  t.deepEqual(hits.map((hit) => hit.index), [1, 3, 0])
+  t.deepEqual(hits.map((hit) => hit.distance), [2, 4, 6])
+  t.true(hits.every((hit) => hit.face == BlockFace.West))
+
+  t.deepEqual(Raycaster.pierce([0, 0.5, 0.5], [10, 0.5, 0.5], boxes, 2).map((hit) => hit.index), [1, 3])
+  t.deepEqual(Raycaster.pierce([0, 0.5, 0.5], [10, 0.5, 0.5], boxes, 0), [])
+  t.deepEqual(Raycaster.pierce([0, 0.5, 0.5], [3.5, 0.5, 0.5], boxes).map((hit) => hit.index), [1])
+  t.deepEqual(Raycaster.pierce([0, 0.5, 0.5], [10, 0.5, 0.5], []), [])
+})
+
+test('falloff kernels are 1 at the center and 0 at the radius', (t) => {
+  t.is(Falloff.linear([0, 0, 0], 4, [2, 0, 0]), 0.5)
+  t.is(Falloff.quadratic([0, 0, 0], 4, [2, 0, 0]), 0.25)
+  t.is(Falloff.smooth([0, 0, 0], 4, [2, 0, 0]), 0.5)
+  t.is(Falloff.smooth([0, 0, 0], 4, [1, 0, 0]), 0.84375)
+
+  for (const kernel of [Falloff.linear, Falloff.quadratic, Falloff.smooth]) {
+    t.is(kernel([1, 2, 3], 5, [1, 2, 3]), 1)
+    t.is(kernel([1, 2, 3], 5, [1, 9, 3]), 0)
+    t.is(kernel([1, 2, 3], 0, [1, 2, 3]), 0)
+    t.is(kernel([1, 2, 3], NaN, [1, 2, 3]), 0)
+  }
+
+  const scales = Falloff.batch([0, 0, 0], 4, new Float64Array([0, 0, 0, 2, 0, 0, 8, 0, 0]), FalloffKind.Quadratic)
+  t.deepEqual([...scales], [1, 0.25, 0])
+  t.deepEqual([...Falloff.batch([0, 0, 0], 4, new Float64Array([2, 0, 0]))], [0.5])
+})
+
+test('chains jump to the nearest target not yet hit within the radius', (t) => {
+  const targets = new Float64Array([10, 0, 0, 2, 0, 0, 4, 0, 0, 20, 0, 0, 0, 0, 3])
+
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 10, 6), [1, 2, 4])
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 2, 6), [1, 2])
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 10, 10), [1, 2, 4])
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 10, 11), [1, 2, 4, 0, 3])
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 0, 10), [])
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 10, 1), [])
+  t.deepEqual(Chain.jumps([0, 0, 0], targets, 10, NaN), [])
+  t.deepEqual(Chain.jumps([0, 0, 0], new Float64Array(), 10, 10), [])
+})
+
+test('clustering separates distant groups of positions', (t) => {
+  const points = new Float64Array([0, 0, 0, 1, 0, 0, 0, 1, 0, 50, 0, 0, 51, 0, 0, 50, 1, 0, 200, 0, 0])
+
+  const kmeans = Cluster.kmeans(points, 3, undefined, 7)
+  const [a, b, c] = [kmeans.assignments[0], kmeans.assignments[3], kmeans.assignments[6]]
+  t.is(new Set([a, b, c]).size, 3)
+  t.deepEqual(kmeans.assignments, [a, a, a, b, b, b, c])
+  t.is(kmeans.centroids[c * 3], 200)
+  t.deepEqual(Cluster.kmeans(points, 3, undefined, 7), kmeans)
+
+  t.deepEqual(Cluster.kmeans(points, 0).assignments, new Array(7).fill(-1))
+  t.is(Cluster.kmeans(points, 100).centroids.length, 21)
+  t.deepEqual(Cluster.kmeans(new Float64Array(), 3), { assignments: [], centroids: new Float64Array() })
+
+  const dbscan = Cluster.dbscan(points, 2, 3)
+  t.deepEqual(dbscan.assignments, [0, 0, 0, 1, 1, 1, -1])
+  t.true(near(dbscan.centroids[3], 151 / 3))
+  t.deepEqual(Cluster.dbscan(points, 2, 4).assignments, new Array(7).fill(-1))
+  t.deepEqual(Cluster.dbscan(points, 0, 1).assignments, [0, 1, 2, 3, 4, 5, 6])
+  t.deepEqual(Cluster.dbscan(points, NaN, 1).assignments, new Array(7).fill(-1))
+})
+
+test('heat grids accumulate, cool down and rank chunk cells', (t) => {
+  const grid = new HeatGrid()
+  grid.add([1, 64, 1], 2)
+  grid.add([15, 0, 15], 3)
+  grid.add([-1, 0, 40], 4)
+  grid.add([100, 0, 100], 1)
+
+  t.is(grid.size(), 3)
+  t.is(grid.sample([8, 0, 8]), 5)
+  t.is(grid.sample([-16, 0, 32]), 4)
+  t.is(grid.sample([16, 0, 0]), 0)
+  t.deepEqual(grid.maxCell(), { x: 0, z: 0, heat: 5 })
+  t.deepEqual(grid.hottest(2).map((cell) => [cell.x, cell.z]), [[0, 0], [-1, 2]])
+  t.is(grid.hottest(10).length, 3)
+
+  grid.decay(0.5)
+  t.is(grid.sample([0, 0, 0]), 2.5)
+  grid.decay(1e-7)
+  t.is(grid.size(), 0)
+  t.is(grid.maxCell(), null)
+
+  grid.add([0, 0, 0], 1)
+  grid.decay(NaN)
+  t.is(grid.size(), 0)
+})
+
+test('path finding walks around obstacles and gives up on unreachable goals', (t) => {
+  const wall = (position) => position.y == 0 && !(position.x == 1 && position.z > -2 && position.z < 2)
+  const path = Pathfinder.find([0, 0, 0], [2, 0, 0], wall)
+  t.is(path.length, 7)
+  t.deepEqual([path[0].x, path[0].z, path[6].x, path[6].z], [0, 0, 2, 0])
+  t.true(path.every((step) => wall(step)))
+  t.true(path.slice(1).every((step, i) => Math.abs(step.x - path[i].x) + Math.abs(step.y - path[i].y) + Math.abs(step.z - path[i].z) == 1))
+
+  t.deepEqual(Pathfinder.find([0.5, 0.2, 0.9], [0, 0, 0], wall).map((step) => [step.x, step.y, step.z]), [[0, 0, 0]])
+  t.deepEqual(Pathfinder.find([0, 0, 0], [2, 0, 0], (position) => position.x <= 0 && position.y == 0, null, { maxNodes: 50 }), [])
+  t.deepEqual(Pathfinder.find([1e300, 0, 0], [-1e300, 0, 0], () => true, null, { maxNodes: 10 }), [])
+  t.throws(() => Pathfinder.find([0, 0, 0], [2, 0, 0], wall, null, { influenceWeight: NaN }))
+})
+
+test('line of fire finds friendlies and blocks in the way of a shot', (t) => {
+  const velocity = LineOfFire.launchVelocity([0, 0, 0], [10, 0, 0], 1, 0)
+  t.deepEqual([velocity.x, velocity.y, velocity.z], [1, 0, 0])
+  t.is(LineOfFire.check([0, 0.5, 0.5], [10, 0.5, 0.5], velocity, [], () => false, { gravity: 0, drag: 1 }), null)
+
+  const ally = new AABB([4, 0, 0], [5, 1, 1])
+  const entity = LineOfFire.check([0, 0.5, 0.5], [10, 0.5, 0.5], velocity, [new AABB([20, 0, 0], [21, 1, 1]), ally], () => false, { gravity: 0, drag: 1 })
+  t.is(entity.kind, ObstructionKind.Entity)
+  t.is(entity.index, 1)
+  t.is(entity.tick, 3)
+  t.true(near(entity.position.x, 4))
+
+  const wall = (position) => position.x == 6
+  const block = LineOfFire.check([0, 0.5, 0.5], [10, 0.5, 0.5], velocity, [], wall, { gravity: 0, drag: 1 })
+  t.is(block.kind, ObstructionKind.Block)
+  t.deepEqual([block.block.x, block.block.y, block.block.z], [6, 0, 0])
+  t.true(near(block.position.x, 6))
+  t.is(LineOfFire.check([0, 0.5, 0.5], [10, 0.5, 0.5], velocity, [ally], wall, { gravity: 0, drag: 1 }).kind, ObstructionKind.Entity)
+  t.is(LineOfFire.check([0, 0.5, 0.5], [5.5, 0.5, 0.5], velocity, [], wall, { gravity: 0, drag: 1 }), null)
+
+  const arc = LineOfFire.launchVelocity([0, 0, 0], [20, 0, 0], 1.5)
+  t.true(arc.y > 0 && arc.x > 0)
+  t.true(near(Math.hypot(arc.x, arc.y, arc.z), 1.5))
+  t.is(LineOfFire.launchVelocity([0, 0, 0], [200, 0, 0], 1), null)
+  t.is(LineOfFire.launchVelocity([0, 0, 0], [0, 0, 0], 1), null)
+  t.is(LineOfFire.launchVelocity([0, 0, 0], [10, 0, 0], NaN), null)
+  t.is(LineOfFire.launchVelocity([0, 0, 0], [10, 0, 0], 1, NaN), null)
+
+  t.throws(() => LineOfFire.check([0, 0, 0], [10, 0, 0], [Infinity, 0, 0], [], () => false))
+  t.throws(() => LineOfFire.check([0, 0, 0], [10, 0, 0], [NaN, 0, 0], [], () => false))
+  t.throws(() => LineOfFire.check([0, 0, 0], [10, 0, 0], [1, 0, 0], [], () => false, { gravity: Infinity }))
+  t.throws(() => LineOfFire.check([0, 0, 0], [10, 0, 0], [1, 0, 0], [], () => false, { drag: 2 }))
+  t.throws(() => LineOfFire.check([0, 0, 0], [10, 0, 0], [1, 0, 0], [], () => false, { drag: NaN }))
+})
+
+test('vectors rotate around arbitrary axes and by yaw', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const v = new Vector3f(1, 0, 0)
+  t.deepEqual(xyz(v.rotateAround([0, 0, 1], Math.PI / 2)), [0, 1, 0])
+  t.deepEqual(xyz(v.rotateAround([0, 0, 5], Math.PI / 2)), [0, 1, 0])
+  t.deepEqual(xyz(v.rotateAround([1, 0, 0], 1.2)), [1, 0, 0])
+  t.deepEqual(xyz(new Vector3f(1, 2, 3).rotateAround([1, 1, 1], (2 * Math.PI) / 3)), [3, 1, 2])
+  t.deepEqual(xyz(v.rotateZ(Math.PI / 2)), xyz(v.rotateAround([0, 0, 1], Math.PI / 2)))
+  t.deepEqual(xyz(new Vector3f(0, 1, 0).rotateX(Math.PI / 2)), [0, 0, 1])
+  t.deepEqual(xyz(new Vector3f(0, 0, 1).rotateY(Math.PI / 2)), [1, 0, 0])
+  t.deepEqual(xyz(new Vector3f(0, 0, 1).rotateYaw(90)), [-1, 0, 0])
+  t.deepEqual(xyz(new Vector3f(0, 0, 1).rotateYaw(-90)), xyz(new Vector3f(0, 0, 1).rotateY(Math.PI / 2)))
+
+  t.deepEqual(xyz(new Vector3f(1, 2, 3).rotateAround([0, 0, 0], 1)), [1, 2, 3])
+  t.true(Number.isNaN(v.rotateAround([0, 1, 0], NaN).x))
+  t.true(Number.isNaN(v.rotateYaw(Infinity).x))
+})
+
+test('interpolation blends square, cube and triangle corners', (t) => {
+  t.is(Interp.lerp(2, 6, 0.25), 3)
+  t.is(Interp.lerp(2, 6, 1.5), 8)
+  t.is(Interp.bilinear(0, 1, 2, 3, 0, 0), 0)
+  t.is(Interp.bilinear(0, 1, 2, 3, 1, 1), 3)
+  t.is(Interp.bilinear(0, 1, 2, 3, 0.5, 0.5), 1.5)
+  t.is(Interp.trilinear(0, 1, 2, 3, 4, 5, 6, 7, 1, 0, 1), 5)
+  t.is(Interp.trilinear(0, 1, 2, 3, 4, 5, 6, 7, 0.5, 0.5, 0.5), 3.5)
+
+  const weights = Interp.barycentric([0.25, 0.25, 7], [0, 0, 0], [1, 0, 0], [0, 1, 0])
+  t.deepEqual([weights.x, weights.y, weights.z], [0.5, 0.25, 0.25])
+  const outside = Interp.barycentric([2, 0, 0], [0, 0, 0], [1, 0, 0], [0, 1, 0])
+  t.deepEqual([outside.x, outside.y, outside.z], [-1, 2, 0])
+  t.is(Interp.triangle([0, 1, 0], [0, 0, 0], [1, 0, 0], [0, 1, 0], 10, 20, 30), 30)
+  t.is(Interp.triangle([0.25, 0.25, 0], [0, 0, 0], [1, 0, 0], [0, 1, 0], 10, 20, 30), 17.5)
+
+  t.true(Number.isNaN(Interp.barycentric([0, 0, 0], [0, 0, 0], [1, 0, 0], [2, 0, 0]).x))
+  t.true(Number.isNaN(Interp.bilinear(0, 1, 2, 3, NaN, 0)))
+  t.true(Number.isNaN(Interp.triangle([NaN, 0, 0], [0, 0, 0], [1, 0, 0], [0, 1, 0], 1, 2, 3)))
+})
+
+test('vectors convert to and from spherical and equirectangular coordinates', (t) => {
+  const up = new Vector3f(0, 2, 0).toSpherical()
+  t.deepEqual([up.radius, up.theta, up.phi], [2, 0, 0])
+  const side = new Vector3f(0, 0, 3).toSpherical()
+  t.true(near(side.theta, Math.PI / 2) && near(side.phi, Math.PI / 2))
+  t.deepEqual(new Vector3f(0, 0, 0).toSpherical(), { radius: 0, theta: 0, phi: 0 })
+
+  const back = Vector3f.fromSpherical(side.radius, side.theta, side.phi)
+  t.true(near(back.x, 0) && near(back.y, 0) && near(back.z, 3))
+  const v = new Vector3f(-1, 2, -3)
+  const s = v.toSpherical()
+  const round = Vector3f.fromSpherical(s.radius, s.theta, s.phi)
+  t.true(near(round.x, -1) && near(round.y, 2) && near(round.z, -3))
+
+  const uv = new Vector3f(-1, 0, 0).toEquirectangular()
+  t.true(near(uv.x, 0.5) && near(uv.y, 0.5))
+  t.true(near(new Vector3f(0, 0, -1).toEquirectangular().x, 0.75))
+  t.is(new Vector3f(0, -1, 0).toEquirectangular().y, 1)
+  const direction = Vector3f.fromEquirectangular(new Vector2f(0.75, 0.5))
+  t.true(near(direction.x, 0) && near(direction.y, 0) && near(direction.z, -1))
+
+  t.true(Number.isNaN(new Vector3f(NaN, 0, 0).toSpherical().radius))
+  t.true(Number.isNaN(Vector3f.fromSpherical(1, NaN, 0).x))
+  const far = new Vector3f(Infinity, 0, 0).toEquirectangular()
+  t.deepEqual([far.x, far.y], [0, 0.5])
+})
+
+test('safe spot search finds the closest two block gap above solid ground', (t) => {
+  const xyz = (v) => v && [v.x, v.y, v.z]
+  const ground = (position) => position.y < 64
+  t.deepEqual(xyz(SafeSpot.find([0.5, 70.2, 0.5], 4, ground)), [0, 64, 0])
+  t.is(SafeSpot.find([0.5, 80, 0.5], 4, ground), null)
+  t.deepEqual(xyz(SafeSpot.find([0.5, 80, 0.5], 4, ground, 16)), [0, 64, 0])
+
+  const calls = new Map()
+  const wall = (position) => {
+    const key = `${position.x},${position.y},${position.z}`
+    calls.set(key, (calls.get(key) ?? 0) + 1)
+    return position.y < 64 || (position.x == 0 && position.z == 0 && position.y < 80)
+  }
+  const spot = SafeSpot.find([0.5, 64, 0.5], 2, wall, 4)
+  t.is(spot.y, 64)
+  t.is(Math.max(Math.abs(spot.x), Math.abs(spot.z)), 1)
+  t.true([...calls.values()].every((count) => count == 1))
+  t.is(SafeSpot.find([0.5, 64, 0.5], 0, wall, 4), null)
+
+  const data = new Uint32Array(3 * 4 * 3)
+  for (let x = 0; x < 3; x++) for (let z = 0; z < 3; z++) data[(x * 4) * 3 + z] = 1
+  t.deepEqual(xyz(SafeSpot.findInVolume(data, [3, 4, 3], [1, 3, 1], 1)), [1, 1, 1])
+  data[(1 * 4 + 1) * 3 + 1] = 1
+  t.deepEqual(xyz(SafeSpot.findInVolume(data, [3, 4, 3], [1, 3, 1], 1)), [1, 2, 1])
+
+  t.deepEqual(xyz(SafeSpot.find([1e300, 1e300, 1e300], 1, () => true, 1)), null)
+  t.is(SafeSpot.find([0, -1e300, 0], 1, (position) => position.y < -1e18, 2), null)
+  t.throws(() => SafeSpot.find([NaN, 64, 0], 2, ground))
+  t.throws(() => SafeSpot.findInVolume(data, [3, 4, 3], [1, Infinity, 1], 1))
+})
+
+test('ticket graphs propagate the lowest level outwards in squares', (t) => {
+  t.is(TicketGraph.playerTicketLevel(10), 21)
+  t.is(TicketGraph.playerTicketLevel(40), 0)
+  t.is(TicketGraph.status(31), ChunkLoadStatus.EntityTicking)
+  t.is(TicketGraph.status(32), ChunkLoadStatus.BlockTicking)
+  t.is(TicketGraph.status(33), ChunkLoadStatus.Full)
+  t.is(TicketGraph.status(44), ChunkLoadStatus.Border)
+  t.is(TicketGraph.status(45), ChunkLoadStatus.Inaccessible)
+
+  const graph = new TicketGraph()
+  t.is(graph.level(0, 0), null)
+  graph.addTicket(0, 0, 31)
+  graph.addTicket(4, 0, 33)
+  t.is(graph.level(0, 0), 31)
+  t.is(graph.level(2, -3), 34)
+  t.is(graph.level(4, 0), 33)
+  t.is(graph.level(5, 0), 34)
+  t.is(graph.level(20, 0), null)
+
+  const loaded = graph.compute(33)
+  t.is(loaded.length, 26)
+  t.deepEqual(loaded[0], { x: 0, z: 0, level: 31 })
+  t.is(loaded.filter((chunk) => chunk.level == 33).length, 17)
+  t.is(graph.compute().length, 27 * 27 + 2 * 23)
+
+  graph.addTicket(0, 0, 31)
+  t.true(graph.removeTicket(0, 0, 31))
+  t.is(graph.level(0, 0), 31)
+  t.true(graph.removeTicket(0, 0, 31))
+  t.false(graph.removeTicket(0, 0, 31))
+  t.is(graph.level(0, 0), 37)
+  graph.clear()
+  t.deepEqual(graph.compute(), [])
+
+  graph.addTicket(2147483647, -2147483648, 43)
+  t.is(graph.level(-2147483648, 2147483647), null)
+  t.is(graph.level(2147483647, -2147483648), 43)
+  t.is(graph.compute().length, 4)
+  graph.addTicket(0, 0, 4294967295)
+  t.is(graph.level(0, 0), null)
+
+  const unbounded = new TicketGraph(4294967295)
+  unbounded.addTicket(0, 0, 0)
+  t.is(unbounded.level(-2147483648, 0), 2147483648)
+  t.throws(() => unbounded.compute())
+  t.is(unbounded.compute(2).length, 25)
+})
+
+test('moving boxes find their time of impact from the relative velocity', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => c + 0)
+  const box = new AABB([0, 0, 0], [1, 1, 1])
+  const other = new AABB([3, 0, 0], [4, 1, 1])
+
+  const head = box.sweepVsMoving([2, 0, 0], other, [-2, 0, 0])
+  t.is(head.time, 0.5)
+  t.deepEqual(xyz(head.normal), [-1, 0, 0])
+  t.deepEqual(xyz(head.displacement), [1, 0, 0])
+  t.is(head.face, BlockFace.West)
+
+  const chase = box.sweepVsMoving([4, 0, 0], other, [2, 0, 0])
+  t.is(chase.time, 1)
+  t.deepEqual(xyz(chase.displacement), [4, 0, 0])
+  t.is(box.sweepVsMoving([2, 0, 0], other, [2, 0, 0]), null)
+  t.is(box.sweepVsMoving([2.5, 0, 0], other, [1, 0, 0]), null)
+  t.is(box.sweepVsMoving([1, 2, 0], other, [0, 0, 0]), null)
+
+  const overlapping = box.sweepVsMoving([1, 0, 0], new AABB([0.5, 0.5, 0.5], [2, 2, 2]), [0, 0, 0])
+  t.is(overlapping.time, 0)
+  t.is(overlapping.face, undefined)
+
+  t.is(box.sweepVsMoving([NaN, 0, 0], other, [0, 0, 0]), null)
+  t.is(box.sweepVsMoving([Infinity, 0, 0], other, [0, 0, 0]), null)
+  t.is(box.sweepVsMoving([1, 0, 0], other, [-Infinity, 0, 0]), null)
+})
+
+test('tick time converts between ticks and milliseconds and accumulates fixed steps', (t) => {
+  t.is(TickTime.tickDuration(), 50)
+  t.is(TickTime.tickDuration(40), 25)
+  t.is(TickTime.ticksToMs(3.5), 175)
+  t.is(TickTime.msToTicks(125), 2.5)
+  t.is(TickTime.alpha(25), 0.5)
+  t.is(TickTime.alpha(80), 1)
+  t.is(TickTime.alpha(-10), 0)
+  const split = TickTime.split(1030)
+  t.deepEqual([split.x, split.y], [20, 0.6000000000000014])
+  t.true(Number.isNaN(TickTime.alpha(NaN)))
+  t.is(TickTime.msToTicks(100, 0), 0)
+
+  const clock = new FixedTimestepAccumulator()
+  t.is(clock.advance(30), 0)
+  t.is(clock.advance(30), 1)
+  t.is(clock.accumulated, 10)
+  t.is(clock.alpha(), 0.2)
+  t.is(clock.advance(140), 3)
+  t.is(clock.ticks, 4)
+  t.is(clock.advance(10000), 10)
+  t.is(clock.accumulated, 50)
+  t.is(clock.advance(-100), 1)
+  t.is(clock.advance(NaN), 0)
+  t.is(clock.ticks, 15)
+  clock.reset()
+  t.deepEqual([clock.ticks, clock.accumulated], [0, 0])
+
+  t.is(clock.advance(Infinity), 10)
+  t.is(clock.accumulated, 50)
+  const unlimited = new FixedTimestepAccumulator(20, 4294967295)
+  t.is(unlimited.advance(Infinity), 4294967295)
+  t.is(new FixedTimestepAccumulator(0).advance(1000), 0)
+  t.is(new FixedTimestepAccumulator(-20).advance(1000), 0)
+  t.is(new FixedTimestepAccumulator(NaN).advance(1000), 0)
+})
+
+test('2d rotations compose and apply as cosine and sine pairs', (t) => {
+  const xy = (v) => [v.x, v.y].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const quarter = Rot2.fromAngle(Math.PI / 2)
+  t.deepEqual(xy(quarter.rotate(new Vector2f(1, 0))), [0, 1])
+  t.deepEqual(xy(quarter.unrotate(new Vector2f(0, 1))), [1, 0])
+  t.true(near(quarter.compose(quarter).toAngle(), Math.PI))
+  t.true(near(quarter.compose(quarter.inverse()).toAngle(), 0))
+  t.true(near(Rot2.between(new Vector2f(1, 0), new Vector2f(0, -3)).toAngle(), -Math.PI / 2))
+
+  const scaled = new Rot2(3, 4)
+  t.true(near(scaled.cos, 0.6) && near(scaled.sin, 0.8))
+  const huge = new Rot2(1e300, 1e300)
+  t.true(near(huge.toAngle(), Math.PI / 4))
+  t.true(near(Rot2.fromAngle(3).slerp(Rot2.fromAngle(-3), 0.5).toAngle(), Math.PI) || near(Rot2.fromAngle(3).slerp(Rot2.fromAngle(-3), 0.5).toAngle(), -Math.PI))
+  t.true(near(Rot2.fromAngle(0.2).slerp(Rot2.fromAngle(1), 0.25).toAngle(), 0.4))
+  const drifted = new Rot2(1, 0)
+  drifted.cos = 2
+  t.true(near(drifted.normalize().cos, 1))
+
+  for (const [cos, sin] of [[0, 0], [NaN, 1], [Infinity, 0], [Infinity, -Infinity]]) {
+    const rotation = new Rot2(cos, sin)
+    t.deepEqual([rotation.cos, rotation.sin], [1, 0])
+  }
+  t.true(Number.isNaN(Rot2.fromAngle(NaN).cos))
+})
+
+test('euler angles round trip through matrices and quaternions in every order', (t) => {
+  const orders = [EulerOrder.XYZ, EulerOrder.XZY, EulerOrder.YXZ, EulerOrder.YZX, EulerOrder.ZXY, EulerOrder.ZYX]
+  const v = [0.3, -1.2, 2.5]
+  for (const order of orders) {
+    const euler = new Euler(0.4, -0.7, 1.1, order)
+    const back = Euler.fromMatrix3(euler.toMatrix3(), order)
+    t.true(near(back.x, 0.4) && near(back.y, -0.7) && near(back.z, 1.1))
+    const fromQuaternion = Euler.fromQuaternion(euler.toQuaternion(), order)
+    t.true(near(fromQuaternion.x, 0.4) && near(fromQuaternion.y, -0.7) && near(fromQuaternion.z, 1.1))
+
+    const a = euler.rotate(v)
+    const b = euler.reorder(order == EulerOrder.ZYX ? EulerOrder.XYZ : EulerOrder.ZYX).rotate(v)
+    t.true(near(a.x, b.x) && near(a.y, b.y) && near(a.z, b.z))
+  }
+
+  const x = new Euler(Math.PI / 2, 0, 0).rotate([0, 1, 0])
+  t.true(near(x.x, 0) && near(x.y, 0) && near(x.z, 1))
+  t.is(new Euler(0, 0, 0).order, EulerOrder.XYZ)
+
+  const locked = new Euler(0.3, Math.PI / 2, 0.5)
+  const unlocked = Euler.fromMatrix3(locked.toMatrix3())
+  t.true(near(unlocked.y, Math.PI / 2))
+  t.is(unlocked.z, 0)
+  const p = locked.rotate(v)
+  const q = unlocked.rotate(v)
+  t.true(near(p.x, q.x) && near(p.y, q.y) && near(p.z, q.z))
+
+  const invalid = Euler.fromMatrix3(new Matrix3([NaN, 0, 0, 0, 1, 0, 0, 0, 1]))
+  t.true(Number.isNaN(invalid.x) || Number.isNaN(invalid.y) || Number.isNaN(invalid.z))
+  t.true(Number.isNaN(new Euler(NaN, 0, 0).rotate([0, 1, 0]).y))
+  const clamped = Euler.fromMatrix3(new Matrix3([1, 0, 0, 0, 1, 0, 2, 0, 1]))
+  t.true(near(clamped.y, Math.PI / 2))
+})
+
+test('matrices compose from and decompose into translation, rotation and scale', (t) => {
+  const rotation = Quaternion.fromAxisAngle([0, 1, 0], 0.8)
+  const matrix = Matrix4.compose([1, 2, 3], rotation, [2, 3, 4])
+  const parts = matrix.decompose()
+  t.deepEqual([parts.translation.x, parts.translation.y, parts.translation.z], [1, 2, 3])
+  t.true(near(parts.scale.x, 2) && near(parts.scale.y, 3) && near(parts.scale.z, 4))
+  t.true(near(Math.abs(parts.rotation.y), Math.abs(rotation.y)) && near(Math.abs(parts.rotation.w), Math.abs(rotation.w)))
+  const point = matrix.transformPoint([1, 1, 1])
+  const recomposed = Matrix4.compose(parts.translation, parts.rotation, parts.scale).transformPoint([1, 1, 1])
+  t.true(near(point.x, recomposed.x) && near(point.y, recomposed.y) && near(point.z, recomposed.z))
+
+  const mirrored = Matrix4.compose([0, 0, 0], Quaternion.identity(), [-1, 1, 1]).decompose()
+  t.deepEqual([mirrored.scale.x, mirrored.scale.y, mirrored.scale.z], [-1, 1, 1])
+  t.true(near(mirrored.rotation.w, 1))
+
+  const flat = Matrix4.compose([0, 0, 0], rotation, [0, 1, 1]).decompose()
+  t.is(flat.scale.x, 0)
+  t.true(near(Math.abs(flat.rotation.y), Math.abs(rotation.y)) && near(Math.abs(flat.rotation.w), Math.abs(rotation.w)))
+  const empty = Matrix4.compose([0, 0, 0], rotation, [0, 0, 0]).decompose().rotation
+  t.deepEqual([empty.x, empty.y, empty.z, empty.w], [0, 0, 0, 1])
+
+  const invalid = Matrix4.compose([0, 0, 0], Quaternion.identity(), [NaN, 1, 1]).decompose()
+  t.true(Number.isNaN(invalid.scale.x))
+  t.deepEqual([invalid.translation.x, invalid.scale.y], [0, 1])
+})
+
+test('transformed boxes are enclosed by the bounds of their corners', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const box = new AABB([0, 0, 0], [2, 1, 1])
+  const moved = Matrix4.compose([10, 0, -5], Quaternion.identity(), [1, 2, 1]).transformAabb(box)
+  t.deepEqual(xyz(moved.min), [10, 0, -5])
+  t.deepEqual(xyz(moved.max), [12, 2, -4])
+
+  const turned = Matrix4.compose([0, 0, 0], Quaternion.fromAxisAngle([0, 1, 0], Math.PI / 2), [1, 1, 1]).transformAabb(box)
+  t.deepEqual(xyz(turned.min), [0, 0, -2])
+  t.deepEqual(xyz(turned.max), [1, 1, 0])
+  const diagonal = Matrix4.compose([0, 0, 0], Quaternion.fromAxisAngle([0, 0, 1], Math.PI / 4), [1, 1, 1]).transformAabb(new AABB([-1, -1, 0], [1, 1, 1]))
+  t.true(near(diagonal.max.x, Math.SQRT2) && near(diagonal.min.y, -Math.SQRT2))
+
+  const mirrored = Matrix4.compose([0, 0, 0], Quaternion.identity(), [-1, 1, 1]).transformAabb(box)
+  t.deepEqual(xyz(mirrored.min), [-2, 0, 0])
+
+  const invalid = Matrix4.compose([NaN, 0, 0], Quaternion.identity(), [1, 1, 1]).transformAabb(box)
+  t.true(Number.isNaN(invalid.min.x) && Number.isNaN(invalid.max.x))
+  t.deepEqual([invalid.min.y, invalid.max.y], [0, 1])
+  const infinite = Matrix4.compose([0, 0, 0], Quaternion.identity(), [Infinity, 1, 1]).transformAabb(box)
+  t.true(Number.isNaN(infinite.min.x))
+})
+
+test('interest sets keep entities on the edge of the view distance with hysteresis', (t) => {
+  const interest = new InterestManager(8)
+  const players = new Float64Array([0, 64, 0, 32, 100, 64, 100, 10])
+  const entities = (x) => new Float64Array([x, 64, 0, 105, 64, 100, -20, 64, -20])
+
+  t.deepEqual(interest.compute(players, entities(30)), [[0, 2], [1]])
+  t.true(interest.isRelevant(0, 0))
+  t.false(interest.isRelevant(1, 0))
+  t.false(interest.isRelevant(5, 0))
+
+  t.deepEqual(interest.compute(players, entities(38)), [[0, 2], [1]])
+  t.deepEqual(interest.compute(players, entities(41)), [[2], [1]])
+  t.deepEqual(interest.compute(players, entities(38)), [[2], [1]])
+  interest.reset()
+  t.deepEqual(interest.compute(players, entities(31)), [[0, 2], [1]])
+
+  const far = new Float64Array([0, 64, 0, Infinity, 1e300, 0, 1e300, 5])
+  t.deepEqual(interest.compute(far, entities(1e6)), [[0, 1, 2], []])
+  t.deepEqual(interest.compute(new Float64Array([0, 64, 0, NaN, NaN, 64, 0, 32]), entities(0)), [[0], []])
+  t.deepEqual(new InterestManager(-5).margin, 0)
+  t.deepEqual(interest.compute(new Float64Array(0), entities(0)), [])
+  t.throws(() => interest.compute(new Float64Array(3), entities(0)))
+  t.throws(() => interest.compute(players, new Float64Array(4)))
+})
+
+test('centroids and geometric medians of packed points', (t) => {
+  const points = new Float64Array([0, 0, 0, 2, 0, 0, 0, 0, 2, 2, 0, 2, 100, 0, 100])
+  const centroid = Points.centroid(points)
+  t.deepEqual([centroid.x, centroid.y, centroid.z], [20.8, 0, 20.8])
+  const weighted = Points.centroid(new Float64Array([0, 0, 0, 4, 0, 0]), [3, 1])
+  t.deepEqual([weighted.x, weighted.y, weighted.z], [1, 0, 0])
+  t.is(Points.centroid(new Float64Array([0, 0, 0, 4, 0, 0]), [1, -1]), null)
+  t.is(Points.centroid(new Float64Array(0)), null)
+  const missing = Points.centroid(new Float64Array([0, 0, 0, 4, 0, 0]), [2])
+  t.is(missing.x, 0)
+
+  const median = Points.geometricMedian(points, 500, 1e-9)
+  t.true(median.x < 2 && median.z < 2 && median.x > 0.9)
+  t.true(Points.totalDistance(points, median) < Points.totalDistance(points, centroid))
+  const single = Points.geometricMedian(new Float64Array([3, 4, 5]))
+  t.deepEqual([single.x, single.y, single.z], [3, 4, 5])
+  const pair = Points.geometricMedian(new Float64Array([0, 0, 0, 0, 0, 0, 6, 0, 0]))
+  t.true(near(pair.x, 0, 1e-4))
+  t.is(Points.geometricMedian(new Float64Array(0)), null)
+  t.is(Points.totalDistance(new Float64Array([0, 3, 4, 0, 0, 0]), [0, 0, 0]), 5)
+
+  t.true(Number.isNaN(Points.centroid(new Float64Array([NaN, 0, 0])).x))
+  t.true(Number.isNaN(Points.geometricMedian(new Float64Array([NaN, 0, 0, 1, 1, 1])).x))
+  const stubborn = Points.geometricMedian(points, 3, NaN)
+  t.true(Number.isFinite(stubborn.x))
+})
+
+test('position filters smooth jitter and wrap angles', (t) => {
+  const filter = new PositionFilter(1, 0)
+  t.is(filter.value(), null)
+  const first = filter.filter([10, 0, 0])
+  t.deepEqual([first.x, first.y, first.z], [10, 0, 0])
+  const second = filter.filter([20, 0, 0])
+  t.true(second.x > 10 && second.x < 20)
+  const alpha = 1 / (1 + 1 / (2 * Math.PI * 0.05))
+  t.true(near(second.x, 10 + 10 * alpha))
+  t.true(near(PositionFilter.ema([10, 0, 0], [20, 0, 0], alpha).x, second.x))
+  t.is(PositionFilter.ema([0, 0, 0], [10, 0, 0], 2).x, 10)
+
+  const responsive = new PositionFilter(1, 1)
+  responsive.filter([0, 0, 0])
+  t.true(responsive.filter([20, 0, 0]).x > 20 * alpha)
+
+  const skipped = filter.filter([NaN, Infinity, 0])
+  t.deepEqual([skipped.x, skipped.y], [second.x, 0])
+  t.true(Number.isFinite(filter.filter([20, 0, 0], NaN).x))
+  t.true(Number.isFinite(filter.filter([20, 0, 0], -1).x))
+  filter.reset()
+  t.is(filter.value(), null)
+  t.true(Number.isNaN(filter.filter([NaN, 0, 0]).x))
+  t.is(filter.value(), null)
+
+  const yaw = new PositionFilter(1, 0, 1, true)
+  yaw.filterRotation(new Vector2f(0, 170))
+  const wrapped = yaw.filterRotation(new Vector2f(0, -170))
+  t.true(wrapped.y > 170 || wrapped.y < -170)
+})
+
+test('knockback halves velocity, pushes away and does not stack within a tick', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  t.deepEqual(xyz(Knockback.apply([0.2, 0, 0], 0.4, 3, 0, true)), [-0.3, 0.4, 0])
+  t.deepEqual(xyz(Knockback.apply([0, -0.2, 0.4], 0.4, 0, -1, false)), [0, -0.2, 0.6])
+  t.deepEqual(xyz(Knockback.apply([0, -0.6, 0], 0.4, 1, 0, true)), [-0.4, 0.1, 0])
+  t.deepEqual(xyz(Knockback.apply([0, 0, 0], 0.4, 1, 0, true, 0.5)), [-0.2, 0.2, 0])
+  t.deepEqual(xyz(Knockback.apply([0.2, 0, 0], 0.4, 1, 0, true, 1)), [0.2, 0, 0])
+  t.deepEqual(xyz(Knockback.apply([0.2, 0, 0], 0.4, 1, 0, true, 5)), [0.2, 0, 0])
+  t.deepEqual(xyz(Knockback.apply([0, 0, 0], 0.4, 0, 0, true)), [0, 0.4, 0])
+
+  t.deepEqual(xyz(Knockback.apply([0.2, 0, 0], NaN, 1, 0, true)), [0.2, 0, 0])
+  t.deepEqual(xyz(Knockback.apply([0.2, 0, 0], 0.4, 1, 0, true, NaN)), [0.2, 0, 0])
+  t.deepEqual(xyz(Knockback.apply([0.2, 0, 0], 0.4, NaN, 0, false)), [0.1, 0, 0])
+
+  t.deepEqual(xyz(Knockback.combine([0.3, 0.1, 0], [0, 0.4, -0.2])), [0.3, 0.4, 0])
+  t.deepEqual(xyz(Knockback.combine([0.1, 0.3, 0], [-0.4, 0, 0.1])), [-0.4, 0.3, 0.1])
+  t.deepEqual(xyz(Knockback.combineAll([[0.1, 0, 0], [0, 0.2, 0.5], [0.3, 0, 0]])), [0, 0.2, 0.5])
+  t.deepEqual(xyz(Knockback.combineAll([])), [0, 0, 0])
+})
+
+test('fluid motion applies vanilla drag, pushes and bubble columns', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const still = { forward: 0, strafe: 0 }
+  t.deepEqual(xyz(FluidMotion.waterStep([1, 0, 0], still, 0)), [0.8, -0.005, 0])
+  t.deepEqual(xyz(FluidMotion.waterStep([1, 0, 0], still, 0, { sprinting: true })), [0.9, 0, 0])
+  t.deepEqual(xyz(FluidMotion.waterStep([1, 0, 0], still, 0, { dolphinsGrace: true, noGravity: true })), [0.96, 0, 0])
+  t.deepEqual(xyz(FluidMotion.waterStep([1, 0, 0], still, 0, { depthStrider: 3, onGround: true, noGravity: true })), [0.54600006, 0, 0])
+  t.deepEqual(xyz(FluidMotion.waterStep([1, 0, 0], still, 0, { depthStrider: 99, onGround: true, noGravity: true })), [0.54600006, 0, 0])
+  t.deepEqual(xyz(FluidMotion.waterStep([0, 0, 0], { forward: 1, strafe: 0 }, 0, { noGravity: true })), [0, 0, 0.016])
+  t.deepEqual(xyz(FluidMotion.lavaStep([1, 1, 0], still, 0)), [0.5, 0.48, 0])
+
+  t.deepEqual(xyz(FluidMotion.swimVertical([0, 0, 0], 90, false, false)), [0, -0.085, 0])
+  t.deepEqual(xyz(FluidMotion.swimVertical([0, 0, 0], -90, false, false)), [0, 0, 0])
+  t.deepEqual(xyz(FluidMotion.swimVertical([0, 0, 0], -90, true, false)), [0, 0.06, 0])
+  t.deepEqual(xyz(FluidMotion.jumpInFluid([0, 0.1, 0])), [0, 0.14, 0])
+
+  t.deepEqual(xyz(FluidMotion.fluidPush([0, 0, 0], [3, 0, 4])), [0.0084, 0, 0.0112])
+  t.deepEqual(xyz(FluidMotion.fluidPush([0, 0, 0], [1, 0, 0], true)), [0.0045, 0, 0])
+  t.deepEqual(xyz(FluidMotion.fluidPush([1, 0, 0], [1, 0, 0], true)), [1.002333333, 0, 0])
+  t.deepEqual(xyz(FluidMotion.fluidPush([1, 0, 0], [0, 0, 0])), [1, 0, 0])
+  t.deepEqual(xyz(FluidMotion.fluidPush([1, 0, 0], [NaN, 0, 0])), [1, 0, 0])
+  t.deepEqual(xyz(FluidMotion.fluidPush([1, 0, 0], [Infinity, 0, 0])), [1, 0, 0])
+
+  t.is(FluidMotion.bubbleColumn([0, -0.29, 0], true, false).y, -0.3)
+  t.is(FluidMotion.bubbleColumn([0, 0, 0], true, true).y, -0.03)
+  t.is(FluidMotion.bubbleColumn([0, 0.68, 0], false, false).y, 0.7)
+  t.is(FluidMotion.bubbleColumn([0, 1.75, 0], false, true).y, 1.8)
+})
+
+test('minecarts follow rails, slopes and powered rails', (t) => {
+  const state = (step) => [step.position.x, step.position.y, step.position.z, step.velocity.x, step.velocity.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const flat = { shape: RailShape.NorthSouth }
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, 0.2], flat)), [0.5, 64, 0.7, 0, 0.192])
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, -0.2], flat)), [0.5, 64, 0.3, 0, -0.192])
+  const snapped = Minecart.step([0.3, 64, 0.5], [0.1, 0, 0.2], flat, { occupied: true })
+  t.is(snapped.position.x, 0.5)
+  t.true(near(snapped.velocity.z, Math.hypot(0.1, 0.2) * 0.997))
+  t.true(snapped.onRail)
+
+  const slope = Minecart.step([0.5, 64, 0.5], [0, 0, 0], { shape: RailShape.AscendingNorth })
+  t.true(slope.velocity.z > 0 && slope.position.y < 64.5)
+
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, 0.2], { shape: RailShape.NorthSouth, poweredRail: true })), [0.5, 64, 0.6, 0, 0.096])
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, 0.02], { shape: RailShape.NorthSouth, poweredRail: true })), [0.5, 64, 0.5, 0, 0])
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, 0.2], { shape: RailShape.NorthSouth, poweredRail: true, active: true })), [0.5, 64, 0.7, 0, 0.252])
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, 5], flat)), [0.5, 64, 0.9, 0, 1.92])
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [0, 0, 5], flat, { inWater: true })), [0.5, 64, 0.7, 0, 1.92])
+
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [NaN, 0, 0], flat)), [0.5, 64, 0.5, 0, 0])
+  const derailed = Minecart.step([0.5, 64, 0.5], [1, 0, 0], null, { onGround: true })
+  t.false(derailed.onRail)
+  t.deepEqual(state(derailed), [0.7, 63.98, 0.5, 0.2, 0])
+  t.deepEqual(state(Minecart.step([0.5, 64, 0.5], [1, 0, 0], null, { inWater: true })), [0.7, 63.96, 0.5, 0.19, 0])
+  t.true(Number.isNaN(Minecart.step([0.5, 64, 0.5], [NaN, 0, 0]).velocity.x))
+})
+
+test('entity push separates overlapping entities in a crowd', (t) => {
+  const boxes = new Float64Array([
+    0, 0, 0, 0.6, 1.8, 0.6,
+    0.5, 0, 0, 1.1, 1.8, 0.6,
+    5, 0, 5, 5.6, 1.8, 5.6,
+    0.2, 3, 0, 0.8, 4, 0.6
+  ])
+  const push = EntityPush.compute(boxes)
+  t.is(push.length, 12)
+  t.true(near(push[0], -0.5 * 0.05 / Math.sqrt(0.5)))
+  t.true(near(push[3], 0.5 * 0.05 / Math.sqrt(0.5)))
+  t.deepEqual([push[1], push[2], push[4], push[5]], [0, 0, 0, 0])
+  t.deepEqual([...push.slice(6)], [0, 0, 0, 0, 0, 0])
+
+  const anchored = EntityPush.compute(boxes, [false])
+  t.deepEqual([anchored[0], anchored[3]], [0, push[3]])
+  const far = EntityPush.compute(new Float64Array([0, 0, 0, 4, 1, 4, 3, 0, 0, 7, 1, 4]))
+  t.true(near(far[3], 3 * 0.05 / 3))
+  t.deepEqual([...EntityPush.compute(new Float64Array([0, 0, 0, 1, 1, 1, 0.005, 0, 0, 1.005, 1, 1]))], [0, 0, 0, 0, 0, 0])
+  t.deepEqual([...EntityPush.compute(new Float64Array(0))], [])
+
+  const invalid = EntityPush.compute(new Float64Array([NaN, 0, 0, 1, 1, 1, 0.5, 0, 0, 1.5, 1, 1, 0.6, 0, 0, 1.6, 1, 1]))
+  t.true(invalid[6] > 0)
+  t.deepEqual([invalid[0], invalid[2]], [0, 0])
+  t.throws(() => EntityPush.compute(new Float64Array(5)))
+})
+
+test('sweep order sorts candidates by entry time and drops duplicates', (t) => {
+  const moving = new AABB([0, 0, 0], [1, 1, 1])
+  const candidates = [
+    new AABB([5, 0, 0], [6, 1, 1]),
+    new AABB([2, 0, 0], [3, 1, 1]),
+    new AABB([0, 5, 0], [1, 6, 1]),
+    new AABB([0.5, 0, 0], [1.5, 1, 1]),
+    new AABB([2, 0, 0], [3, 1, 1]),
+    new AABB([12, 0, 0], [13, 1, 1])
+  ]
+  t.deepEqual(SweepOrder.sort(moving, [10, 0, 0], candidates), [3, 1, 0])
+  t.deepEqual(SweepOrder.sort(moving, [-10, 0, 0], candidates), [3])
+  t.deepEqual(SweepOrder.sort(moving, [0, 0, 0], candidates), [3])
+  t.deepEqual(SweepOrder.sort(moving, [10, 0, 0], []), [])
+
+  t.is(SweepOrder.entryTime(moving, [10, 0, 0], candidates[1]), 0.1)
+  t.is(SweepOrder.entryTime(moving, [10, 0, 0], candidates[3]), 0)
+  t.is(SweepOrder.entryTime(moving, [10, 0, 0], candidates[2]), null)
+
+  t.deepEqual(SweepOrder.sort(moving, [NaN, 0, 0], candidates), [])
+  t.deepEqual(SweepOrder.sort(moving, [Infinity, 0, 0], candidates), [])
+  t.is(SweepOrder.entryTime(moving, [Infinity, 0, 0], candidates[1]), null)
+})
+
+test('climbing regions sit in front of the face an entity looks towards', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const south = Climbing.frontRegion([0.5, 64, 0.5], 10, 0.6, 1.8)
+  t.deepEqual(xyz(south.min), [0.2, 64, 0.8])
+  t.deepEqual(xyz(south.max), [0.8, 65.8, 0.9])
+  const west = Climbing.frontRegion([0.5, 64, 0.5], 95, 0.6, 1.8, 0.2)
+  t.deepEqual(xyz(west.min), [0, 64, 0.2])
+  t.deepEqual(xyz(west.max), [0.2, 65.8, 0.8])
+  const north = Climbing.frontRegion([0.5, 64, 0.5], -180, 0.6, 1.8)
+  t.deepEqual(xyz(north.max), [0.8, 65.8, 0.2])
+  const east = Climbing.frontRegion([0.5, 64, 0.5], -90, 0.6, 1.8, -1)
+  t.deepEqual(xyz(east.min), [0.8, 64, 0.2])
+  t.deepEqual(xyz(east.max), [0.8, 65.8, 0.8])
+
+  t.deepEqual(xyz(Climbing.feetBlock([-0.5, 64.9, 3.2])), [-1, 64, 3])
+  t.deepEqual(Climbing.frontBlocks([0.5, 64, 0.5], 0, 0.6, 1.8).map(xyz), [[0, 64, 0], [0, 65, 0]])
+  t.deepEqual(Climbing.frontBlocks([0.5, 64, 0.7], 0, 0.6, 1).map(xyz), [[0, 64, 1]])
+  t.deepEqual(Climbing.frontBlocks([0.5, 64, 0.5], 90, 2, 1).map(xyz), [[-1, 64, -1], [-1, 64, 0], [-1, 64, 1]])
+  t.deepEqual(Climbing.frontBlocks([0.5, 64, 0.5], 0, 0.6, -1), [])
+
+  t.throws(() => Climbing.frontBlocks([0.5, 64, 0.5], 0, NaN, 1.8))
+  t.throws(() => Climbing.frontBlocks([0.5, 64, 0.5], 0, 0.6, Infinity))
+  t.throws(() => Climbing.frontBlocks([0.5, 64, 0.5], 0, 1e9, 1e9))
+  t.true(Number.isNaN(Climbing.frontRegion([NaN, 64, 0], 0, 0.6, 1.8).min.x))
+})
+
+test('sweeping a box finds the first contact even through thin obstacles', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => c + 0)
+  const box = new AABB([0, 0, 0], [1, 1, 1])
+  const wall = new AABB([3, -5, -5], [3.1, 5, 5])
+
+  const hit = box.sweep(wall, [10, 0, 0])
+  t.is(hit.time, 0.2)
+  t.deepEqual(xyz(hit.normal), [-1, 0, 0])
+  t.deepEqual(xyz(hit.displacement), [2, 0, 0])
+  const floor = box.sweep(new AABB([-5, -2, -5], [5, -1, 5]), [0.5, -4, 0])
+  t.is(floor.time, 0.25)
+  t.deepEqual(xyz(floor.normal), [0, 1, 0])
+  t.deepEqual(xyz(floor.displacement), [0.125, -1, 0])
+  const corner = box.sweep(new AABB([2, 2, 0], [3, 3, 1]), [2, 2, 0])
+  t.is(corner.time, 0.5)
+
+  t.is(box.sweep(wall, [1, 0, 0]), null)
+  t.is(box.sweep(wall, [-10, 0, 0]), null)
+  t.is(box.sweep(wall, [0, 0, 0]), null)
+  t.is(box.sweep(new AABB([3, 2, 0], [4, 3, 1]), [10, 0, 0]), null)
+  t.is(box.sweep(new AABB([0.5, 0.5, 0.5], [2, 2, 2]), [0, 0, 0]).time, 0)
+
+  t.is(box.sweep(wall, [NaN, 0, 0]), null)
+  t.is(box.sweep(wall, [Infinity, 0, 0]), null)
+  t.is(box.sweep(wall, [10, -Infinity, 0]), null)
+})
+
+test('movement is clipped one axis at a time so entities slide and land', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const entity = new AABB([0.2, 1, 0.2], [0.8, 2.8, 0.8])
+  const floor = new AABB([-5, 0, -5], [5, 1, 5])
+  const wall = new AABB([1.5, 1, -5], [2.5, 5, 5])
+
+  const landed = CollisionResolver.resolveMovement(entity, [0, -0.5, 0], [floor])
+  t.deepEqual(xyz(landed.movement), [0, 0, 0])
+  t.true(landed.onGround && landed.collidedY)
+  t.false(landed.collidedX)
+
+  const slid = CollisionResolver.resolveMovement(entity, [1, -0.1, 0.4], [floor, wall])
+  t.deepEqual(xyz(slid.movement), [0.7, 0, 0.4])
+  t.true(slid.collidedX && slid.onGround)
+  t.false(slid.collidedZ)
+
+  const jumped = CollisionResolver.resolveMovement(entity, [0, 0.4, 0], [floor, new AABB([-5, 3, -5], [5, 4, 5])])
+  t.deepEqual(xyz(jumped.movement), [0, 0.2, 0])
+  t.true(jumped.collidedY)
+  t.false(jumped.onGround)
+  t.deepEqual(xyz(CollisionResolver.resolveMovement(entity, [3, 0, 0], []).movement), [3, 0, 0])
+
+  t.is(CollisionResolver.clipAxis(entity, Axis.X, 5, [wall]), 0.7)
+  t.is(CollisionResolver.clipAxis(entity, Axis.X, -5, [wall]), -5)
+  t.is(CollisionResolver.clipAxis(entity, Axis.Y, -5, [floor, wall]), 0)
+
+  const invalid = CollisionResolver.resolveMovement(entity, [NaN, -0.5, 0.2], [floor])
+  t.deepEqual(xyz(invalid.movement), [0, 0, 0.2])
+  t.true(invalid.collidedX)
+  const far = CollisionResolver.resolveMovement(entity, [Infinity, 0, 0], [wall])
+  t.deepEqual(xyz(far.movement), [0.7, 0, 0])
+  t.true(far.collidedX)
+})
+
+test('eye rays start at the pose specific eye height', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  t.is(EyeRay.eyeHeight(Pose.Standing), 1.62)
+  t.is(EyeRay.eyeHeight(Pose.Sneaking), 1.27)
+  t.is(EyeRay.eyeHeight(Pose.Swimming), 0.4)
+  t.is(EyeRay.eyeHeight(Pose.SpinAttack), 0.4)
+  t.is(EyeRay.eyeHeight(Pose.Sleeping), 0.2)
+
+  const ray = EyeRay.compute([0.5, 64, 0.5], Pose.Standing, new Vector2f(0, 0))
+  t.deepEqual(xyz(ray.origin), [0.5, 65.62, 0.5])
+  t.deepEqual(xyz(ray.direction), [0, 0, 1])
+  t.deepEqual(xyz(EyeRay.compute([0, 0, 0], Pose.Sneaking, new Vector2f(90, 0)).direction), [0, -1, 0])
+  t.deepEqual(xyz(EyeRay.compute([0, 0, 0], Pose.Standing, new Vector2f(0, 90), 2).origin), [0, 3.24, 0])
+  t.deepEqual(xyz(EyeRay.end([0, 0, 0], Pose.Standing, new Vector2f(0, 90), 5)), [-5, 1.62, 0])
+  t.deepEqual(xyz(EyeRay.end([0, 0, 0], Pose.Crawling, new Vector2f(0, 0), 0)), [0, 0.4, 0])
+
+  t.true(Number.isNaN(EyeRay.compute([0, 0, 0], Pose.Standing, new Vector2f(0, 0), NaN).origin.y))
+  t.true(Number.isNaN(EyeRay.compute([0, 0, 0], Pose.Standing, new Vector2f(NaN, 0)).direction.y))
+})
+
+test('bases built from a facing stay orthonormal even looking straight up', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const south = Basis.fromForward([0, 0, 3])
+  t.deepEqual(xyz(south.forward), [0, 0, 1])
+  t.deepEqual(xyz(south.up), [0, 1, 0])
+  t.deepEqual(xyz(south.right), [-1, 0, 0])
+
+  const diagonal = Basis.fromForward([1, -1, 2])
+  const dot = (a, b) => a.x * b.x + a.y * b.y + a.z * b.z
+  t.true(near(dot(diagonal.right, diagonal.up), 0) && near(dot(diagonal.up, diagonal.forward), 0) && near(dot(diagonal.right, diagonal.forward), 0))
+  t.true(near(dot(diagonal.forward, diagonal.forward), 1))
+  t.true(near(diagonal.forward.x * Math.sqrt(6), 1))
+
+  const up = Basis.fromForward([0, 5, 0], 90)
+  t.deepEqual(xyz(up.forward), [0, 1, 0])
+  t.deepEqual(xyz(up.right), [0, 0, -1])
+  t.deepEqual(xyz(Basis.fromRotation(new Vector2f(0, 90)).forward), [-1, 0, 0])
+  t.deepEqual(xyz(Basis.fromRotation(new Vector2f(90, 0)).forward), [0, -1, 0])
+  t.deepEqual(xyz(Basis.toWorld(south, [1, 2, 3])), [-1, 2, 3])
+
+  t.deepEqual(xyz(Basis.fromForward([1e300, 0, 1e300]).forward), xyz(Basis.fromForward([1, 0, 1]).forward))
+  for (const forward of [[0, 0, 0], [NaN, 0, 1], [Infinity, 0, 0]]) {
+    t.deepEqual(xyz(Basis.fromForward(forward, 180).forward), [0, 0, -1])
+  }
+})
+
+test('hits report the face of the box that was entered', (t) => {
+  const box = new AABB([0, 0, 0], [1, 1, 1])
+  const cases = [
+    [[-5, 0.5, 0.5], BlockFace.West, [-1, 0, 0]],
+    [[5, 0.5, 0.5], BlockFace.East, [1, 0, 0]],
+    [[0.5, -5, 0.5], BlockFace.Down, [0, -1, 0]],
+    [[0.5, 5, 0.5], BlockFace.Up, [0, 1, 0]],
+    [[0.5, 0.5, -5], BlockFace.North, [0, 0, -1]],
+    [[0.5, 0.5, 5], BlockFace.South, [0, 0, 1]]
+  ]
+  for (const [start, face, normal] of cases) {
+    const hit = AABB.Intercept(box, start, [0.5, 0.5, 0.5])
+    t.is(hit.face, face)
+    t.deepEqual([hit.normal.x, hit.normal.y, hit.normal.z].map((c) => c + 0), normal)
+    t.is(hit.distance, start.some((c) => c < 0) ? 5 : 4)
+    t.is(Beam.intercept(box, start, [0.5, 0.5, 0.5], 0.25).face, face)
+  }
+
+  const pierced = Raycaster.pierce([-5, 0.5, 0.5], [5, 0.5, 0.5], [new AABB([2, 0, 0], [3, 1, 1]), box])
+  t.deepEqual(pierced.map((hit) => [hit.index, hit.face]), [[1, BlockFace.West], [0, BlockFace.West]])
+  const transforms = new Float64Array(Matrix4.compose([0, 0, 0], Quaternion.identity(), [1, 1, 1]).elements)
+  t.is(Picking.raycastEntities([0, 5, 0], [0, -5, 0], transforms, new Float64Array([0.5, 0.5, 0.5])).face, BlockFace.Up)
+
+  t.is(AABB.Intercept(box, [NaN, 0.5, 0.5], [0.5, 0.5, 0.5]), null)
+  t.is(AABB.Intercept(box, [-5, 0.5, 0.5], [-4, 0.5, 0.5]), null)
+  t.is(Beam.intercept(box, [NaN, 0.5, 0.5], [0.5, 0.5, 0.5], 0.25), null)
+  t.is(Beam.intercept(box, [-5, 0.5, 0.5], [Infinity, 0.5, 0.5], 0.25), null)
+  t.deepEqual(Beam.pierce([NaN, 0.5, 0.5], [5, 0.5, 0.5], 0.25, [box]), [])
+})
+
+test('command coordinates resolve relative and local notation', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const parsed = Coordinates.parse('  ~ ~1.5\t-2 ')
+  t.deepEqual([parsed.x, parsed.y, parsed.z], [
+    { mode: CoordinateMode.Relative, value: 0 },
+    { mode: CoordinateMode.Relative, value: 1.5 },
+    { mode: CoordinateMode.Absolute, value: -2 }
+  ])
+  t.deepEqual(xyz(Coordinates.resolve(parsed, [10, 64, 10], new Vector2f(0, 0))), [10, 65.5, -2])
+
+  const facing = new Vector2f(0, 0)
+  t.deepEqual(xyz(Coordinates.resolve(Coordinates.parse('^ ^ ^3'), [0, 64, 0], facing)), [0, 64, 3])
+  t.deepEqual(xyz(Coordinates.resolve(Coordinates.parse('^1 ^ ^'), [0, 64, 0], facing)), [1, 64, 0])
+  t.deepEqual(xyz(Coordinates.resolve(Coordinates.parse('^ ^2 ^'), [0, 64, 0], new Vector2f(90, 0))), [0, 64, 2])
+  t.deepEqual(xyz(Coordinates.resolve(Coordinates.parse('^ ^ ^1'), [0, 0, 0], new Vector2f(0, 90))), [-1, 0, 0])
+
+  t.throws(() => Coordinates.parse('~ ~'))
+  t.throws(() => Coordinates.parse('1 2 3 4'))
+  t.throws(() => Coordinates.parse('1 two 3'))
+  t.throws(() => Coordinates.parse('NaN 0 0'))
+  t.throws(() => Coordinates.parse('~Infinity 0 0'))
+  t.throws(() => Coordinates.parse('1e400 0 0'))
+  t.throws(() => Coordinates.parse('^~ 0 0'))
+  t.throws(() => Coordinates.resolve(Coordinates.parse('^ ~ ^'), [0, 0, 0], facing))
+  t.throws(() => Coordinates.parse('~é 0 0'))
+})
+
+test('raycaster collects the blocks along a segment natively', (t) => {
+  const xyz = (blocks) => blocks.map((v) => [v.x, v.y, v.z])
+  t.deepEqual(xyz(Raycaster.traverseBlocksCollect([0.5, 0.5, 0.5], [3.5, 0.5, 0.5])), [[0, 0, 0], [1, 0, 0], [2, 0, 0], [3, 0, 0]])
+  t.deepEqual(xyz(Raycaster.traverseBlocksCollect([0.5, 0.5, 0.5], [3.5, 0.5, 0.5], 1.2)), [[0, 0, 0], [1, 0, 0]])
+  t.deepEqual(xyz(Raycaster.traverseBlocksCollect([0.5, 0.5, 0.5], [0.5, -1.5, 0.5], -3)), [])
+  t.deepEqual(xyz(Raycaster.traverseBlocksCollect([0.5, 0.5, 0.5], [0.5, -1.5, 0.5], Infinity)), [[0, 0, 0], [0, -1, 0], [0, -2, 0]])
+  t.deepEqual(Array.from(Raycaster.traverseBlocksPacked([0.5, 0.5, 0.5], [0.5, 0.5, 2.5])), [0, 0, 0, 0, 0, 1, 0, 0, 2])
+  t.deepEqual(Array.from(Raycaster.traverseBlocksPacked([1, 1, 1], [1, 1, 1])), [])
+
+  t.throws(() => Raycaster.traverseBlocksCollect([0, 0, 0], [Infinity, 0, 0]))
+  t.throws(() => Raycaster.traverseBlocksCollect([NaN, 0, 0], [1, 0, 0]))
+  t.throws(() => Raycaster.traverseBlocksCollect([0, 0, 0], [1, 0, 0], NaN))
+  t.throws(() => Raycaster.traverseBlocksPacked([0, 0, 0], [0, 0, -Infinity]))
+  t.throws(() => Raycaster.traverseBlocksPacked([0, 0, 0], [1e300, 0, 0]))
+  t.deepEqual(Raycaster.traverseBlocksCollect([0, 0, 0], [1e300, 0, 0], 2).length, 3)
+})
+
+test('axis angle rotations convert to quaternions and matrices', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const quarter = new AxisAngle([0, 2, 0], Math.PI / 2)
+  t.deepEqual(xyz(quarter.axis), [0, 1, 0])
+  t.deepEqual(xyz(quarter.rotate([1, 0, 0])), [0, 0, -1])
+  t.deepEqual(xyz(quarter.toMatrix3().transform([1, 0, 0])), [0, 0, -1])
+  t.deepEqual(xyz(quarter.inverse().rotate([1, 0, 0])), [0, 0, 1])
+
+  const back = AxisAngle.fromQuaternion(quarter.toQuaternion())
+  t.deepEqual(xyz(back.axis), [0, 1, 0])
+  t.true(near(back.angle, Math.PI / 2))
+  const matrix = AxisAngle.fromMatrix3(new AxisAngle([1, 0, 0], -0.5).toMatrix3())
+  t.deepEqual(xyz(matrix.axis), [-1, 0, 0])
+  t.true(near(matrix.angle, 0.5))
+
+  const huge = new AxisAngle([0, 1e200, 0], Math.PI / 2)
+  t.deepEqual(xyz(huge.axis), [0, 1, 0])
+  for (const axis of [[0, 0, 0], [0, Infinity, 0], [NaN, 1, 0]]) {
+    const none = new AxisAngle(axis, 1)
+    t.deepEqual([...xyz(none.axis), none.angle], [1, 0, 0, 0])
+  }
+  t.deepEqual(xyz(AxisAngle.fromQuaternion(new Quaternion(0, 0, 0, 0)).axis), [1, 0, 0])
+})
+
+test('matrix stacks compose nested transforms in local space', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const stack = new MatrixStack()
+  stack.translate([10, 0, 0])
+  stack.push()
+  stack.rotateAxis([0, 1, 0], Math.PI / 2)
+  stack.scale([2, 2, 2])
+  t.is(stack.depth(), 1)
+  t.deepEqual(xyz(stack.transformPoint([1, 0, 0])), [10, 0, -2])
+
+  const parts = stack.decompose()
+  t.deepEqual(xyz(parts.translation), [10, 0, 0])
+  t.deepEqual(xyz(parts.scale), [2, 2, 2])
+  t.deepEqual(xyz(parts.rotation.rotateVector([1, 0, 0])), [0, 0, -1])
+
+  stack.pop()
+  t.is(stack.depth(), 0)
+  t.deepEqual(xyz(stack.transformPoint([1, 0, 0])), [11, 0, 0])
+  t.throws(() => stack.pop())
+
+  stack.rotate(Quaternion.fromAxisAngle([0, 0, 1], Math.PI / 2))
+  stack.multiply(Matrix4.translation([1, 0, 0]))
+  t.deepEqual(xyz(stack.transformPoint([0, 0, 0])), [10, 1, 0])
+  stack.loadIdentity()
+  t.true(stack.current().equals(Matrix4.identity()))
+
+  const based = new MatrixStack(Matrix4.translation([0, 5, 0]))
+  based.rotateAxis([0, 1e200, 0], Math.PI)
+  t.deepEqual(xyz(based.transformPoint([1, 0, 0])), [-1, 5, 0])
+  based.rotateAxis([0, 0, 0], 1)
+  based.rotateAxis([Infinity, 0, 0], 1)
+  t.deepEqual(xyz(based.transformPoint([1, 0, 0])), [-1, 5, 0])
+  based.translate([NaN, 0, 0])
+  t.true(Number.isNaN(based.transformPoint([0, 0, 0]).x))
+})
+
+test('quaternions compose, interpolate and rotate vectors', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const xyzw = (q) => [q.x, q.y, q.z, q.w].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const yaw = Quaternion.fromAxisAngle([0, 1, 0], Math.PI / 2)
+  const pitch = Quaternion.fromAxisAngle([1, 0, 0], Math.PI / 2)
+  t.deepEqual(xyz(yaw.rotateVector([1, 0, 0])), [0, 0, -1])
+  t.deepEqual(xyz(yaw.multiply(pitch).rotateVector([0, 1, 0])), [1, 0, 0])
+  t.deepEqual(xyz(yaw.conjugate().rotateVector([0, 0, -1])), [1, 0, 0])
+  t.deepEqual(xyzw(Quaternion.fromMatrix3(yaw.toMatrix3())), xyzw(yaw))
+  t.deepEqual(xyzw(Quaternion.fromEuler(new Euler(Math.PI / 2, 0, 0, EulerOrder.XYZ))), xyzw(pitch))
+
+  const half = Quaternion.identity().slerp(yaw, 0.5)
+  t.deepEqual(xyzw(half), xyzw(Quaternion.fromAxisAngle([0, 1, 0], Math.PI / 4)))
+  const flipped = new Quaternion(-yaw.x, -yaw.y, -yaw.z, -yaw.w)
+  t.deepEqual(xyz(Quaternion.identity().slerp(flipped, 0.5).rotateVector([1, 0, 0])), xyz(half.rotateVector([1, 0, 0])))
+  t.true(near(new Quaternion(0, 3, 0, 4).length(), 5))
+  t.deepEqual(xyzw(new Quaternion(0, 3, 0, 4).normalize()), [0, 0.6, 0, 0.8])
+
+  const huge = new Quaternion(0, 1e200, 0, 1e200)
+  t.true(near(huge.length() / 1e200, Math.SQRT2))
+  t.deepEqual(xyz(huge.rotateVector([1, 0, 0])), [0, 0, -1])
+  t.deepEqual(xyz(huge.toMatrix3().transform([1, 0, 0])), [0, 0, -1])
+  for (const invalid of [new Quaternion(0, 0, 0, 0), new Quaternion(Infinity, 0, 0, 1), new Quaternion(NaN, 0, 0, 1)]) {
+    t.deepEqual(xyzw(invalid.normalize()), [0, 0, 0, 1])
+    t.deepEqual(xyz(invalid.rotateVector([1, 2, 3])), [1, 2, 3])
+  }
+  t.deepEqual(xyzw(Quaternion.identity().slerp(yaw, NaN)), [0, 0, 0, 1])
+})
+
+test('hermite curves and natural cubic splines pass through their keyframes', (t) => {
+  t.true(near(Interp.hermite(0, 0, 1, 0, 0.5), 0.5))
+  t.true(near(Interp.hermite(0, 1, 0, 1, 0.5), 0))
+  t.true(near(Interp.hermite(2, 5, 7, -1, 1), 7))
+
+  const spline = new CubicSpline([0, 1, 2], [0, 1, 0])
+  t.true(near(spline.evaluate(1), 1))
+  t.true(near(spline.evaluate(0.5), 0.6875))
+  t.true(near(spline.evaluate(1.5), 0.6875))
+  t.true(near(spline.derivative(1), 0))
+  t.is(spline.evaluate(-5), 0)
+  t.is(spline.evaluate(5), 0)
+  t.is(spline.derivative(5), 0)
+  t.true(near(new CubicSpline([0, 2], [1, 5]).evaluate(0.5), 2))
+  t.true(Number.isNaN(spline.evaluate(NaN)))
+
+  t.throws(() => new CubicSpline([0], [1]))
+  t.throws(() => new CubicSpline([0, 1], [1]))
+  t.throws(() => new CubicSpline([0, 0], [1, 2]))
+  t.throws(() => new CubicSpline([0, NaN, 2], [1, 2, 3]))
+  t.throws(() => new CubicSpline([0, 1, Infinity], [1, 2, 3]))
+  t.throws(() => new CubicSpline([0, 1, 2], [1, NaN, 3]))
+  t.throws(() => new CubicSpline([-1e308, 1e308], [1, 2]))
+})
+
+test('keyframe tracks interpolate, loop and hold', (t) => {
+  const hold = new NumberTrack([0, 1, 2], [1, 10, 4])
+  t.is(hold.duration(), 2)
+  t.true(near(hold.sample(0.5), 5.5))
+  t.is(hold.sample(-1), 1)
+  t.is(hold.sample(5), 4)
+  t.is(hold.sample(Infinity), 4)
+  t.is(hold.sample(-Infinity), 1)
+  t.is(hold.sample(NaN), 1)
+
+  const loop = new NumberTrack([0, 1, 2], [1, 10, 4], KeyframeInterpolation.Linear, PlaybackMode.Loop)
+  t.true(near(loop.sample(2.5), 5.5))
+  t.true(near(loop.sample(-0.5), 7))
+  t.is(loop.sample(Infinity), 1)
+  const pingPong = new NumberTrack([0, 1, 2], [1, 10, 4], KeyframeInterpolation.Linear, PlaybackMode.PingPong)
+  t.true(near(pingPong.sample(3), 10))
+  t.true(near(pingPong.sample(3.5), 5.5))
+  t.is(pingPong.sample(NaN), 1)
+
+  const step = new NumberTrack([0, 1, 2], [1, 10, 4], KeyframeInterpolation.Step)
+  t.is(step.sample(0.9), 1)
+  t.is(step.sample(1), 10)
+  t.true(near(new NumberTrack([0, 1, 2, 3], [0, 1, 2, 3], KeyframeInterpolation.CatmullRom).sample(1.5), 1.5))
+  t.is(new NumberTrack([4], [7]).sample(100), 7)
+
+  const vectors = new VectorTrack([0, 2], [[0, 0, 0], [2, 4, -2]]).sample(1)
+  t.deepEqual([vectors.x, vectors.y, vectors.z], [1, 2, -1])
+  const rotation = new QuaternionTrack([0, 1], [Quaternion.identity(), Quaternion.fromAxisAngle([0, 1, 0], Math.PI / 2)]).sample(0.5)
+  const expected = Quaternion.fromAxisAngle([0, 1, 0], Math.PI / 4)
+  t.true(near(rotation.y, expected.y) && near(rotation.w, expected.w))
+
+  t.throws(() => new NumberTrack([], []))
+  t.throws(() => new NumberTrack([0, 1], [1]))
+  t.throws(() => new NumberTrack([1, 1], [1, 2]))
+  t.throws(() => new NumberTrack([0, NaN], [1, 2]))
+  t.throws(() => new NumberTrack([0, Infinity], [1, 2]))
+  t.throws(() => new NumberTrack([-1e308, 1e308], [1, 2]))
+})
+
+test('voxel shapes combine boxes for raycasts and collisions', (t) => {
+  const corners = (aabb) => [aabb.min.x, aabb.min.y, aabb.min.z, aabb.max.x, aabb.max.y, aabb.max.z]
+  const stair = new VoxelShape([new AABB([0, 0, 0], [1, 0.5, 1]), new AABB([0, 0.5, 0.5], [1, 1, 1])])
+  t.false(stair.contains([0.5, 0.75, 0.25]))
+  t.true(stair.contains([0.5, 0.75, 0.75]))
+  t.deepEqual(corners(stair.bounds()), [0, 0, 0, 1, 1, 1])
+  t.true(VoxelShape.empty().isEmpty())
+  t.is(VoxelShape.empty().bounds(), null)
+  t.is(stair.union(VoxelShape.block()).boxes().length, 1)
+  t.is(VoxelShape.block().union(VoxelShape.block()).boxes().length, 1)
+  t.deepEqual(stair.intersect(VoxelShape.cuboid([0, 0, 0], [1, 1, 0.5])).boxes().map(corners), [[0, 0, 0, 1, 0.5, 0.5]])
+  t.true(stair.offset([3, 0, 0]).intersects(new AABB([3.5, 0.9, 0.9], [4, 2, 2])))
+
+  const hit = stair.raycast([0.5, 2, 0.25], [0.5, -1, 0.25])
+  t.true(near(hit.distance, 1.5))
+  t.is(hit.face, BlockFace.Up)
+  t.is(stair.raycast([2, 2, 2], [3, 3, 3]), null)
+
+  const onBack = stair.collide(new AABB([0.1, 1, 0.6], [0.4, 2, 0.9]), [0, -2, 0])
+  t.true(onBack.onGround && near(onBack.movement.y, 0))
+  const onSlab = stair.collide(new AABB([0.1, 1, 0.1], [0.4, 2, 0.4]), [0, -2, 0])
+  t.true(onSlab.onGround && near(onSlab.movement.y, -0.5))
+
+  const invalid = VoxelShape.cuboid([NaN, 0, 0], [1, 1, 1])
+  t.true(invalid.intersect(VoxelShape.block()).isEmpty())
+  t.true(VoxelShape.block().intersect(invalid).isEmpty())
+  t.false(stair.offset([NaN, 0, 0]).contains([0.5, 0.25, 0.5]))
+  t.is(stair.raycast([NaN, 2, 0.5], [0.5, -1, 0.5]), null)
+})
+
+test('platforms carry the entities standing on them', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const platform = new AABB([0, 0, 0], [2, 1, 2])
+  const rider = new AABB([0.5, 1, 0.5], [1.1, 2.8, 1.1])
+  const raised = new AABB([0, 1, 0], [2, 2, 2])
+
+  const lifted = Platform.carry(platform, raised, rider)
+  t.deepEqual(xyz(lifted.displacement), [0, 1, 0])
+  t.true(lifted.standing && lifted.supported)
+  const turned = Platform.carry(platform, platform, rider, 90)
+  t.deepEqual(xyz(turned.displacement), [0.4, 0, 0])
+  const slid = Platform.carry(platform, new AABB([5, 0, 0], [7, 1, 2]), rider)
+  t.deepEqual(xyz(slid.displacement), [5, 0, 0])
+  t.true(slid.supported)
+
+  const jumping = Platform.carry(platform, raised, new AABB([0.5, 1.2, 0.5], [1.1, 3, 1.1]))
+  t.deepEqual(xyz(jumping.displacement), [0, 0, 0])
+  t.false(jumping.standing || jumping.supported)
+  t.true(Platform.isStanding(platform, new AABB([0.5, 1.04, 0.5], [1.1, 2.8, 1.1])))
+  t.false(Platform.isStanding(platform, new AABB([2, 1, 0.5], [2.6, 2.8, 1.1])))
+
+  t.deepEqual(xyz(Platform.carry(platform, raised, rider, NaN).displacement), [0, 1, 0])
+  t.deepEqual(xyz(Platform.carry(platform, raised, rider, Infinity).displacement), [0, 1, 0])
+  t.false(Platform.carry(platform, raised, rider, 0, NaN).standing)
+  t.false(Platform.isStanding(platform, rider, NaN))
+  const lost = Platform.carry(platform, new AABB([NaN, 1, 0], [2, 2, 2]), rider)
+  t.true(lost.standing && !lost.supported)
+})
+
+test('spatial indexes find boxes by id near a box or a ray', (t) => {
+  const index = new SpatialIndex()
+  for (let id = 0; id < 40; id++) t.true(index.insert(id * 3, new AABB([id, 0, 0], [id + 1, 1, 1])))
+  t.is(index.size(), 40)
+  t.deepEqual(index.queryAABB(new AABB([10.5, 0.5, 0.5], [11.5, 2, 2])).sort((a, b) => a - b), [30, 33])
+  t.deepEqual(index.queryRay([2.5, 0.5, -1], [2.5, 0.5, 2]).map((hit) => hit.index), [6])
+  t.deepEqual(index.queryRay([-1, 0.5, 0.5], [2.5, 0.5, 0.5]).map((hit) => [hit.index, hit.distance]), [[0, 1], [3, 2], [6, 3]])
+
+  t.false(index.insert(30, new AABB([100, 0, 0], [101, 1, 1])))
+  t.deepEqual([index.get(30).min.x, index.has(30), index.size()], [100, true, 40])
+  t.true(index.update(33, new AABB([200, 0, 0], [201, 1, 1])))
+  t.false(index.update(1, new AABB([0, 0, 0], [1, 1, 1])))
+  t.deepEqual(index.queryAABB(new AABB([10.5, 0.5, 0.5], [11.5, 2, 2])), [])
+  t.true(index.remove(30))
+  t.false(index.remove(30))
+  t.is(index.get(30), null)
+
+  t.throws(() => index.insert(500, new AABB([NaN, 0, 0], [1, 1, 1])))
+  t.throws(() => index.update(0, new AABB([0, 0, 0], [1, 1, NaN])))
+  t.false(index.has(500))
+  t.deepEqual(index.queryRay([NaN, 0.5, 0.5], [2.5, 0.5, 0.5]), [])
+  t.throws(() => new SpatialIndex(-1))
+  t.throws(() => new SpatialIndex(NaN))
+
+  index.clear()
+  t.is(index.size(), 0)
+  t.deepEqual(index.queryAABB(new AABB([-1, -1, -1], [300, 2, 2])), [])
+})
+
+test('door sweeps find the first angle a swinging panel touches a box', (t) => {
+  const panel = { hinge: [0, 0, 0], width: 1, height: 2, thickness: 0.2 }
+  const box = new AABB([-0.8, 0, 0.3], [-0.5, 1.8, 0.6])
+  t.true(near(DoorSweep.contactAngle(panel, 0, 90, box), Math.atan2(0.4, 0.7) * 180 / Math.PI))
+  t.true(DoorSweep.contactAngle(panel, 90, 0, box) > 45)
+  t.true(DoorSweep.intersects(panel, 0, 90, box))
+  t.false(DoorSweep.intersects(panel, 0, 20, box))
+  t.is(DoorSweep.contactAngle(panel, 0, 90, new AABB([-0.1, 0, 0.5], [0.1, 1, 0.7])), 0)
+  t.is(DoorSweep.contactAngle(panel, 0, 90, new AABB([-0.8, 2, 0.3], [-0.5, 3, 0.6])), null)
+  t.true(near(DoorSweep.contactAngle(panel, 0, Infinity, new AABB([0.3, 0, -0.6], [0.6, 1.8, -0.3])), 195.94539590092288, 1e-6))
+
+  const bounds = DoorSweep.bounds(panel, 0, 90)
+  t.deepEqual([bounds.min.x, bounds.min.y, bounds.min.z, bounds.max.x, bounds.max.y, bounds.max.z].map((c) => Math.round(c * 1e9) / 1e9), [-1.1, 0, -0.1, 0.1, 2, 1.1])
+
+  t.is(DoorSweep.contactAngle(panel, NaN, 90, box), null)
+  t.is(DoorSweep.contactAngle(panel, Infinity, 90, box), null)
+  t.is(DoorSweep.contactAngle(panel, 0, NaN, box), null)
+  t.false(DoorSweep.intersects({ ...panel, width: NaN }, 0, 90, new AABB([-0.1, 0, 0.5], [0.1, 1, 0.7])))
+  t.false(DoorSweep.intersects({ ...panel, thickness: NaN }, 0, 90, box))
+  t.false(DoorSweep.intersects(panel, 0, 90, new AABB([NaN, 0, 0.3], [-0.5, 1.8, 0.6])))
+  t.true(Number.isNaN(DoorSweep.bounds(panel, NaN, 90).min.x))
+  t.true(Number.isNaN(DoorSweep.bounds(panel, 0, NaN).max.z))
+})
+
+test('integer block positions floor, offset and key exactly', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z]
+  t.deepEqual(xyz(Vector3i.fromVector3f([1.9, -0.1, -3])), [1, -1, -3])
+  t.deepEqual(xyz(Vector3i.fromVector3f([2147483647.5, -2147483648, 0])), [2147483647, -2147483648, 0])
+  t.deepEqual(xyz(new Vector3i(1, 2, 3).center()), [1.5, 2.5, 3.5])
+  t.deepEqual(xyz(new Vector3i(1, 2, 3).offset(BlockFace.North, 4)), [1, 2, -1])
+  t.deepEqual(new Vector3i(0, 0, 0).neighbors().map(xyz), [[0, -1, 0], [0, 1, 0], [0, 0, -1], [0, 0, 1], [-1, 0, 0], [1, 0, 0]])
+  t.is(new Vector3i(1, 2, 3).manhattanDistance(new Vector3i(-1, 0, 0)), 7)
+  t.is(new Vector3i(1, 2, 3).distanceSquared(new Vector3i(-1, 0, 0)), 17)
+  t.is(new Vector3i(2147483647, 0, 0).manhattanDistance(new Vector3i(-2147483648, 0, 0)), 4294967295)
+  t.deepEqual(xyz(new Vector3i(2147483647, 0, 0).add(new Vector3i(1, 0, 0))), [-2147483648, 0, 0])
+
+  const position = new Vector3i(-4, 70, 12)
+  t.is(position.key(), '-4,70,12')
+  t.true(Vector3i.fromKey(position.key()).equals(position))
+  t.true(Vector3i.fromKey(' -4, 70 ,12').equals(position))
+  t.is(new Vector3i(1, 2, 3).hashCode(), (2 + 3 * 31) * 31 + 1)
+
+  t.throws(() => Vector3i.fromKey('1,2'))
+  t.throws(() => Vector3i.fromKey('1,2,3.5'))
+  t.throws(() => Vector3i.fromKey('1,2,99999999999'))
+  t.throws(() => Vector3i.fromVector3f([NaN, 0, 0]))
+  t.throws(() => Vector3i.fromVector3f([0, 2147483648, 0]))
+  t.throws(() => Vector3i.fromVector3f([0, 0, -Infinity]))
+})
+
+test('explosions push and damage entities by distance and exposure', (t) => {
+  const xyz = (v) => [v.x, v.y, v.z].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const box = new AABB([2.7, 0, -0.3], [3.3, 1.8, 0.3])
+  const hit = Explosion.impulse(box, [0, 0, 0], 4, 1)
+  const length = Math.hypot(3, 1.53)
+  t.deepEqual(xyz(hit.velocity), xyz({ x: 0.625 * 3 / length, y: 0.625 * 1.53 / length, z: 0 }))
+  t.is(hit.damage, 29)
+  t.deepEqual(xyz(Explosion.impulse(box, [0, 0, 0], 4, 1, 1.53, 1).velocity), [0, 0, 0])
+  t.is(Explosion.impulse(box, [20, 0, 0], 4, 1).damage, 0)
+  t.is(Explosion.impulse(box, [0, 0, 0], 0, 1).damage, 0)
+
+  const batch = Explosion.impulseBatch(new Float64Array([2.7, 0, -0.3, 3.3, 1.8, 0.3]), [0, 0, 0], 4, new Float64Array([0.5]), [0.5])
+  t.true(near(batch[0], 0.3125 * 0.5 * 3 / length) && batch[2] === 0 && batch[3] === 12)
+  t.throws(() => Explosion.impulseBatch(new Float64Array(5), [0, 0, 0], 4, new Float64Array(1)))
+
+  t.is(Explosion.exposureSamples(box).length, 45 * 3)
+  t.is(Explosion.exposure(box, [0, 0.5, 0], () => false), 1)
+  t.is(Explosion.exposure(box, [0, 0.5, 0], () => true), 0)
+  t.is(Explosion.exposureSamples(new AABB([0, 0, 0], [NaN, 1, 1])).length, 0)
+
+  const nothing = (impulse) => [...xyz(impulse.velocity), impulse.damage]
+  t.deepEqual(nothing(Explosion.impulse(box, [0, 0, 0], NaN, 1)), [0, 0, 0, 0])
+  t.deepEqual(nothing(Explosion.impulse(box, [NaN, 0, 0], 4, 1)), [0, 0, 0, 0])
+  t.is(Explosion.impulse(box, [0, 0, 0], 4, NaN).damage, 1)
+  t.deepEqual(xyz(Explosion.impulse(box, [0, 0, 0], 4, 1, NaN, NaN).velocity), xyz(hit.velocity))
+  t.throws(() => Explosion.exposure(box, [Infinity, 0, 0], () => false))
+  t.throws(() => Explosion.exposure(box, [1e300, 0, 0], () => false))
+  t.throws(() => Explosion.exposureSamples(new AABB([0, 0, 0], [1e6, 1e6, 1e6])))
+})
+
+test('2d vectors rotate, cross and convert to the horizontal plane', (t) => {
+  const xy = (v) => [v.x, v.y].map((c) => Math.round(c * 1e9) / 1e9 + 0)
+  const a = new Vector2f(3, 4)
+  t.is(a.length(), 5)
+  t.is(a.squareLength(), 25)
+  t.deepEqual(xy(a.normalize()), [0.6, 0.8])
+  t.is(a.dot(new Vector2f(1, 1)), 7)
+  t.is(new Vector2f(1, 0).cross(new Vector2f(0, 1)), 1)
+  t.deepEqual(xy(new Vector2f(1, 0).rotate(Math.PI / 2)), [0, 1])
+  t.deepEqual(xy(a.perpendicular()), [-4, 3])
+  t.true(near(new Vector2f(0, -2).angle(), -Math.PI / 2))
+  t.deepEqual(xy(a.lerp(new Vector2f(5, 0), 0.5)), [4, 2])
+  t.is(a.distance(new Vector2f(0, 0)), 5)
+  t.deepEqual(xy(a.add(a).subtract(new Vector2f(1, 1)).multiply(2).negate()), [-10, -14])
+  t.deepEqual(xy(Vector2f.fromVector3f([1, 2, 3])), [1, 3])
+  const lifted = a.toVector3f(64)
+  t.deepEqual([lifted.x, lifted.y, lifted.z], [3, 64, 4])
+  t.true(a.equals(new Vector2f(3, 4)))
+
+  const huge = new Vector2f(3e200, 4e200)
+  t.true(near(huge.length() / 1e200, 5))
+  t.deepEqual(xy(huge.normalize()), [0.6, 0.8])
+  t.deepEqual(xy(new Vector2f(0, 0).normalize()), [0, 0])
+  t.deepEqual(xy(new Vector2f(Infinity, 1).normalize()), [0, 0])
+  t.deepEqual(xy(new Vector2f(NaN, 1).normalize()), [0, 0])
+})
+
+test('vector buffers apply bulk operations to packed triplets', (t) => {
+  const points = new Float64Array([3, 4, 0, 0, 0, 0, 1, 2, 2])
+  t.deepEqual(Array.from(VectorBuffer.addScalar(points, 1)), [4, 5, 1, 1, 1, 1, 2, 3, 3])
+  t.deepEqual(Array.from(VectorBuffer.addVector(points, [1, 0, -1])), [4, 4, -1, 1, 0, -1, 2, 2, 1])
+  t.deepEqual(Array.from(VectorBuffer.multiply(points, 2)), [6, 8, 0, 0, 0, 0, 2, 4, 4])
+  t.deepEqual(Array.from(VectorBuffer.lengths(points)), [5, 0, 3])
+  const distances = VectorBuffer.distanceTo(points, [3, 4, 0])
+  t.true(distances[0] === 0 && distances[1] === 5 && near(distances[2], Math.sqrt(12)))
+  t.deepEqual(Array.from(VectorBuffer.normalize(points)).map((c) => Math.round(c * 1e9) / 1e9), [0.6, 0.8, 0, 0, 0, 0, 1 / 3, 2 / 3, 2 / 3].map((c) => Math.round(c * 1e9) / 1e9))
+  t.deepEqual(Array.from(VectorBuffer.transform(points, Matrix4.translation([0, 10, 0]))), [3, 14, 0, 0, 10, 0, 1, 12, 2])
+  t.deepEqual(Array.from(points), [3, 4, 0, 0, 0, 0, 1, 2, 2])
+
+  const target = new Float64Array([1, 1, 1])
+  const same = VectorBuffer.multiply(target, 3, true)
+  t.deepEqual(Array.from(target), [3, 3, 3])
+  t.deepEqual(Array.from(same), [3, 3, 3])
+  t.throws(() => VectorBuffer.lengths(new Float64Array(4)))
+  t.throws(() => VectorBuffer.addScalar(new Float64Array(2), 1))
+
+  const extreme = new Float64Array([3e200, 4e200, 0, Infinity, 0, 0, NaN, 1, 0])
+  const normalized = Array.from(VectorBuffer.normalize(extreme))
+  t.deepEqual(normalized.slice(0, 6).map((c) => Math.round(c * 1e9) / 1e9), [0.6, 0.8, 0, Infinity, 0, 0])
+  t.true(Number.isNaN(normalized[6]) && normalized[7] === 1)
+  t.true(near(VectorBuffer.lengths(extreme)[0] / 1e200, 5))
+})
+
+test('line of sight trackers recheck the last blocker before traversing', (t) => {
+  const sight = new LineOfSight()
+  let wall = true
+  const isSolid = (position) => wall && position.x === 3 && position.y === 0
+  t.false(sight.check([0.5, 0.5, 0.5], [6.5, 0.5, 0.5], isSolid))
+  t.deepEqual([sight.blocker().x, sight.blocker().y, sight.blocker().z], [3, 0, 0])
+  t.false(sight.check([0.5, 0.6, 0.5], [6.5, 0.4, 0.5], isSolid))
+  t.deepEqual([sight.traversals(), sight.revalidations()], [1, 1])
+
+  t.true(sight.check([0.5, 1.5, 0.5], [6.5, 1.5, 0.5], isSolid))
+  t.is(sight.blocker(), null)
+  t.false(sight.check([0.5, 0.5, 0.5], [6.5, 0.5, 0.5], isSolid))
+  wall = false
+  t.true(sight.check([0.5, 0.5, 0.5], [6.5, 0.5, 0.5], isSolid))
+  t.deepEqual([sight.traversals(), sight.revalidations()], [4, 1])
+
+  wall = true
+  sight.check([0.5, 0.5, 0.5], [6.5, 0.5, 0.5], isSolid)
+  sight.reset()
+  t.is(sight.blocker(), null)
+  t.throws(() => sight.check([NaN, 0.5, 0.5], [6.5, 0.5, 0.5], isSolid))
+  t.throws(() => sight.check([0.5, 0.5, 0.5], [Infinity, 0.5, 0.5], isSolid))
+  t.throws(() => sight.check([0.5, 0.5, 0.5], [1e300, 0.5, 0.5], isSolid))
+  t.deepEqual([sight.traversals(), sight.revalidations()], [5, 1])
+})
+
+test('boxes combine into unions, intersections and merges', (t) => {
+  const corners = (aabb) => [aabb.min.x, aabb.min.y, aabb.min.z, aabb.max.x, aabb.max.y, aabb.max.z]
+  const a = new AABB([0, 0, 0], [2, 2, 2])
+  const b = new AABB([1, -1, 1], [3, 1, 4])
+  t.deepEqual(corners(a.union(b)), [0, -1, 0, 3, 2, 4])
+  t.deepEqual(corners(a.intersection(b)), [1, 0, 1, 2, 1, 2])
+  t.is(a.intersection(new AABB([2, 0, 0], [3, 2, 2])), null)
+  t.is(a.intersection(new AABB([5, 5, 5], [6, 6, 6])), null)
+  t.deepEqual(corners(AABB.merge([a, b, new AABB([-5, 0, 0], [-4, 1, 1])])), [-5, -1, 0, 3, 2, 4])
+  t.is(AABB.merge([]), null)
+
+  t.is(b.volume(), 2 * 2 * 3)
+  t.deepEqual([b.center().x, b.center().y, b.center().z], [2, 0, 2.5])
+  t.deepEqual([b.size().x, b.size().y, b.size().z], [2, 2, 3])
+  const points = a.corners().map((v) => [v.x, v.y, v.z])
+  t.is(points.length, 8)
+  t.deepEqual([points[0], points[1], points[2], points[4], points[7]], [[0, 0, 0], [2, 0, 0], [0, 2, 0], [0, 0, 2], [2, 2, 2]])
+
+  const invalid = new AABB([NaN, 0, 0], [1, 1, 1])
+  t.true(Number.isNaN(a.union(invalid).min.x))
+  t.true(Number.isNaN(invalid.union(a).min.x))
+  t.is(a.union(invalid).max.x, 2)
+  t.is(a.intersection(invalid), null)
+  t.is(invalid.intersection(a), null)
+  t.true(Number.isNaN(AABB.merge([a, invalid, b]).min.x))
+  t.true(Number.isNaN(new VoxelShape([a, invalid]).bounds().min.x))
+})
+
+test('sensors see targets in range, inside the cone and not occluded', (t) => {
+  const sensor = new Sensor(16, 90)
+  const target = new AABB(new Vector3f(-0.3, -1.62, 4.7), new Vector3f(0.3, 0.18, 5.3))
+  t.true(sensor.inView(new Vector3f(0, 1.62, 5)))
+  t.false(sensor.inView(new Vector3f(0, 1.62, -5)))
+  t.false(sensor.inView(new Vector3f(0, 1.62, 20)))
+  t.true(sensor.canSee(target, () => false))
+  t.false(sensor.canSee(target, (block) => block.z === 3))
+
+  t.false(sensor.inView(new Vector3f(NaN, 1.62, 5)))
+  sensor.range = NaN
+  t.false(sensor.inView(new Vector3f(0, 1.62, 5)))
+  t.false(sensor.canSee(target, () => false))
+
+  sensor.range = Infinity
+  sensor.fov = 360
+  const far = new AABB(new Vector3f(0, 0, Infinity), new Vector3f(1, 1, Infinity))
+  t.throws(() => sensor.canSee(far, () => false))
+})
+
+test('vibrations lose strength with distance and dampening blocks', (t) => {
+  t.is(Vibration.strength(0), 15)
+  t.is(Vibration.strength(4), 8)
+  t.is(Vibration.strength(8), 1)
+  t.is(Vibration.strength(9), 0)
+  t.is(Vibration.strength(8, 16), 8)
+  t.is(Vibration.strength(-4), 15)
+  t.is(Vibration.strength(NaN), 0)
+  t.is(Vibration.strength(4, NaN), 0)
+  t.is(Vibration.strength(4, 0), 0)
+
+  const source = new Vector3f(0.5, 0.5, 0.5)
+  const listener = new Vector3f(4.5, 0.5, 0.5)
+  const clear = Vibration.propagate(source, listener, () => false)
+  t.deepEqual([clear.distance, clear.dampeners, clear.effectiveDistance, clear.received, clear.strength], [4, 0, 4, true, 8])
+
+  const wool = (block) => block.x === 2
+  const occluded = Vibration.propagate(source, listener, wool)
+  t.deepEqual([occluded.dampeners, occluded.effectiveDistance, occluded.received, occluded.strength], [1, Infinity, false, 0])
+  const dampened = Vibration.propagate(source, listener, wool, 8, 2)
+  t.deepEqual([dampened.dampeners, dampened.effectiveDistance, dampened.strength], [1, 6, 4])
+
+  const far = Vibration.propagate(source, new Vector3f(20.5, 0.5, 0.5), () => t.fail())
+  t.false(far.received)
+  const lost = Vibration.propagate(source, new Vector3f(NaN, 0.5, 0.5), () => t.fail())
+  t.deepEqual([lost.received, lost.strength], [false, 0])
+  t.throws(() => Vibration.propagate(source, new Vector3f(Infinity, 0.5, 0.5), () => false, Infinity))
+})
+
+test('overlapping boxes separate along the axis of least penetration', (t) => {
+  const block = new AABB(new Vector3f(0, 0, 0), new Vector3f(1, 1, 1))
+  const entity = new AABB(new Vector3f(0.8, 0.5, 0.2), new Vector3f(1.4, 1.3, 0.8))
+  const penetration = entity.penetration(block)
+  t.is(penetration.axis, Axis.X)
+  t.true(near(penetration.depth, 0.2))
+  t.true(near(penetration.mtv.x, 0.2))
+  t.deepEqual([penetration.mtv.y, penetration.mtv.z], [0, 0])
+
+  const sinking = new AABB(new Vector3f(0.2, 0.9, 0.2), new Vector3f(0.8, 2.9, 0.8))
+  const up = sinking.penetration(block)
+  t.is(up.axis, Axis.Y)
+  t.true(near(up.mtv.y, 0.1))
+
+  t.is(block.penetration(new AABB(new Vector3f(1, 0, 0), new Vector3f(2, 1, 1))), null)
+  t.is(block.penetration(new AABB(new Vector3f(0.5, NaN, 0.5), new Vector3f(1.5, 0.5, 1.5))), null)
+  t.is(new AABB(new Vector3f(0.5, 0, NaN), new Vector3f(1.5, 1, 1.5)).penetration(block), null)
+})
+
+test('spheres, planes and triangles intersect line segments', (t) => {
+  const sphere = new Sphere([0, 0, 0], -1)
+  t.is(sphere.radius, 1)
+  t.true(sphere.contains([0, 1, 0]))
+  const sphereHit = sphere.raycast([-5, 0, 0], [5, 0, 0])
+  t.true(near(sphereHit.distance, 4))
+  t.deepEqual([sphereHit.normal.x, sphereHit.normal.y, sphereHit.normal.z], [-1, 0, 0])
+  t.is(sphereHit.face, BlockFace.West)
+  t.is(sphere.raycast([-5, 2, 0], [5, 2, 0]), null)
+  t.is(sphere.raycast([NaN, 0, 0], [5, 0, 0]), null)
+  t.false(sphere.intersectsAabb(new AABB([-0.5, NaN, -0.5], [0.5, 0.5, 0.5])))
+  t.false(new Sphere([0, 0, 0], NaN).contains([0, 0, 0]))
+
+  const plane = new Plane([0, 2, 0], -2)
+  t.deepEqual([plane.normal.x, plane.normal.y, plane.normal.z, plane.constant], [0, 1, 0, -1])
+  t.is(plane.distanceToPoint([3, 4, 5]), 3)
+  const projected = plane.projectPoint([3, 4, 5])
+  t.deepEqual([projected.x, projected.y, projected.z], [3, 1, 5])
+  const planeHit = plane.raycast([0, 3, 0], [0, -1, 0])
+  t.deepEqual([planeHit.distance, planeHit.position.y, planeHit.normal.y], [2, 1, 1])
+  t.is(plane.raycast([0, 3, 0], [5, 3, 0]), null)
+
+  const huge = new Plane([1e300, 0, 0], 1e300)
+  t.deepEqual([huge.normal.x, huge.constant], [1, 1])
+  for (const normal of [[0, 0, 0], [NaN, 0, 0], [Infinity, 0, 0]]) {
+    const fallback = Plane.fromPointNormal([0, 2, 0], normal)
+    t.deepEqual([fallback.normal.x, fallback.normal.y, fallback.normal.z, fallback.constant], [0, 1, 0, -2])
+    t.is(new Plane(normal, 1).normal.y, 1)
+  }
+  t.is(Plane.fromPoints([0, 0, 0], [1, 0, 0], [2, 0, 0]).normal.y, 1)
+
+  const triangle = new Triangle([0, 0, 0], [1, 0, 0], [0, 0, 1])
+  t.is(triangle.area(), 0.5)
+  t.is(triangle.normal().y, -1)
+  const triangleHit = triangle.raycast([0.2, 1, 0.2], [0.2, -1, 0.2])
+  t.deepEqual([triangleHit.distance, triangleHit.normal.y], [1, 1])
+  t.is(triangle.raycast([0.8, 1, 0.8], [0.8, -1, 0.8]), null)
+  t.is(triangle.raycast([0.2, NaN, 0.2], [0.2, -1, 0.2]), null)
+})
+
+test('trail maps decay scent and lead to the strongest neighbor', (t) => {
+  const trail = new TrailMap(0.5, 0.2)
+  trail.deposit([0.5, 64, 0.5], 1)
+  trail.deposit([1.5, 64, 0.5], 2)
+  trail.deposit([1.7, 64.2, 0.9], 1)
+  t.is(trail.size(), 2)
+  t.is(trail.sample([1, 64, 0]), 3)
+  t.is(trail.sample([5, 64, 5]), 0)
+
+  const next = trail.strongestNeighbor([0.5, 64, 0.5])
+  t.deepEqual([next.x, next.y, next.z], [1, 64, 0])
+  t.is(trail.strongestNeighbor([10, 64, 10]), null)
+
+  trail.decay()
+  t.deepEqual([trail.sample([0, 64, 0]), trail.sample([1, 64, 0])], [0.5, 1.5])
+  trail.decay()
+  trail.decay()
+  t.is(trail.size(), 1)
+  trail.clear()
+  t.is(trail.size(), 0)
+
+  t.is(new TrailMap(0.5, Infinity).threshold, 0.01)
+  t.is(new TrailMap(0.5, NaN).threshold, 0.01)
+  t.throws(() => trail.deposit([0, 64, 0], NaN))
+  t.throws(() => trail.deposit([0, 64, 0], Infinity))
+  t.throws(() => trail.deposit([NaN, 64, 0], 1))
+  t.throws(() => trail.sample([2 ** 31, 64, 0]))
+  t.throws(() => trail.strongestNeighbor([0, Infinity, 0]))
+  t.is(trail.size(), 0)
+
+  const edge = 2 ** 31 - 1
+  trail.deposit([edge - 1, 64, edge], 1)
+  const last = trail.strongestNeighbor([edge, 64, edge])
+  t.deepEqual([last.x, last.y, last.z], [edge - 1, 64, edge])
+  t.notThrows(() => trail.strongestNeighbor([-(2 ** 31), 64, -(2 ** 31)]))
+})
+
+test('frustums cull points, spheres and boxes outside their planes', (t) => {
+  const cube = Frustum.fromMatrix(Matrix4.identity())
+  const planes = cube.planes()
+  t.is(planes.length, 6)
+  t.deepEqual([planes[0].normal.x, planes[0].constant, planes[1].normal.x, planes[1].constant], [1, 1, -1, 1])
+
+  t.true(cube.containsPoint([0, 0, 0]))
+  t.true(cube.containsPoint([1, -1, 1]))
+  t.false(cube.containsPoint([1.5, 0, 0]))
+  t.false(cube.containsPoint([NaN, 0, 0]))
+
+  t.true(cube.containsSphere(new Sphere([1.5, 0, 0], 1)))
+  t.false(cube.containsSphere(new Sphere([2.5, 0, 0], 1)))
+  t.false(cube.containsSphere(new Sphere([0, 0, 0], NaN)))
+
+  t.true(cube.containsAABB(new AABB([0.5, 0.5, 0.5], [3, 3, 3])))
+  t.false(cube.containsAABB(new AABB([2, 0, 0], [3, 1, 1])))
+  t.false(cube.containsAABB(new AABB([0, NaN, 0], [1, 1, 1])))
+
+  const copy = new Frustum(planes)
+  t.true(copy.containsPoint([0.5, 0.5, 0.5]))
+  t.throws(() => new Frustum(planes.slice(0, 5)))
+
+  const broken = Frustum.fromMatrix(new Matrix4(new Array(16).fill(NaN)))
+  t.false(broken.containsPoint([0, 0, 0]))
+  t.true(broken.planes().every((plane) => plane.normal.y === 1))
+})
+
+test('formations place followers behind the leader and turn with its yaw', (t) => {
+  const xyz = (positions) => positions.map((p) => [p.x, p.y, p.z])
+  t.deepEqual(xyz(Formation.positions([0, 64, 0], 0, FormationKind.Line, 3, 2)), [[2, 64, -2], [0, 64, -2], [-2, 64, -2]])
+  t.deepEqual(xyz(Formation.positions([0, 64, 0], 0, FormationKind.Wedge, 3, 1)), [[-1, 64, -1], [1, 64, -1], [-2, 64, -2]])
+  t.deepEqual(xyz(Formation.positions([0, 64, 0], 0, FormationKind.Grid, 5, 1)).slice(3), [[0.5, 64, -2], [-0.5, 64, -2]])
+
+  const circle = Formation.positions([0, 64, 0], 0, FormationKind.Circle, 4, 1)
+  t.true(circle.every((p) => near(Math.hypot(p.x, p.z), 1)))
+
+  const west = Formation.positions([10, 64, 10], 90, FormationKind.Line, 1, 2)[0]
+  t.true(near(west.x, 12) && near(west.z, 10))
+  t.deepEqual(Formation.positions([0, 64, 0], 0, FormationKind.Grid, 0, 1), [])
+
+  t.throws(() => Formation.positions([NaN, 64, 0], 0, FormationKind.Line, 3, 2))
+  t.throws(() => Formation.positions([0, 64, 0], Infinity, FormationKind.Line, 3, 2))
+  t.throws(() => Formation.positions([0, 64, 0], 0, FormationKind.Circle, 3, NaN))
+})
+
+test('rotations convert to directions and back', (t) => {
+  const south = Rotation.directionFromRotation(0, 0)
+  t.deepEqual([south.x, south.y, south.z], [-0, -0, 1])
+  t.true(near(Rotation.directionFromRotation(0, 90).y, -1))
+
+  const down = Rotation.rotationFromDirection([0, -3, 0])
+  t.deepEqual([down.yaw, down.pitch], [0, 90])
+  t.deepEqual(Rotation.rotationFromDirection([0, 0, 0]), { yaw: 0, pitch: 0 })
+  const look = Rotation.lookAt([0, 64, 0], [1, 65, 1])
+  t.true(near(look.yaw, -45))
+  t.true(near(look.pitch, -Math.atan(1 / Math.SQRT2) * 180 / Math.PI))
+
+  const huge = Rotation.rotationFromDirection([1e200, -1e200, 0])
+  t.deepEqual([huge.yaw, huge.pitch], [-90, 45])
+  t.is(Rotation.rotationFromDirection([Infinity, 0, 0]).yaw, -90)
+  t.true(Number.isNaN(Rotation.rotationFromDirection([NaN, 0, 1]).yaw))
+
+  t.is(Rotation.wrapDegrees(180), -180)
+  t.is(Rotation.wrapDegrees(-190), 170)
+  t.is(Rotation.difference(350, 10), 20)
+  t.true(Number.isNaN(Rotation.wrapDegrees(Infinity)))
+})
+
+test('random generators reject seeds that are not 64-bit integers', (t) => {
+  t.is(new JavaRandom(-(2 ** 63)).nextInt(), new JavaRandom('-9223372036854775808').nextInt())
+  t.is(new JavaRandom(2 ** 53).nextLong(), new JavaRandom('9007199254740992').nextLong())
+  t.deepEqual(new JavaRandom(7).sample(3, 5).sort(), [0, 1, 2])
+
+  for (const seed of [NaN, Infinity, -Infinity, 1.5, 2 ** 63, '9223372036854775808', '']) {
+    t.throws(() => new JavaRandom(seed))
+    t.throws(() => new Xoroshiro128PlusPlus(seed))
+    t.throws(() => Xoroshiro128PlusPlus.fromState(seed, 1))
+  }
+
+  const random = new JavaRandom(1)
+  t.throws(() => random.setSeed(NaN))
+  t.is(random.nextInt(), new JavaRandom(1).nextInt())
+  t.throws(() => random.nextInt(2 ** 31))
+  t.throws(() => random.nextInt(NaN))
+})
@@ -3,132 +3,5106 @@
 
 /* auto-generated by NAPI-RS */
 
+export type VectorLike = { x: number, y: number, z: number } | [number, number, number]
+
 export const enum Axis {
   X = 0,
   Y = 1,
   Z = 2
 }
+/**
+ * Represents a position in spherical coordinates, with Y as the up axis.
+*/
+export interface Spherical {
+  radius: number
+  theta: number
+  phi: number
+}
+/**
+ * Represents a transform split into its translation, rotation and scale.
+*/
+export interface Decomposition {
+  translation: Vector3f
+  rotation: Quaternion
+  scale: Vector3f
+}
+export const enum ObstructionKind {
+  Entity = 0,
+  Block = 1
+}
+/**
+ * Represents the first thing a projectile would hit before its target.
+*/
+export interface Obstruction {
+  kind: ObstructionKind
+  index?: number
+  block?: Vector3f
+  position: Vector3f
+  tick: number
+}
+/**
+ * Represents the physics of a simulated projectile.
+*/
+export interface ProjectileOptions {
+  gravity?: number
+  drag?: number
+  maxTicks?: number
+}
+export const enum FormationKind {
+  Line = 0,
+  Wedge = 1,
+  Circle = 2,
+  Grid = 3
+}
+/**
+ * Represents a cell of a heat grid.
+*/
+export interface HeatCell {
+  x: number
+  z: number
+  heat: number
+}
+/**
+ * Represents the options of a path search.
+*/
+export interface PathOptions {
+  maxNodes?: number
+  influenceWeight?: number
+}
+/**
+ * Represents an orthonormal basis of directions relative to a facing.
+*/
+export interface BasisVectors {
+  right: Vector3f
+  up: Vector3f
+  forward: Vector3f
+}
+export const enum Projection {
+  Perspective = 0,
+  Orthographic = 1
+}
+/**
+ * Represents the size of the screen a camera renders to, in pixels.
+*/
+export interface Viewport {
+  width: number
+  height: number
+}
+/**
+ * Represents the outcome of a clustering.
+*/
+export interface ClusterResult {
+  assignments: Array<number>
+  centroids: Float64Array
+}
+/**
+ * Represents a vertical panel turning around a hinge, such as a door or a fence gate leaf.
+*/
+export interface DoorPanel {
+  hinge: Vector3f
+  width: number
+  height: number
+  thickness: number
+}
+/**
+ * Represents a ray with an origin and a normalized direction.
+*/
+export interface Ray {
+  origin: Vector3f
+  direction: Vector3f
+}
+/**
+ * The poses of an entity that change the height of its eyes.
+*/
+export const enum Pose {
+  Standing = 0,
+  Sneaking = 1,
+  Swimming = 2,
+  Crawling = 3,
+  Gliding = 4,
+  SpinAttack = 5,
+  Sleeping = 6
+}
+/**
+ * Represents the ray an entity looks along.
+*/
+export interface EyeRayResult {
+  origin: Vector3f
+  direction: Vector3f
+}
+/**
+ * The faces of a block or box, numbered like Bedrock's block face values.
+*/
+export const enum BlockFace {
+  Down = 0,
+  Up = 1,
+  North = 2,
+  South = 3,
+  West = 4,
+  East = 5
+}
 export interface HitResult {
   distance: number
   position: Vector3f
+  face: BlockFace
+  normal: Vector3f
+}
+/**
+ * Represents a hit against the shape of a block.
+*/
+export interface BlockHitResult {
+  block: Vector3f
+  position: Vector3f
+  distance: number
+  face: BlockFace
+}
+/**
+ * Represents a hit against one box of a list, keeping the index of the box.
+*/
+export interface IndexedHitResult {
+  index: number
+  distance: number
+  position: Vector3f
+  face: BlockFace
+}
+/**
+ * Represents the first contact of a box moving towards another.
+*/
+export interface SweepResult {
+  time: number
+  normal: Vector3f
+  displacement: Vector3f
+  face?: BlockFace
+}
+/**
+ * Represents the smallest movement that separates two overlapping boxes.
+*/
+export interface Penetration {
+  axis: Axis
+  depth: number
+  mtv: Vector3f
+}
+/**
+ * Represents a movement after it was clipped against colliders.
+*/
+export interface MovementResult {
+  movement: Vector3f
+  collidedX: boolean
+  collidedY: boolean
+  collidedZ: boolean
+  onGround: boolean
+}
+/**
+ * Represents two overlapping proxies, with the lower id first.
+*/
+export interface OverlapPair {
+  a: number
+  b: number
+}
+/**
+ * Represents the options of a visibility precomputation.
+*/
+export interface VisibilityOptions {
+  samples?: number
+  maxDepth?: number
+}
+export const enum AngleUnit {
+  Degrees = 0,
+  Radians = 1
+}
+/**
+ * How a command coordinate is interpreted.
+*/
+export const enum CoordinateMode {
+  Absolute = 0,
+  Relative = 1,
+  Local = 2
+}
+/**
+ * Represents a single command coordinate.
+*/
+export interface CoordinateValue {
+  mode: CoordinateMode
+  value: number
+}
+/**
+ * Represents the three coordinates of a command position.
+*/
+export interface CoordinateInput {
+  x: CoordinateValue
+  y: CoordinateValue
+  z: CoordinateValue
+}
+/**
+ * The order of the rotations of Euler angles. The order names intrinsic rotations,
+ * so XYZ rotates around X, then around the rotated Y, then around the twice rotated Z,
+ * resulting in the matrix Rx * Ry * Rz.
+*/
+export const enum EulerOrder {
+  XYZ = 0,
+  XZY = 1,
+  YXZ = 2,
+  YZX = 3,
+  ZXY = 4,
+  ZYX = 5
+}
+export const enum ModifierOperation {
+  Addition = 0,
+  MultiplyBase = 1,
+  MultiplyTotal = 2,
+  Cap = 3
+}
+/**
+ * Represents a modifier applied to an attribute.
+*/
+export interface AttributeModifier {
+  amount: number
+  operation: ModifierOperation
+}
+/**
+ * Modifiers applied to fall damage.
+*/
+export interface FallModifiers {
+  jumpBoost?: number
+  multiplier?: number
+  slowFalling?: boolean
+  safeDistance?: number
+}
+export const enum Difficulty {
+  Peaceful = 0,
+  Easy = 1,
+  Normal = 2,
+  Hard = 3
+}
+/**
+ * Represents the local (regional) difficulty at a position.
+*/
+export interface LocalDifficulty {
+  effective: number
+  clamped: number
+}
+/**
+ * Represents a level reached with an amount of experience.
+*/
+export interface LevelProgress {
+  level: number
+  progress: number
+  remaining: number
+}
+/**
+ * Represents the effect of an explosion on an entity.
+*/
+export interface ExplosionImpulse {
+  velocity: Vector3f
+  damage: number
+}
+export const enum FalloffKind {
+  Linear = 0,
+  Quadratic = 1,
+  Smooth = 2
+}
+export const enum HungerAction {
+  Swim = 0,
+  Sprint = 1,
+  Jump = 2,
+  SprintJump = 3,
+  Attack = 4,
+  Damage = 5,
+  BreakBlock = 6,
+  Regenerate = 7,
+  HungerEffect = 8
+}
+/**
+ * Represents the outcome of a hunger tick.
+*/
+export interface HungerTick {
+  heal: number
+  damage: number
+}
+export const enum Dimension {
+  Overworld = 0,
+  Nether = 1,
+  End = 2
+}
+export const enum ChunkLoadStatus {
+  EntityTicking = 0,
+  BlockTicking = 1,
+  Full = 2,
+  Border = 3,
+  Inaccessible = 4
+}
+/**
+ * Represents the ticket level of a chunk.
+*/
+export interface ChunkLevel {
+  x: number
+  z: number
+  level: number
+}
+/**
+ * Represents how a vibration reached a listener.
+*/
+export interface VibrationResult {
+  distance: number
+  dampeners: number
+  effectiveDistance: number
+  received: boolean
+  strength: number
+}
+export const enum KeyframeInterpolation {
+  Linear = 0,
+  Step = 1,
+  CatmullRom = 2
+}
+export const enum PlaybackMode {
+  Hold = 0,
+  Loop = 1,
+  PingPong = 2
+}
+/**
+ * Represents the movement updates of a batch of entities, ready to be written to MoveActorDelta packets.
+*/
+export interface MoveDeltaBatch {
+  flags: Array<number>
+  positions: Float64Array
+  deltas: Float64Array
+  rotations: Uint8Array
+}
+/**
+ * Represents the slice of noise an image is rendered from.
+*/
+export interface NoiseSlice {
+  axis?: Axis
+  depth?: number
+  min?: number
+  max?: number
+}
+/**
+ * Options controlling the shape of a noise worm.
+*/
+export interface WormOptions {
+  length: number
+  step?: number
+  radius?: number
+  radiusVariation?: number
+  turnStrength?: number
+  maxPitch?: number
+  frequency?: number
+}
+/**
+ * Represents the generated path of a worm: packed x, y, z node positions and a radius per node.
+*/
+export interface WormPath {
+  points: Float64Array
+  radii: Float64Array
+}
+/**
+ * Represents the outcome of a minimization.
+*/
+export interface MinimizeResult {
+  x: number
+  value: number
+  iterations: number
+}
+/**
+ * Where a boat is relative to water and ground, as determined by the caller from the blocks around it.
+*/
+export const enum BoatStatus {
+  InWater = 0,
+  UnderWater = 1,
+  UnderFlowingWater = 2,
+  OnLand = 3,
+  InAir = 4
+}
+/**
+ * The keys held by the player paddling a boat.
+*/
+export interface BoatInput {
+  forward?: boolean
+  backward?: boolean
+  left?: boolean
+  right?: boolean
+}
+/**
+ * Flags of an entity moving through water.
+*/
+export interface SwimOptions {
+  sprinting?: boolean
+  onGround?: boolean
+  depthStrider?: number
+  dolphinsGrace?: boolean
+  movementSpeed?: number
+  noGravity?: boolean
+}
+/**
+ * Represents the rail a minecart is on.
+*/
+export interface MinecartRail {
+  shape: RailShape
+  poweredRail?: boolean
+  active?: boolean
+}
+/**
+ * Flags of a minecart.
+*/
+export interface MinecartOptions {
+  occupied?: boolean
+  inWater?: boolean
+  onGround?: boolean
+}
+/**
+ * Represents the state of a minecart after a step.
+*/
+export interface MinecartStep {
+  position: Vector3f
+  velocity: Vector3f
+  onRail: boolean
+}
+/**
+ * Represents movement input relative to the facing of an entity, as Bedrock encodes it.
+ * Positive forward moves in the facing direction, positive strafe moves to the left.
+*/
+export interface MovementInput {
+  forward: number
+  strafe: number
+}
+/**
+ * Represents how a platform carries a rider during a step.
+*/
+export interface PlatformCarry {
+  displacement: Vector3f
+  standing: boolean
+  supported: boolean
+}
+/**
+ * The shapes a rail block can take, numbered like the rail_direction block state.
+*/
+export const enum RailShape {
+  NorthSouth = 0,
+  EastWest = 1,
+  AscendingEast = 2,
+  AscendingWest = 3,
+  AscendingNorth = 4,
+  AscendingSouth = 5,
+  SouthEast = 6,
+  SouthWest = 7,
+  NorthWest = 8,
+  NorthEast = 9
+}
+/**
+ * Represents a point on a rail path.
+*/
+export interface RailPoint {
+  position: Vector3f
+  direction: Vector3f
+}
+/**
+ * Represents a look rotation in the configured angle unit, degrees by default.
+*/
+export interface YawPitch {
+  yaw: number
+  pitch: number
+}
+/**
+ * Represents a structure selection as its minimum block and its size in blocks.
+*/
+export interface StructureRegion {
+  origin: Vector3f
+  size: Vector3f
+}
+/**
+ * Represents a volume of block ids together with its dimensions.
+*/
+export interface Volume {
+  data: Uint32Array
+  size: Vector3f
+}
+export const enum FitMode {
+  Contain = 0,
+  Cover = 1,
+  Stretch = 2
+}
+export declare class Vector2f {
+  x: number
+  y: number
+  constructor(x: number, y: number)
+  dot(other: Vector2f): number
+  length(): number
+  squareLength(): number
+  /**
+  * Returns the vector scaled to unit length, or a zero vector when it has no length
+  * or a length that is not finite.
+  */
+  normalize(): Vector2f
+  add(other: Vector2f): Vector2f
+  subtract(other: Vector2f): Vector2f
+  multiply(scalar: number): Vector2f
+  negate(): Vector2f
+  lerp(other: Vector2f, t: number): Vector2f
+  distance(other: Vector2f): number
+  /**
+  * Returns the z component of the 3D cross product, positive when other is counterclockwise from this vector.
+  */
+  cross(other: Vector2f): number
+  /**
+  * Rotates the vector counterclockwise, from +x towards +y.
+  *
+  * @param angle - The angle in radians.
+  */
+  rotate(angle: number): Vector2f
+  /**
+  * Returns the vector rotated by 90 degrees counterclockwise.
+  */
+  perpendicular(): Vector2f
+  /**
+  * Returns the angle of the vector from +x towards +y in radians (-PI..PI).
+  */
+  angle(): number
+  /**
+  * Returns the horizontal part of a vector, with x as x and z as y.
+  *
+  * @param v - The vector to flatten (Vector3f).
+  */
+  static fromVector3f(v: VectorLike): Vector2f
+  /**
+  * Lifts the vector into the horizontal plane, with x as x and y as z.
+  *
+  * @param y - The height of the result, defaults to 0.
+  */
+  toVector3f(y?: number | undefined | null): Vector3f
+  equals(other: Vector2f): boolean
 }
 export declare class Vector3f {
   x: number
   y: number
   z: number
   constructor(x: number, y: number, z: number)
-  dot(other: Vector3f): number
   length(): number
   squareLength(): number
-  normalize(): Vector3f
-  lerp(other: Vector3f, t: number): Vector3f
-  add(other: Vector3f): Vector3f
-  subtract(other: Vector3f): Vector3f
-  multiply(scalar: number): Vector3f
-  cross(other: Vector3f): Vector3f
   absolute(): Vector3f
-  distance(other: Vector3f): number
   floor(): Vector3f
   round(): Vector3f
   ceil(): Vector3f
-  slerp(other: Vector3f, t: number): Vector3f
-  equals(other: Vector3f): boolean
-  axis(axis: Axis): number
-}
-/**
- * Represents an Axis-Aligned Bounding Box (AABB) in 3D space.
- * An AABB is defined by its minimum and maximum corners.
-*/
-export declare class AABB {
-  min: Vector3f
-  max: Vector3f
+  slerp(other: VectorLike, t: number): Vector3f
   /**
-  * Constructs an AABB with specified minimum and maximum corners.
+  * Rotates the vector around an axis using Rodrigues' formula.
+  * The rotation is counter-clockwise when looking from the tip of the axis towards the origin.
   *
-  * @param min - The minimum corner of the AABB (Vector3f).
-  * @param max - The maximum corner of the AABB (Vector3f).
+  * @param axis - The axis to rotate around, normalized before use; a zero axis leaves the vector unchanged (Vector3f).
+  * @param angle - The angle in radians.
   */
-  constructor(min: Vector3f, max: Vector3f)
+  rotateAround(axis: VectorLike, angle: number): Vector3f
   /**
-  * Moves the AABB by a given position vector.
+  * Rotates the vector around the X axis.
+  * The rotation is counter-clockwise when looking from +X towards the origin.
   *
-  * @param position - The vector to move the AABB by (Vector3f).
-  * @return A new AABB moved by the position vector.
+  * @param angle - The angle in radians.
   */
-  translate(v: Vector3f): AABB
+  rotateX(angle: number): Vector3f
   /**
-  * Expands the AABB by a given vector. The expansion affects the min and max points
-  * depending on whether the expansion vector is positive or negative.
+  * Rotates the vector around the Y axis.
+  * The rotation is counter-clockwise when looking from +Y towards the origin.
   *
-  * @param position - The expansion vector (Vector3f).
-  * @return A new AABB expanded by the position vector.
+  * @param angle - The angle in radians.
   */
-  expand(v: Vector3f): AABB
+  rotateY(angle: number): Vector3f
   /**
-  * Checks if a given point is inside the AABB.
+  * Rotates the vector around the Z axis.
+  * The rotation is counter-clockwise when looking from +Z towards the origin.
   *
-  * @param position - The point to check (Vector3f).
-  * @return True if the point is inside the AABB; otherwise, false.
+  * @param angle - The angle in radians.
   */
-  contains(v: Vector3f): boolean
-  within(v: Vector3f): boolean
-  grow(growScale: number): AABB
-  intersects(aabb: AABB): boolean
+  rotateZ(angle: number): Vector3f
   /**
-  * Determines if a given value intersects a line segment defined by two vectors
-  * along a specified axis, and returns the intersection point if it falls within
-  * the segment bounds.
+  * Rotates the vector around the Y axis by an angle in degrees, like a yaw change.
+  * Positive angles turn clockwise when seen from above, matching Minecraft's yaw.
   *
-  * @param axis - The axis along which to check the intersection (Axis).
-  * @param vecA - The start of the line segment (Vector3f).
-  * @param vecB - The end of the line segment (Vector3f).
-  * @param value - The value to check for intersection.
-  * @return The intersection point as a Vector3f if it is valid; otherwise, undefined.
+  * @param degrees - The angle in degrees.
   */
-  static onLine(axis: Axis, vecA: Vector3f, vecB: Vector3f, value: number): Vector3f | null
+  rotateYaw(degrees: number): Vector3f
   /**
-  * Checks if a given vector is within the AABB bounds along specified axes.
+  * Converts spherical coordinates into a vector, with Y as the up axis.
   *
-  * @param axis - An array of axes to check against (Array<Axis>).
-  * @param vec - The vector to check (Vector3f).
-  * @return True if the vector is within the bounds on the specified axes; otherwise, false.
+  * @param radius - The distance from the origin.
+  * @param theta - The polar angle from +Y in radians.
+  * @param phi - The azimuth around Y from +X towards +Z in radians.
   */
-  withinAxis(axis: Array<Axis>, vector: Vector3f): boolean
+  static fromSpherical(radius: number, theta: number, phi: number): Vector3f
   /**
-  * Determines if a ray defined by a start and end vector intersects with the AABB.
-  * Returns the hit result with the intersection details.
-  *
-  * @param aabb - The AABB to check for intersection (AABB).
-  * @param start - The start point of the ray (Vector3f).
-  * @param end - The end point of the ray (Vector3f).
-  * @return A HitResult if an intersection is found; otherwise, undefined.
+  * Converts the vector into spherical coordinates, the inverse of fromSpherical.
+  * The zero vector has all angles at 0.
   */
-  static Intercept(aabb: AABB, start: Vector3f, end: Vector3f): HitResult | null
-}
-export declare class Raycaster {
+  toSpherical(): Spherical
   /**
-  * Traverses blocks along a line segment defined by start and end vectors.
-  * Applies a given condition function to each block and stops if the condition is met.
+  * Maps the direction of the vector onto an equirectangular image, as used for sky textures.
   *
-  * @param start - The starting point of the line segment (Vector3f).
-  * @param end - The ending point of the line segment (Vector3f).
-  * @param condition - A function that takes BlockCoordinates and returns a boolean,
-  *                    defining the condition to stop traversing when met.
+  * @return The texture coordinates (0..1), u following the azimuth and v going from +Y (0) to -Y (1).
   */
-  static transverseBlocks(start: Vector3f, end: Vector3f, condition: (position: Vector3f) => bool): void
+  toEquirectangular(): Vector2f
   /**
-  * Returns a Vector3f containing the sign of each component of the given vector.
+  * Converts equirectangular texture coordinates back into a unit direction.
   *
-  * @param vec - The vector to get the sign from.
-  * @return A new Vector3f with the sign of each component.
+  * @param uv - The texture coordinates (Vector2f).
   */
-  static sign(vector: Vector3f): Vector3f
-  static stepSize(step: Vector3f, direction: Vector3f): Vector3f
-  /**
-  * Calculates the distance from the given number to the next lower integer (boundary).
+  static fromEquirectangular(uv: Vector2f): Vector3f
+  dot(other: VectorLike): number
+  distance(other: VectorLike): number
+  normalize(out?: Vector3f): Vector3f
+  lerp(other: VectorLike, t: number, out?: Vector3f): Vector3f
+  add(other: VectorLike, out?: Vector3f): Vector3f
+  subtract(other: VectorLike, out?: Vector3f): Vector3f
+  multiply(scalar: number, out?: Vector3f): Vector3f
+  cross(other: VectorLike, out?: Vector3f): Vector3f
+  equals(other: VectorLike): boolean
+  axis(axis: Axis): number
+}
+/**
+ * Represents an integer block position. Unlike Vector3f, components are always whole numbers,
+ * so positions compare exactly and make stable map keys.
+*/
+export declare class Vector3i {
+  x: number
+  y: number
+  z: number
+  constructor(x: number, y: number, z: number)
+  /**
+  * Returns the block containing a position, flooring every component.
+  *
+  * @param v - The position (Vector3f).
+  * Throws if a component is NaN or the block is outside the range of a 32-bit integer.
+  */
+  static fromVector3f(v: VectorLike): Vector3i
+  /**
+  * Returns the unit offset pointing out of a face.
+  *
+  * @param face - The face (BlockFace).
+  */
+  static fromFace(face: BlockFace): Vector3i
+  /**
+  * Parses a key created by key.
+  *
+  * @param key - The key, formatted as "x,y,z".
+  */
+  static fromKey(key: string): Vector3i
+  toVector3f(): Vector3f
+  /**
+  * Returns the center of the block.
+  */
+  center(): Vector3f
+  add(other: Vector3i): Vector3i
+  subtract(other: Vector3i): Vector3i
+  multiply(scalar: number): Vector3i
+  negate(): Vector3i
+  /**
+  * Returns the position moved out of a face.
+  *
+  * @param face - The direction to move in (BlockFace).
+  * @param distance - The number of blocks to move, defaults to 1.
+  */
+  offset(face: BlockFace, distance?: number | undefined | null): Vector3i
+  /**
+  * Returns the six blocks sharing a face with this one, in BlockFace order.
+  */
+  neighbors(): Array<Vector3i>
+  manhattanDistance(other: Vector3i): number
+  distanceSquared(other: Vector3i): number
+  equals(other: Vector3i): boolean
+  /**
+  * Returns a string key identifying the position, for use in Map and Set.
+  */
+  key(): string
+  /**
+  * Returns a 32-bit hash of the position, using the same formula as Java's Vec3i.
+  */
+  hashCode(): number
+}
+/**
+ * Represents a 3x3 matrix, typically a rotation or a linear transform without translation.
+ * Elements are stored in column-major order, like Matrix4.
+*/
+export declare class Matrix3 {
+  /**
+  * Constructs a matrix from 9 column-major elements.
+  *
+  * @param elements - The matrix elements, defaults to the identity matrix.
+  */
+  constructor(elements?: Array<number> | undefined | null)
+  get elements(): Array<number>
+  /**
+  * Replaces the elements of the matrix.
+  *
+  * @param elements - The 9 column-major elements.
+  */
+  set elements(elements: Array<number>)
+  /**
+  * Creates the identity matrix.
+  */
+  static identity(): Matrix3
+  /**
+  * Creates a matrix from its three columns.
+  *
+  * @param x - The first column, the image of the X axis (Vector3f).
+  * @param y - The second column, the image of the Y axis (Vector3f).
+  * @param z - The third column, the image of the Z axis (Vector3f).
+  */
+  static fromColumns(x: VectorLike, y: VectorLike, z: VectorLike): Matrix3
+  /**
+  * Extracts the upper left 3x3 block of a 4x4 matrix.
+  *
+  * @param matrix - The matrix to extract from (Matrix4).
+  */
+  static fromMatrix4(matrix: Matrix4): Matrix3
+  /**
+  * Expands this matrix into a 4x4 matrix without translation.
+  */
+  toMatrix4(): Matrix4
+  /**
+  * Returns an element of the matrix.
+  *
+  * @param row - The row (0..3).
+  * @param column - The column (0..3).
+  * Throws if the row or column is out of range.
+  */
+  get(row: number, column: number): number
+  /**
+  * Multiplies this matrix with another matrix.
+  *
+  * @param other - The right hand side matrix (Matrix3).
+  * @return The product of both matrices.
+  */
+  multiply(other: Matrix3): Matrix3
+  /**
+  * Transforms a vector by this matrix.
+  *
+  * @param v - The vector to transform (Vector3f).
+  * @return The transformed vector.
+  */
+  transform(v: VectorLike): Vector3f
+  /**
+  * Returns the transpose of this matrix, which is the inverse of a rotation matrix.
+  */
+  transpose(): Matrix3
+  /**
+  * Calculates the determinant of this matrix.
+  */
+  determinant(): number
+  /**
+  * Calculates the inverse of this matrix.
+  *
+  * @return The inverse matrix; otherwise, undefined if the matrix is singular.
+  */
+  inverse(): Matrix3 | null
+  equals(other: Matrix3): boolean
+}
+/**
+ * Represents a 4x4 transformation matrix.
+ * Elements are stored in column-major order, matching WebGL and glTF.
+*/
+export declare class Matrix4 {
+  /**
+  * Constructs a matrix from 16 column-major elements.
+  *
+  * @param elements - The matrix elements, defaults to the identity matrix.
+  */
+  constructor(elements?: Array<number> | undefined | null)
+  get elements(): Array<number>
+  /**
+  * Replaces the elements of the matrix.
+  *
+  * @param elements - The 16 column-major elements.
+  */
+  set elements(elements: Array<number>)
+  /**
+  * Creates the identity matrix.
+  */
+  static identity(): Matrix4
+  /**
+  * Creates a translation matrix.
+  *
+  * @param v - The translation (Vector3f).
+  */
+  static translation(v: VectorLike): Matrix4
+  /**
+  * Creates a scale matrix.
+  *
+  * @param v - The scale along each axis (Vector3f).
+  */
+  static scaling(v: VectorLike): Matrix4
+  /**
+  * Creates a right-handed perspective projection matrix with a clip space depth of -1..1.
+  *
+  * @param fov - The vertical field of view in degrees.
+  * @param aspect - The viewport width divided by its height.
+  * @param near - The distance to the near clipping plane.
+  * @param far - The distance to the far clipping plane.
+  */
+  static perspective(fov: number, aspect: number, near: number, far: number): Matrix4
+  /**
+  * Creates a right-handed orthographic projection matrix with a clip space depth of -1..1.
+  */
+  static orthographic(left: number, right: number, bottom: number, top: number, near: number, far: number): Matrix4
+  /**
+  * Creates a view matrix for an eye looking at a target point.
+  *
+  * @param eye - The position of the eye (Vector3f).
+  * @param target - The point being looked at (Vector3f).
+  * @param up - The world up direction (Vector3f).
+  */
+  static lookAt(eye: VectorLike, target: VectorLike, up: VectorLike): Matrix4
+  /**
+  * Multiplies this matrix by another matrix (this * other).
+  *
+  * @param other - The right hand side matrix (Matrix4).
+  * @return The product of both matrices.
+  */
+  multiply(other: Matrix4): Matrix4
+  /**
+  * Transforms a point by this matrix, including translation and the perspective divide.
+  *
+  * @param v - The point to transform (Vector3f).
+  * @return The transformed point.
+  */
+  transformPoint(v: VectorLike): Vector3f
+  /**
+  * Transforms a direction by this matrix, ignoring translation.
+  *
+  * @param v - The direction to transform (Vector3f).
+  * @return The transformed direction.
+  */
+  transformDirection(v: VectorLike): Vector3f
+  /**
+  * Calculates the world bounds of a box transformed by this matrix, from all 8 transformed corners.
+  * The result encloses rotated or projected boxes so they can feed AABB based broad phases.
+  *
+  * @param aabb - The box to transform (AABB).
+  * @return The axis-aligned bounds of the transformed box, NaN along axes any corner is NaN on.
+  */
+  transformAabb(aabb: AABB): AABB
+  /**
+  * Returns the transpose of this matrix.
+  */
+  transpose(): Matrix4
+  /**
+  * Calculates the determinant of this matrix.
+  */
+  determinant(): number
+  /**
+  * Calculates the inverse of this matrix.
+  *
+  * @return The inverse matrix; otherwise, undefined if the matrix is singular.
+  */
+  inverse(): Matrix4 | null
+  /**
+  * Creates a matrix applying a scale, then a rotation, then a translation.
+  *
+  * @param translation - The translation (Vector3f).
+  * @param rotation - The rotation (Quaternion).
+  * @param scale - The scale along each axis (Vector3f).
+  */
+  static compose(translation: VectorLike, rotation: Quaternion, scale: VectorLike): Matrix4
+  /**
+  * Splits an affine matrix into translation, rotation and scale, the inverse of compose.
+  * A mirroring matrix results in a negative X scale. Shear cannot be represented and is lost.
+  * An axis scaled to zero is rebuilt from the other two, and with more the rotation is the identity.
+  *
+  * @return The translation, rotation and scale of the matrix.
+  */
+  decompose(): Decomposition
+  equals(other: Matrix4): boolean
+}
+/**
+ * A stack of transforms for building nested arrangements, such as display entities
+ * attached to each other. Every operation is applied in the local space of the current
+ * transform, like the matrix stacks of OpenGL and the Java renderer.
+*/
+export declare class MatrixStack {
+  /**
+  * Constructs a stack holding a single transform.
+  *
+  * @param base - The initial transform, defaults to the identity matrix (Matrix4).
+  */
+  constructor(base?: Matrix4 | undefined | null)
+  /**
+  * Saves the current transform, so it can be restored with pop.
+  */
+  push(): void
+  /**
+  * Restores the transform saved by the last push.
+  *
+  * @return An error when there is no saved transform to restore.
+  */
+  pop(): void
+  /**
+  * Returns the number of saved transforms, 0 when only the base transform is left.
+  */
+  depth(): number
+  /**
+  * Returns a copy of the current transform.
+  */
+  current(): Matrix4
+  /**
+  * Translates the current transform.
+  *
+  * @param v - The translation in local space (Vector3f).
+  */
+  translate(v: VectorLike): void
+  /**
+  * Rotates the current transform by a quaternion.
+  *
+  * @param rotation - The rotation (Quaternion).
+  */
+  rotate(rotation: Quaternion): void
+  /**
+  * Rotates the current transform around an axis.
+  *
+  * @param axis - The axis to rotate around (Vector3f).
+  * @param angle - The angle in radians, counterclockwise when looking down the axis.
+  */
+  rotateAxis(axis: VectorLike, angle: number): void
+  /**
+  * Scales the current transform.
+  *
+  * @param v - The scale on every local axis (Vector3f).
+  */
+  scale(v: VectorLike): void
+  /**
+  * Applies an arbitrary transform in the local space of the current transform.
+  *
+  * @param matrix - The transform to apply (Matrix4).
+  */
+  multiply(matrix: Matrix4): void
+  /**
+  * Replaces the current transform with the identity matrix, keeping saved transforms.
+  */
+  loadIdentity(): void
+  /**
+  * Transforms a point from local space into the space of the base transform.
+  *
+  * @param v - The point to transform (Vector3f).
+  */
+  transformPoint(v: VectorLike): Vector3f
+  /**
+  * Splits the current transform into its translation, rotation and scale,
+  * ready to be sent as the transformation of a display entity.
+  */
+  decompose(): Decomposition
+}
+/**
+ * Represents a rotation as a unit quaternion, with the vector part in x, y, z and the scalar part in w.
+*/
+export declare class Quaternion {
+  x: number
+  y: number
+  z: number
+  w: number
+  constructor(x: number, y: number, z: number, w: number)
+  /**
+  * Returns the quaternion of no rotation.
+  */
+  static identity(): Quaternion
+  /**
+  * Constructs a rotation from Euler angles, composing the rotation of every axis in the order of the angles.
+  *
+  * @param euler - The angles to convert (Euler).
+  */
+  static fromEuler(euler: Euler): Quaternion
+  /**
+  * Constructs a rotation around an axis.
+  *
+  * @param axis - The axis to rotate around, it does not need to be normalized (Vector3f).
+  * @param angle - The angle in radians, counterclockwise when looking down the axis.
+  */
+  static fromAxisAngle(axis: VectorLike, angle: number): Quaternion
+  /**
+  * Extracts the rotation of a rotation matrix, using the largest diagonal term for stability.
+  *
+  * @param matrix - The rotation matrix (Matrix3).
+  */
+  static fromMatrix3(matrix: Matrix3): Quaternion
+  /**
+  * Converts the rotation into a rotation matrix. The quaternion is normalized first.
+  */
+  toMatrix3(): Matrix3
+  /**
+  * Combines two rotations. The result applies the other rotation first, then this one,
+  * matching the order of matrix multiplication.
+  *
+  * @param other - The rotation applied first (Quaternion).
+  */
+  multiply(other: Quaternion): Quaternion
+  /**
+  * Spherically interpolates towards another rotation at a constant angular speed,
+  * taking the shortest path.
+  *
+  * @param other - The rotation to interpolate towards (Quaternion).
+  * @param t - The interpolation factor (0..1).
+  */
+  slerp(other: Quaternion, t: number): Quaternion
+  /**
+  * Returns the quaternion scaled to unit length, or the identity when it has no length
+  * or a length that is not finite.
+  */
+  normalize(): Quaternion
+  /**
+  * Returns the conjugate, which is the inverse rotation for unit quaternions.
+  */
+  conjugate(): Quaternion
+  /**
+  * Rotates a vector by the rotation. The quaternion is normalized first.
+  *
+  * @param v - The vector to rotate (Vector3f).
+  */
+  rotateVector(v: VectorLike): Vector3f
+  dot(other: Quaternion): number
+  length(): number
+  equals(other: Quaternion): boolean
+}
+/**
+ * Represents an axis aligned rectangle in 2D space.
+ * The rectangle is defined by its top left corner and its size, with y growing downwards.
+*/
+export declare class Rect {
+  x: number
+  y: number
+  width: number
+  height: number
+  constructor(x: number, y: number, width: number, height: number)
+  /**
+  * Constructs a rectangle from a position and a size.
+  *
+  * @param position - The top left corner (Vector2f).
+  * @param size - The width and height (Vector2f).
+  */
+  static fromPositionSize(position: Vector2f, size: Vector2f): Rect
+  position(): Vector2f
+  size(): Vector2f
+  center(): Vector2f
+  contains(point: Vector2f): boolean
+  intersects(other: Rect): boolean
+}
+/**
+ * A 2D rotation stored as a cosine and sine pair, like a unit complex number.
+ * Composing and applying rotations needs no trigonometry, so repeated rotations stay cheap and exact.
+*/
+export declare class Rot2 {
+  cos: number
+  sin: number
+  /**
+  * Constructs a rotation from a cosine and sine pair, normalized to unit length.
+  * A zero pair, or one that is not finite, results in the identity.
+  *
+  * @param cos - The cosine of the angle.
+  * @param sin - The sine of the angle.
+  */
+  constructor(cos: number, sin: number)
+  /**
+  * Returns the rotation by zero radians.
+  */
+  static identity(): Rot2
+  /**
+  * Constructs a rotation from an angle, counterclockwise from +X toward +Y.
+  *
+  * @param angle - The angle in radians.
+  */
+  static fromAngle(angle: number): Rot2
+  /**
+  * Constructs the rotation turning one direction onto another.
+  *
+  * @param from - The starting direction (Vector2f).
+  * @param to - The target direction (Vector2f).
+  */
+  static between(from: Vector2f, to: Vector2f): Rot2
+  /**
+  * Returns the angle of the rotation in radians (-PI..PI).
+  */
+  toAngle(): number
+  /**
+  * Combines two rotations, rotating by this rotation and then by the other.
+  *
+  * @param other - The rotation applied after this one (Rot2).
+  */
+  compose(other: Rot2): Rot2
+  /**
+  * Returns the rotation undoing this one.
+  */
+  inverse(): Rot2
+  /**
+  * Rotates a vector.
+  *
+  * @param vector - The vector to rotate (Vector2f).
+  */
+  rotate(vector: Vector2f): Vector2f
+  /**
+  * Rotates a vector by the inverse of this rotation.
+  *
+  * @param vector - The vector to rotate (Vector2f).
+  */
+  unrotate(vector: Vector2f): Vector2f
+  /**
+  * Interpolates between two rotations along the shorter arc.
+  *
+  * @param other - The target rotation (Rot2).
+  * @param t - The interpolation factor (0..1).
+  */
+  slerp(other: Rot2, t: number): Rot2
+  /**
+  * Renormalizes the pair, removing drift after many compositions.
+  */
+  normalize(): Rot2
+}
+/**
+ * Safety checks for ranged mobs, so they hold fire when an ally or a wall is in the way.
+*/
+export declare class LineOfFire {
+  /**
+  * Calculates the launch velocity that hits a target with the flatter of the two arcs,
+  * ignoring drag.
+  *
+  * @param start - The launch position (Vector3f).
+  * @param target - The position to hit (Vector3f).
+  * @param speed - The launch speed in blocks per tick.
+  * @param gravity - The gravity per tick, defaults to 0.05.
+  * @return The launch velocity; otherwise, undefined when the target is out of range or at the start, or the speed is not finite.
+  */
+  static launchVelocity(start: VectorLike, target: VectorLike, speed: number, gravity?: number | undefined | null): Vector3f | null
+  /**
+  * Simulates a projectile tick by tick and finds the first friendly box or blocked cell
+  * it passes through before reaching the target's horizontal distance.
+  *
+  * @param start - The launch position (Vector3f).
+  * @param target - The position aimed at (Vector3f).
+  * @param velocity - The launch velocity, see launchVelocity (Vector3f).
+  * @param friendlies - The boxes that must not be hit (Array<AABB>).
+  * @param blocked - Returns whether a block stops the projectile.
+  * @param options - The physics of the projectile (ProjectileOptions).
+  * @return The first obstruction; otherwise, undefined when the line of fire is clear.
+  * Throws if the velocity or gravity is not finite, or the drag is outside 0 to 1.
+  */
+  static check(start: VectorLike, target: VectorLike, velocity: VectorLike, friendlies: Array<AABB>, blocked: (position: Vector3f) => boolean, options?: ProjectileOptions | undefined | null): Obstruction | null
+}
+/**
+ * Slot positions for groups following a leader, such as pets, minions and parades.
+ * Formations are laid out behind the leader and turn with its yaw.
+*/
+export declare class Formation {
+  /**
+  * Computes the world positions of the followers of a leader.
+  *
+  * @param leader_pos - The position of the leader (Vector3f).
+  * @param leader_yaw - The yaw of the leader in degrees.
+  * @param kind - The shape of the formation (FormationKind).
+  * @param count - The number of followers.
+  * @param spacing - The distance between neighboring followers in blocks.
+  * @return The position of every follower, at the height of the leader.
+  * Throws if the position, yaw or spacing is not finite, or the followers do not fit in memory.
+  */
+  static positions(leaderPos: VectorLike, leaderYaw: number, kind: FormationKind, count: number, spacing: number): Array<Vector3f>
+}
+/**
+ * A sparse 2D grid accumulating heat per cell, chunk aligned by default.
+ * Useful for danger maps, player activity analytics and dynamic spawning.
+*/
+export declare class HeatGrid {
+  /**
+  * Constructs an empty heat grid.
+  *
+  * @param cell_size - The size of a cell in blocks, defaults to 16 (a chunk).
+  */
+  constructor(cellSize?: number | undefined | null)
+  get cellSize(): number
+  /**
+  * Changes the size of a cell, removing the heat of every cell since the old cells no longer line up.
+  *
+  * @param cell_size - The size of a cell in blocks, a finite number above 0.
+  */
+  set cellSize(cellSize: number)
+  /**
+  * Adds heat to the cell containing a position.
+  *
+  * @param position - The position to add heat at (Vector3f).
+  * @param amount - The heat to add.
+  */
+  add(position: VectorLike, amount: number): void
+  /**
+  * Multiplies the heat of every cell by a factor, usually once per tick.
+  * Cells that cool down to nothing are removed.
+  *
+  * @param factor - The factor to keep, such as 0.99 to lose 1% per call.
+  */
+  decay(factor: number): void
+  /**
+  * Returns the heat of the cell containing a position.
+  *
+  * @param position - The position to sample (Vector3f).
+  */
+  sample(position: VectorLike): number
+  /**
+  * Returns the hottest cell; otherwise, undefined when the grid is empty.
+  */
+  maxCell(): HeatCell | null
+  /**
+  * Returns the hottest cells, hottest first.
+  *
+  * @param count - The number of cells to return.
+  */
+  hottest(count: number): Array<HeatCell>
+  /**
+  * Returns the number of cells holding heat.
+  */
+  size(): number
+  /**
+  * Removes the heat of every cell.
+  */
+  clear(): void
+}
+/**
+ * A* path finding over face connected block cells.
+*/
+export declare class Pathfinder {
+  /**
+  * Finds the cheapest path between two blocks. Every step costs 1, plus the heat of the
+  * influence map at the cell stepped into times the influence weight, so mobs avoid recently
+  * dangerous areas without a custom cost function.
+  *
+  * @param start - The block to start from (Vector3f).
+  * @param goal - The block to reach (Vector3f).
+  * @param walkable - Returns whether an entity can stand in a block, called once per block.
+  * @param influence - The influence map adding cost per cell (HeatGrid).
+  * @param options - The limits and weights of the search (PathOptions).
+  * @return The blocks of the path from start to goal; otherwise, an empty array when the goal is unreachable.
+  * Throws if the influence weight is negative.
+  */
+  static find(start: VectorLike, goal: VectorLike, walkable: (position: Vector3f) => boolean, influence?: HeatGrid | undefined | null, options?: PathOptions | undefined | null): Array<Vector3f>
+}
+/**
+ * The perception of a mob, combining a view distance, a view cone and a line of sight check
+ * in one native call. The mob is posed with its feet position and rotation, and sees a target
+ * when the eyes or the center of the target are in range, inside the cone and not occluded.
+*/
+export declare class Sensor {
+  range: number
+  fov: number
+  eyeHeight: number
+  position: Vector3f
+  yaw: number
+  pitch: number
+  /**
+  * Constructs a sensor posed at the origin, facing south.
+  *
+  * @param range - The view distance in blocks.
+  * @param fov - The full angle of the view cone in degrees.
+  * @param eye_height - The height of the eyes above the feet, defaults to 1.62.
+  */
+  constructor(range: number, fov: number, eyeHeight?: number | undefined | null)
+  /**
+  * Moves and turns the mob the sensor belongs to.
+  *
+  * @param position - The feet position of the mob (Vector3f).
+  * @param yaw - The yaw of the mob in degrees.
+  * @param pitch - The pitch of the mob in degrees.
+  */
+  setPose(position: VectorLike, yaw: number, pitch: number): void
+  /**
+  * Returns the eye position of the mob.
+  */
+  eye(): Vector3f
+  /**
+  * Checks whether a point is within range and inside the view cone, without checking occlusion.
+  * Nothing is in view when the point, the pose or the range is NaN.
+  *
+  * @param point - The point to check (Vector3f).
+  */
+  inView(point: VectorLike): boolean
+  /**
+  * Checks whether the mob sees a target. The eyes and then the center of the target are checked,
+  * and the target is seen when either of them is in view and the line to it passes no occluding block.
+  *
+  * @param target_aabb - The hitbox of the target (AABB).
+  * @param is_occluding - Returns whether the block at a position blocks the view.
+  * @return Whether the target is seen.
+  * Throws if the line to a point in view is not finite or passes through more blocks than an array can hold.
+  */
+  canSee(targetAabb: AABB, isOccluding: (position: Vector3f) => boolean): boolean
+}
+/**
+ * A sparse 3D map of decaying scent per block, for tracking mobs that follow the trail
+ * a target left behind. Only blocks holding scent are stored.
+*/
+export declare class TrailMap {
+  /**
+  * Constructs an empty trail map.
+  *
+  * @param decay_factor - The fraction of the scent kept per decay, defaults to 0.95.
+  * @param threshold - The scent below which blocks are forgotten, defaults to 0.01 when not finite.
+  */
+  constructor(decayFactor?: number | undefined | null, threshold?: number | undefined | null)
+  get decayFactor(): number
+  /**
+  * Changes the fraction of the scent kept per decay.
+  *
+  * @param decay_factor - The fraction, between 0 and 1 so scent never grows.
+  */
+  set decayFactor(decayFactor: number)
+  get threshold(): number
+  /**
+  * Changes the scent below which blocks are forgotten, applied on the next decay.
+  *
+  * @param threshold - The scent, a finite number of at least 0.
+  */
+  set threshold(threshold: number)
+  /**
+  * Adds scent to the block containing a position, usually every tick for each target.
+  *
+  * @param position - The position of the target (Vector3f).
+  * @param strength - The scent to add.
+  * Throws if the position is not within the range of a block position or the strength is not finite.
+  */
+  deposit(position: VectorLike, strength: number): void
+  /**
+  * Returns the scent of the block containing a position.
+  *
+  * @param position - The position to sample (Vector3f).
+  * Throws if the position is not within the range of a block position.
+  */
+  sample(position: VectorLike): number
+  /**
+  * Finds the block with the strongest scent among the 26 blocks around the block containing
+  * a position, which is the next step for a mob following the trail. Blocks beyond the range
+  * of a block position are not considered.
+  *
+  * @param position - The position of the tracking mob (Vector3f).
+  * @return The strongest neighboring block; otherwise, undefined when none of them holds scent.
+  * Throws if the position is not within the range of a block position.
+  */
+  strongestNeighbor(position: VectorLike): Vector3i | null
+  /**
+  * Multiplies the scent of every block by the decay factor, usually once per tick.
+  * Blocks whose scent falls below the threshold are removed.
+  */
+  decay(): void
+  /**
+  * Returns the number of blocks holding scent.
+  */
+  size(): number
+  /**
+  * Removes the scent of every block.
+  */
+  clear(): void
+}
+/**
+ * Represents a rotation by an angle around an axis, as used by some Bedrock
+ * attachable and animation data.
+*/
+export declare class AxisAngle {
+  axis: Vector3f
+  angle: number
+  /**
+  * Constructs a rotation around an axis. A zero axis, or one that is not finite,
+  * results in no rotation around +X.
+  *
+  * @param axis - The axis, normalized before use (Vector3f).
+  * @param angle - The angle in radians.
+  */
+  constructor(axis: VectorLike, angle: number)
+  /**
+  * Extracts the axis and angle of a quaternion, with the angle in 0..PI.
+  *
+  * @param quaternion - The rotation, normalized before use (Quaternion).
+  */
+  static fromQuaternion(quaternion: Quaternion): AxisAngle
+  /**
+  * Extracts the axis and angle of a rotation matrix.
+  *
+  * @param matrix - The rotation matrix (Matrix3).
+  */
+  static fromMatrix3(matrix: Matrix3): AxisAngle
+  /**
+  * Converts the rotation into a quaternion.
+  */
+  toQuaternion(): Quaternion
+  /**
+  * Converts the rotation into a rotation matrix using Rodrigues' formula.
+  */
+  toMatrix3(): Matrix3
+  /**
+  * Rotates a vector around the axis.
+  *
+  * @param vector - The vector to rotate (Vector3f).
+  */
+  rotate(vector: VectorLike): Vector3f
+  /**
+  * Returns the rotation undoing this one.
+  */
+  inverse(): AxisAngle
+}
+/**
+ * Sound attenuation and listener space math matching vanilla's linear falloff,
+ * where a sound carries 16 blocks per unit of volume above 1.
+*/
+export declare class Audio {
+  /**
+  * Returns the distance a sound of some volume can be heard from.
+  *
+  * @param base_volume - The volume the sound is played with.
+  */
+  static range(baseVolume: number): number
+  /**
+  * Calculates the volume a listener hears a sound at, falling off linearly to zero at the range.
+  * Volumes above 1 only increase the range, never the loudness.
+  *
+  * @param listener - The position of the listener (Vector3f).
+  * @param source - The position of the sound (Vector3f).
+  * @param base_volume - The volume the sound is played with.
+  * @param range - The distance the sound carries, defaults to the range of the volume.
+  * @return The heard volume (0..1).
+  */
+  static volumeAt(listener: VectorLike, source: VectorLike, baseVolume: number, range?: number | undefined | null): number
+  /**
+  * Checks whether a listener is within the range of a sound.
+  *
+  * @param listener - The position of the listener (Vector3f).
+  * @param source - The position of the sound (Vector3f).
+  * @param base_volume - The volume the sound is played with.
+  * @param range - The distance the sound carries, defaults to the range of the volume.
+  */
+  static isAudible(listener: VectorLike, source: VectorLike, baseVolume: number, range?: number | undefined | null): boolean
+  /**
+  * Converts the position of a sound into the listener's space:
+  * x points to the listener's right, y up and z forward.
+  *
+  * @param listener - The position of the listener's head (Vector3f).
+  * @param yaw - The yaw of the listener in degrees.
+  * @param pitch - The pitch of the listener in degrees.
+  * @param source - The position of the sound (Vector3f).
+  */
+  static relativePosition(listener: VectorLike, yaw: number, pitch: number, source: VectorLike): Vector3f
+  /**
+  * Calculates a stereo panning hint for a sound, from -1 (fully left) to 1 (fully right).
+  * Only the yaw is considered, so sounds straight above or below are centered.
+  *
+  * @param listener - The position of the listener's head (Vector3f).
+  * @param yaw - The yaw of the listener in degrees.
+  * @param source - The position of the sound (Vector3f).
+  */
+  static pan(listener: VectorLike, yaw: number, source: VectorLike): number
+}
+/**
+ * Orthonormal bases for particle orientation, beam quads and local space offsets.
+ * The basis is built from the yaw and pitch of the facing rather than from cross products
+ * with a world up vector, so it stays well defined when looking straight up or down.
+ * Yaw 0 faces south (+Z) and increases clockwise, positive pitch looks down.
+*/
+export declare class Basis {
+  /**
+  * Builds the basis of a forward direction. Straight up or down the yaw is undefined,
+  * so the fallback yaw decides where right points. A zero direction, or one that is not
+  * finite, faces horizontally along the fallback yaw.
+  *
+  * @param forward - The forward direction, normalized before use (Vector3f).
+  * @param fallback_yaw - The yaw in degrees used for vertical directions, defaults to 0.
+  * @return The right, up and forward directions.
+  */
+  static fromForward(forward: VectorLike, fallbackYaw?: number | undefined | null): BasisVectors
+  /**
+  * Builds the basis of a rotation.
+  *
+  * @param rotation - The rotation in degrees, x being the pitch and y the yaw (Vector2f).
+  * @return The right, up and forward directions.
+  */
+  static fromRotation(rotation: Vector2f): BasisVectors
+  /**
+  * Converts an offset in a basis into a world offset.
+  *
+  * @param basis - The basis (BasisVectors).
+  * @param local - The offset along right (x), up (y) and forward (z) (Vector3f).
+  */
+  static toWorld(basis: BasisVectors, local: VectorLike): Vector3f
+}
+/**
+ * Bulk operations over vectors packed as x, y, z triplets in a Float64Array, so thousands of
+ * particle or entity positions are processed in a single native call. Operations return a new
+ * array, or write into the given array when in place is set.
+*/
+export declare class VectorBuffer {
+  /**
+  * Adds a number to every component.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @param scalar - The number to add.
+  * @param in_place - Whether to write into the given array, defaults to false.
+  */
+  static addScalar(points: Float64Array, scalar: number, inPlace?: boolean | undefined | null): Float64Array
+  /**
+  * Adds a vector to every vector.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @param offset - The vector to add (Vector3f).
+  * @param in_place - Whether to write into the given array, defaults to false.
+  */
+  static addVector(points: Float64Array, offset: VectorLike, inPlace?: boolean | undefined | null): Float64Array
+  /**
+  * Multiplies every vector by a number.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @param scalar - The number to multiply by.
+  * @param in_place - Whether to write into the given array, defaults to false.
+  */
+  static multiply(points: Float64Array, scalar: number, inPlace?: boolean | undefined | null): Float64Array
+  /**
+  * Scales every vector to unit length. Zero vectors, and vectors with a length that is not finite, are left unchanged.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @param in_place - Whether to write into the given array, defaults to false.
+  */
+  static normalize(points: Float64Array, inPlace?: boolean | undefined | null): Float64Array
+  /**
+  * Transforms every vector as a point by a matrix, including translation.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @param matrix - The transformation (Matrix4).
+  * @param in_place - Whether to write into the given array, defaults to false.
+  */
+  static transform(points: Float64Array, matrix: Matrix4, inPlace?: boolean | undefined | null): Float64Array
+  /**
+  * Computes the distance from every vector to a point.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @param target - The point to measure to (Vector3f).
+  * @return The distance of every vector, in order (Float64Array).
+  */
+  static distanceTo(points: Float64Array, target: VectorLike): Float64Array
+  /**
+  * Computes the length of every vector.
+  *
+  * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+  * @return The length of every vector, in order (Float64Array).
+  */
+  static lengths(points: Float64Array): Float64Array
+}
+/**
+ * Represents a camera positioned and rotated using Minecraft conventions.
+ * Yaw 0 faces south (+Z) and increases clockwise, positive pitch looks down.
+*/
+export declare class Camera {
+  position: Vector3f
+  yaw: number
+  pitch: number
+  fov: number
+  near: number
+  far: number
+  projection: Projection
+  /**
+  * Constructs a camera at the given position and rotation.
+  *
+  * @param position - The eye position (Vector3f).
+  * @param yaw - The yaw in degrees.
+  * @param pitch - The pitch in degrees.
+  * @param fov - The vertical field of view in degrees, defaults to 70.
+  * @param near - The near plane distance, defaults to 0.05.
+  * @param far - The far plane distance, defaults to 256.
+  * @param projection - The projection kind, defaults to perspective.
+  */
+  constructor(position: VectorLike, yaw: number, pitch: number, fov?: number | undefined | null, near?: number | undefined | null, far?: number | undefined | null, projection?: Projection | undefined | null)
+  /**
+  * Returns the normalized direction the camera is facing.
+  */
+  forward(): Vector3f
+  /**
+  * Returns the normalized up direction of the camera, perpendicular to forward.
+  */
+  up(): Vector3f
+  /**
+  * Returns the normalized right direction of the camera.
+  */
+  right(): Vector3f
+  /**
+  * Builds the world to view space matrix of the camera.
+  * The basis is derived from the rotation directly, so looking straight up or down is stable.
+  */
+  viewMatrix(): Matrix4
+  /**
+  * Builds the view to clip space matrix of the camera.
+  *
+  * @param aspect - The viewport width divided by its height.
+  */
+  projectionMatrix(aspect: number): Matrix4
+  /**
+  * Builds the combined world to clip space matrix (projection * view).
+  *
+  * @param aspect - The viewport width divided by its height.
+  */
+  viewProjectionMatrix(aspect: number): Matrix4
+  /**
+  * Builds the view frustum of the camera, for culling what it cannot see.
+  *
+  * @param aspect - The viewport width divided by its height.
+  */
+  frustum(aspect: number): Frustum
+  /**
+  * Projects a world position onto the screen.
+  * Screen coordinates start at the top left corner, the z component holds the depth (-1..1).
+  *
+  * @param point - The world position (Vector3f).
+  * @param viewport - The screen size in pixels (Viewport).
+  * @return The screen position; otherwise, undefined if the point is behind the camera.
+  */
+  worldToScreen(point: VectorLike, viewport: Viewport): Vector3f | null
+  /**
+  * Creates a world space ray passing through a screen position.
+  *
+  * @param x - The horizontal screen position in pixels.
+  * @param y - The vertical screen position in pixels, from the top.
+  * @param viewport - The screen size in pixels (Viewport).
+  * @return The ray starting at the near plane; otherwise, undefined if the projection cannot be inverted.
+  */
+  screenToRay(x: number, y: number, viewport: Viewport): Ray | null
+}
+/**
+ * Clustering of positions, for finding groups of players or mobs.
+*/
+export declare class Cluster {
+  /**
+  * Partitions points into k clusters with k-means, seeded with k-means++ so the
+  * result is deterministic for a given seed.
+  *
+  * @param points - The positions, packed as x, y, z triplets (Float64Array).
+  * @param k - The number of clusters, at most the number of points.
+  * @param max_iterations - The maximum number of refinement passes, defaults to 100.
+  * @param seed - The seed used to pick the initial centroids, defaults to 0.
+  * @return The cluster of every point and the centroids.
+  */
+  static kmeans(points: Float64Array, k: number, maxIterations?: number | undefined | null, seed?: number | undefined | null): ClusterResult
+  /**
+  * Groups points that lie densely together with DBSCAN. Points with fewer than min_points
+  * neighbors within the radius, that are not near such a point either, are noise.
+  *
+  * @param points - The positions, packed as x, y, z triplets (Float64Array).
+  * @param radius - The distance within which points are neighbors.
+  * @param min_points - The neighbors, including the point itself, needed to form a cluster.
+  * @return The cluster of every point, -1 for noise, and the centroids.
+  */
+  static dbscan(points: Float64Array, radius: number, minPoints: number): ClusterResult
+}
+/**
+ * Represents an Axis-Aligned Bounding Box (AABB) in 3D space.
+ * An AABB is defined by its minimum and maximum corners.
+*/
+export declare class AABB {
+  min: Vector3f
+  max: Vector3f
+  /**
+  * Constructs an AABB with specified minimum and maximum corners.
+  *
+  * @param min - The minimum corner of the AABB (Vector3f).
+  * @param max - The maximum corner of the AABB (Vector3f).
+  */
+  constructor(min: VectorLike, max: VectorLike)
+  /**
+  * Moves the AABB by a given position vector.
+  *
+  * @param position - The vector to move the AABB by (Vector3f).
+  * @return A new AABB moved by the position vector.
+  */
+  translate(v: VectorLike): AABB
+  /**
+  * Expands the AABB by a given vector. The expansion affects the min and max points
+  * depending on whether the expansion vector is positive or negative.
+  *
+  * @param position - The expansion vector (Vector3f).
+  * @return A new AABB expanded by the position vector.
+  */
+  expand(v: VectorLike): AABB
+  /**
+  * Checks if a given point is inside the AABB.
+  *
+  * @param position - The point to check (Vector3f).
+  * @return True if the point is inside the AABB; otherwise, false.
+  */
+  contains(v: VectorLike): boolean
+  within(v: VectorLike): boolean
+  grow(growScale: number): AABB
+  /**
+  * Computes the smallest AABB enclosing both this AABB and another.
+  *
+  * @param other - The other AABB (AABB).
+  * @return A new AABB enclosing both boxes, NaN along axes either box is NaN on.
+  */
+  union(other: AABB): AABB
+  /**
+  * Computes the overlapping region of this AABB and another.
+  *
+  * @param other - The other AABB (AABB).
+  * @return The overlapping AABB; otherwise, undefined when the boxes do not overlap, only touch or have NaN corners.
+  */
+  intersection(other: AABB): AABB | null
+  /**
+  * Computes the smallest AABB enclosing every given box.
+  *
+  * @param boxes - The boxes to enclose (AABB[]).
+  * @return The enclosing AABB, NaN along axes any box is NaN on; otherwise, undefined when no boxes are given.
+  */
+  static merge(boxes: Array<AABB>): AABB | null
+  /**
+  * Returns the volume of the AABB.
+  */
+  volume(): number
+  /**
+  * Returns the center point of the AABB.
+  */
+  center(): Vector3f
+  /**
+  * Returns the extent of the AABB along each axis.
+  */
+  size(): Vector3f
+  /**
+  * Returns the 8 corners of the AABB. Bit 0 of the index selects the max x, bit 1 the max y
+  * and bit 2 the max z, so the first corner is the minimum and the last is the maximum.
+  */
+  corners(): Array<Vector3f>
+  intersects(aabb: AABB): boolean
+  /**
+  * Computes how deep this AABB overlaps another, and the smallest movement that separates them,
+  * for pushing entities apart or moving an entity out of a block it is stuck in.
+  *
+  * @param other - The other AABB (AABB).
+  * @return The axis, depth and minimum translation vector; otherwise, undefined when the boxes do not intersect or have NaN corners.
+  */
+  penetration(other: AABB): Penetration | null
+  /**
+  * Determines if a given value intersects a line segment defined by two vectors
+  * along a specified axis, and returns the intersection point if it falls within
+  * the segment bounds.
+  *
+  * @param axis - The axis along which to check the intersection (Axis).
+  * @param vecA - The start of the line segment (Vector3f).
+  * @param vecB - The end of the line segment (Vector3f).
+  * @param value - The value to check for intersection.
+  * @return The intersection point as a Vector3f if it is valid; otherwise, undefined.
+  */
+  static onLine(axis: Axis, vecA: VectorLike, vecB: VectorLike, value: number): Vector3f | null
+  /**
+  * Checks if a given vector is within the AABB bounds along specified axes.
+  *
+  * @param axis - An array of axes to check against (Array<Axis>).
+  * @param vec - The vector to check (Vector3f).
+  * @return True if the vector is within the bounds on the specified axes; otherwise, false.
+  */
+  withinAxis(axis: Array<Axis>, vector: VectorLike): boolean
+  /**
+  * Determines if a ray defined by a start and end vector intersects with the AABB.
+  * Returns the hit result with the intersection details, where the distance is measured from the start.
+  *
+  * @param aabb - The AABB to check for intersection (AABB).
+  * @param start - The start point of the ray (Vector3f).
+  * @param end - The end point of the ray (Vector3f).
+  * @return A HitResult with the face that was entered if an intersection is found; otherwise, undefined.
+  */
+  static Intercept(aabb: AABB, start: VectorLike, end: VectorLike): HitResult | null
+  /**
+  * Computes when this AABB, moving with a velocity, first touches another moving AABB.
+  * Both boxes are in motion, so the test uses their relative velocity.
+  *
+  * @param my_velocity - The movement of this AABB during the step (Vector3f).
+  * @param other - The other AABB (AABB).
+  * @param other_velocity - The movement of the other AABB during the step (Vector3f).
+  * @return The time of impact, the normal of the face hit on the other AABB and the movement of this AABB until the contact; otherwise, undefined, also for velocities that are not finite.
+  */
+  sweepVsMoving(myVelocity: VectorLike, other: AABB, otherVelocity: VectorLike): SweepResult | null
+  /**
+  * Sweeps this AABB along a velocity against a stationary AABB, finding the first contact
+  * even when the movement would pass through the other box within a single step.
+  *
+  * @param other - The stationary AABB (AABB).
+  * @param velocity - The movement of this AABB during the step (Vector3f).
+  * @return The time of impact (0..1), the normal of the face hit and the movement until the contact; otherwise, undefined, also for velocities that are not finite.
+  */
+  sweep(other: AABB, velocity: VectorLike): SweepResult | null
+}
+/**
+ * Intersections of thick rays, where a sphere of some radius is swept along a line segment,
+ * for beam weapons and guardian-style attacks.
+*/
+export declare class Beam {
+  /**
+  * Sweeps a sphere along a line segment and finds where it first touches a box.
+  * Corners and edges of the box are rounded by the radius, like the real contact shape.
+  *
+  * @param aabb - The box to test against (AABB).
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param radius - The radius of the beam.
+  * @return The center of the sphere when it touches the box, the distance travelled and the face
+  * closest to the contact; otherwise, undefined, also for segments that are not finite.
+  */
+  static intercept(aabb: AABB, start: VectorLike, end: VectorLike, radius: number): HitResult | null
+  /**
+  * Sweeps a sphere along a line segment through every box it touches.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param radius - The radius of the beam.
+  * @param boxes - The boxes to test against (Array<AABB>).
+  * @param limit - The maximum number of hits to return, defaults to all of them.
+  * @return The hits sorted by distance from the start, with the index of the box that was hit.
+  */
+  static pierce(start: VectorLike, end: VectorLike, radius: number, boxes: Array<AABB>, limit?: number | undefined | null): Array<IndexedHitResult>
+}
+/**
+ * A small cache of block raycasts, for AI that repeats nearly identical line of sight checks
+ * every tick. Rays are keyed by their start and end snapped to a grid, so rays closer than the
+ * precision share a result, and results expire after a number of ticks so block changes are picked up.
+ * When the cache is full, the result closest to expiring makes room for the new one.
+*/
+export declare class RaycastCache {
+  /**
+  * Constructs an empty cache.
+  *
+  * @param capacity - The maximum number of cached rays, defaults to 256.
+  * @param precision - The grid size rays are snapped to in blocks, defaults to 0.25.
+  * @param ttl - The number of ticks a result stays valid, defaults to 20.
+  * Throws if the precision is not a finite number above 0.
+  */
+  constructor(capacity?: number | undefined | null, precision?: number | undefined | null, ttl?: number | undefined | null)
+  get precision(): number
+  /**
+  * Changes the grid size rays are snapped to, dropping every cached result since their keys no longer apply.
+  *
+  * @param precision - The grid size in blocks, a finite number above 0.
+  */
+  set precision(precision: number)
+  get ttl(): number
+  /**
+  * Changes the number of ticks a result stays valid, which also applies to the results already cached.
+  *
+  * @param ttl - The number of ticks.
+  */
+  set ttl(ttl: number)
+  /**
+  * Advances the clock of the cache, expiring results older than the time to live.
+  *
+  * @param ticks - The number of ticks that passed, defaults to 1.
+  */
+  advance(ticks?: number | undefined | null): void
+  /**
+  * Checks whether the line between two points passes no solid block, reusing a recent result
+  * of a nearby ray when there is one.
+  *
+  * @param start - The start of the line (Vector3f).
+  * @param end - The end of the line (Vector3f).
+  * @param is_solid - Returns whether the block at a position blocks the line of sight.
+  * Throws if the line is not finite or passes through more blocks than an array can hold.
+  */
+  lineOfSight(start: VectorLike, end: VectorLike, isSolid: (position: Vector3f) => boolean): boolean
+  /**
+  * Finds the first solid block along a line, reusing a recent result of a nearby ray when there is one.
+  *
+  * @param start - The start of the line (Vector3f).
+  * @param end - The end of the line (Vector3f).
+  * @param is_solid - Returns whether the block at a position blocks the line.
+  * @return The position of the first solid block; otherwise, undefined.
+  * Throws if the line is not finite or passes through more blocks than an array can hold.
+  */
+  firstBlock(start: VectorLike, end: VectorLike, isSolid: (position: Vector3f) => boolean): Vector3f | null
+  /**
+  * Removes every cached result, for example after a large block change.
+  */
+  clear(): void
+  /**
+  * Returns the number of cached rays.
+  */
+  size(): number
+  /**
+  * Returns the number of queries answered from the cache.
+  */
+  hits(): number
+  /**
+  * Returns the number of queries that had to be traced.
+  */
+  misses(): number
+}
+/**
+ * Collision of panels swinging between two angles. Angles are in degrees and follow entity yaw:
+ * at 0 the panel extends from the hinge towards south (+Z), and increasing angles turn it clockwise
+ * seen from above.
+*/
+export declare class DoorSweep {
+  /**
+  * Finds the first angle at which a swinging panel touches a box.
+  *
+  * @param panel - The panel (DoorPanel).
+  * @param from - The angle the swing starts at.
+  * @param to - The angle the swing ends at, a full turn or more sweeps every angle.
+  * @param aabb - The box to test, usually an entity hitbox (AABB).
+  * @return The angle of the first contact, the start angle when they already touch; otherwise, undefined,
+  *         also when the start angle is not finite or any other input is NaN.
+  */
+  static contactAngle(panel: DoorPanel, from: number, to: number, aabb: AABB): number | null
+  /**
+  * Checks whether a box is in the way of a swinging panel.
+  *
+  * @param panel - The panel (DoorPanel).
+  * @param from - The angle the swing starts at.
+  * @param to - The angle the swing ends at.
+  * @param aabb - The box to test, usually an entity hitbox (AABB).
+  * @return Whether the box is in the way, false when the start angle is not finite or any other input is NaN.
+  */
+  static intersects(panel: DoorPanel, from: number, to: number, aabb: AABB): boolean
+  /**
+  * Returns the box enclosing the region swept by a panel, useful to gather nearby entities first.
+  *
+  * @param panel - The panel (DoorPanel).
+  * @param from - The angle the swing starts at.
+  * @param to - The angle the swing ends at.
+  * @return The box, with NaN corners when the start angle is not finite or the end angle is NaN.
+  */
+  static bounds(panel: DoorPanel, from: number, to: number): AABB
+}
+export declare class Raycaster {
+  /**
+  * Traverses blocks along a line segment defined by start and end vectors.
+  * Applies a given condition function to each block and stops if the condition is met.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param condition - A function that takes BlockCoordinates and returns a boolean,
+  *                    defining the condition to stop traversing when met.
+  */
+  static transverseBlocks(start: VectorLike, end: VectorLike, condition: (position: Vector3f) => boolean): void
+  /**
+  * Traverses blocks along a line segment entirely in native code, collecting every block
+  * passed through instead of calling back into JavaScript for each one.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param max_distance - The maximum distance to traverse from the start, defaults to the whole segment.
+  * @return The positions of the blocks, in the order they are passed through.
+  * Throws if the segment is not finite, max_distance is NaN, or the segment passes through more blocks than an array can hold.
+  */
+  static traverseBlocksCollect(start: VectorLike, end: VectorLike, maxDistance?: number | undefined | null): Array<Vector3f>
+  /**
+  * Traverses blocks along a line segment like traverseBlocksCollect, returning the blocks packed
+  * in a typed array, which avoids creating an object per block.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param max_distance - The maximum distance to traverse from the start, defaults to the whole segment.
+  * @return The positions of the blocks, packed as x, y, z triplets (Float64Array).
+  * Throws if the segment is not finite, max_distance is NaN, or the segment passes through more blocks than an array can hold.
+  */
+  static traverseBlocksPacked(start: VectorLike, end: VectorLike, maxDistance?: number | undefined | null): Float64Array
+  /**
+  * Intersects a line segment with every box it passes through, for piercing projectiles and beams.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param boxes - The boxes to test against (Array<AABB>).
+  * @param limit - The maximum number of hits to return, defaults to all of them.
+  * @return The hits sorted by distance from the start, with the index of the box that was hit.
+  */
+  static pierce(start: VectorLike, end: VectorLike, boxes: Array<AABB>, limit?: number | undefined | null): Array<IndexedHitResult>
+  /**
+  * Returns a Vector3f containing the sign of each component of the given vector.
+  *
+  * @param vec - The vector to get the sign from.
+  * @return A new Vector3f with the sign of each component.
+  */
+  static sign(vector: VectorLike): Vector3f
+  static stepSize(step: VectorLike, direction: VectorLike): Vector3f
+  /**
+  * Calculates the distance from the given number to the next lower integer (boundary).
+  *
+  * @param number - The number to calculate the boundary for.
+  * @return The distance from the number to the nearest lower integer.
+  */
+  static boundary(n: number): number
+}
+/**
+ * The eye ray of an entity, so every raycast starts from identical eye math. The height uses
+ * the player's vanilla eye heights per pose and ignores view bobbing, which only exists on the client.
+*/
+export declare class EyeRay {
+  /**
+  * Computes the eye position and look direction of an entity.
+  *
+  * @param position - The feet position of the entity (Vector3f).
+  * @param pose - The pose of the entity (Pose).
+  * @param rotation - The rotation of the entity in degrees, x being the pitch and y the yaw (Vector2f).
+  * @param scale - The scale of the entity, multiplying the eye height, defaults to 1.
+  * @return The origin and direction of the eye ray.
+  */
+  static compute(position: VectorLike, pose: Pose, rotation: Vector2f, scale?: number | undefined | null): EyeRayResult
+  /**
+  * Returns the end point of the eye ray of an entity at a distance.
+  *
+  * @param position - The feet position of the entity (Vector3f).
+  * @param pose - The pose of the entity (Pose).
+  * @param rotation - The rotation of the entity in degrees, x being the pitch and y the yaw (Vector2f).
+  * @param distance - The length of the ray.
+  * @param scale - The scale of the entity, multiplying the eye height, defaults to 1.
+  */
+  static end(position: VectorLike, pose: Pose, rotation: Vector2f, distance: number, scale?: number | undefined | null): Vector3f
+  /**
+  * Returns the height of the eyes of a player above its feet in a pose.
+  *
+  * @param pose - The pose of the player (Pose).
+  */
+  static eyeHeight(pose: Pose): number
+}
+/**
+ * Represents a view volume bounded by 6 planes whose normals point inwards, for culling
+ * entities and chunks a client cannot see before sending them.
+*/
+export declare class Frustum {
+  /**
+  * Constructs a frustum from its planes, with normals pointing into the volume.
+  *
+  * @param planes - The 6 planes, ordered left, right, bottom, top, near and far (Array<Plane>).
+  */
+  constructor(planes: Array<Plane>)
+  /**
+  * Extracts the frustum of a view projection matrix with a clip space depth of -1..1,
+  * such as the one built by Camera.viewProjectionMatrix.
+  *
+  * @param matrix - The combined projection * view matrix (Matrix4).
+  */
+  static fromMatrix(matrix: Matrix4): Frustum
+  /**
+  * Returns the planes of the frustum, ordered left, right, bottom, top, near and far.
+  */
+  planes(): Array<Plane>
+  /**
+  * Checks if a point is inside the frustum.
+  *
+  * @param point - The point to check (Vector3f).
+  */
+  containsPoint(point: VectorLike): boolean
+  /**
+  * Checks if a sphere is at least partly inside the frustum. Like most culling tests it is
+  * conservative, and may accept spheres just outside a corner of the frustum.
+  *
+  * @param sphere - The sphere to check (Sphere).
+  */
+  containsSphere(sphere: Sphere): boolean
+  /**
+  * Checks if a box is at least partly inside the frustum, by testing the corner of the box
+  * furthest along the normal of every plane. Like most culling tests it is conservative,
+  * and may accept boxes just outside a corner of the frustum.
+  *
+  * @param aabb - The box to check (AABB).
+  */
+  containsAABB(aabb: AABB): boolean
+}
+/**
+ * A broad phase index of boxes keyed by caller chosen ids, such as entity runtime ids or packed
+ * block positions. Boxes are stored in a dynamic bounding volume tree, so box and ray queries
+ * only test the boxes near them.
+*/
+export declare class SpatialIndex {
+  /**
+  * Constructs an empty index.
+  *
+  * @param margin - The distance boxes are enlarged by in the tree, so small movements are cheap, defaults to 0.1.
+  * Throws if the margin is not a finite number of at least 0.
+  */
+  constructor(margin?: number | undefined | null)
+  /**
+  * Inserts a box, replacing the box already stored with the same id.
+  *
+  * @param id - The id of the box.
+  * @param aabb - The box (AABB).
+  * @return Whether the id was new.
+  * Throws if a corner of the box is NaN.
+  */
+  insert(id: number, aabb: AABB): boolean
+  /**
+  * Removes a box.
+  *
+  * @param id - The id of the box.
+  * @return Whether the id was in the index.
+  */
+  remove(id: number): boolean
+  /**
+  * Moves a box to new bounds.
+  *
+  * @param id - The id of the box.
+  * @param aabb - The new box (AABB).
+  * @return Whether the id was in the index.
+  * Throws if a corner of the box is NaN.
+  */
+  update(id: number, aabb: AABB): boolean
+  /**
+  * Returns the box stored for an id; otherwise, undefined.
+  *
+  * @param id - The id of the box.
+  */
+  get(id: number): AABB | null
+  /**
+  * Checks whether an id is in the index.
+  *
+  * @param id - The id of the box.
+  */
+  has(id: number): boolean
+  /**
+  * Returns the ids of every box overlapping a box.
+  *
+  * @param aabb - The box to query (AABB).
+  */
+  queryAABB(aabb: AABB): Array<number>
+  /**
+  * Finds every box a line segment enters, nearest first.
+  *
+  * @param start - The start point of the ray (Vector3f).
+  * @param end - The end point of the ray (Vector3f).
+  * @return The hits, with the id of the box hit as the index.
+  */
+  queryRay(start: VectorLike, end: VectorLike): Array<IndexedHitResult>
+  /**
+  * Returns the number of boxes in the index.
+  */
+  size(): number
+  /**
+  * Removes every box.
+  */
+  clear(): void
+}
+/**
+ * Ordering of collision candidates along a movement, so resolvers visit the boxes in the order
+ * they would be hit and skip boxes the movement can never reach.
+*/
+export declare class SweepOrder {
+  /**
+  * Sorts candidate boxes by the time a moving box first touches them. Boxes the movement does
+  * not reach are dropped, boxes already overlapping come first, and exact duplicates, such as
+  * the shared shapes of neighbouring blocks gathered twice, are kept only once.
+  *
+  * @param moving - The moving box (AABB).
+  * @param movement - The movement of the box during the step (Vector3f).
+  * @param candidates - The boxes that may be hit (Array<AABB>).
+  * @return The indices of the reachable candidates, in the order they are hit; none for movements that are not finite.
+  */
+  static sort(moving: AABB, movement: VectorLike, candidates: Array<AABB>): Array<number>
+  /**
+  * Calculates the time a moving box first touches another, as used for the ordering.
+  *
+  * @param moving - The moving box (AABB).
+  * @param movement - The movement of the box during the step (Vector3f).
+  * @param candidate - The box that may be hit (AABB).
+  * @return The fraction of the movement (0..1), 0 when already overlapping; otherwise, undefined, also for movements that are not finite.
+  */
+  static entryTime(moving: AABB, movement: VectorLike, candidate: AABB): number | null
+}
+/**
+ * Ray picking against rotated and scaled entity hitboxes, such as display entities.
+*/
+export declare class Picking {
+  /**
+  * Finds the first entity hitbox hit by a line segment. Every hitbox is a box centered on
+  * the local origin, placed in the world by its transform; the segment is moved into the
+  * local space of each box instead of transforming the boxes.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @param transforms - The local to world matrix of every entity, 16 column-major elements each (Float64Array).
+  * @param half_extents - The half size of every hitbox, packed as x, y, z triplets (Float64Array).
+  * @return The closest hit with the index of the entity and the face of its hitbox in local space; otherwise, undefined.
+  * Throws if the transform of an entity is singular, instead of silently missing it.
+  */
+  static raycastEntities(start: VectorLike, end: VectorLike, transforms: Float64Array, halfExtents: Float64Array): IndexedHitResult | null
+}
+/**
+ * Represents a sphere, for approximating round hitboxes and area of effect shapes.
+*/
+export declare class Sphere {
+  center: Vector3f
+  radius: number
+  /**
+  * Constructs a sphere.
+  *
+  * @param center - The center of the sphere (Vector3f).
+  * @param radius - The radius of the sphere.
+  */
+  constructor(center: VectorLike, radius: number)
+  /**
+  * Checks if a point is inside or on the sphere.
+  *
+  * @param point - The point to check (Vector3f).
+  */
+  contains(point: VectorLike): boolean
+  /**
+  * Checks if the sphere overlaps a box. A box with NaN corners overlaps nothing.
+  *
+  * @param aabb - The box to check (AABB).
+  */
+  intersectsAabb(aabb: AABB): boolean
+  /**
+  * Finds where a line segment first enters the sphere. A segment starting inside the sphere
+  * hits at its start, with the normal facing back along the segment.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @return The hit, with the distance measured from the start; otherwise, undefined.
+  */
+  raycast(start: VectorLike, end: VectorLike): HitResult | null
+}
+/**
+ * Represents an infinite plane of the points p where normal · p + constant = 0.
+ * The normal is kept at unit length, so the signed distance of a point is normal · p + constant.
+*/
+export declare class Plane {
+  normal: Vector3f
+  constant: number
+  /**
+  * Constructs a plane from a normal and a constant. The normal does not need to be normalized,
+  * the constant is scaled along with it. A zero or non-finite normal faces up.
+  *
+  * @param normal - The normal of the plane (Vector3f).
+  * @param constant - The constant of the plane equation.
+  */
+  constructor(normal: VectorLike, constant: number)
+  /**
+  * Constructs the plane through a point with a normal. A zero or non-finite normal faces up.
+  *
+  * @param point - A point on the plane (Vector3f).
+  * @param normal - The normal of the plane (Vector3f).
+  */
+  static fromPointNormal(point: VectorLike, normal: VectorLike): Plane
+  /**
+  * Constructs the plane through three points, with the normal facing the side the points
+  * wind counterclockwise from. Points on one line give a plane facing up.
+  *
+  * @param a - The first point (Vector3f).
+  * @param b - The second point (Vector3f).
+  * @param c - The third point (Vector3f).
+  */
+  static fromPoints(a: VectorLike, b: VectorLike, c: VectorLike): Plane
+  /**
+  * Returns the signed distance from the plane to a point, positive on the side the normal points to.
+  *
+  * @param point - The point to measure (Vector3f).
+  */
+  distanceToPoint(point: VectorLike): number
+  /**
+  * Returns the closest point on the plane to a point.
+  *
+  * @param point - The point to project (Vector3f).
+  */
+  projectPoint(point: VectorLike): Vector3f
+  /**
+  * Finds where a line segment crosses the plane, from either side.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @return The hit, with the distance measured from the start; otherwise, undefined.
+  */
+  raycast(start: VectorLike, end: VectorLike): HitResult | null
+}
+/**
+ * Represents a triangle, for custom geometry such as entity models and slopes.
+*/
+export declare class Triangle {
+  a: Vector3f
+  b: Vector3f
+  c: Vector3f
+  /**
+  * Constructs a triangle from its corners.
+  *
+  * @param a - The first corner (Vector3f).
+  * @param b - The second corner (Vector3f).
+  * @param c - The third corner (Vector3f).
+  */
+  constructor(a: VectorLike, b: VectorLike, c: VectorLike)
+  /**
+  * Returns the unit normal of the triangle, facing the side the corners wind counterclockwise from.
+  */
+  normal(): Vector3f
+  /**
+  * Returns the area of the triangle.
+  */
+  area(): number
+  /**
+  * Finds where a line segment crosses the triangle, from either side, using the
+  * Möller–Trumbore algorithm.
+  *
+  * @param start - The starting point of the line segment (Vector3f).
+  * @param end - The ending point of the line segment (Vector3f).
+  * @return The hit, with the distance measured from the start; otherwise, undefined.
+  */
+  raycast(start: VectorLike, end: VectorLike): HitResult | null
+}
+/**
+ * A sweep and prune broad phase over packed boxes. The endpoints of every box along one axis
+ * are kept sorted between ticks, so coherent motion only needs a few swaps to re-sort.
+*/
+export declare class SweepAndPrune {
+  axis: Axis
+  /**
+  * Constructs an empty broad phase.
+  *
+  * @param axis - The axis to sort along, ideally the one entities are most spread on, defaults to X (Axis).
+  */
+  constructor(axis?: Axis | undefined | null)
+  /**
+  * Updates the boxes and returns every overlapping pair. Boxes keep their index between calls;
+  * when the number of boxes or the axis changes the endpoints are rebuilt and fully sorted.
+  *
+  * @param boxes - The boxes, packed as minX, minY, minZ, maxX, maxY, maxZ (Float64Array).
+  * @return The overlapping pairs of box indices, lower index first.
+  */
+  update(boxes: Float64Array): Array<OverlapPair>
+  /**
+  * Returns the number of boxes of the last update.
+  */
+  size(): number
+}
+/**
+ * Resolves entity movement against block and entity colliders the way vanilla entity physics does.
+*/
+export declare class CollisionResolver {
+  /**
+  * Clips a movement against colliders one axis at a time: Y first, then the larger of X and Z,
+  * then the other. Each axis is moved as far as possible before the next is resolved, which
+  * lets entities slide along walls and land on floors.
+  *
+  * @param entity_box - The hitbox of the entity before moving (AABB).
+  * @param desired - The movement the entity attempts (Vector3f).
+  * @param colliders - The boxes the entity collides with, typically gathered around the expanded hitbox (Array<AABB>).
+  * @return The clipped movement and the axes that collided. A NaN movement along an axis is stopped, as if it collided.
+  */
+  static resolveMovement(entityBox: AABB, desired: VectorLike, colliders: Array<AABB>): MovementResult
+  /**
+  * Clips the movement of a box along a single axis against colliders.
+  *
+  * @param entity_box - The box before moving (AABB).
+  * @param axis - The axis of the movement (Axis).
+  * @param distance - The signed distance to move along the axis.
+  * @param colliders - The boxes to collide with (Array<AABB>).
+  * @return The signed distance that can be moved.
+  */
+  static clipAxis(entityBox: AABB, axis: Axis, distance: number, colliders: Array<AABB>): number
+}
+/**
+ * Block targeting that reproduces the client's picking rules, so the server agrees
+ * with the outline players see.
+*/
+export declare class Selection {
+  /**
+  * Picks the block an entity is looking at. Blocks are visited in the order the eye ray
+  * passes through them, and the first block whose outline shape is hit is selected.
+  *
+  * The lookup returns the outline shape of a block as boxes relative to the block, or an empty
+  * array for blocks that cannot be targeted. It is told whether liquids are selectable, and should
+  * then return the shape of the fluid (its height) for fluid blocks, like the client does when
+  * holding a bucket.
+  *
+  * @param origin - The eye position of the entity (Vector3f).
+  * @param rotation - The rotation of the entity in degrees, x being the pitch and y the yaw (Vector2f).
+  * @param reach - The maximum distance to pick at.
+  * @param liquids - Whether liquid blocks can be selected.
+  * @param block_shape_lookup - Returns the outline boxes of the block at a position.
+  * @return The block hit and where its outline was hit; otherwise, undefined.
+  * Throws if the reach is negative or not finite.
+  */
+  static pickBlock(origin: VectorLike, rotation: Vector2f, reach: number, liquids: boolean, blockShapeLookup: (position: Vector3f, liquids: boolean) => Array<AABB>): BlockHitResult | null
+}
+/**
+ * Represents the shape of a block as a union of boxes, such as the two boxes of a stair
+ * or the post and arms of a fence. Boxes are in block space, a full block spanning 0..1.
+*/
+export declare class VoxelShape {
+  /**
+  * Constructs a shape from boxes.
+  *
+  * @param boxes - The boxes of the shape, defaults to an empty shape (Array<AABB>).
+  */
+  constructor(boxes?: Array<AABB> | undefined | null)
+  /**
+  * Returns a shape without any boxes, such as the shape of air.
+  */
+  static empty(): VoxelShape
+  /**
+  * Returns the shape of a full block.
+  */
+  static block(): VoxelShape
+  /**
+  * Returns a shape made of a single box.
+  *
+  * @param min - The minimum corner of the box (Vector3f).
+  * @param max - The maximum corner of the box (Vector3f).
+  */
+  static cuboid(min: VectorLike, max: VectorLike): VoxelShape
+  /**
+  * Returns copies of the boxes of the shape.
+  */
+  boxes(): Array<AABB>
+  isEmpty(): boolean
+  /**
+  * Returns the box enclosing the whole shape; otherwise, undefined for an empty shape.
+  */
+  bounds(): AABB | null
+  /**
+  * Moves every box of the shape, for example to place a block shape in the world.
+  *
+  * @param v - The offset, usually the position of the block (Vector3f).
+  */
+  offset(v: VectorLike): VoxelShape
+  /**
+  * Combines the boxes of two shapes. Boxes fully inside another box are dropped.
+  *
+  * @param other - The shape to combine with (VoxelShape).
+  */
+  union(other: VoxelShape): VoxelShape
+  /**
+  * Returns the space covered by both shapes, as the overlaps of their boxes.
+  * Boxes with NaN corners cover no space, so they overlap nothing.
+  *
+  * @param other - The shape to intersect with (VoxelShape).
+  */
+  intersect(other: VoxelShape): VoxelShape
+  /**
+  * Checks whether a point is inside any box of the shape.
+  *
+  * @param v - The point (Vector3f).
+  */
+  contains(v: VectorLike): boolean
+  /**
+  * Checks whether a box overlaps any box of the shape.
+  *
+  * @param aabb - The box to test (AABB).
+  */
+  intersects(aabb: AABB): boolean
+  /**
+  * Finds where a line segment first enters the shape.
+  *
+  * @param start - The start point of the ray (Vector3f).
+  * @param end - The end point of the ray (Vector3f).
+  * @return The nearest hit and the face of the box that was entered; otherwise, undefined.
+  */
+  raycast(start: VectorLike, end: VectorLike): HitResult | null
+  /**
+  * Clips the movement of a box against the shape, axis by axis like entity movement.
+  *
+  * @param entity_box - The box before moving (AABB).
+  * @param movement - The movement the box attempts (Vector3f).
+  * @return The clipped movement and the axes that collided.
+  */
+  collide(entityBox: AABB, movement: VectorLike): MovementResult
+}
+/**
+ * Tracks the line of sight between one observer and one target over time. The block that blocked
+ * the last check is tested first, and while it still blocks the line no traversal is needed,
+ * which makes repeated checks in static scenes cost a single lookup.
+*/
+export declare class LineOfSight {
+  /**
+  * Constructs a tracker without a remembered blocker.
+  */
+  constructor()
+  /**
+  * Checks whether the target is visible from the observer. When the remembered blocker is still
+  * solid and still on the line, the line is blocked without traversing it again.
+  *
+  * @param start - The eye position of the observer (Vector3f).
+  * @param end - The position of the target (Vector3f).
+  * @param is_solid - Returns whether the block at a position blocks the line of sight.
+  * @return Whether the line of sight is clear.
+  * Throws if the line is not finite or passes through more blocks than an array can hold.
+  */
+  check(start: VectorLike, end: VectorLike, isSolid: (position: Vector3f) => boolean): boolean
+  /**
+  * Returns the block that blocked the last check; otherwise, undefined when it was clear.
+  */
+  blocker(): Vector3f | null
+  /**
+  * Forgets the remembered blocker, for example after the observer or target teleported.
+  */
+  reset(): void
+  /**
+  * Returns the number of checks that needed a full traversal.
+  */
+  traversals(): number
+  /**
+  * Returns the number of checks answered by the remembered blocker.
+  */
+  revalidations(): number
+}
+/**
+ * A dynamic bounding volume tree for broad phase collision between moving entities.
+ * Leaves store enlarged ("fat") boxes, so small movements do not restructure the tree,
+ * and the tree is kept balanced with rotations. Proxy ids stay valid until removed.
+*/
+export declare class DynamicAabbTree {
+  /**
+  * Constructs an empty tree.
+  *
+  * @param margin - The distance leaves are enlarged by on every side, defaults to 0.1.
+  * Throws if the margin is not a finite number of at least 0.
+  */
+  constructor(margin?: number | undefined | null)
+  get margin(): number
+  /**
+  * Changes the distance leaves are enlarged by, applied to leaves inserted or reinserted afterwards.
+  *
+  * @param margin - The distance in blocks, a finite number of at least 0 so fat boxes contain the real bounds.
+  */
+  set margin(margin: number)
+  /**
+  * Inserts a box into the tree.
+  *
+  * @param aabb - The box to insert (AABB).
+  * @return The id of the proxy.
+  * Throws if a corner of the box is NaN.
+  */
+  insert(aabb: AABB): number
+  /**
+  * Removes a proxy from the tree.
+  *
+  * @param id - The id of the proxy.
+  * @return Whether the proxy existed.
+  */
+  remove(id: number): boolean
+  /**
+  * Moves a proxy to a new box. The tree is only restructured when the box leaves the fat box
+  * of the proxy, which is then enlarged in the direction of the displacement as well.
+  *
+  * @param id - The id of the proxy.
+  * @param aabb - The new box of the proxy (AABB).
+  * @param displacement - The movement expected next tick, used to predict the fat box, ignored along axes it is not finite on (Vector3f).
+  * @return Whether the proxy was reinserted.
+  * Throws if a corner of the box is NaN.
+  */
+  update(id: number, aabb: AABB, displacement?: VectorLike | undefined | null): boolean
+  /**
+  * Returns the exact box of a proxy; otherwise, undefined if the id is not in the tree.
+  *
+  * @param id - The id of the proxy.
+  */
+  get(id: number): AABB | null
+  /**
+  * Returns the ids of every proxy overlapping a box.
+  *
+  * @param aabb - The box to query (AABB).
+  */
+  query(aabb: AABB): Array<number>
+  /**
+  * Returns every pair of overlapping proxies.
+  */
+  overlappingPairs(): Array<OverlapPair>
+  /**
+  * Returns the pairs of overlapping proxies involving a proxy inserted or moved since the
+  * last call, then clears the change tracking. Calling this every tick only reports new contacts
+  * and contacts of moving entities.
+  */
+  movedPairs(): Array<OverlapPair>
+  /**
+  * Returns the ids of the proxies inserted or moved since moved pairs were last queried.
+  */
+  movedProxies(): Array<number>
+  /**
+  * Returns the number of proxies in the tree.
+  */
+  size(): number
+  /**
+  * Returns the height of the tree, 0 when it holds a single proxy.
+  */
+  height(): number
+}
+/**
+ * A room to room potentially visible set, precomputed from room boxes and the portals
+ * (doorways and windows) between them, so interest management queries are O(1) lookups.
+*/
+export declare class PotentiallyVisibleSet {
+  /**
+  * Computes which rooms can see each other. Portals are flat boxes lying on the shared faces of the rooms
+  * they connect. A room sees another when a line passes from the first portal of a chain
+  * through every portal of the chain, found by sampling points on the first and last portal.
+  * Every portal is crossed at most once in each direction per first portal, so densely connected rooms
+  * do not explode into every possible chain.
+  *
+  * @param rooms - The boxes of the rooms (Array<AABB>).
+  * @param portals - The flat boxes of the portals (Array<AABB>).
+  * @param options - The sampling precision (VisibilityOptions).
+  * Throws if there are too many rooms for the room to room matrix or there is not enough memory for it.
+  */
+  static compute(rooms: Array<AABB>, portals: Array<AABB>, options?: VisibilityOptions | undefined | null): PotentiallyVisibleSet
+  /**
+  * Checks whether a room can see another.
+  *
+  * @param from - The index of the viewing room.
+  * @param to - The index of the other room.
+  */
+  isVisible(from: number, to: number): boolean
+  /**
+  * Returns the indices of every room visible from a room, including itself.
+  *
+  * @param from - The index of the viewing room.
+  */
+  visibleFrom(from: number): Array<number>
+  /**
+  * Returns the number of rooms.
+  */
+  size(): number
+}
+/**
+ * Crate wide settings: the tolerance AABB intersection tests and collision clipping use, the unit
+ * of the Rotation conversions, and the up axis shapes and noise slices default to. Values are validated
+ * when the config is built, and a config can be installed globally to become the default of every call.
+ * Other tolerances, such as ray parallelism, are fixed by their algorithms.
+*/
+export declare class MathConfig {
+  /**
+  * Constructs and validates a config.
+  *
+  * @param epsilon - The tolerance of AABB intersections and collision clipping, defaults to 1e-7.
+  * @param angle_unit - The unit of the Rotation conversions, defaults to degrees like Minecraft rotations (AngleUnit).
+  * @param up_axis - The axis helices wind around and noise slices are perpendicular to by default, defaults to Y (Axis).
+  */
+  constructor(epsilon?: number | undefined | null, angleUnit?: AngleUnit | undefined | null, upAxis?: Axis | undefined | null)
+  /**
+  * Returns a copy of the global config.
+  */
+  static global(): MathConfig
+  /**
+  * Installs a config as the global default.
+  *
+  * @param config - The config to install (MathConfig).
+  */
+  static setGlobal(config: MathConfig): void
+  /**
+  * Restores the built in defaults.
+  */
+  static resetGlobal(): void
+  get epsilon(): number
+  get angleUnit(): AngleUnit
+  get upAxis(): Axis
+  /**
+  * Converts an angle in the configured unit into degrees.
+  *
+  * @param angle - The angle in the configured unit.
+  */
+  toDegrees(angle: number): number
+  /**
+  * Converts an angle in degrees into the configured unit.
+  *
+  * @param degrees - The angle in degrees.
+  */
+  fromDegrees(degrees: number): number
+}
+/**
+ * Resolution of command coordinates with the semantics of ~ (relative) and ^ (local) notation.
+*/
+export declare class Coordinates {
+  /**
+  * Parses the three coordinates of a command, such as "~ ~1.5 ~-2" or "^ ^ ^3".
+  *
+  * @param input - The coordinates separated by whitespace.
+  * @return The mode and value of every coordinate.
+  */
+  static parse(input: string): CoordinateInput
+  /**
+  * Resolves command coordinates into a world position. Local coordinates must be used on all
+  * three axes or none, like in commands; they offset the base along the left (x), up (y) and
+  * forward (z) directions of the rotation.
+  *
+  * @param input - The coordinates to resolve (CoordinateInput).
+  * @param base - The position the command runs at, the eye position for local coordinates of entities (Vector3f).
+  * @param rotation - The rotation the command runs with in degrees, x being the pitch and y the yaw (Vector2f).
+  * @return The resolved position.
+  */
+  static resolve(input: CoordinateInput, base: VectorLike, rotation: Vector2f): Vector3f
+}
+/**
+ * Represents a rotation as three angles around the X, Y and Z axes, applied in an explicit order.
+*/
+export declare class Euler {
+  x: number
+  y: number
+  z: number
+  order: EulerOrder
+  /**
+  * Constructs Euler angles.
+  *
+  * @param x - The angle around X in radians.
+  * @param y - The angle around Y in radians.
+  * @param z - The angle around Z in radians.
+  * @param order - The order of the rotations, defaults to XYZ (EulerOrder).
+  */
+  constructor(x: number, y: number, z: number, order?: EulerOrder | undefined | null)
+  /**
+  * Extracts Euler angles from a rotation matrix. When the middle rotation reaches
+  * +-90 degrees (gimbal lock) the last angle is set to zero and the first absorbs the rotation.
+  *
+  * @param matrix - The rotation matrix (Matrix3).
+  * @param order - The order of the rotations, defaults to XYZ (EulerOrder).
+  */
+  static fromMatrix3(matrix: Matrix3, order?: EulerOrder | undefined | null): Euler
+  /**
+  * Extracts Euler angles from a quaternion.
+  *
+  * @param quaternion - The rotation (Quaternion).
+  * @param order - The order of the rotations, defaults to XYZ (EulerOrder).
+  */
+  static fromQuaternion(quaternion: Quaternion, order?: EulerOrder | undefined | null): Euler
+  /**
+  * Converts the angles into a rotation matrix.
+  */
+  toMatrix3(): Matrix3
+  /**
+  * Converts the angles into a quaternion.
+  */
+  toQuaternion(): Quaternion
+  /**
+  * Expresses the same rotation with angles in another order.
+  *
+  * @param order - The new order of the rotations (EulerOrder).
+  */
+  reorder(order: EulerOrder): Euler
+  /**
+  * Rotates a vector by the angles.
+  *
+  * @param vector - The vector to rotate (Vector3f).
+  */
+  rotate(vector: VectorLike): Vector3f
+}
+/**
+ * Smooths noisy position or rotation streams with a One Euro filter: a low-pass filter
+ * whose cutoff rises with speed, removing jitter at rest while keeping fast movement responsive.
+ * With a beta of 0 it is a plain exponential moving average.
+*/
+export declare class PositionFilter {
+  minCutoff: number
+  beta: number
+  derivativeCutoff: number
+  angular: boolean
+  /**
+  * Constructs a filter without any samples yet.
+  *
+  * @param min_cutoff - The cutoff frequency at rest in Hz, defaults to 1.
+  * @param beta - The increase of the cutoff per unit of speed, defaults to 0.
+  * @param derivative_cutoff - The cutoff frequency of the speed estimate in Hz, defaults to 1.
+  * @param angular - Whether the components are angles in degrees, defaults to false.
+  */
+  constructor(minCutoff?: number | undefined | null, beta?: number | undefined | null, derivativeCutoff?: number | undefined | null, angular?: boolean | undefined | null)
+  /**
+  * Filters a sample. The first sample is returned unchanged, and samples that are not
+  * finite are skipped, returning the last smoothed value instead.
+  *
+  * @param sample - The raw sample (Vector3f).
+  * @param delta - The seconds elapsed since the previous sample, defaults to 0.05 (one tick).
+  * @return The smoothed value.
+  */
+  filter(sample: VectorLike, delta?: number | undefined | null): Vector3f
+  /**
+  * Filters a rotation sample of pitch (x) and yaw (y). The filter should be angular.
+  * Samples that are not finite are skipped like in filter.
+  *
+  * @param rotation - The raw rotation in degrees (Vector2f).
+  * @param delta - The seconds elapsed since the previous sample, defaults to 0.05 (one tick).
+  * @return The smoothed rotation.
+  */
+  filterRotation(rotation: Vector2f, delta?: number | undefined | null): Vector2f
+  /**
+  * Returns the last smoothed value; otherwise, undefined before the first sample.
+  */
+  value(): Vector3f | null
+  /**
+  * Forgets all samples, so the next sample is returned unchanged.
+  */
+  reset(): void
+  /**
+  * Blends a value towards a target by a fixed factor, a single step of an exponential moving average.
+  *
+  * @param previous - The previous smoothed value (Vector3f).
+  * @param sample - The new sample (Vector3f).
+  * @param alpha - The weight of the new sample (0..1).
+  */
+  static ema(previous: VectorLike, sample: VectorLike, alpha: number): Vector3f
+}
+/**
+ * Attribute and damage reduction math with vanilla's operation order.
+*/
+export declare class AttributeMath {
+  /**
+  * Applies modifiers to a base value in vanilla order: additions first, then base
+  * multipliers (which all scale the same added value), then total multipliers in sequence,
+  * and finally caps. The result is clamped to the optional bounds.
+  *
+  * @param base - The base value of the attribute.
+  * @param modifiers - The modifiers to apply (Array<AttributeModifier>).
+  * @param min - The minimum value of the attribute.
+  * @param max - The maximum value of the attribute.
+  * @return The modified value.
+  */
+  static apply(base: number, modifiers: Array<AttributeModifier>, min?: number | undefined | null, max?: number | undefined | null): number
+  /**
+  * Calculates the enchantment protection factor (EPF) of the armor worn.
+  * Protection counts once per level, a specialized protection matching the damage
+  * (fire, blast or projectile protection) twice, and feather falling against falls three times.
+  *
+  * @param protection - The summed protection levels.
+  * @param specialized - The summed levels of the specialized protection matching the damage.
+  * @param feather_falling - The feather falling level, only when the damage is a fall.
+  * @return The uncapped protection factor, saturating at u32::MAX.
+  */
+  static protectionFactor(protection: number, specialized?: number | undefined | null, featherFalling?: number | undefined | null): number
+  /**
+  * Reduces damage by an enchantment protection factor, capped at 20 (80% reduction).
+  *
+  * @param damage - The incoming damage.
+  * @param protection_factor - The enchantment protection factor.
+  * @return The damage after the reduction.
+  */
+  static enchantmentReduction(damage: number, protectionFactor: number): number
+  /**
+  * Reduces damage by armor points and toughness.
+  *
+  * @param damage - The incoming damage.
+  * @param armor - The armor points of the entity.
+  * @param toughness - The armor toughness of the entity, defaults to 0.
+  * @return The damage after the reduction.
+  */
+  static armorReduction(damage: number, armor: number, toughness?: number | undefined | null): number
+  /**
+  * Reduces damage by the resistance effect, 20% per level.
+  *
+  * @param damage - The incoming damage.
+  * @param level - The resistance level (amplifier + 1).
+  * @return The damage after the reduction.
+  */
+  static resistanceReduction(damage: number, level: number): number
+}
+/**
+ * Day cycle formulas: celestial angle, moon phases and sky darkness, per vanilla.
+ * Times are in ticks, where 0 is sunrise and 6000 is noon.
+*/
+export declare class Celestial {
+  /**
+  * Calculates the celestial angle of the sun for a time of day.
+  * The angle is 0 at noon, 0.5 at midnight, and is eased around sunrise and sunset.
+  *
+  * @param time_of_day - The time of day in ticks.
+  * @return The angle as a fraction of a full rotation (0..1).
+  */
+  static angle(timeOfDay: number): number
+  /**
+  * Calculates the moon phase of a day, 0 being the full moon and 4 the new moon.
+  *
+  * @param day - The day number, or the total time in ticks when is_ticks is set.
+  * @param is_ticks - Whether the value is a time in ticks, defaults to false.
+  */
+  static moonPhase(day: number, isTicks?: boolean | undefined | null): number
+  /**
+  * Returns the brightness of the moon (0..1) for a moon phase.
+  *
+  * @param phase - The moon phase (0..7).
+  */
+  static moonBrightness(phase: number): number
+  /**
+  * Calculates how much the sky darkens the world, subtracted from sky light.
+  *
+  * @param angle - The celestial angle (0..1).
+  * @param rain - The rain strength (0..1), defaults to 0.
+  * @param thunder - The thunder strength (0..1), defaults to 0.
+  * @return The sky darkness (0..11), 0 during a clear day.
+  */
+  static skyDarkness(angle: number, rain?: number | undefined | null, thunder?: number | undefined | null): number
+  /**
+  * Calculates the brightness of the sky (0..1), the inverse of the sky darkness before rounding.
+  *
+  * @param angle - The celestial angle (0..1).
+  * @param rain - The rain strength (0..1), defaults to 0.
+  * @param thunder - The thunder strength (0..1), defaults to 0.
+  */
+  static skyBrightness(angle: number, rain?: number | undefined | null, thunder?: number | undefined | null): number
+  /**
+  * Checks whether it is day, meaning the sky darkness is below 4.
+  * Undead mobs burn and beds cannot be used during the day.
+  *
+  * @param time_of_day - The time of day in ticks.
+  * @param rain - The rain strength (0..1), defaults to 0.
+  * @param thunder - The thunder strength (0..1), defaults to 0.
+  */
+  static isDay(timeOfDay: number, rain?: number | undefined | null, thunder?: number | undefined | null): boolean
+  /**
+  * Checks whether players can sleep, which is possible at night or during a thunderstorm.
+  *
+  * @param time_of_day - The time of day in ticks.
+  * @param thundering - Whether a thunderstorm is active, defaults to false.
+  */
+  static canSleep(timeOfDay: number, thundering?: boolean | undefined | null): boolean
+}
+/**
+ * Target selection for attacks that jump between entities, such as chain lightning.
+*/
+export declare class Chain {
+  /**
+  * Computes the order an attack jumps between targets. Every jump goes to the nearest
+  * target not yet hit, and the chain stops when no target is left within the jump radius.
+  *
+  * @param origin - The position the chain starts from (Vector3f).
+  * @param targets - The candidate positions, packed as x, y, z triplets (Float64Array).
+  * @param max_jumps - The maximum number of targets hit.
+  * @param radius - The maximum distance of a single jump.
+  * @return The indices of the targets hit, in order.
+  */
+  static jumps(origin: VectorLike, targets: Float64Array, maxJumps: number, radius: number): Array<number>
+}
+/**
+ * Vanilla damage formulas for falls and impacts. Damage is in health points (half hearts)
+ * and is applied before armor and enchantment reductions.
+*/
+export declare class Damage {
+  /**
+  * Calculates the damage taken from a fall.
+  *
+  * @param fall_distance - The distance fallen in blocks.
+  * @param modifiers - The modifiers of the fall (FallModifiers).
+  * @return The damage, rounded up like vanilla.
+  */
+  static fall(fallDistance: number, modifiers?: FallModifiers | undefined | null): number
+  /**
+  * Calculates the distance an entity has fallen to reach a vertical velocity,
+  * simulating vanilla gravity and drag from rest.
+  *
+  * @param vertical_velocity - The vertical velocity at impact in blocks per tick (negative when falling).
+  * @param slow_falling - Whether the slow falling effect was active, defaults to false.
+  * @return The fall distance in blocks, 0 for a NaN velocity.
+  */
+  static fallDistanceFromVelocity(verticalVelocity: number, slowFalling?: boolean | undefined | null): number
+  /**
+  * Calculates the fall damage for an impact at a vertical velocity.
+  *
+  * @param vertical_velocity - The vertical velocity at impact in blocks per tick (negative when falling).
+  * @param modifiers - The modifiers of the fall (FallModifiers).
+  */
+  static impact(verticalVelocity: number, modifiers?: FallModifiers | undefined | null): number
+  /**
+  * Calculates the damage of flying into a wall with elytra, from the horizontal speed lost.
+  *
+  * @param speed_before - The horizontal speed before the collision in blocks per tick.
+  * @param speed_after - The horizontal speed after the collision in blocks per tick.
+  */
+  static kinetic(speedBefore: number, speedAfter: number): number
+}
+/**
+ * Vanilla formulas for local difficulty and mob caps, which gate spawning and equipment chances.
+*/
+export declare class DifficultyMath {
+  /**
+  * Calculates the local difficulty of a chunk.
+  *
+  * @param difficulty - The world difficulty (Difficulty).
+  * @param game_time - The total game time of the world in ticks.
+  * @param inhabited_time - The ticks players have spent in the chunk.
+  * @param moon_phase - The current moon phase (0..7), 0 being the full moon.
+  * @return The effective and clamped local difficulty.
+  */
+  static local(difficulty: Difficulty, gameTime: number, inhabitedTime: number, moonPhase: number): LocalDifficulty
+  /**
+  * Clamps a regional difficulty: below 2 becomes 0, above 4 becomes 1, linear in between.
+  *
+  * @param effective - The regional difficulty.
+  */
+  static clamp(effective: number): number
+  /**
+  * Calculates the global cap of a mob category, which scales with the chunks players can spawn mobs in.
+  *
+  * @param category_cap - The base cap of the category, for example 70 for monsters.
+  * @param spawnable_chunks - The number of chunks eligible for spawning.
+  */
+  static mobCap(categoryCap: number, spawnableChunks: number): number
+  /**
+  * Checks whether more mobs of a category may spawn.
+  *
+  * @param current - The number of mobs of the category currently loaded.
+  * @param category_cap - The base cap of the category.
+  * @param spawnable_chunks - The number of chunks eligible for spawning.
+  */
+  static canSpawn(current: number, categoryCap: number, spawnableChunks: number): boolean
+}
+/**
+ * Values derived from status effect levels, using the vanilla formulas.
+ * Levels are the amplifier + 1, so level 1 is "Speed I".
+*/
+export declare class EffectMath {
+  /**
+  * Calculates the movement speed multiplier of the speed and slowness effects.
+  * Speed adds 20% per level, slowness removes 15% per level.
+  *
+  * @param speed - The speed level.
+  * @param slowness - The slowness level.
+  */
+  static speedMultiplier(speed: number, slowness?: number | undefined | null): number
+  /**
+  * Calculates the initial vertical velocity of a jump with jump boost.
+  *
+  * @param level - The jump boost level.
+  */
+  static jumpVelocity(level: number): number
+  /**
+  * Calculates the peak height of a jump with jump boost, simulating gravity and drag.
+  *
+  * @param level - The jump boost level.
+  * @return The height in blocks.
+  */
+  static jumpHeight(level: number): number
+  /**
+  * Returns the gravity applied per tick, which slow falling reduces from 0.08 to 0.01.
+  *
+  * @param slow_falling - Whether the slow falling effect is active.
+  */
+  static gravity(slowFalling: boolean): number
+  /**
+  * Calculates the vertical velocity after a tick of levitation.
+  * The velocity approaches 0.05 blocks per tick per level.
+  *
+  * @param velocity - The current vertical velocity.
+  * @param level - The levitation level.
+  */
+  static levitationVelocity(velocity: number, level: number): number
+  /**
+  * Calculates the extra health granted by absorption or health boost, 4 points (2 hearts) per level.
+  *
+  * @param level - The effect level.
+  */
+  static bonusHealth(level: number): number
+  /**
+  * Calculates the mining speed multiplier of haste and mining fatigue.
+  * Haste adds 20% per level, mining fatigue uses vanilla's multipliers, capped at level 4.
+  *
+  * @param haste - The haste (or conduit power) level.
+  * @param mining_fatigue - The mining fatigue level.
+  */
+  static miningSpeedMultiplier(haste: number, miningFatigue?: number | undefined | null): number
+  /**
+  * Calculates the extra melee damage of strength (+3 per level) and weakness (-4 per level).
+  *
+  * @param strength - The strength level.
+  * @param weakness - The weakness level.
+  */
+  static attackBonus(strength: number, weakness?: number | undefined | null): number
+  /**
+  * Calculates the ticks between heals of regeneration.
+  *
+  * @param level - The regeneration level.
+  */
+  static regenerationInterval(level: number): number
+  /**
+  * Calculates the ticks between damage ticks of poison.
+  *
+  * @param level - The poison level.
+  */
+  static poisonInterval(level: number): number
+  /**
+  * Calculates the ticks between damage ticks of wither.
+  *
+  * @param level - The wither level.
+  */
+  static witherInterval(level: number): number
+}
+/**
+ * Experience and level curves, using vanilla's piecewise formulas.
+*/
+export declare class Experience {
+  /**
+  * Calculates the experience needed to advance from a level to the next, saturating at u32::MAX.
+  *
+  * @param level - The current level.
+  */
+  static xpForNextLevel(level: number): number
+  /**
+  * Calculates the total experience needed to reach a level from zero, saturating at u32::MAX.
+  *
+  * @param level - The level to reach.
+  */
+  static levelToTotalXp(level: number): number
+  /**
+  * Converts a total amount of experience into the level it reaches.
+  *
+  * @param total - The total experience.
+  * @return The level, the progress towards the next level and the experience still needed.
+  */
+  static xpToLevel(total: number): LevelProgress
+  /**
+  * Splits an amount of experience into orbs the way vanilla does when dropping it.
+  *
+  * @param amount - The experience to split.
+  * @return The value of every orb, largest first.
+  */
+  static splitOrbs(amount: number): Array<number>
+  /**
+  * Returns the largest orb size that fits into an amount of experience.
+  *
+  * @param amount - The experience remaining.
+  */
+  static orbSize(amount: number): number
+}
+/**
+ * Explosion knockback and damage using vanilla's formulas. The exposure of an entity is the fraction
+ * of rays from points spread over its hitbox that reach the center of the explosion unobstructed.
+*/
+export declare class Explosion {
+  /**
+  * Computes the knockback and damage an explosion deals to an entity. Entities are affected within
+  * twice the power, and are pushed from the center towards their eyes, scaled by the distance
+  * and the exposure.
+  *
+  * @param entity_aabb - The hitbox of the entity (AABB).
+  * @param explosion_center - The center of the explosion (Vector3f).
+  * @param power - The power of the explosion, 4 for TNT.
+  * @param exposure - The fraction of the entity exposed to the explosion (0..1), NaN counting as 0.
+  * @param eye_height - The eye height of the entity above its feet, defaults to 85% of the hitbox height, also when NaN.
+  * @param knockback_resistance - The explosion knockback resistance of the entity (0..1), defaults to 0, also when NaN.
+  * @return The velocity to add and the damage dealt, nothing when the power or the distance is NaN.
+  */
+  static impulse(entityAabb: AABB, explosionCenter: VectorLike, power: number, exposure: number, eyeHeight?: number | undefined | null, knockbackResistance?: number | undefined | null): ExplosionImpulse
+  /**
+  * Computes the knockback and damage of an explosion for many entities at once.
+  *
+  * @param boxes - The hitboxes of the entities, packed as min x, y, z and max x, y, z (Float64Array).
+  * @param explosion_center - The center of the explosion (Vector3f).
+  * @param power - The power of the explosion, 4 for TNT.
+  * @param exposures - The exposure of every entity, NaN counting as 0 (Float64Array).
+  * @param knockback_resistance - The explosion knockback resistance of every entity, defaults to 0 for all of them and for NaN.
+  * @return The velocity to add and the damage of every entity, packed as x, y, z, damage (Float64Array).
+  */
+  static impulseBatch(boxes: Float64Array, explosionCenter: VectorLike, power: number, exposures: Float64Array, knockbackResistance?: Array<number> | undefined | null): Float64Array
+  /**
+  * Returns the points vanilla casts exposure rays from, spread over the hitbox of an entity
+  * at steps of about half a block.
+  *
+  * @param entity_aabb - The hitbox of the entity (AABB).
+  * @return The points, packed as x, y, z triplets (Float64Array).
+  * Throws if the hitbox is so large it has more points than an array can hold.
+  */
+  static exposureSamples(entityAabb: AABB): Float64Array
+  /**
+  * Computes the exposure of an entity by casting a ray from every sample point to the center
+  * of the explosion. Blocks are treated as full cubes.
+  *
+  * @param entity_aabb - The hitbox of the entity (AABB).
+  * @param explosion_center - The center of the explosion (Vector3f).
+  * @param is_solid - Returns whether the block at a position obstructs the explosion.
+  * @return The fraction of rays that are not obstructed (0..1).
+  * Throws if the center is not finite, the hitbox is so large it has more points than an array can hold,
+  * or a ray passes through more blocks than an array can hold.
+  */
+  static exposure(entityAabb: AABB, explosionCenter: VectorLike, isSolid: (position: Vector3f) => boolean): number
+}
+/**
+ * Distance falloff kernels for area of effect damage and knockback.
+ * Every kernel is 1 at the center and reaches 0 at the radius.
+*/
+export declare class Falloff {
+  /**
+  * Scales linearly with the distance from the center.
+  *
+  * @param center - The center of the effect (Vector3f).
+  * @param radius - The radius of the effect.
+  * @param point - The position to scale at (Vector3f).
+  * @return The scale (0..1).
+  */
+  static linear(center: VectorLike, radius: number, point: VectorLike): number
+  /**
+  * Scales with the square of the linear falloff, dropping quickly away from the center.
+  *
+  * @param center - The center of the effect (Vector3f).
+  * @param radius - The radius of the effect.
+  * @param point - The position to scale at (Vector3f).
+  * @return The scale (0..1).
+  */
+  static quadratic(center: VectorLike, radius: number, point: VectorLike): number
+  /**
+  * Scales with a smoothstep curve, which eases out at the center and the edge.
+  *
+  * @param center - The center of the effect (Vector3f).
+  * @param radius - The radius of the effect.
+  * @param point - The position to scale at (Vector3f).
+  * @return The scale (0..1).
+  */
+  static smooth(center: VectorLike, radius: number, point: VectorLike): number
+  /**
+  * Applies a falloff to many positions at once, such as every entity caught by an explosion.
+  *
+  * @param center - The center of the effect (Vector3f).
+  * @param radius - The radius of the effect.
+  * @param points - The positions, packed as x, y, z triplets (Float64Array).
+  * @param kind - The falloff kernel, defaults to linear (FalloffKind).
+  * @return The scale of every position, in order (Float64Array).
+  */
+  static batch(center: VectorLike, radius: number, points: Float64Array, kind?: FalloffKind | undefined | null): Float64Array
+}
+/**
+ * Probability math for random ticks, crop growth and animal aging, so plugins and
+ * the server growth system agree on the numbers.
+*/
+export declare class GrowthMath {
+  /**
+  * Calculates the chance that a specific block receives at least one random tick during a game tick.
+  * Every section picks randomTickSpeed blocks per tick, with replacement.
+  *
+  * @param random_tick_speed - The randomTickSpeed game rule, typically 1 on Bedrock and 3 on Java.
+  * @param section_volume - The number of blocks random ticks are spread over, defaults to 4096.
+  */
+  static randomTickChance(randomTickSpeed: number, sectionVolume?: number | undefined | null): number
+  /**
+  * Calculates the chance per random tick that a crop advances a stage, where the growth
+  * factor depends on farmland moisture and neighboring crops (1..10).
+  *
+  * @param growth_factor - The growth factor of the crop.
+  */
+  static cropGrowthChance(growthFactor: number): number
+  /**
+  * Calculates the expected number of game ticks until an event driven by random ticks happens.
+  *
+  * @param random_tick_speed - The randomTickSpeed game rule.
+  * @param success_chance - The chance the event happens when the block is random ticked, defaults to 1.
+  * @return The expected ticks; infinity when the event cannot happen.
+  */
+  static expectedTicks(randomTickSpeed: number, successChance?: number | undefined | null): number
+  /**
+  * Calculates the expected ticks for a crop to pass through all of its growth stages.
+  *
+  * @param stages - The number of stages still to grow.
+  * @param random_tick_speed - The randomTickSpeed game rule.
+  * @param growth_factor - The growth factor of the crop.
+  */
+  static expectedTicksToMature(stages: number, randomTickSpeed: number, growthFactor: number): number
+  /**
+  * Calculates the chance that an event with a fixed chance per tick happens at least once within some ticks.
+  *
+  * @param chance_per_tick - The chance of the event per tick.
+  * @param ticks - The number of ticks.
+  */
+  static probabilityWithin(chancePerTick: number, ticks: number): number
+  /**
+  * Calculates how many ticks pass before an event with a fixed chance per tick has
+  * happened with the given probability.
+  *
+  * @param chance_per_tick - The chance of the event per tick.
+  * @param probability - The desired probability (0..1).
+  */
+  static ticksForProbability(chancePerTick: number, probability: number): number
+  /**
+  * Calculates the age of a baby animal after it is fed, which skips 10% of its remaining growth time.
+  *
+  * @param age - The current age in ticks (negative for babies).
+  */
+  static feedBaby(age: number): number
+}
+/**
+ * Tracks food, saturation and exhaustion using vanilla's constants and thresholds.
+*/
+export declare class Hunger {
+  food: number
+  saturation: number
+  exhaustion: number
+  timer: number
+  /**
+  * Constructs a full hunger state: 20 food and 5 saturation.
+  */
+  constructor(food?: number | undefined | null, saturation?: number | undefined | null)
+  /**
+  * Returns the exhaustion caused by an action.
+  *
+  * @param action - The action performed (HungerAction).
+  * @param amount - The blocks travelled, health healed or effect level, defaults to 1.
+  */
+  static actionExhaustion(action: HungerAction, amount?: number | undefined | null): number
+  /**
+  * Adds the exhaustion of an action.
+  *
+  * @param action - The action performed (HungerAction).
+  * @param amount - The blocks travelled, health healed or effect level, defaults to 1.
+  */
+  exhaust(action: HungerAction, amount?: number | undefined | null): void
+  /**
+  * Adds raw exhaustion, capped at 40 like vanilla.
+  */
+  addExhaustion(exhaustion: number): void
+  /**
+  * Restores food after eating. Saturation gained is nutrition * saturation modifier * 2,
+  * never exceeding the food level.
+  *
+  * @param nutrition - The nutrition of the food.
+  * @param saturation_modifier - The saturation modifier of the food.
+  */
+  eat(nutrition: number, saturationModifier: number): void
+  /**
+  * Advances the hunger state by a tick: consumes exhaustion, then applies natural
+  * regeneration or starvation.
+  *
+  * @param health - The current health of the entity.
+  * @param max_health - The maximum health of the entity.
+  * @param difficulty - The world difficulty, defaults to normal (Difficulty).
+  * @param natural_regeneration - Whether the naturalRegeneration game rule is on, defaults to true.
+  * @return The health restored and the starvation damage dealt this tick.
+  */
+  tick(health: number, maxHealth: number, difficulty?: Difficulty | undefined | null, naturalRegeneration?: boolean | undefined | null): HungerTick
+  /**
+  * Checks whether the entity can sprint, which requires more than 6 food.
+  */
+  canSprint(): boolean
+}
+/**
+ * Knockback velocities with vanilla's rules, so several hits in a tick do not stack into a launch.
+*/
+export declare class Knockback {
+  /**
+  * Applies a knockback to a velocity like vanilla: the current velocity is halved and the push
+  * away from the attacker is added horizontally. Grounded entities are also lifted, up to 0.4.
+  *
+  * @param velocity - The current velocity of the entity (Vector3f).
+  * @param strength - The strength of the knockback, 0.4 for a plain hit.
+  * @param direction_x - The x direction pointing from the entity towards the attacker.
+  * @param direction_z - The z direction pointing from the entity towards the attacker.
+  * @param on_ground - Whether the entity is on the ground.
+  * @param resistance - The knockback resistance of the entity (0..1), defaults to 0.
+  * @return The new velocity, unchanged when the strength or resistance is NaN.
+  */
+  static apply(velocity: VectorLike, strength: number, directionX: number, directionZ: number, onGround: boolean, resistance?: number | undefined | null): Vector3f
+  /**
+  * Combines two knockback impulses received within the same tick instead of summing them.
+  * The stronger horizontal impulse replaces the weaker one as a whole, keeping a consistent
+  * direction, and the vertical component is the larger of both.
+  *
+  * @param existing - The impulse already accumulated this tick (Vector3f).
+  * @param incoming - The new impulse (Vector3f).
+  * @return The combined impulse.
+  */
+  static combine(existing: VectorLike, incoming: VectorLike): Vector3f
+  /**
+  * Combines any number of knockback impulses of a tick, in order.
+  *
+  * @param impulses - The impulses received this tick (Array<Vector3f>).
+  * @return The combined impulse; zero without impulses.
+  */
+  static combineAll(impulses: Array<VectorLike>): Vector3f
+}
+/**
+ * Nether portal linking math: coordinate scaling between dimensions and the search
+ * for an existing portal around the scaled destination, following vanilla.
+*/
+export declare class Portal {
+  /**
+  * Returns how many overworld blocks a block of a dimension spans.
+  *
+  * @param dimension - The dimension (Dimension).
+  */
+  static coordinateScale(dimension: Dimension): number
+  /**
+  * Scales a position into another dimension, dividing by 8 into the nether and multiplying
+  * by 8 out of it. The result is clamped inside the world border and floored to a block.
+  *
+  * @param position - The position in the source dimension (Vector3f).
+  * @param from - The source dimension (Dimension).
+  * @param to - The destination dimension (Dimension).
+  * @param border - The furthest block coordinate inside the world border, defaults to 29999984.
+  * @return The block position in the destination dimension, keeping the y coordinate.
+  * Throws if the border is below 1 or NaN.
+  */
+  static scale(position: VectorLike, from: Dimension, to: Dimension, border?: number | undefined | null): Vector3f
+  /**
+  * Returns the horizontal radius searched for an existing portal: 16 blocks in the nether, 128 elsewhere,
+  * so both cover the same overworld distance.
+  *
+  * @param dimension - The destination dimension (Dimension).
+  */
+  static searchRadius(dimension: Dimension): number
+  /**
+  * Builds the box searched for existing portal blocks around a scaled destination.
+  *
+  * @param destination - The scaled block position (Vector3f).
+  * @param dimension - The destination dimension (Dimension).
+  * @param min_y - The lowest block of the dimension.
+  * @param max_y - The highest block of the dimension.
+  */
+  static searchBox(destination: VectorLike, dimension: Dimension, minY: number, maxY: number): AABB
+  /**
+  * Picks the portal block a traveller links to: the closest one within the search radius,
+  * preferring the lowest on ties.
+  *
+  * @param destination - The scaled block position (Vector3f).
+  * @param dimension - The destination dimension (Dimension).
+  * @param candidates - The portal block positions found, packed as x, y, z triplets (Float64Array).
+  * @return The index of the chosen portal block; otherwise, undefined when a new portal is needed.
+  */
+  static closest(destination: VectorLike, dimension: Dimension, candidates: Float64Array): number | null
+}
+/**
+ * Searches for positions an entity can safely stand at: a 2 block tall air gap
+ * above solid ground. Used by teleport, spawn point and respawn logic.
+*/
+export declare class SafeSpot {
+  /**
+  * Finds the safe position closest to an origin, searching columns in rings spiraling outwards.
+  *
+  * @param origin - The position to search around (Vector3f).
+  * @param search_radius - The maximum horizontal distance, in blocks, of the columns searched.
+  * @param is_solid - Returns whether a block can be stood on and blocks movement. Called at most once per block.
+  * @param vertical_range - The blocks searched above and below the origin, defaults to 8.
+  * @return The block position of the feet; otherwise, undefined if no safe position was found.
+  * Throws if the origin is not finite.
+  */
+  static find(origin: VectorLike, searchRadius: number, isSolid: (position: Vector3f) => boolean, verticalRange?: number | undefined | null): Vector3f | null
+  /**
+  * Finds the safe position closest to an origin inside a volume of block ids, where 0 is air
+  * and every other id is solid. Positions outside the volume are air.
+  *
+  * @param data - The block ids of the volume (Uint32Array).
+  * @param size - The dimensions of the volume (Vector3f).
+  * @param origin - The position to search around, relative to the volume (Vector3f).
+  * @param search_radius - The maximum horizontal distance, in blocks, of the columns searched.
+  * @param vertical_range - The blocks searched above and below the origin, defaults to 8.
+  * @return The block position of the feet, relative to the volume; otherwise, undefined.
+  * Throws if the origin is not finite.
+  */
+  static findInVolume(data: Uint32Array, size: VectorLike, origin: VectorLike, searchRadius: number, verticalRange?: number | undefined | null): Vector3f | null
+}
+/**
+ * Propagates chunk ticket levels like vanilla's distance graph: a ticket gives its chunk
+ * its level, and the level grows by one per chunk outwards (in a square), with every chunk
+ * keeping the lowest level reaching it.
+*/
+export declare class TicketGraph {
+  maxLevel: number
+  /**
+  * Constructs an empty graph.
+  *
+  * @param max_level - The highest level propagated, defaults to 44 (the border level).
+  */
+  constructor(maxLevel?: number | undefined | null)
+  /**
+  * Returns the ticket level of a player, which makes the chunks within the simulation distance entity ticking.
+  *
+  * @param simulation_distance - The simulation distance in chunks.
+  */
+  static playerTicketLevel(simulationDistance: number): number
+  /**
+  * Returns what a chunk of a level is loaded and ticked for.
+  *
+  * @param level - The ticket level.
+  */
+  static status(level: number): ChunkLoadStatus
+  /**
+  * Adds a ticket to a chunk. A chunk can hold several tickets, even of the same level.
+  *
+  * @param x - The x coordinate of the chunk.
+  * @param z - The z coordinate of the chunk.
+  * @param level - The level of the ticket.
+  */
+  addTicket(x: number, z: number, level: number): void
+  /**
+  * Removes a ticket of a level from a chunk.
+  *
+  * @param x - The x coordinate of the chunk.
+  * @param z - The z coordinate of the chunk.
+  * @param level - The level of the ticket.
+  * @return Whether a ticket was removed.
+  */
+  removeTicket(x: number, z: number, level: number): boolean
+  /**
+  * Removes every ticket.
+  */
+  clear(): void
+  /**
+  * Returns the propagated level of a chunk; otherwise, undefined if no ticket reaches it.
+  *
+  * @param x - The x coordinate of the chunk.
+  * @param z - The z coordinate of the chunk.
+  */
+  level(x: number, z: number): number | null
+  /**
+  * Propagates every ticket and returns the level of every chunk reached.
+  *
+  * @param max_level - Only return chunks at or below this level, such as 33 for loaded chunks.
+  * Throws if the tickets reach more chunks than an array can hold.
+  */
+  compute(maxLevel?: number | undefined | null): Array<ChunkLevel>
+}
+/**
+ * Propagation of vibrations and sounds to listeners such as sculk sensors and wardens.
+ * Dampening blocks along the straight line from the source add distance, and by default
+ * fully occlude the vibration like wool does in vanilla.
+*/
+export declare class Vibration {
+  /**
+  * Computes the signal strength of a vibration received at a distance, like the redstone
+  * output of a sculk sensor.
+  *
+  * @param distance - The distance the vibration travelled.
+  * @param range - The range of the listener, defaults to 8.
+  * @return The strength (1..15); otherwise, 0 when out of range or either value is NaN.
+  */
+  static strength(distance: number, range?: number | undefined | null): number
+  /**
+  * Propagates a vibration from a source to a listener, traversing the blocks along the line
+  * between them and counting the dampening ones.
+  *
+  * @param source - The position the vibration is emitted at (Vector3f).
+  * @param listener - The position of the listener (Vector3f).
+  * @param is_dampening - Returns whether the block at a position dampens vibrations.
+  * @param range - The range of the listener, defaults to 8.
+  * @param attenuation - The distance added per dampening block, defaults to fully occluding.
+  * Throws if the line to a listener in range is not finite or passes through more blocks than an array can hold.
+  */
+  static propagate(source: VectorLike, listener: VectorLike, isDampening: (position: Vector3f) => boolean, range?: number, attenuation?: number): VibrationResult
+}
+/**
+ * Weather math: rain and thunder strength transitions, thunderstorm checks and lightning strikes.
+ * Strengths range from 0 (clear) to 1 (full rain or thunder).
+*/
+export declare class Weather {
+  /**
+  * Moves a rain or thunder strength a tick towards its target, like vanilla does every tick.
+  *
+  * @param level - The current strength (0..1).
+  * @param target - The target strength, 1 while it is raining or thundering and 0 otherwise.
+  * @param rate - The change per tick, defaults to 0.01 when missing or not finite.
+  */
+  static step(level: number, target: number, rate?: number | undefined | null): number
+  /**
+  * Calculates the strength part way through a transition.
+  *
+  * @param from - The strength at the start of the transition.
+  * @param to - The strength at the end of the transition.
+  * @param elapsed - The ticks elapsed since the start.
+  * @param duration - The length of the transition in ticks.
+  */
+  static interpolate(from: number, to: number, elapsed: number, duration: number): number
+  /**
+  * Calculates how many ticks a transition between two strengths takes.
+  *
+  * @param from - The current strength.
+  * @param to - The target strength.
+  * @param rate - The change per tick, defaults to 0.01 when missing or not finite.
+  */
+  static transitionTicks(from: number, to: number, rate?: number | undefined | null): number
+  /**
+  * Returns the effective thunder strength, which vanilla scales by the rain strength.
+  *
+  * @param rain - The rain strength.
+  * @param thunder - The thunder strength.
+  */
+  static thunderLevel(rain: number, thunder: number): number
+  /**
+  * Checks whether it is raining, meaning the rain strength is above 0.2.
+  *
+  * @param rain - The rain strength.
+  */
+  static isRaining(rain: number): boolean
+  /**
+  * Checks whether there is a thunderstorm, meaning the effective thunder strength is above 0.9.
+  *
+  * @param rain - The rain strength.
+  * @param thunder - The thunder strength.
+  */
+  static isThundering(rain: number, thunder: number): boolean
+  /**
+  * Calculates the chance that lightning strikes a chunk at least once during a thunderstorm.
+  *
+  * @param ticks - The number of ticks the thunderstorm lasts.
+  * @param chunks - The number of chunks considered, defaults to 1.
+  * @param chance - The chance per chunk per tick, defaults to 1 in 100000.
+  */
+  static strikeProbability(ticks: number, chunks?: number | undefined | null, chance?: number | undefined | null): number
+  /**
+  * Calculates the expected number of lightning strikes during a thunderstorm.
+  *
+  * @param ticks - The number of ticks the thunderstorm lasts.
+  * @param chunks - The number of chunks considered, defaults to 1.
+  * @param chance - The chance per chunk per tick, defaults to 1 in 100000.
+  */
+  static expectedStrikes(ticks: number, chunks?: number | undefined | null, chance?: number | undefined | null): number
+  /**
+  * Rolls whether lightning strikes a chunk this tick.
+  *
+  * @param random - The random number generator to roll with (JavaRandom).
+  * @param rain - The rain strength.
+  * @param thunder - The thunder strength.
+  */
+  static shouldStrike(random: JavaRandom, rain: number, thunder: number): boolean
+  /**
+  * Picks the position of a lightning strike within a chunk: a random column snapped to its heightmap.
+  *
+  * @param random - The random number generator to pick the column with (JavaRandom).
+  * @param chunk_x - The x coordinate of the chunk.
+  * @param chunk_z - The z coordinate of the chunk.
+  * @param heightmap - Returns the y of the highest motion blocking block in a column, plus one.
+  * @return The block position the lightning strikes.
+  */
+  static lightningPosition(random: JavaRandom, chunkX: number, chunkZ: number, heightmap: (x: number, z: number) => number): Vector3f
+}
+/**
+ * Interpolation building blocks for upsampling noise, sampling meshes and evaluating animation curves.
+ * Corner values are named after their offsets, so c101 sits at x = 1, y = 0, z = 1.
+*/
+export declare class Interp {
+  static lerp(a: number, b: number, t: number): number
+  /**
+  * Interpolates with a cubic Hermite curve between two values with tangents.
+  *
+  * @param p0 - The value at t = 0.
+  * @param m0 - The tangent at t = 0.
+  * @param p1 - The value at t = 1.
+  * @param m1 - The tangent at t = 1.
+  * @param t - The position along the curve (0..1).
+  */
+  static hermite(p0: number, m0: number, p1: number, m1: number, t: number): number
+  /**
+  * Interpolates between the four corners of a square.
+  *
+  * @param tx - The position along x (0..1).
+  * @param ty - The position along y (0..1).
+  */
+  static bilinear(c00: number, c10: number, c01: number, c11: number, tx: number, ty: number): number
+  /**
+  * Interpolates between the eight corners of a cube.
+  *
+  * @param tx - The position along x (0..1).
+  * @param ty - The position along y (0..1).
+  * @param tz - The position along z (0..1).
+  */
+  static trilinear(c000: number, c100: number, c010: number, c110: number, c001: number, c101: number, c011: number, c111: number, tx: number, ty: number, tz: number): number
+  /**
+  * Calculates the barycentric coordinates of a point relative to a triangle.
+  * The point is projected onto the plane of the triangle first.
+  *
+  * @param point - The point (Vector3f).
+  * @param a - The first corner of the triangle (Vector3f).
+  * @param b - The second corner of the triangle (Vector3f).
+  * @param c - The third corner of the triangle (Vector3f).
+  * @return The weights of a, b and c as x, y and z, summing to 1; NaN for degenerate triangles.
+  */
+  static barycentric(point: VectorLike, a: VectorLike, b: VectorLike, c: VectorLike): Vector3f
+  /**
+  * Interpolates values given at the corners of a triangle at a point.
+  *
+  * @param point - The point (Vector3f).
+  * @param a - The first corner of the triangle (Vector3f).
+  * @param b - The second corner of the triangle (Vector3f).
+  * @param c - The third corner of the triangle (Vector3f).
+  * @param value_a - The value at a.
+  * @param value_b - The value at b.
+  * @param value_c - The value at c.
+  */
+  static triangle(point: VectorLike, a: VectorLike, b: VectorLike, c: VectorLike, valueA: number, valueB: number, valueC: number): number
+}
+/**
+ * A natural cubic spline fitted through scalar keyframes. The curve passes through every keyframe
+ * with continuous first and second derivatives, and has no curvature at both ends.
+*/
+export declare class CubicSpline {
+  /**
+  * Fits a spline through keyframes.
+  *
+  * @param times - The times of the keyframes, strictly increasing.
+  * @param values - The value at every keyframe.
+  * Throws if a time or value is not finite, or the keyframes span more time than a number can hold.
+  */
+  constructor(times: Array<number>, values: Array<number>)
+  /**
+  * Evaluates the spline. Times outside the keyframes hold the first or last value.
+  *
+  * @param t - The time to evaluate at.
+  */
+  evaluate(t: number): number
+  /**
+  * Evaluates the slope of the spline, 0 outside the keyframes.
+  *
+  * @param t - The time to evaluate at.
+  */
+  derivative(t: number): number
+}
+/**
+ * Samples an animated number, such as a scale factor or an opacity, by time.
+*/
+export declare class NumberTrack {
+  /**
+  * Constructs a track from keyframes.
+  *
+  * @param times - The times of the keyframes, finite and strictly increasing.
+  * @param values - The value at every keyframe.
+  * @param interpolation - How values between keyframes are computed, defaults to linear (KeyframeInterpolation).
+  * @param mode - What happens past the last keyframe, defaults to hold (PlaybackMode).
+  */
+  constructor(times: Array<number>, values: Array<number>, interpolation?: KeyframeInterpolation | undefined | null, mode?: PlaybackMode | undefined | null)
+  /**
+  * Samples the value of the track at a time.
+  *
+  * @param time - The playback time, sampling the first keyframe when it is NaN.
+  */
+  sample(time: number): number
+  /**
+  * Returns the time between the first and the last keyframe.
+  */
+  duration(): number
+}
+/**
+ * Samples an animated vector, such as a position or a scale, by time.
+*/
+export declare class VectorTrack {
+  /**
+  * Constructs a track from keyframes.
+  *
+  * @param times - The times of the keyframes, finite and strictly increasing.
+  * @param values - The vector at every keyframe (Array<Vector3f>).
+  * @param interpolation - How values between keyframes are computed, defaults to linear (KeyframeInterpolation).
+  * @param mode - What happens past the last keyframe, defaults to hold (PlaybackMode).
+  */
+  constructor(times: Array<number>, values: Array<VectorLike>, interpolation?: KeyframeInterpolation | undefined | null, mode?: PlaybackMode | undefined | null)
+  /**
+  * Samples the vector of the track at a time.
+  *
+  * @param time - The playback time, sampling the first keyframe when it is NaN.
+  */
+  sample(time: number): Vector3f
+  /**
+  * Returns the time between the first and the last keyframe.
+  */
+  duration(): number
+}
+/**
+ * Samples an animated rotation by time. Linear interpolation uses slerp.
+*/
+export declare class QuaternionTrack {
+  /**
+  * Constructs a track from keyframes.
+  *
+  * @param times - The times of the keyframes, finite and strictly increasing.
+  * @param values - The rotation at every keyframe (Array<Quaternion>).
+  * @param interpolation - How values between keyframes are computed, defaults to linear (KeyframeInterpolation).
+  * @param mode - What happens past the last keyframe, defaults to hold (PlaybackMode).
+  */
+  constructor(times: Array<number>, values: Array<Quaternion>, interpolation?: KeyframeInterpolation | undefined | null, mode?: PlaybackMode | undefined | null)
+  /**
+  * Samples the rotation of the track at a time.
+  *
+  * @param time - The playback time, sampling the first keyframe when it is NaN.
+  */
+  sample(time: number): Quaternion
+  /**
+  * Returns the time between the first and the last keyframe.
+  */
+  duration(): number
+}
+/**
+ * Batch encoding of entity movement for network broadcasts using MoveActorDelta semantics:
+ * every position axis and rotation is flagged and sent only when it changed.
+*/
+export declare class MoveDelta {
+  /**
+  * Compares the previous and current state of many entities and computes which fields changed.
+  * Positions are compared after rounding to float precision and rotations after quantizing to
+  * byte angles, so nothing is flagged that the client could not observe. The returned positions
+  * keep the last sent value on axes that are not sent, so feeding them back as the previous
+  * positions lets slow drift accumulate until it passes the threshold.
+  *
+  * @param previous_positions - The positions last sent, packed as x, y, z triplets (Float64Array).
+  * @param positions - The current positions, packed as x, y, z triplets (Float64Array).
+  * @param previous_rotations - The rotations last sent, packed as pitch, yaw, head yaw triplets in degrees (Float64Array).
+  * @param rotations - The current rotations, packed as pitch, yaw, head yaw triplets in degrees (Float64Array).
+  * @param on_ground - Whether every entity is on the ground, sets the ground flag.
+  * @param threshold - The movement on an axis below which it is not sent, defaults to 0.
+  * @return The flags, positions, deltas and quantized rotations of every entity.
+  */
+  static compute(previousPositions: Float64Array, positions: Float64Array, previousRotations: Float64Array, rotations: Float64Array, onGround?: Array<boolean> | undefined | null, threshold?: number | undefined | null): MoveDeltaBatch
+  /**
+  * Quantizes an angle to a byte, 256 steps per full turn.
+  *
+  * @param degrees - The angle in degrees.
+  */
+  static quantizeAngle(degrees: number): number
+  /**
+  * Converts a byte angle back to degrees (0..360).
+  *
+  * @param angle - The byte angle.
+  */
+  static dequantizeAngle(angle: number): number
+  /**
+  * Checks whether the flags of an entity carry any movement or rotation to send.
+  *
+  * @param flags - The MoveActorDelta header flags.
+  */
+  static hasChanges(flags: number): boolean
+}
+/**
+ * Computes which entities are relevant to every player for broadcasts, with hysteresis:
+ * an entity enters the set of a player within the view distance, and only leaves it beyond
+ * the view distance plus the margin, so entities on the edge do not flicker in and out.
+ * Distances are horizontal, in blocks.
+*/
+export declare class InterestManager {
+  margin: number
+  /**
+  * Constructs an interest manager without any relevant entities.
+  *
+  * @param margin - The hysteresis margin in blocks, defaults to 8.
+  */
+  constructor(margin?: number | undefined | null)
+  /**
+  * Computes the relevant entities of every player. Players and entities are identified by their
+  * index, so the order must stay stable between calls for the hysteresis to apply.
+  *
+  * @param players - The players, packed as x, y, z, view distance quadruplets (Float64Array).
+  * @param entities - The entity positions, packed as x, y, z triplets (Float64Array).
+  * @return The indices of the entities relevant to every player, sorted.
+  */
+  compute(players: Float64Array, entities: Float64Array): Array<Array<number>>
+  /**
+  * Checks whether an entity was relevant to a player after the last compute.
+  *
+  * @param player - The index of the player.
+  * @param entity - The index of the entity.
+  */
+  isRelevant(player: number, entity: number): boolean
+  /**
+  * Forgets all relevant sets, so the next compute applies no hysteresis.
+  */
+  reset(): void
+}
+/**
+ * Renders slices of noise into grayscale images, to inspect world generation noise from JS tooling.
+ * Images are row major with one byte per pixel: the first row is the top of the region.
+*/
+export declare class NoiseDebug {
+  /**
+  * Renders a region of Perlin noise. The region spans the plane perpendicular to the slice axis,
+  * mapped like ShapeGen planes: Y uses (x, z), X uses (z, y) and Z uses (x, y).
+  *
+  * @param noise - The noise to sample (PerlinNoise).
+  * @param region - The region of the plane to render, in noise coordinates (Rect).
+  * @param resolution - The width and height of the image in pixels (Vector2f).
+  * @param slice - The slice and value range to render (NoiseSlice).
+  * @return The grayscale pixels (Uint8Array).
+  * Throws if the resolution is below one pixel or holds more pixels than a typed array.
+  */
+  static render(noise: PerlinNoise, region: Rect, resolution: Vector2f, slice?: NoiseSlice | undefined | null): Uint8Array
+  /**
+  * Renders a region of any noise, sampled by a function.
+  *
+  * @param sampler - Returns the noise value at a position.
+  * @param region - The region of the plane to render, in noise coordinates (Rect).
+  * @param resolution - The width and height of the image in pixels (Vector2f).
+  * @param slice - The slice and value range to render (NoiseSlice).
+  * @return The grayscale pixels (Uint8Array).
+  * Throws if the resolution is below one pixel or holds more pixels than a typed array.
+  */
+  static renderWith(sampler: (position: Vector3f) => number, region: Rect, resolution: Vector2f, slice?: NoiseSlice | undefined | null): Uint8Array
+}
+/**
+ * Seeded 3D Perlin noise, equivalent to vanilla's ImprovedNoise.
+ * Samples are roughly within -1..1 and are zero on integer lattice points of the offset space.
+*/
+export declare class PerlinNoise {
+  xo: number
+  yo: number
+  zo: number
+  /**
+  * Constructs the noise from a seed, consuming a JavaRandom the same way vanilla does.
+  *
+  * @param seed - The seed of the noise.
+  */
+  constructor(seed: number)
+  /**
+  * Samples the noise at a position.
+  */
+  sample(x: number, y: number, z: number): number
+  /**
+  * Samples the noise at a position given as a vector.
+  *
+  * @param v - The position to sample (Vector3f).
+  */
+  sampleVector(v: VectorLike): number
+}
+/**
+ * Generates "noise worms", seeded random walks steered by Perlin noise, used to carve tunnels and rivers.
+ * The same seed and options always produce the same path.
+*/
+export declare class NoiseWorm {
+  /**
+  * Generates the path of a worm.
+  *
+  * @param seed - The seed of the worm.
+  * @param start - The position of the first node (Vector3f).
+  * @param options - The shape options (WormOptions).
+  * @return The node positions and radii.
+  * Throws if the path is too long to fit in a typed array or there is not enough memory.
+  */
+  static generate(seed: number, start: VectorLike, options: WormOptions): WormPath
+}
+/**
+ * Small numeric optimizers for tuning angles, timings and similar scalar parameters.
+*/
+export declare class Optimizer {
+  /**
+  * Finds the minimum of a function on an interval using a golden-section search,
+  * refined by a Newton step on the final bracket. The function should be unimodal on the interval.
+  * The function is only ever evaluated inside the interval.
+  *
+  * @param f - The function to minimize.
+  * @param lower - The lower bound of the interval.
+  * @param upper - The upper bound of the interval.
+  * @param tolerance - The width of the bracket at which the search stops, defaults to 1e-6.
+  * @param max_iterations - The maximum number of iterations, defaults to 100.
+  * @return The position and value of the minimum.
+  */
+  static minimize1D(f: (x: number) => number, lower: number, upper: number, tolerance?: number, maxIterations?: number): MinimizeResult
+}
+/**
+ * Represents a boat, stepped with vanilla's buoyancy, friction and paddling math.
+ * Block collisions are left to the caller, the step only moves the boat by its velocity.
+*/
+export declare class Boat {
+  position: Vector3f
+  velocity: Vector3f
+  yaw: number
+  deltaRotation: number
+  /**
+  * Constructs a boat at rest.
+  *
+  * @param position - The position of the boat (Vector3f).
+  * @param yaw - The yaw of the boat in degrees, defaults to 0.
+  */
+  constructor(position: VectorLike, yaw?: number | undefined | null)
+  /**
+  * Advances the boat by a tick: applies gravity, buoyancy and friction for its status,
+  * then the paddle input, then moves it by its velocity.
+  *
+  * @param status - Where the boat is relative to water and ground (BoatStatus).
+  * @param input - The keys held by the paddling player, if any (BoatInput).
+  * @param water_level - The height of the water surface, needed while in water.
+  * @param land_friction - The averaged friction of the blocks below while on land, defaults to 0.6.
+  * @param no_gravity - Whether the boat ignores gravity, defaults to false.
+  */
+  step(status: BoatStatus, input?: BoatInput | undefined | null, waterLevel?: number | undefined | null, landFriction?: number | undefined | null, noGravity?: boolean | undefined | null): void
+  /**
+  * Applies a tick of paddle input: turning, and forward force along the yaw.
+  * Turning in place also moves the boat forward slightly.
+  *
+  * @param input - The keys held by the paddling player (BoatInput).
+  */
+  paddle(input: BoatInput): void
+  /**
+  * Calculates the height a boat settles at when it lands on water, just above the surface.
+  *
+  * @param water_level - The height of the water surface.
+  */
+  static surfaceHeight(waterLevel: number): number
+}
+/**
+ * Regions checked for climbable blocks and interactions around an entity, so every consumer
+ * uses the same offsets. Positions are at the feet of the entity, hitboxes are width x height x width.
+ * Yaw is in degrees, 0 faces south (+Z) and increases clockwise.
+*/
+export declare class Climbing {
+  /**
+  * Calculates the thin region directly in front of the face of the hitbox the entity looks towards.
+  * The yaw is snapped to the nearest cardinal direction, like the facing of the entity.
+  *
+  * @param position - The feet position of the entity (Vector3f).
+  * @param yaw - The yaw of the entity in degrees.
+  * @param width - The width of the hitbox.
+  * @param height - The height of the hitbox.
+  * @param depth - The thickness of the region, defaults to 0.1.
+  * @return The region in front of the entity.
+  */
+  static frontRegion(position: VectorLike, yaw: number, width: number, height: number, depth?: number | undefined | null): AABB
+  /**
+  * Returns the block checked for a climbable block at the feet of an entity, like vanilla's onClimbable.
+  *
+  * @param position - The feet position of the entity (Vector3f).
+  */
+  static feetBlock(position: VectorLike): Vector3f
+  /**
+  * Returns the blocks the region in front of an entity touches, to test for climbable blocks.
+  *
+  * @param position - The feet position of the entity (Vector3f).
+  * @param yaw - The yaw of the entity in degrees.
+  * @param width - The width of the hitbox.
+  * @param height - The height of the hitbox.
+  * @param depth - The thickness of the region, defaults to 0.1.
+  * @return The positions of the blocks, lowest first.
+  * Throws if the region is not finite or spans more blocks than an array can hold.
+  */
+  static frontBlocks(position: VectorLike, yaw: number, width: number, height: number, depth?: number | undefined | null): Array<Vector3f>
+}
+/**
+ * Elytra gliding and riptide launches with vanilla's formulas, for movement simulation and validation.
+ * Rotations are in degrees, yaw 0 faces south (+Z) and increases clockwise, positive pitch looks down.
+*/
+export declare class Flight {
+  /**
+  * Advances the velocity of a gliding entity by a tick. Looking down trades height for speed,
+  * looking up trades speed for height, and the velocity is steered towards the look direction.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param pitch - The pitch of the entity in degrees.
+  * @param yaw - The yaw of the entity in degrees.
+  * @param gravity - The gravity per tick, defaults to 0.08.
+  * @return The velocity after the tick.
+  */
+  static glide(velocity: VectorLike, pitch: number, yaw: number, gravity?: number | undefined | null): Vector3f
+  /**
+  * Applies a tick of firework rocket boost to a gliding entity, which pulls the velocity
+  * towards 1.5 blocks per tick in the look direction.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param pitch - The pitch of the entity in degrees.
+  * @param yaw - The yaw of the entity in degrees.
+  * @return The boosted velocity.
+  */
+  static fireworkBoost(velocity: VectorLike, pitch: number, yaw: number): Vector3f
+  /**
+  * Calculates the velocity added by a riptide launch, 3 * (1 + level) / 4 blocks per tick in
+  * the look direction. Grounded entities are additionally moved up by riptideLift.
+  *
+  * @param pitch - The pitch of the entity in degrees.
+  * @param yaw - The yaw of the entity in degrees.
+  * @param level - The riptide level.
+  * @return The velocity to add.
+  */
+  static riptideLaunch(pitch: number, yaw: number, level: number): Vector3f
+  /**
+  * Returns the blocks a grounded entity is moved up when launched by riptide.
+  */
+  static riptideLift(): number
+}
+/**
+ * Per tick velocity updates of entities in fluids and bubble columns, with vanilla's constants.
+ * Movement input is applied before the drag of the tick, collisions are left to the caller.
+ * Rotations are in degrees, yaw 0 faces south (+Z) and increases clockwise.
+*/
+export declare class FluidMotion {
+  /**
+  * Advances the velocity of an entity in water by a tick: input acceleration, drag and the
+  * reduced gravity of fluids.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param input - The movement input (MovementInput).
+  * @param yaw - The yaw of the entity in degrees.
+  * @param options - The flags of the entity (SwimOptions).
+  * @return The velocity after the tick.
+  */
+  static waterStep(velocity: VectorLike, input: MovementInput, yaw: number, options?: SwimOptions | undefined | null): Vector3f
+  /**
+  * Advances the velocity of an entity in lava by a tick.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param input - The movement input (MovementInput).
+  * @param yaw - The yaw of the entity in degrees.
+  * @param gravity - The gravity per tick, defaults to 0.08.
+  * @return The velocity after the tick.
+  */
+  static lavaStep(velocity: VectorLike, input: MovementInput, yaw: number, gravity?: number | undefined | null): Vector3f
+  /**
+  * Steers the vertical velocity of a sprint swimming player towards the look direction.
+  * Looking down pulls harder (0.085) than looking up (0.06), and looking up only has an
+  * effect while jumping or with fluid above the head.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param pitch - The pitch of the player in degrees.
+  * @param jumping - Whether the player is holding jump.
+  * @param fluid_above - Whether there is fluid above the head of the player.
+  * @return The velocity with the vertical component adjusted.
+  */
+  static swimVertical(velocity: VectorLike, pitch: number, jumping: boolean, fluidAbove: boolean): Vector3f
+  /**
+  * Returns the vertical velocity gained per tick by jumping in a fluid.
+  */
+  static jumpInFluid(velocity: VectorLike): Vector3f
+  /**
+  * Pushes an entity along the flow of a fluid. Entities at rest are pushed at least 0.0045 blocks per tick.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param flow - The summed flow of the fluid blocks the entity touches (Vector3f).
+  * @param lava - Whether the fluid is lava, defaults to false.
+  * @return The pushed velocity; otherwise, the velocity unchanged for a flow that is zero or not finite.
+  */
+  static fluidPush(velocity: VectorLike, flow: VectorLike, lava?: boolean | undefined | null): Vector3f
+  /**
+  * Applies a tick of a bubble column. Inside the column, magma columns drag down to at most 0.3
+  * blocks per tick and soul sand columns lift up to 0.7; at the surface just above the column
+  * the limits rise to 0.9 and 1.8, which launches entities out of the water.
+  *
+  * @param velocity - The current velocity (Vector3f).
+  * @param drag_down - Whether the column drags down (magma) instead of up (soul sand).
+  * @param surface - Whether the entity is in the air just above the column rather than inside it.
+  * @return The velocity after the tick.
+  */
+  static bubbleColumn(velocity: VectorLike, dragDown: boolean, surface: boolean): Vector3f
+}
+/**
+ * Minecart movement with vanilla's rail snapping, slope acceleration and powered rails.
+ * Block collisions are left to the caller; derailed carts only fall and slow down.
+*/
+export declare class Minecart {
+  /**
+  * Advances a minecart by a tick.
+  *
+  * @param position - The position of the minecart (Vector3f).
+  * @param velocity - The velocity of the minecart (Vector3f).
+  * @param rail - The rail in the block at the position; undefined when derailed (MinecartRail).
+  * @param options - The flags of the minecart (MinecartOptions).
+  * @return The new position and velocity, and whether the minecart moved along a rail. A NaN velocity stops a minecart on a rail.
+  */
+  static step(position: VectorLike, velocity: VectorLike, rail?: MinecartRail | undefined | null, options?: MinecartOptions | undefined | null): MinecartStep
+}
+/**
+ * Conversions between world space movement and yaw relative movement input.
+ * Yaw is in degrees, 0 faces south (+Z) and increases clockwise.
+*/
+export declare class Movement {
+  /**
+  * Decomposes a horizontal velocity into forward and strafe components relative to a yaw.
+  *
+  * @param velocity - The world space velocity, the y component is ignored (Vector3f).
+  * @param yaw - The yaw the movement is relative to, in degrees.
+  * @return The forward and strafe components.
+  */
+  static decompose(velocity: VectorLike, yaw: number): MovementInput
+  /**
+  * Composes a horizontal world space velocity from forward and strafe input.
+  * This is the inverse of decompose.
+  *
+  * @param input - The forward and strafe components (MovementInput).
+  * @param yaw - The yaw the input is relative to, in degrees.
+  * @return The world space velocity, with a y component of 0.
+  */
+  static compose(input: MovementInput, yaw: number): Vector3f
+  /**
+  * Applies movement input the way vanilla does each tick: input longer than 1 is
+  * normalized before being scaled by the speed and rotated by the yaw.
+  *
+  * @param input - The raw movement input (MovementInput).
+  * @param yaw - The yaw of the entity, in degrees.
+  * @param speed - The movement speed of the entity.
+  * @return The velocity to add for this tick.
+  */
+  static relative(input: MovementInput, yaw: number, speed: number): Vector3f
+  /**
+  * Calculates the signed difference between a body and head yaw, wrapped to -180..180.
+  *
+  * @param body_yaw - The body yaw in degrees.
+  * @param head_yaw - The head yaw in degrees.
+  */
+  static yawDifference(bodyYaw: number, headYaw: number): number
+}
+/**
+ * Kinematic platforms such as elevators and moving floors, which carry the entities standing on them.
+*/
+export declare class Platform {
+  /**
+  * Computes how far a rider is carried by a platform moving from one box to another, optionally
+  * turning around its center. The feet of the rider follow the top of the platform, and turning
+  * swings the rider around the center of the platform.
+  *
+  * @param old_box - The box of the platform before the step (AABB).
+  * @param new_box - The box of the platform after the step (AABB).
+  * @param rider - The hitbox of the rider before the step (AABB).
+  * @param rotation - The yaw the platform turned by during the step in degrees, clockwise like entity yaw, defaults to 0.
+  *                   A rotation that is not finite is ignored.
+  * @param tolerance - The gap between the feet and the platform still counted as standing, defaults to 0.05.
+  * @return The displacement of the rider, whether it was standing and whether it is still supported.
+  */
+  static carry(oldBox: AABB, newBox: AABB, rider: AABB, rotation?: number | undefined | null, tolerance?: number | undefined | null): PlatformCarry
+  /**
+  * Checks whether a rider stands on top of a platform: its feet are at the top of the platform
+  * and its footprint overlaps the platform.
+  *
+  * @param platform - The box of the platform (AABB).
+  * @param rider - The hitbox of the rider (AABB).
+  * @param tolerance - The gap between the feet and the platform still counted as standing, defaults to 0.05.
+  */
+  static isStanding(platform: AABB, rider: AABB, tolerance?: number | undefined | null): boolean
+}
+/**
+ * The soft push vanilla applies between overlapping entities, computed for a crowd in one call.
+*/
+export declare class EntityPush {
+  /**
+  * Computes the horizontal push velocities of overlapping entities. Every overlapping pair pushes
+  * apart along the line between their centers; the push grows as the entities get closer, up to
+  * 0.05 blocks per tick, and pairs closer than 0.01 blocks on both axes are left alone like vanilla.
+  *
+  * @param boxes - The hitboxes of the entities, packed as min x, y, z and max x, y, z (Float64Array).
+  * @param pushable - Whether every entity can be pushed, defaults to all of them.
+  * @return The velocity to add to every entity, packed as x, y, z triplets (Float64Array).
+  */
+  static compute(boxes: Float64Array, pushable?: Array<boolean> | undefined | null): Float64Array
+}
+/**
+ * Evaluates the path a minecart follows along a rail block.
+ * Progress 0 is the first exit of the shape and 1 the second exit; positions are
+ * relative to the block origin unless a block position is added.
+*/
+export declare class RailPath {
+  /**
+  * Returns the two exits of a rail shape as block offsets, like vanilla's exit table.
+  * An exit with a y of -1 marks the lower end of an ascending rail.
+  *
+  * @param shape - The rail shape (RailShape).
+  */
+  static exits(shape: RailShape): Array<Vector3f>
+  /**
+  * Evaluates the position and direction on a rail for a progress value.
+  * Curved rails follow the straight chord between their exits like vanilla minecarts,
+  * or a quarter circle when smooth is set.
+  *
+  * @param block - The position of the rail block (Vector3f).
+  * @param shape - The rail shape (RailShape).
+  * @param t - The progress along the rail from the first to the second exit (0..1).
+  * @param smooth - Whether curved rails follow an arc, defaults to false.
+  * @return The position and direction of travel.
+  */
+  static evaluate(block: VectorLike, shape: RailShape, t: number, smooth?: boolean | undefined | null): RailPoint
+  /**
+  * Calculates the length of the path along a rail block.
+  *
+  * @param shape - The rail shape (RailShape).
+  * @param smooth - Whether curved rails follow an arc, defaults to false.
+  */
+  static length(shape: RailShape, smooth?: boolean | undefined | null): number
+  /**
+  * Projects a position onto the straight path of a rail block, returning the progress
+  * of the closest point. This matches how vanilla snaps minecarts onto rails.
+  *
+  * @param block - The position of the rail block (Vector3f).
+  * @param shape - The rail shape (RailShape).
+  * @param position - The position to project (Vector3f).
+  * @return The progress of the closest point on the rail (0..1).
+  */
+  static project(block: VectorLike, shape: RailShape, position: VectorLike): number
+  static isAscending(shape: RailShape): boolean
+  static isCurved(shape: RailShape): boolean
+}
+/**
+ * Stores the positions, velocities and hitbox sizes of many entities in packed arrays,
+ * with kernels running a tick of simple motion for all of them in a few calls.
+ * Positions are at the feet of the entity, hitboxes are width x height x width like vanilla.
+*/
+export declare class EntityStateBuffer {
+  /**
+  * Constructs a buffer of entities at the origin, at rest and without a hitbox.
+  *
+  * @param count - The number of entities.
+  */
+  constructor(count: number)
+  /**
+  * Returns the number of entities.
+  */
+  size(): number
+  /**
+  * Changes the number of entities, appending entities at the origin or dropping the last ones.
+  * Returns an error, leaving the buffer unchanged, when the count is too large or there is not enough memory.
+  *
+  * @param count - The new number of entities.
+  */
+  resize(count: number): void
+  /**
+  * Returns the positions, packed as x, y, z triplets (Float64Array).
+  */
+  positions(): Float64Array
+  /**
+  * Replaces the positions of all entities.
+  *
+  * @param positions - The positions, packed as x, y, z triplets (Float64Array).
+  */
+  setPositions(positions: Float64Array): void
+  /**
+  * Returns the velocities, packed as x, y, z triplets (Float64Array).
+  */
+  velocities(): Float64Array
+  /**
+  * Replaces the velocities of all entities.
+  *
+  * @param velocities - The velocities, packed as x, y, z triplets (Float64Array).
+  */
+  setVelocities(velocities: Float64Array): void
+  /**
+  * Replaces the hitbox sizes of all entities.
+  *
+  * @param sizes - The sizes, packed as width, height pairs (Float64Array).
+  */
+  setSizes(sizes: Float64Array): void
+  /**
+  * Returns the hitboxes as computed by the last recomputeBounds call.
+  *
+  * @return The boxes, packed as min x, y, z and max x, y, z (Float64Array).
+  */
+  bounds(): Float64Array
+  /**
+  * Returns the position of an entity.
+  *
+  * @param index - The index of the entity.
+  */
+  getPosition(index: number): Vector3f | null
+  /**
+  * Sets the position of an entity. Out of range indices are ignored.
+  *
+  * @param index - The index of the entity.
+  * @param position - The new position (Vector3f).
+  */
+  setPosition(index: number, position: VectorLike): void
+  /**
+  * Returns the velocity of an entity.
+  *
+  * @param index - The index of the entity.
+  */
+  getVelocity(index: number): Vector3f | null
+  /**
+  * Sets the velocity of an entity. Out of range indices are ignored.
+  *
+  * @param index - The index of the entity.
+  * @param velocity - The new velocity (Vector3f).
+  */
+  setVelocity(index: number, velocity: VectorLike): void
+  /**
+  * Moves every entity by its velocity.
+  *
+  * @param delta - The number of ticks to move for, defaults to 1.
+  */
+  integrate(delta?: number | undefined | null): void
+  /**
+  * Applies a tick of gravity and air drag to every velocity, in vanilla order:
+  * gravity is subtracted first, then the vertical and horizontal drag are applied.
+  *
+  * @param gravity - The downwards acceleration per tick, defaults to 0.08.
+  * @param vertical_drag - The vertical velocity kept per tick, defaults to 0.98.
+  * @param horizontal_drag - The horizontal velocity kept per tick, defaults to 0.91.
+  */
+  applyGravity(gravity?: number | undefined | null, verticalDrag?: number | undefined | null, horizontalDrag?: number | undefined | null): void
+  /**
+  * Recomputes the hitbox of every entity from its position and size.
+  *
+  * @return The boxes, packed as min x, y, z and max x, y, z (Float64Array).
+  */
+  recomputeBounds(): Float64Array
+  /**
+  * Finds the entities that moved further than a threshold since their last reported movement,
+  * then remembers their positions for the next check.
+  *
+  * @param threshold - The distance an entity must move to be dirty, defaults to 0.
+  * @return The indices of the dirty entities.
+  */
+  computeDirty(threshold?: number | undefined | null): Array<number>
+}
+/**
+ * Central points of groups of positions, for gathering points, spawn anchors and AI group targets.
+*/
+export declare class Points {
+  /**
+  * Calculates the centroid (mean position) of points.
+  *
+  * @param points - The positions, packed as x, y, z triplets (Float64Array).
+  * @param weights - The weight of every point, defaults to 1 each.
+  * @return The centroid; otherwise, undefined without points or with a total weight of 0.
+  */
+  static centroid(points: Float64Array, weights?: Array<number> | undefined | null): Vector3f | null
+  /**
+  * Approximates the geometric median of points with Weiszfeld's algorithm: the position with the
+  * smallest summed distance to all points. Unlike the centroid it is not dragged away by outliers.
+  *
+  * @param points - The positions, packed as x, y, z triplets (Float64Array).
+  * @param max_iterations - The maximum number of refinement steps, defaults to 100.
+  * @param tolerance - The movement of the estimate below which it is considered converged, defaults to 1e-6.
+  * @return The geometric median; otherwise, undefined without points.
+  */
+  static geometricMedian(points: Float64Array, maxIterations?: number | undefined | null, tolerance?: number | undefined | null): Vector3f | null
+  /**
+  * Sums the distances from a position to all points, the quantity the geometric median minimizes.
+  *
+  * @param points - The positions, packed as x, y, z triplets (Float64Array).
+  * @param position - The position to measure from (Vector3f).
+  */
+  static totalDistance(points: Float64Array, position: VectorLike): number
+}
+/**
+ * Hands out reusable Vector3f instances, so code running per entity per tick does not
+ * allocate thousands of short lived vectors. Pooled vectors are kept alive by the pool,
+ * and must not be used after they are released.
+*/
+export declare class VectorPool {
+  /**
+  * Constructs an empty pool.
+  */
+  constructor()
+  /**
+  * Takes a vector from the pool, or creates one when the pool is empty. The vector is reset to zero.
+  */
+  acquire(): Vector3f
+  /**
+  * Returns a vector to the pool. Releasing a vector which is already pooled does nothing.
+  *
+  * @param vector - The vector to return (Vector3f).
+  */
+  release(vector: Vector3f): void
+  /**
+  * Calls a function and releases every vector acquired while it runs once it returns, even if it throws.
+  * A vector returned by the function stays with the caller and is not released.
   *
-  * @param number - The number to calculate the boundary for.
-  * @return The distance from the number to the nearest lower integer.
+  * @param callback - The function to run.
+  * @return The return value of the function.
   */
-  static boundary(n: number): number
+  scoped(callback: () => unknown): unknown
+  /**
+  * Returns the number of vectors waiting in the pool.
+  */
+  size(): number
+  /**
+  * Drops every pooled vector, letting them be garbage collected.
+  */
+  clear(): void
+}
+/**
+ * Utilities for converting user supplied text into world seeds.
+ * The conversions mirror vanilla so the same input always produces the same world.
+*/
+export declare class Seed {
+  /**
+  * Converts a seed entered as text into a numeric seed, like vanilla's world creation screen.
+  * Numeric input, including 0, is parsed as a 64-bit integer, anything else is hashed using
+  * Java's String.hashCode. The seed is returned as a decimal string, since 64-bit seeds
+  * lose precision as JS numbers.
+  *
+  * @param s - The seed text.
+  * @return The resulting seed, or "0" for empty input.
+  */
+  static fromString(s: string): string
+  /**
+  * Computes Java's String.hashCode over the UTF-16 code units of a string.
+  *
+  * @param s - The string to hash.
+  * @return The 32-bit hash, wrapping exactly like Java int arithmetic.
+  */
+  static hashCode(s: string): number
+  /**
+  * Computes the 64-bit variant of String.hashCode, using the same polynomial
+  * with Java long arithmetic.
+  *
+  * @param s - The string to hash.
+  * @return The 64-bit hash as a decimal string, since it may exceed the precision of JS numbers.
+  */
+  static hashCode64(s: string): string
+  /**
+  * Converts a UUID string into a 64-bit seed by XORing its most and least significant halves.
+  * This is the first step of Java's UUID.hashCode, which then folds the result down to 32 bits;
+  * the seed keeps all 64 bits.
+  *
+  * @param uuid - The UUID in its canonical 8-4-4-4-12 form.
+  * @return The seed derived from the UUID, as a decimal string.
+  */
+  static fromUuid(uuid: string): string
+  /**
+  * Computes the seed vanilla derives from a block position, which positional generators mix
+  * into their own seed so every block gets a reproducible sequence.
+  *
+  * @param x - The x coordinate of the block.
+  * @param y - The y coordinate of the block.
+  * @param z - The z coordinate of the block.
+  * @return The seed of the position.
+  */
+  static positional(x: number, y: number, z: number): number
+}
+/**
+ * A seedable random number generator producing the exact sequence of java.util.Random,
+ * which vanilla world generation and loot rolls are built on.
+*/
+export declare class JavaRandom {
+  /**
+  * Constructs a generator from a seed, scrambled the same way Java does.
+  *
+  * @param seed - The seed as a number, or a decimal string beyond 2^53; only the lower 48 bits are used.
+  * Throws if the seed is not a 64-bit integer.
+  */
+  constructor(seed: number | string)
+  /**
+  * Resets the generator to a new seed.
+  *
+  * @param seed - The seed as a number, or a decimal string beyond 2^53; only the lower 48 bits are used.
+  * Throws if the seed is not a 64-bit integer.
+  */
+  setSeed(seed: number | string): void
+  /**
+  * Returns a uniformly distributed int, bounded to [0, bound) when a bound is given.
+  * Like Java, a bound that is not positive is an error.
+  *
+  * @param bound - The exclusive upper bound, must be positive.
+  */
+  nextInt(bound?: number | undefined | null): number
+  /**
+  * Returns a uniformly distributed 64-bit integer. Values beyond 2^53 lose precision once they reach JS.
+  */
+  nextLong(): number
+  nextBoolean(): boolean
+  /**
+  * Returns a uniformly distributed float in [0, 1), with 24 bits of precision.
+  */
+  nextFloat(): number
+  /**
+  * Returns a uniformly distributed double in [0, 1), with 53 bits of precision.
+  */
+  nextDouble(): number
+  /**
+  * Returns a normally distributed double with a mean of 0 and a standard deviation of 1,
+  * using the polar method. Values are generated in pairs, so every other call draws nothing.
+  */
+  nextGaussian(): number
+  /**
+  * Splits off an independent generator seeded from this one, like vanilla's fork.
+  */
+  fork(): JavaRandom
+  /**
+  * Shuffles values in place with Fisher-Yates, in the same order as Java's Collections.shuffle.
+  *
+  * @param values - The values to shuffle, such as indices into a list.
+  * @return The shuffled values.
+  */
+  shuffle(values: Array<number>): Array<number>
+  /**
+  * Picks k distinct indices out of n, in the order they were drawn.
+  * Memory grows with k only, so small samples of huge ranges are cheap.
+  *
+  * @param n - The number of indices to pick from, at most 2^31 - 1 like a Java int bound.
+  * @param k - The number of indices to pick, at most n.
+  * @return The picked indices.
+  */
+  sample(n: number, k: number): Array<number>
+}
+/**
+ * A seedable random number generator producing the exact sequence of vanilla's Xoroshiro128++
+ * random source, which modern world generation uses instead of java.util.Random.
+*/
+export declare class Xoroshiro128PlusPlus {
+  /**
+  * Constructs a generator from a 64-bit seed, expanded to 128 bits the same way vanilla does.
+  *
+  * @param seed - The seed as a number, or a decimal string beyond 2^53.
+  * Throws if the seed is not a 64-bit integer.
+  */
+  constructor(seed: number | string)
+  /**
+  * Constructs a generator from its raw 128-bit state. An all zero state is replaced
+  * by a fixed one, since it would only produce zeros.
+  *
+  * @param low - The lower 64 bits of the state, as a number or a decimal string beyond 2^53.
+  * @param high - The upper 64 bits of the state, as a number or a decimal string beyond 2^53.
+  * Throws if the state is not made of 64-bit integers.
+  */
+  static fromState(low: number | string, high: number | string): Xoroshiro128PlusPlus
+  /**
+  * Resets the generator to a new seed.
+  *
+  * @param seed - The seed as a number, or a decimal string beyond 2^53.
+  * Throws if the seed is not a 64-bit integer.
+  */
+  setSeed(seed: number | string): void
+  /**
+  * Returns a uniformly distributed 64-bit integer. Values beyond 2^53 lose precision once they reach JS.
+  */
+  nextLong(): number
+  /**
+  * Returns a uniformly distributed int, bounded to [0, bound) when a bound is given.
+  * Bounded values use Lemire's multiply and reject method, like vanilla, and a bound that
+  * is not positive is an error.
+  *
+  * @param bound - The exclusive upper bound, must be positive.
+  */
+  nextInt(bound?: number | undefined | null): number
+  nextBoolean(): boolean
+  /**
+  * Returns a uniformly distributed float in [0, 1), with 24 bits of precision.
+  */
+  nextFloat(): number
+  /**
+  * Returns a uniformly distributed double in [0, 1), with 53 bits of precision.
+  */
+  nextDouble(): number
+  /**
+  * Returns a normally distributed double with a mean of 0 and a standard deviation of 1,
+  * using the polar method. Values are generated in pairs, so every other call draws nothing.
+  * Vanilla takes the logarithm with Math.log here, which the JVM only guarantees to within
+  * one ulp, so rare values can differ from a given JVM in the last bit.
+  */
+  nextGaussian(): number
+  /**
+  * Skips a number of values, as if next long had been called that many times.
+  *
+  * @param count - The number of values to skip.
+  */
+  consume(count: number): void
+  /**
+  * Splits off an independent generator whose state is drawn from this one, like vanilla's fork.
+  */
+  fork(): Xoroshiro128PlusPlus
+  /**
+  * Returns the generator of a block position, using this generator as a positional factory
+  * without advancing it. Forking first and then calling this for every position matches
+  * vanilla's forkPositional().at(x, y, z).
+  *
+  * @param x - The x coordinate of the block.
+  * @param y - The y coordinate of the block.
+  * @param z - The z coordinate of the block.
+  */
+  at(x: number, y: number, z: number): Xoroshiro128PlusPlus
+}
+/**
+ * Conversions between look rotations and directions using Minecraft's conventions: yaw 0 faces
+ * south (+Z), 90 degrees faces west (-X), and positive pitch looks down. Angles are in the angle unit
+ * of the global MathConfig, degrees by default, except for wrapDegrees which always takes degrees.
+*/
+export declare class Rotation {
+  /**
+  * Returns the unit direction an entity with a rotation looks in.
+  *
+  * @param yaw - The yaw in the configured unit.
+  * @param pitch - The pitch in the configured unit.
+  */
+  static directionFromRotation(yaw: number, pitch: number): Vector3f
+  /**
+  * Returns the rotation that looks along a direction. Straight up or down directions keep a yaw of 0,
+  * and a zero vector gives a zero rotation.
+  *
+  * @param v - The direction, it does not need to be normalized (Vector3f).
+  */
+  static rotationFromDirection(v: VectorLike): YawPitch
+  /**
+  * Returns the rotation to look from one position at another.
+  *
+  * @param from - The eye position (Vector3f).
+  * @param to - The position to look at (Vector3f).
+  */
+  static lookAt(from: VectorLike, to: VectorLike): YawPitch
+  /**
+  * Wraps an angle into -180..180 like vanilla, with 180 wrapping to -180.
+  *
+  * @param degrees - The angle in degrees.
+  */
+  static wrapDegrees(degrees: number): number
+  /**
+  * Returns the shortest signed turn from one angle to another.
+  *
+  * @param from - The current angle in the configured unit.
+  * @param to - The target angle in the configured unit.
+  * @return The turn (-180..180 degrees) in the configured unit, positive turning clockwise.
+  */
+  static difference(from: number, to: number): number
+  /**
+  * Turns an angle towards a target by at most a step, taking the shortest way around,
+  * like mobs turning their heads.
+  *
+  * @param from - The current angle in the configured unit.
+  * @param to - The target angle in the configured unit.
+  * @param max_step - The largest turn allowed in the configured unit, a NaN step leaves the angle unchanged.
+  */
+  static approach(from: number, to: number, maxStep: number): number
+}
+/**
+ * Generates block positions for common building shapes.
+ * All shapes are rasterized around the block containing the center position.
+*/
+export declare class ShapeGen {
+  /**
+  * Generates the block positions of a circle, ring or arc lying in a plane.
+  * The plane is perpendicular to the given axis: Y uses the (x, z) plane, X uses (z, y) and Z uses (x, y).
+  * Angles are measured in degrees from the first plane axis towards the second.
+  *
+  * @param center - The center of the circle (Vector3f).
+  * @param radius - The radius in blocks.
+  * @param axis - The axis perpendicular to the plane of the circle (Axis).
+  * @param thickness - The thickness of the ring in blocks, defaults to 1.
+  * @param filled - Whether the circle is filled, defaults to false.
+  * @param start_angle - The start angle of the arc in degrees, defaults to 0.
+  * @param end_angle - The end angle of the arc in degrees, defaults to 360.
+  * @return The positions of all blocks in the shape.
+  * Throws if the circle spans too many blocks to generate.
+  */
+  static circle(center: VectorLike, radius: number, axis: Axis, thickness?: number | undefined | null, filled?: boolean | undefined | null, startAngle?: number | undefined | null, endAngle?: number | undefined | null): Array<Vector3f>
+  /**
+  * Generates the block positions of a helix winding around an axis.
+  *
+  * @param center - The center of the helix base (Vector3f).
+  * @param radius - The radius in blocks.
+  * @param height - The height of the helix along the axis, may be negative.
+  * @param turns - The number of full turns.
+  * @param axis - The axis the helix winds around, defaults to the up axis of the global MathConfig (Axis).
+  * @param thickness - The thickness of the curve in blocks, defaults to 1.
+  * @return The connected block positions, ordered from the base to the top.
+  * Throws if the thickened curve covers too many blocks to generate.
+  */
+  static helix(center: VectorLike, radius: number, height: number, turns: number, axis?: Axis | undefined | null, thickness?: number | undefined | null): Array<Vector3f>
+  /**
+  * Rasterizes a parametric curve, given as sampled points, into a connected set of blocks.
+  * Consecutive samples are joined by straight segments, so any f(t) can be sampled in JS.
+  *
+  * @param points - The sampled points, packed as x, y, z triplets (Float64Array).
+  * @param thickness - The thickness of the curve in blocks, defaults to 1.
+  * @return The connected block positions, ordered along the curve.
+  * Throws if the thickened curve covers too many blocks to generate.
+  */
+  static curve(points: Float64Array, thickness?: number | undefined | null): Array<Vector3f>
+}
+/**
+ * Helpers for structure blocks and world edit selections: normalizing two corners into
+ * an origin and size, enforcing Bedrock's size limits and checking for overlaps.
+*/
+export declare class StructureBounds {
+  /**
+  * Normalizes two arbitrary corner blocks into the minimum block and the size of the selection.
+  * Both corners are included, so equal corners select a single block.
+  *
+  * @param corner_a - The first corner block (Vector3f).
+  * @param corner_b - The second corner block (Vector3f).
+  */
+  static normalize(cornerA: VectorLike, cornerB: VectorLike): StructureRegion
+  /**
+  * Returns the inclusive corner block opposite to the origin of a selection.
+  *
+  * @param region - The selection (StructureRegion).
+  */
+  static maxCorner(region: StructureRegion): Vector3f
+  /**
+  * Checks whether a size fits within the structure size limits.
+  *
+  * @param size - The size in blocks (Vector3f).
+  * @param limit - The maximum size, defaults to Bedrock's 64x384x64 (Vector3f).
+  * Throws if the limit is below 1 or NaN on any axis.
+  */
+  static fits(size: VectorLike, limit?: VectorLike | undefined | null): boolean
+  /**
+  * Shrinks a selection to fit within the structure size limits, keeping its origin.
+  *
+  * @param region - The selection (StructureRegion).
+  * @param limit - The maximum size, defaults to Bedrock's 64x384x64 (Vector3f).
+  * Throws if the limit is below 1 or NaN on any axis.
+  */
+  static clamp(region: StructureRegion, limit?: VectorLike | undefined | null): StructureRegion
+  /**
+  * Normalizes two corner blocks and checks the selection against the structure size limits.
+  *
+  * @param corner_a - The first corner block (Vector3f).
+  * @param corner_b - The second corner block (Vector3f).
+  * @param limit - The maximum size, defaults to Bedrock's 64x384x64 (Vector3f).
+  * @return The selection; otherwise, an error naming the size when it is too large or the limit when it is below 1.
+  */
+  static validate(cornerA: VectorLike, cornerB: VectorLike, limit?: VectorLike | undefined | null): StructureRegion
+  /**
+  * Converts a selection into the AABB covering its blocks.
+  *
+  * @param region - The selection (StructureRegion).
+  */
+  static toAabb(region: StructureRegion): AABB
+  /**
+  * Checks whether two selections share at least one block.
+  *
+  * @param a - The first selection (StructureRegion).
+  * @param b - The second selection (StructureRegion).
+  */
+  static overlaps(a: StructureRegion, b: StructureRegion): boolean
+  /**
+  * Finds the regions a selection must not intersect that it overlaps, such as protected areas
+  * or other pending structures.
+  *
+  * @param region - The selection (StructureRegion).
+  * @param forbidden - The regions the selection must not intersect (Array<StructureRegion>).
+  * @return The indices of the overlapped regions, empty when the selection is allowed.
+  */
+  static findOverlaps(region: StructureRegion, forbidden: Array<StructureRegion>): Array<number>
+}
+/**
+ * Transforms volumes of block ids, such as structure and clipboard data.
+ * Volumes are stored in ZYX order like .mcstructure files: the index of (x, y, z)
+ * is (x * sizeY + y) * sizeZ + z.
+*/
+export declare class VolumeTransform {
+  /**
+  * Rotates a volume around the Y axis in steps of 90 degrees clockwise, seen from above.
+  *
+  * @param data - The block ids of the volume (Uint32Array).
+  * @param size - The dimensions of the volume (Vector3f).
+  * @param times - The number of 90 degree steps, negative values rotate counterclockwise.
+  * @return The rotated volume and its new dimensions.
+  */
+  static rotate90(data: Uint32Array, size: VectorLike, times: number): Volume
+  /**
+  * Mirrors a volume along an axis, flipping the order of blocks on that axis.
+  *
+  * @param data - The block ids of the volume (Uint32Array).
+  * @param size - The dimensions of the volume (Vector3f).
+  * @param axis - The axis to flip (Axis).
+  * @return The mirrored volume, its dimensions are unchanged.
+  */
+  static mirror(data: Uint32Array, size: VectorLike, axis: Axis): Volume
+  /**
+  * Scales a volume up by an integer factor, repeating every block (nearest neighbor).
+  *
+  * @param data - The block ids of the volume (Uint32Array).
+  * @param size - The dimensions of the volume (Vector3f).
+  * @param factor - The integer scale factor, at least 1.
+  * @return The scaled volume and its new dimensions.
+  */
+  static upscale(data: Uint32Array, size: VectorLike, factor: number): Volume
+  /**
+  * Scales a volume down by an integer factor. Every output block takes the most common
+  * id of the cell it covers, ties keep the id found first. Cells on the far edges may be partial.
+  *
+  * @param data - The block ids of the volume (Uint32Array).
+  * @param size - The dimensions of the volume (Vector3f).
+  * @param factor - The integer scale factor, at least 1.
+  * @param ignore - An id (usually air) that only wins when a cell contains nothing else.
+  * @return The scaled volume and its new dimensions.
+  */
+  static downsample(data: Uint32Array, size: VectorLike, factor: number, ignore?: number | undefined | null): Volume
+  /**
+  * Calculates the index of a position inside a volume of the given size.
+  *
+  * @param size - The dimensions of the volume (Vector3f).
+  * @param position - The position inside the volume (Vector3f).
+  * @return The index; otherwise, undefined if the position is outside the volume.
+  */
+  static index(size: VectorLike, position: VectorLike): number | null
+}
+/**
+ * A Catmull-Rom spline passing through its control points, for camera paths and cinematics.
+ * The arc length is sampled adaptively on construction, so points can be looked up by the
+ * distance travelled and paths can be followed at constant speed.
+*/
+export declare class Spline {
+  /**
+  * Constructs a spline through control points.
+  *
+  * @param points - The control points, packed as x, y, z triplets (Float64Array).
+  * @param closed - Whether the spline loops back to its first point, defaults to false.
+  * @param tolerance - The maximum error of the arc length per segment, defaults to 0.001 blocks.
+  * Throws if there are fewer than 2 control points or a coordinate is not finite.
+  */
+  constructor(points: Float64Array, closed?: boolean | undefined | null, tolerance?: number | undefined | null)
+  /**
+  * Returns the point at a parameter, where 0 is the start and 1 the end of the spline.
+  * Equal steps of the parameter do not cover equal distances, see pointAtDistance.
+  *
+  * @param t - The parameter (0..1).
+  */
+  pointAt(t: number): Vector3f
+  /**
+  * Returns the length of the spline.
+  */
+  totalLength(): number
+  /**
+  * Returns the parameter reached after travelling a distance along the spline.
+  *
+  * @param distance - The distance from the start, clamped to the length.
+  */
+  tAtDistance(distance: number): number
+  /**
+  * Returns the point reached after travelling a distance along the spline, for constant speed movement.
+  *
+  * @param distance - The distance from the start, clamped to the length.
+  */
+  pointAtDistance(distance: number): Vector3f
+  /**
+  * Samples points spaced evenly along the spline.
+  *
+  * @param count - The number of points, including both ends.
+  * @return The points, packed as x, y, z triplets (Float64Array).
+  * Throws if the points do not fit in a typed array or there is not enough memory.
+  */
+  sampleEvenly(count: number): Float64Array
+}
+/**
+ * Helpers distributing recurring tasks across the ticks of a period, so that
+ * mass updates are spread out instead of all running on the same tick.
+*/
+export declare class TickSchedule {
+  /**
+  * Distributes tasks using the golden-ratio sequence. Every prefix of the sequence
+  * is close to evenly spread, so tasks can be added over time without rebalancing.
+  *
+  * @param count - The number of tasks.
+  * @param period - The number of ticks between runs of a task.
+  * @param start - The index of the first task in the sequence, defaults to 0.
+  * @return The tick offset (0..period) of every task.
+  * Throws if there is not enough memory for the offsets.
+  */
+  static goldenRatioOffsets(count: number, period: number, start?: number | undefined | null): Array<number>
+  /**
+  * Distributes tasks evenly by splitting the period into one stratum per task,
+  * placing each task at a jittered position inside its stratum.
+  *
+  * @param count - The number of tasks.
+  * @param period - The number of ticks between runs of a task.
+  * @param seed - The seed for the jitter; without a seed tasks sit in the middle of their stratum.
+  * @return The tick offset (0..period) of every task.
+  * Throws if there is not enough memory for the offsets.
+  */
+  static stratifiedOffsets(count: number, period: number, seed?: number | undefined | null): Array<number>
+  /**
+  * Counts how many tasks run on every tick of the period.
+  *
+  * @param offsets - The tick offsets of the tasks.
+  * @param period - The number of ticks in the period.
+  * @return The number of tasks per tick.
+  * Throws if there is not enough memory for the period.
+  */
+  static load(offsets: Array<number>, period: number): Array<number>
+  /**
+  * Checks whether a task with the given offset runs on a tick.
+  *
+  * @param tick - The current tick.
+  * @param offset - The offset of the task.
+  * @param period - The number of ticks between runs of the task.
+  */
+  static isDue(tick: number, offset: number, period: number): boolean
+}
+/**
+ * Conversions between ticks, milliseconds and partial ticks, so systems interpolating
+ * between ticks share the same math.
+*/
+export declare class TickTime {
+  /**
+  * Returns the duration of a tick in milliseconds.
+  *
+  * @param tick_rate - The ticks per second, defaults to 20.
+  */
+  static tickDuration(tickRate?: number | undefined | null): number
+  /**
+  * Converts ticks into milliseconds.
+  *
+  * @param ticks - The ticks, fractions included.
+  * @param tick_rate - The ticks per second, defaults to 20.
+  */
+  static ticksToMs(ticks: number, tickRate?: number | undefined | null): number
+  /**
+  * Converts milliseconds into ticks, fractions included.
+  *
+  * @param ms - The milliseconds.
+  * @param tick_rate - The ticks per second, defaults to 20.
+  */
+  static msToTicks(ms: number, tickRate?: number | undefined | null): number
+  /**
+  * Calculates the partial tick alpha, the progress from the last tick towards the next.
+  *
+  * @param ms_since_tick - The milliseconds elapsed since the last tick.
+  * @param tick_rate - The ticks per second, defaults to 20.
+  * @return The alpha (0..1).
+  */
+  static alpha(msSinceTick: number, tickRate?: number | undefined | null): number
+  /**
+  * Splits a time into the whole tick it falls in and the alpha within that tick.
+  *
+  * @param ms - The milliseconds since tick 0.
+  * @param tick_rate - The ticks per second, defaults to 20.
+  * @return The tick (x) and the alpha (y).
+  */
+  static split(ms: number, tickRate?: number | undefined | null): Vector2f
+}
+/**
+ * Accumulates frame time and hands out fixed ticks, carrying the remainder over so no
+ * time is lost. The alpha of the remainder interpolates rendering between the last two ticks.
+*/
+export declare class FixedTimestepAccumulator {
+  tickRate: number
+  maxSteps: number
+  accumulated: number
+  ticks: number
+  /**
+  * Constructs an empty accumulator.
+  *
+  * @param tick_rate - The ticks per second, defaults to 20.
+  * @param max_steps - The maximum ticks run per advance, defaults to 10.
+  */
+  constructor(tickRate?: number | undefined | null, maxSteps?: number | undefined | null)
+  /**
+  * Adds elapsed time and consumes it in whole ticks. Time beyond the step limit is dropped,
+  * and a tick rate that is not positive never ticks.
+  *
+  * @param delta_ms - The milliseconds elapsed since the last advance.
+  * @return The number of ticks to run.
+  */
+  advance(deltaMs: number): number
+  /**
+  * Returns the alpha of the remaining time, for interpolating between the last two ticks.
+  */
+  alpha(): number
+  /**
+  * Clears the accumulated time and the tick count.
+  */
+  reset(): void
+}
+/**
+ * Layout helpers for server driven user interfaces.
+ * All positions use screen conventions: the origin is the top left corner and y grows downwards.
+*/
+export declare class Layout {
+  /**
+  * Resolves the rectangle of an element anchored inside a parent rectangle.
+  *
+  * @param parent - The parent rectangle (Rect).
+  * @param size - The size of the element (Vector2f).
+  * @param anchor - The normalized point of the parent the element is attached to, (0, 0) is top left (Vector2f).
+  * @param pivot - The normalized point of the element placed on the anchor (Vector2f).
+  * @param offset - The offset applied after anchoring, defaults to no offset (Vector2f).
+  * @return The resolved element rectangle.
+  */
+  static anchor(parent: Rect, size: Vector2f, anchor: Vector2f, pivot: Vector2f, offset?: Vector2f | undefined | null): Rect
+  /**
+  * Splits a rectangle into the nine regions of a 9-slice.
+  * When the borders do not fit inside the rectangle they are scaled down proportionally.
+  *
+  * @param rect - The rectangle to split (Rect).
+  * @param left - The width of the left border.
+  * @param top - The height of the top border.
+  * @param right - The width of the right border.
+  * @param bottom - The height of the bottom border.
+  * @return The nine regions, row by row from the top left to the bottom right corner.
+  */
+  static nineSlice(rect: Rect, left: number, top: number, right: number, bottom: number): Array<Rect>
+  /**
+  * Fits content of a given size into a container, preserving the aspect ratio
+  * unless stretching. The result is centered inside the container.
+  *
+  * @param content - The natural size of the content (Vector2f).
+  * @param container - The container rectangle (Rect).
+  * @param mode - How the content is scaled (FitMode).
+  * @return The rectangle the content occupies.
+  */
+  static fit(content: Vector2f, container: Rect, mode: FitMode): Rect
+}
+/**
+ * Text measurement using the vanilla font metrics, so chat, scoreboard and
+ * form content can be aligned without guessing.
+ * Formatting codes (for example "§l") are zero width, bold text advances one extra pixel per character.
+*/
+export declare class FontMetrics {
+  /**
+  * Returns the advance of a single character in pixels.
+  *
+  * @param character - The character to measure; only the first character is used.
+  * @param bold - Whether the character is rendered bold.
+  */
+  static charWidth(character: string, bold?: boolean | undefined | null): number
+  /**
+  * Measures the width of a line of text in pixels.
+  *
+  * @param text - The text to measure, may contain formatting codes.
+  * @return The width of the widest line when the text spans multiple lines.
+  */
+  static measure(text: string): number
+  /**
+  * Wraps text into lines no wider than the given width. Lines are broken at spaces,
+  * words wider than a full line are split. Active formatting codes are carried onto
+  * the following lines like vanilla chat does.
+  *
+  * @param text - The text to wrap, may contain formatting codes and line breaks.
+  * @param max_width - The maximum line width in pixels.
+  * @return The wrapped lines.
+  */
+  static wrap(text: string, maxWidth: number): Array<string>
+  /**
+  * Calculates how many spaces pad a text to the given width, for aligning columns.
+  *
+  * @param text - The text being padded.
+  * @param width - The target width in pixels.
+  * @return The number of spaces which keeps the text closest to, without exceeding, the width.
+  */
+  static padding(text: string, width: number): number
 }
@@ -310,9 +310,123 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { Axis, Vector3f, AABB, Raycaster } = nativeBinding
+const { Vector2f, Axis, Vector3f, Vector3i, Matrix3, Matrix4, MatrixStack, Quaternion, Rect, Rot2, ObstructionKind, LineOfFire, FormationKind, Formation, HeatGrid, Pathfinder, Sensor, TrailMap, AxisAngle, Audio, Basis, VectorBuffer, Projection, Camera, Cluster, AABB, Beam, RaycastCache, DoorSweep, Raycaster, Pose, EyeRay, Frustum, BlockFace, SpatialIndex, SweepOrder, Picking, Sphere, Plane, Triangle, SweepAndPrune, CollisionResolver, Selection, VoxelShape, LineOfSight, DynamicAabbTree, PotentiallyVisibleSet, AngleUnit, MathConfig, CoordinateMode, Coordinates, EulerOrder, Euler, PositionFilter, ModifierOperation, AttributeMath, Celestial, Chain, Damage, Difficulty, DifficultyMath, EffectMath, Experience, Explosion, FalloffKind, Falloff, GrowthMath, HungerAction, Hunger, Knockback, Dimension, Portal, SafeSpot, ChunkLoadStatus, TicketGraph, Vibration, Weather, Interp, CubicSpline, KeyframeInterpolation, PlaybackMode, NumberTrack, VectorTrack, QuaternionTrack, MoveDelta, InterestManager, NoiseDebug, PerlinNoise, NoiseWorm, Optimizer, BoatStatus, Boat, Climbing, Flight, FluidMotion, Minecart, Movement, Platform, EntityPush, RailShape, RailPath, EntityStateBuffer, Points, VectorPool, Seed, JavaRandom, Xoroshiro128PlusPlus, Rotation, ShapeGen, StructureBounds, VolumeTransform, Spline, TickSchedule, TickTime, FixedTimestepAccumulator, FitMode, Layout, FontMetrics } = nativeBinding
 
+module.exports.Vector2f = Vector2f
 module.exports.Axis = Axis
 module.exports.Vector3f = Vector3f
+module.exports.Vector3i = Vector3i
+module.exports.Matrix3 = Matrix3
+module.exports.Matrix4 = Matrix4
+module.exports.MatrixStack = MatrixStack
+module.exports.Quaternion = Quaternion
+module.exports.Rect = Rect
+module.exports.Rot2 = Rot2
+module.exports.ObstructionKind = ObstructionKind
+module.exports.LineOfFire = LineOfFire
+module.exports.FormationKind = FormationKind
+module.exports.Formation = Formation
+module.exports.HeatGrid = HeatGrid
+module.exports.Pathfinder = Pathfinder
+module.exports.Sensor = Sensor
+module.exports.TrailMap = TrailMap
+module.exports.AxisAngle = AxisAngle
+module.exports.Audio = Audio
+module.exports.Basis = Basis
+module.exports.VectorBuffer = VectorBuffer
+module.exports.Projection = Projection
+module.exports.Camera = Camera
+module.exports.Cluster = Cluster
 module.exports.AABB = AABB
+module.exports.Beam = Beam
+module.exports.RaycastCache = RaycastCache
+module.exports.DoorSweep = DoorSweep
 module.exports.Raycaster = Raycaster
+module.exports.Pose = Pose
+module.exports.EyeRay = EyeRay
+module.exports.Frustum = Frustum
+module.exports.BlockFace = BlockFace
+module.exports.SpatialIndex = SpatialIndex
+module.exports.SweepOrder = SweepOrder
+module.exports.Picking = Picking
+module.exports.Sphere = Sphere
+module.exports.Plane = Plane
+module.exports.Triangle = Triangle
+module.exports.SweepAndPrune = SweepAndPrune
+module.exports.CollisionResolver = CollisionResolver
+module.exports.Selection = Selection
+module.exports.VoxelShape = VoxelShape
+module.exports.LineOfSight = LineOfSight
+module.exports.DynamicAabbTree = DynamicAabbTree
+module.exports.PotentiallyVisibleSet = PotentiallyVisibleSet
+module.exports.AngleUnit = AngleUnit
+module.exports.MathConfig = MathConfig
+module.exports.CoordinateMode = CoordinateMode
+module.exports.Coordinates = Coordinates
+module.exports.EulerOrder = EulerOrder
+module.exports.Euler = Euler
+module.exports.PositionFilter = PositionFilter
+module.exports.ModifierOperation = ModifierOperation
+module.exports.AttributeMath = AttributeMath
+module.exports.Celestial = Celestial
+module.exports.Chain = Chain
+module.exports.Damage = Damage
+module.exports.Difficulty = Difficulty
+module.exports.DifficultyMath = DifficultyMath
+module.exports.EffectMath = EffectMath
+module.exports.Experience = Experience
+module.exports.Explosion = Explosion
+module.exports.FalloffKind = FalloffKind
+module.exports.Falloff = Falloff
+module.exports.GrowthMath = GrowthMath
+module.exports.HungerAction = HungerAction
+module.exports.Hunger = Hunger
+module.exports.Knockback = Knockback
+module.exports.Dimension = Dimension
+module.exports.Portal = Portal
+module.exports.SafeSpot = SafeSpot
+module.exports.ChunkLoadStatus = ChunkLoadStatus
+module.exports.TicketGraph = TicketGraph
+module.exports.Vibration = Vibration
+module.exports.Weather = Weather
+module.exports.Interp = Interp
+module.exports.CubicSpline = CubicSpline
+module.exports.KeyframeInterpolation = KeyframeInterpolation
+module.exports.PlaybackMode = PlaybackMode
+module.exports.NumberTrack = NumberTrack
+module.exports.VectorTrack = VectorTrack
+module.exports.QuaternionTrack = QuaternionTrack
+module.exports.MoveDelta = MoveDelta
+module.exports.InterestManager = InterestManager
+module.exports.NoiseDebug = NoiseDebug
+module.exports.PerlinNoise = PerlinNoise
+module.exports.NoiseWorm = NoiseWorm
+module.exports.Optimizer = Optimizer
+module.exports.BoatStatus = BoatStatus
+module.exports.Boat = Boat
+module.exports.Climbing = Climbing
+module.exports.Flight = Flight
+module.exports.FluidMotion = FluidMotion
+module.exports.Minecart = Minecart
+module.exports.Movement = Movement
+module.exports.Platform = Platform
+module.exports.EntityPush = EntityPush
+module.exports.RailShape = RailShape
+module.exports.RailPath = RailPath
+module.exports.EntityStateBuffer = EntityStateBuffer
+module.exports.Points = Points
+module.exports.VectorPool = VectorPool
+module.exports.Seed = Seed
+module.exports.JavaRandom = JavaRandom
+module.exports.Xoroshiro128PlusPlus = Xoroshiro128PlusPlus
+module.exports.Rotation = Rotation
+module.exports.ShapeGen = ShapeGen
+module.exports.StructureBounds = StructureBounds
+module.exports.VolumeTransform = VolumeTransform
+module.exports.Spline = Spline
+module.exports.TickSchedule = TickSchedule
+module.exports.TickTime = TickTime
+module.exports.FixedTimestepAccumulator = FixedTimestepAccumulator
+module.exports.FitMode = FitMode
+module.exports.Layout = Layout
+module.exports.FontMetrics = FontMetrics
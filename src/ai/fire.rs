@@ -0,0 +1,172 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::collision::ray::Raycaster;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const ARROW_GRAVITY: f64 = 0.05; // Gravity applied to arrows per tick
+const ARROW_DRAG: f64 = 0.99; // Velocity kept by arrows per tick in air
+
+#[napi]
+#[derive(PartialEq)]
+pub enum ObstructionKind {
+	Entity, // A friendly entity box
+	Block // A blocked cell
+}
+
+/**
+ * Represents the first thing a projectile would hit before its target.
+ */
+#[napi(object)]
+pub struct Obstruction {
+	pub kind: ObstructionKind,
+	pub index: Option<u32>, // Index of the friendly box, for entity obstructions
+	pub block: Option<Vector3f>, // Position of the blocked cell, for block obstructions
+	pub position: Vector3f, // Where the projectile would be obstructed
+	pub tick: u32, // Tick of the flight during which it is obstructed
+}
+
+/**
+ * Represents the physics of a simulated projectile.
+ */
+#[napi(object)]
+pub struct ProjectileOptions {
+	pub gravity: Option<f64>, // Gravity per tick, defaults to 0.05 like arrows
+	pub drag: Option<f64>, // Velocity kept per tick, defaults to 0.99 like arrows
+	pub max_ticks: Option<u32>, // Maximum ticks simulated, defaults to 200
+}
+
+/**
+ * Safety checks for ranged mobs, so they hold fire when an ally or a wall is in the way.
+ */
+#[napi]
+pub struct LineOfFire {}
+
+#[napi]
+impl LineOfFire {
+	/**
+	 * Calculates the launch velocity that hits a target with the flatter of the two arcs,
+	 * ignoring drag.
+	 *
+	 * @param start - The launch position (Vector3f).
+	 * @param target - The position to hit (Vector3f).
+	 * @param speed - The launch speed in blocks per tick.
+	 * @param gravity - The gravity per tick, defaults to 0.05.
+	 * @return The launch velocity; otherwise, undefined when the target is out of range or at the start, or the speed is not finite.
+	 */
+	#[napi]
+	pub fn launch_velocity(start: VectorLike, target: VectorLike, speed: f64, gravity: Option<f64>) -> Option<Vector3f> {
+		let gravity = gravity.unwrap_or(ARROW_GRAVITY);
+		let offset = target.subtract(&start);
+		let distance = (offset.x * offset.x + offset.z * offset.z).sqrt();
+
+		// There is no direction or speed to fire with.
+		if (distance == 0.0 && offset.y == 0.0) || !speed.is_finite() { return None }
+
+		if distance == 0.0 || gravity <= 0.0 {
+			return Some(offset.normalize().multiply(speed));
+		}
+
+		let speed2 = speed * speed;
+		let discriminant = speed2 * speed2 - gravity * (gravity * distance * distance + 2.0 * offset.y * speed2);
+		if discriminant.is_nan() || discriminant < 0.0 { return None }
+
+		let angle = ((speed2 - discriminant.sqrt()) / (gravity * distance)).atan();
+		let horizontal = speed * angle.cos() / distance;
+
+		Some(Vector3f::new(offset.x * horizontal, speed * angle.sin(), offset.z * horizontal))
+	}
+
+	/**
+	 * Simulates a projectile tick by tick and finds the first friendly box or blocked cell
+	 * it passes through before reaching the target's horizontal distance.
+	 *
+	 * @param start - The launch position (Vector3f).
+	 * @param target - The position aimed at (Vector3f).
+	 * @param velocity - The launch velocity, see launchVelocity (Vector3f).
+	 * @param friendlies - The boxes that must not be hit (Array<AABB>).
+	 * @param blocked - Returns whether a block stops the projectile.
+	 * @param options - The physics of the projectile (ProjectileOptions).
+	 * @return The first obstruction; otherwise, undefined when the line of fire is clear.
+	 * Throws if the velocity or gravity is not finite, or the drag is outside 0 to 1.
+	 */
+	#[napi(ts_args_type = "start: VectorLike, target: VectorLike, velocity: VectorLike, friendlies: Array<AABB>, blocked: (position: Vector3f) => boolean, options?: ProjectileOptions | undefined | null")]
+	pub fn check(
+		start: VectorLike,
+		target: VectorLike,
+		velocity: VectorLike,
+		friendlies: Vec<ClassInstance<AABB>>,
+		blocked: JsFunction,
+		options: Option<ProjectileOptions>
+	) -> napi::Result<Option<Obstruction>> {
+		let gravity = options.as_ref().and_then(|options| options.gravity).unwrap_or(ARROW_GRAVITY);
+		let drag = options.as_ref().and_then(|options| options.drag).unwrap_or(ARROW_DRAG);
+		let max_ticks = options.as_ref().and_then(|options| options.max_ticks).unwrap_or(200);
+
+		if !(velocity.x.is_finite() && velocity.y.is_finite() && velocity.z.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, format!("Velocity must be finite, got ({}, {}, {})", velocity.x, velocity.y, velocity.z)));
+		}
+
+		// Unbounded physics would send the projectile across more cells than could ever be traversed.
+		if !gravity.is_finite() {
+			return Err(Error::new(Status::InvalidArg, format!("Gravity must be finite, got {}", gravity)));
+		}
+		if !(0.0..=1.0).contains(&drag) {
+			return Err(Error::new(Status::InvalidArg, format!("Drag must be between 0 and 1, got {}", drag)));
+		}
+
+		let horizontal = |v: &Vector3f| ((v.x - start.x).powi(2) + (v.z - start.z).powi(2)).sqrt();
+		let range = horizontal(&target);
+
+		let mut position = start.clone();
+		let mut velocity = velocity.clone();
+
+		for tick in 0..max_ticks {
+			let mut next = position.add(&velocity);
+
+			// Clip the last segment at the target's horizontal distance.
+			let (travelled, reach) = (horizontal(&position), horizontal(&next));
+			let arrived = reach >= range;
+			if arrived && reach > travelled {
+				next = position.lerp(&next, (range - travelled) / (reach - travelled));
+			}
+
+			let entity = friendlies.iter()
+				.enumerate()
+				.filter_map(|(index, aabb)| AABB::intercept(aabb, VectorLike::from(&position), VectorLike::from(&next)).map(|hit| (index, hit)))
+				.min_by(|a, b| a.1.distance.total_cmp(&b.1.distance));
+
+			let mut block: Option<Vector3f> = None;
+			Raycaster::traverse(&position, &next, |cell| {
+				if blocked.call1::<Vector3f, bool>(cell.clone())? { block = Some(cell.clone()) }
+				Ok(block.is_some())
+			})?;
+
+			// A friendly in front of the blocked cell is hit first.
+			let block_distance = block.as_ref().map_or(f64::INFINITY, |cell| {
+				let aabb = AABB::new(cell.into(), cell.add(&Vector3f::new(1.0, 1.0, 1.0)).into());
+				if aabb.contains(VectorLike::from(&position)) { return 0.0 }
+				AABB::intercept(&aabb, VectorLike::from(&position), VectorLike::from(&next)).map_or(0.0, |hit| hit.distance)
+			});
+
+			if let Some((index, hit)) = entity.filter(|(_, hit)| hit.distance <= block_distance) {
+				return Ok(Some(Obstruction { kind: ObstructionKind::Entity, index: Some(index as u32), block: None, position: hit.position, tick }));
+			}
+
+			if let Some(cell) = block {
+				let hit = position.add(&next.subtract(&position).normalize().multiply(block_distance));
+				return Ok(Some(Obstruction { kind: ObstructionKind::Block, index: None, block: Some(cell), position: hit, tick }));
+			}
+
+			if arrived { break }
+
+			position = next;
+			velocity = velocity.multiply(drag);
+			velocity.y -= gravity;
+		}
+
+		return Ok(None);
+	}
+}
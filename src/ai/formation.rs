@@ -0,0 +1,92 @@
+use std::f64::consts::TAU;
+
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+#[napi]
+#[derive(PartialEq)]
+pub enum FormationKind {
+	Line, // One row abreast behind the leader
+	Wedge, // A V opening backwards, with the leader at its tip
+	Circle, // A ring around the leader
+	Grid // Rows of equal width behind the leader
+}
+
+/**
+ * Slot positions for groups following a leader, such as pets, minions and parades.
+ * Formations are laid out behind the leader and turn with its yaw.
+ */
+#[napi]
+pub struct Formation {}
+
+#[napi]
+impl Formation {
+	/**
+	 * Computes the world positions of the followers of a leader.
+	 *
+	 * @param leader_pos - The position of the leader (Vector3f).
+	 * @param leader_yaw - The yaw of the leader in degrees.
+	 * @param kind - The shape of the formation (FormationKind).
+	 * @param count - The number of followers.
+	 * @param spacing - The distance between neighboring followers in blocks.
+	 * @return The position of every follower, at the height of the leader.
+	 * Throws if the position, yaw or spacing is not finite, or the followers do not fit in memory.
+	 */
+	#[napi]
+	pub fn positions(leader_pos: VectorLike, leader_yaw: f64, kind: FormationKind, count: u32, spacing: f64) -> napi::Result<Vec<Vector3f>> {
+		if ![leader_pos.x, leader_pos.y, leader_pos.z].iter().all(|c| c.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, format!("Leader position must be finite, got ({}, {}, {})", leader_pos.x, leader_pos.y, leader_pos.z)));
+		}
+		if !leader_yaw.is_finite() {
+			return Err(Error::new(Status::InvalidArg, format!("Leader yaw must be finite, got {}", leader_yaw)));
+		}
+		if !spacing.is_finite() {
+			return Err(Error::new(Status::InvalidArg, format!("Spacing must be finite, got {}", spacing)));
+		}
+
+		let mut positions = Vec::new();
+		if positions.try_reserve_exact(count as usize).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} formation slots", count)));
+		}
+
+		for slot in 0..count {
+			let (lateral, back) = Formation::slot(&kind, slot, count, spacing);
+
+			// Offsets are laid out facing south, where the right of the leader is -X and behind it is -Z
+			positions.push(leader_pos.add(&Vector3f::new(-lateral, 0.0, -back).rotate_yaw(leader_yaw)));
+		}
+
+		return Ok(positions);
+	}
+
+	/**
+	 * Returns the offset of a slot to the right of and behind the leader.
+	 */
+	fn slot(kind: &FormationKind, slot: u32, count: u32, spacing: f64) -> (f64, f64) {
+		match kind {
+			FormationKind::Line => ((slot as f64 - (count - 1) as f64 / 2.0) * spacing, spacing),
+			FormationKind::Wedge => {
+				let rank = (slot / 2 + 1) as f64;
+				let side = if slot.is_multiple_of(2) { 1.0 } else { -1.0 };
+
+				(side * rank * spacing, rank * spacing)
+			}
+			FormationKind::Circle => {
+				// The radius keeps neighbors about the spacing apart, and never closer to the leader than it
+				let radius = (spacing * count as f64 / TAU).max(spacing);
+				let angle = slot as f64 / count as f64 * TAU;
+
+				(radius * angle.sin(), radius * angle.cos())
+			}
+			FormationKind::Grid => {
+				let columns = (count as f64).sqrt().ceil() as u32;
+				let (row, column) = (slot / columns, slot % columns);
+				let width = columns.min(count - row * columns);
+
+				((column as f64 - (width - 1) as f64 / 2.0) * spacing, (row + 1) as f64 * spacing)
+			}
+		}
+	}
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+const MIN_HEAT: f64 = 1e-6; // Heat below which cells are forgotten
+
+/**
+ * Represents a cell of a heat grid.
+ */
+#[napi(object)]
+pub struct HeatCell {
+	pub x: i32, // Cell coordinate along x, the chunk x for 16 block cells
+	pub z: i32, // Cell coordinate along z, the chunk z for 16 block cells
+	pub heat: f64,
+}
+
+/**
+ * A sparse 2D grid accumulating heat per cell, chunk aligned by default.
+ * Useful for danger maps, player activity analytics and dynamic spawning.
+ */
+#[napi]
+pub struct HeatGrid {
+	cell_size: f64, // Size of a cell in blocks
+	cells: HashMap<(i32, i32), f64>
+}
+
+#[napi]
+impl HeatGrid {
+	/**
+	 * Constructs an empty heat grid.
+	 *
+	 * @param cell_size - The size of a cell in blocks, defaults to 16 (a chunk).
+	 */
+	#[napi(constructor)]
+	pub fn new(cell_size: Option<f64>) -> HeatGrid {
+		HeatGrid {
+			cell_size: cell_size.filter(|size| size.is_finite() && *size > 0.0).unwrap_or(16.0),
+			cells: HashMap::new()
+		}
+	}
+
+	#[napi(getter)]
+	pub fn cell_size(&self) -> f64 {
+		self.cell_size
+	}
+
+	/**
+	 * Changes the size of a cell, removing the heat of every cell since the old cells no longer line up.
+	 *
+	 * @param cell_size - The size of a cell in blocks, a finite number above 0.
+	 */
+	#[napi(setter, js_name = "cellSize")]
+	pub fn set_cell_size(&mut self, cell_size: f64) -> napi::Result<()> {
+		if !cell_size.is_finite() || cell_size <= 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Cell size must be a finite number above 0, got {}", cell_size)));
+		}
+
+		self.cell_size = cell_size;
+		self.clear();
+		Ok(())
+	}
+
+	/**
+	 * Adds heat to the cell containing a position.
+	 *
+	 * @param position - The position to add heat at (Vector3f).
+	 * @param amount - The heat to add.
+	 */
+	#[napi]
+	pub fn add(&mut self, position: VectorLike, amount: f64) {
+		let cell = self.cell(&position);
+		*self.cells.entry(cell).or_insert(0.0) += amount;
+	}
+
+	/**
+	 * Multiplies the heat of every cell by a factor, usually once per tick.
+	 * Cells that cool down to nothing are removed.
+	 *
+	 * @param factor - The factor to keep, such as 0.99 to lose 1% per call.
+	 */
+	#[napi]
+	pub fn decay(&mut self, factor: f64) {
+		self.cells.retain(|_, heat| {
+			*heat *= factor;
+			heat.abs() > MIN_HEAT
+		});
+	}
+
+	/**
+	 * Returns the heat of the cell containing a position.
+	 *
+	 * @param position - The position to sample (Vector3f).
+	 */
+	#[napi]
+	pub fn sample(&self, position: VectorLike) -> f64 {
+		self.heat_at(self.cell(&position))
+	}
+
+	/**
+	 * Returns the hottest cell; otherwise, undefined when the grid is empty.
+	 */
+	#[napi]
+	pub fn max_cell(&self) -> Option<HeatCell> {
+		self.hottest(1).pop()
+	}
+
+	/**
+	 * Returns the hottest cells, hottest first.
+	 *
+	 * @param count - The number of cells to return.
+	 */
+	#[napi]
+	pub fn hottest(&self, count: u32) -> Vec<HeatCell> {
+		let mut cells: Vec<HeatCell> = self.cells.iter()
+			.map(|((x, z), heat)| HeatCell { x: *x, z: *z, heat: *heat })
+			.collect();
+
+		cells.sort_by(|a, b| b.heat.total_cmp(&a.heat).then(a.x.cmp(&b.x)).then(a.z.cmp(&b.z)));
+		cells.truncate(count as usize);
+
+		return cells;
+	}
+
+	/**
+	 * Returns the number of cells holding heat.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.cells.len() as u32
+	}
+
+	/**
+	 * Removes the heat of every cell.
+	 */
+	#[napi]
+	pub fn clear(&mut self) {
+		self.cells.clear();
+	}
+
+	/**
+	 * Returns the heat of a cell by its coordinates.
+	 */
+	pub(crate) fn heat_at(&self, cell: (i32, i32)) -> f64 {
+		self.cells.get(&cell).copied().unwrap_or(0.0)
+	}
+
+	/**
+	 * Returns the coordinates of the cell containing a position.
+	 */
+	pub(crate) fn cell(&self, position: &Vector3f) -> (i32, i32) {
+		((position.x / self.cell_size).floor() as i32, (position.z / self.cell_size).floor() as i32)
+	}
+}
@@ -0,0 +1,6 @@
+pub mod fire;
+pub mod formation;
+pub mod heat;
+pub mod path;
+pub mod sensor;
+pub mod trail;
\ No newline at end of file
@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::heat::HeatGrid;
+
+const NEIGHBORS: [(i64, i64, i64); 6] = [(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)];
+
+type Cell = (i64, i64, i64);
+
+/**
+ * Represents the options of a path search.
+ */
+#[napi(object)]
+pub struct PathOptions {
+	pub max_nodes: Option<u32>, // Maximum cells expanded before giving up, defaults to 10000
+	pub influence_weight: Option<f64>, // Extra cost per unit of heat of the influence map, at least 0, defaults to 1
+}
+
+/**
+ * An entry of the open set, ordered so the binary heap pops the lowest estimate first.
+ */
+struct Open {
+	estimate: f64,
+	cell: Cell
+}
+
+impl PartialEq for Open {
+	fn eq(&self, other: &Self) -> bool { self.estimate == other.estimate }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Open {
+	fn cmp(&self, other: &Self) -> Ordering { other.estimate.total_cmp(&self.estimate) }
+}
+
+/**
+ * A* path finding over face connected block cells.
+ */
+#[napi]
+pub struct Pathfinder {}
+
+#[napi]
+impl Pathfinder {
+	/**
+	 * Finds the cheapest path between two blocks. Every step costs 1, plus the heat of the
+	 * influence map at the cell stepped into times the influence weight, so mobs avoid recently
+	 * dangerous areas without a custom cost function.
+	 *
+	 * @param start - The block to start from (Vector3f).
+	 * @param goal - The block to reach (Vector3f).
+	 * @param walkable - Returns whether an entity can stand in a block, called once per block.
+	 * @param influence - The influence map adding cost per cell (HeatGrid).
+	 * @param options - The limits and weights of the search (PathOptions).
+	 * @return The blocks of the path from start to goal; otherwise, an empty array when the goal is unreachable.
+	 * Throws if the influence weight is negative.
+	 */
+	#[napi(ts_args_type = "start: VectorLike, goal: VectorLike, walkable: (position: Vector3f) => boolean, influence?: HeatGrid | undefined | null, options?: PathOptions | undefined | null")]
+	pub fn find(start: VectorLike, goal: VectorLike, walkable: JsFunction, influence: Option<&HeatGrid>, options: Option<PathOptions>) -> napi::Result<Vec<Vector3f>> {
+		let max_nodes = options.as_ref().and_then(|options| options.max_nodes).unwrap_or(10000);
+		let weight = options.as_ref().and_then(|options| options.influence_weight).unwrap_or(1.0);
+
+		// Negative step costs would break the admissible heuristic, and with it the optimal path.
+		if !weight.is_finite() || weight < 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Influence weight must be a finite number of at least 0, got {}", weight)));
+		}
+
+		let to_cell = |v: &Vector3f| -> Cell { (v.x.floor() as i64, v.y.floor() as i64, v.z.floor() as i64) };
+		let to_vector = |cell: &Cell| Vector3f::new(cell.0 as f64, cell.1 as f64, cell.2 as f64);
+		let heuristic = |a: &Cell, b: &Cell| (a.0 as f64 - b.0 as f64).abs() + (a.1 as f64 - b.1 as f64).abs() + (a.2 as f64 - b.2 as f64).abs();
+
+		let (start, goal) = (to_cell(&start), to_cell(&goal));
+		let mut walkable_cache: HashMap<Cell, bool> = HashMap::new();
+		let mut costs: HashMap<Cell, f64> = HashMap::from([(start, 0.0)]);
+		let mut parents: HashMap<Cell, Cell> = HashMap::new();
+		let mut open = BinaryHeap::from([Open { estimate: heuristic(&start, &goal), cell: start }]);
+		let mut expanded: u32 = 0;
+
+		while let Some(Open { cell, estimate }) = open.pop() {
+			if cell == goal {
+				let mut path = vec![to_vector(&cell)];
+				let mut current = cell;
+				while let Some(parent) = parents.get(&current) {
+					path.push(to_vector(parent));
+					current = *parent;
+				}
+				path.reverse();
+				return Ok(path);
+			}
+
+			// Skip stale entries left behind when a cheaper route was found.
+			let cost = costs[&cell];
+			if estimate > cost + heuristic(&cell, &goal) { continue }
+
+			expanded += 1;
+			if expanded > max_nodes { break }
+
+			for offset in NEIGHBORS {
+				// Cells at the edge of the i64 range, reached from huge coordinates, have no neighbor beyond it.
+				let (Some(x), Some(y), Some(z)) = (cell.0.checked_add(offset.0), cell.1.checked_add(offset.1), cell.2.checked_add(offset.2)) else { continue };
+				let next: Cell = (x, y, z);
+
+				let is_walkable = match walkable_cache.get(&next) {
+					Some(value) => *value,
+					None => {
+						let value: bool = walkable.call1(to_vector(&next))?;
+						walkable_cache.insert(next, value);
+						value
+					}
+				};
+				if !is_walkable { continue }
+
+				let heat = influence.map_or(0.0, |grid| grid.sample(to_vector(&next).into()).max(0.0));
+				let next_cost = cost + 1.0 + heat * weight;
+				if costs.get(&next).is_some_and(|known| *known <= next_cost) { continue }
+
+				costs.insert(next, next_cost);
+				parents.insert(next, cell);
+				open.push(Open { estimate: next_cost + heuristic(&next, &goal), cell: next });
+			}
+		}
+
+		return Ok(Vec::new());
+	}
+}
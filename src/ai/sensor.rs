@@ -0,0 +1,111 @@
+use napi::JsFunction;
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::collision::ray::Raycaster;
+use crate::rotation::Rotation;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const TARGET_EYE_RATIO: f64 = 0.85; // Height of the eyes of a target relative to its height
+
+/**
+ * The perception of a mob, combining a view distance, a view cone and a line of sight check
+ * in one native call. The mob is posed with its feet position and rotation, and sees a target
+ * when the eyes or the center of the target are in range, inside the cone and not occluded.
+ */
+#[napi]
+pub struct Sensor {
+	pub range: f64, // View distance in blocks
+	pub fov: f64, // Full angle of the view cone in degrees, 360 or more sees all around
+	pub eye_height: f64, // Height of the eyes above the feet
+	pub position: Vector3f, // Feet position of the mob
+	pub yaw: f64, // Yaw of the mob in degrees
+	pub pitch: f64 // Pitch of the mob in degrees
+}
+
+#[napi]
+impl Sensor {
+	/**
+	 * Constructs a sensor posed at the origin, facing south.
+	 *
+	 * @param range - The view distance in blocks.
+	 * @param fov - The full angle of the view cone in degrees.
+	 * @param eye_height - The height of the eyes above the feet, defaults to 1.62.
+	 */
+	#[napi(constructor)]
+	pub fn new(range: f64, fov: f64, eye_height: Option<f64>) -> Sensor {
+		Sensor {
+			range,
+			fov,
+			eye_height: eye_height.unwrap_or(1.62),
+			position: Vector3f::new(0.0, 0.0, 0.0),
+			yaw: 0.0,
+			pitch: 0.0
+		}
+	}
+
+	/**
+	 * Moves and turns the mob the sensor belongs to.
+	 *
+	 * @param position - The feet position of the mob (Vector3f).
+	 * @param yaw - The yaw of the mob in degrees.
+	 * @param pitch - The pitch of the mob in degrees.
+	 */
+	#[napi]
+	pub fn set_pose(&mut self, position: VectorLike, yaw: f64, pitch: f64) {
+		self.position = position.clone();
+		self.yaw = yaw;
+		self.pitch = pitch;
+	}
+
+	/**
+	 * Returns the eye position of the mob.
+	 */
+	#[napi]
+	pub fn eye(&self) -> Vector3f {
+		self.position.add(&Vector3f::new(0.0, self.eye_height, 0.0))
+	}
+
+	/**
+	 * Checks whether a point is within range and inside the view cone, without checking occlusion.
+	 * Nothing is in view when the point, the pose or the range is NaN.
+	 *
+	 * @param point - The point to check (Vector3f).
+	 */
+	#[napi]
+	pub fn in_view(&self, point: VectorLike) -> bool {
+		let offset = point.subtract(&self.eye());
+		let distance = offset.length();
+		if distance.is_nan() || distance > self.range || self.range.is_nan() { return false }
+		if distance == 0.0 || self.fov >= 360.0 { return true }
+
+		let forward = Rotation::direction(self.yaw, self.pitch);
+		forward.dot(&offset) / distance >= (self.fov.max(0.0) / 2.0).to_radians().cos()
+	}
+
+	/**
+	 * Checks whether the mob sees a target. The eyes and then the center of the target are checked,
+	 * and the target is seen when either of them is in view and the line to it passes no occluding block.
+	 *
+	 * @param target_aabb - The hitbox of the target (AABB).
+	 * @param is_occluding - Returns whether the block at a position blocks the view.
+	 * @return Whether the target is seen.
+	 * Throws if the line to a point in view is not finite or passes through more blocks than an array can hold.
+	 */
+	#[napi(ts_args_type = "targetAabb: AABB, isOccluding: (position: Vector3f) => boolean")]
+	pub fn can_see(&self, target_aabb: &AABB, is_occluding: JsFunction) -> napi::Result<bool> {
+		let center = target_aabb.center();
+		let eyes = Vector3f::new(center.x, target_aabb.min.y + (target_aabb.max.y - target_aabb.min.y) * TARGET_EYE_RATIO, center.z);
+		let eye = self.eye();
+
+		for point in [eyes, center] {
+			if !self.in_view(VectorLike::from(&point)) { continue }
+			Raycaster::segment(&eye, &point, None)?;
+
+			let occluded = Raycaster::traverse(&eye, &point, |block| is_occluding.call1::<Vector3f, bool>(block.clone()))?;
+			if !occluded { return Ok(true) }
+		}
+
+		Ok(false)
+	}
+}
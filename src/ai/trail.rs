@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::VectorLike;
+use crate::vec3i::Vector3i;
+
+const DEFAULT_DECAY: f64 = 0.95; // Fraction of the scent kept per decay
+const DEFAULT_THRESHOLD: f64 = 0.01; // Scent below which blocks are forgotten
+
+/**
+ * A sparse 3D map of decaying scent per block, for tracking mobs that follow the trail
+ * a target left behind. Only blocks holding scent are stored.
+ */
+#[napi]
+pub struct TrailMap {
+	decay_factor: f64, // Fraction of the scent kept per decay
+	threshold: f64, // Scent below which blocks are forgotten
+	cells: HashMap<Vector3i, f64>
+}
+
+#[napi]
+impl TrailMap {
+	/**
+	 * Constructs an empty trail map.
+	 *
+	 * @param decay_factor - The fraction of the scent kept per decay, defaults to 0.95.
+	 * @param threshold - The scent below which blocks are forgotten, defaults to 0.01 when not finite.
+	 */
+	#[napi(constructor)]
+	pub fn new(decay_factor: Option<f64>, threshold: Option<f64>) -> TrailMap {
+		TrailMap {
+			decay_factor: decay_factor.filter(|factor| !factor.is_nan()).unwrap_or(DEFAULT_DECAY).clamp(0.0, 1.0),
+			threshold: threshold.filter(|threshold| threshold.is_finite()).unwrap_or(DEFAULT_THRESHOLD).max(0.0),
+			cells: HashMap::new()
+		}
+	}
+
+	#[napi(getter)]
+	pub fn decay_factor(&self) -> f64 {
+		self.decay_factor
+	}
+
+	/**
+	 * Changes the fraction of the scent kept per decay.
+	 *
+	 * @param decay_factor - The fraction, between 0 and 1 so scent never grows.
+	 */
+	#[napi(setter, js_name = "decayFactor")]
+	pub fn set_decay_factor(&mut self, decay_factor: f64) -> napi::Result<()> {
+		if !(0.0..=1.0).contains(&decay_factor) {
+			return Err(Error::new(Status::InvalidArg, format!("Decay factor must be between 0 and 1, got {}", decay_factor)));
+		}
+
+		self.decay_factor = decay_factor;
+		Ok(())
+	}
+
+	#[napi(getter)]
+	pub fn threshold(&self) -> f64 {
+		self.threshold
+	}
+
+	/**
+	 * Changes the scent below which blocks are forgotten, applied on the next decay.
+	 *
+	 * @param threshold - The scent, a finite number of at least 0.
+	 */
+	#[napi(setter, js_name = "threshold")]
+	pub fn set_threshold(&mut self, threshold: f64) -> napi::Result<()> {
+		if !threshold.is_finite() || threshold < 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Threshold must be a finite number of at least 0, got {}", threshold)));
+		}
+
+		self.threshold = threshold;
+		Ok(())
+	}
+
+	/**
+	 * Adds scent to the block containing a position, usually every tick for each target.
+	 *
+	 * @param position - The position of the target (Vector3f).
+	 * @param strength - The scent to add.
+	 * Throws if the position is not within the range of a block position or the strength is not finite.
+	 */
+	#[napi]
+	pub fn deposit(&mut self, position: VectorLike, strength: f64) -> napi::Result<()> {
+		if !strength.is_finite() {
+			return Err(Error::new(Status::InvalidArg, format!("Strength must be finite, got {}", strength)));
+		}
+
+		*self.cells.entry(Vector3i::from_vector3f(position)?).or_insert(0.0) += strength;
+		Ok(())
+	}
+
+	/**
+	 * Returns the scent of the block containing a position.
+	 *
+	 * @param position - The position to sample (Vector3f).
+	 * Throws if the position is not within the range of a block position.
+	 */
+	#[napi]
+	pub fn sample(&self, position: VectorLike) -> napi::Result<f64> {
+		Ok(self.cells.get(&Vector3i::from_vector3f(position)?).copied().unwrap_or(0.0))
+	}
+
+	/**
+	 * Finds the block with the strongest scent among the 26 blocks around the block containing
+	 * a position, which is the next step for a mob following the trail. Blocks beyond the range
+	 * of a block position are not considered.
+	 *
+	 * @param position - The position of the tracking mob (Vector3f).
+	 * @return The strongest neighboring block; otherwise, undefined when none of them holds scent.
+	 * Throws if the position is not within the range of a block position.
+	 */
+	#[napi]
+	pub fn strongest_neighbor(&self, position: VectorLike) -> napi::Result<Option<Vector3i>> {
+		let center = Vector3i::from_vector3f(position)?;
+		let mut strongest: Option<(Vector3i, f64)> = None;
+
+		for dx in -1..=1 {
+			for dy in -1..=1 {
+				for dz in -1..=1 {
+					if dx == 0 && dy == 0 && dz == 0 { continue }
+
+					// Neighbors of a block at the edge of the i32 range would overflow
+					let (Some(x), Some(y), Some(z)) = (center.x.checked_add(dx), center.y.checked_add(dy), center.z.checked_add(dz)) else { continue };
+					let block = Vector3i::new(x, y, z);
+					let Some(&strength) = self.cells.get(&block) else { continue };
+					if strongest.as_ref().is_some_and(|(_, best)| *best >= strength) { continue }
+
+					strongest = Some((block, strength));
+				}
+			}
+		}
+
+		Ok(strongest.map(|(block, _)| block))
+	}
+
+	/**
+	 * Multiplies the scent of every block by the decay factor, usually once per tick.
+	 * Blocks whose scent falls below the threshold are removed.
+	 */
+	#[napi]
+	pub fn decay(&mut self) {
+		let (factor, threshold) = (self.decay_factor, self.threshold);
+		self.cells.retain(|_, strength| {
+			*strength *= factor;
+			*strength >= threshold
+		});
+	}
+
+	/**
+	 * Returns the number of blocks holding scent.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.cells.len() as u32
+	}
+
+	/**
+	 * Removes the scent of every block.
+	 */
+	#[napi]
+	pub fn clear(&mut self) {
+		self.cells.clear();
+	}
+}
@@ -0,0 +1,92 @@
+use napi_derive::napi;
+
+use crate::camera::Camera;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const BLOCKS_PER_VOLUME: f64 = 16.0; // Blocks a sound carries per unit of volume
+
+/**
+ * Sound attenuation and listener space math matching vanilla's linear falloff,
+ * where a sound carries 16 blocks per unit of volume above 1.
+ */
+#[napi]
+pub struct Audio {}
+
+#[napi]
+impl Audio {
+	/**
+	 * Returns the distance a sound of some volume can be heard from.
+	 *
+	 * @param base_volume - The volume the sound is played with.
+	 */
+	#[napi]
+	pub fn range(base_volume: f64) -> f64 {
+		base_volume.max(1.0) * BLOCKS_PER_VOLUME
+	}
+
+	/**
+	 * Calculates the volume a listener hears a sound at, falling off linearly to zero at the range.
+	 * Volumes above 1 only increase the range, never the loudness.
+	 *
+	 * @param listener - The position of the listener (Vector3f).
+	 * @param source - The position of the sound (Vector3f).
+	 * @param base_volume - The volume the sound is played with.
+	 * @param range - The distance the sound carries, defaults to the range of the volume.
+	 * @return The heard volume (0..1).
+	 */
+	#[napi]
+	pub fn volume_at(listener: VectorLike, source: VectorLike, base_volume: f64, range: Option<f64>) -> f64 {
+		let range = range.unwrap_or_else(|| Audio::range(base_volume));
+		if range <= 0.0 { return 0.0 }
+
+		let falloff = 1.0 - listener.distance(&source) / range;
+		(base_volume.clamp(0.0, 1.0) * falloff).max(0.0)
+	}
+
+	/**
+	 * Checks whether a listener is within the range of a sound.
+	 *
+	 * @param listener - The position of the listener (Vector3f).
+	 * @param source - The position of the sound (Vector3f).
+	 * @param base_volume - The volume the sound is played with.
+	 * @param range - The distance the sound carries, defaults to the range of the volume.
+	 */
+	#[napi]
+	pub fn is_audible(listener: VectorLike, source: VectorLike, base_volume: f64, range: Option<f64>) -> bool {
+		let range = range.unwrap_or_else(|| Audio::range(base_volume));
+		listener.distance(&source) < range
+	}
+
+	/**
+	 * Converts the position of a sound into the listener's space:
+	 * x points to the listener's right, y up and z forward.
+	 *
+	 * @param listener - The position of the listener's head (Vector3f).
+	 * @param yaw - The yaw of the listener in degrees.
+	 * @param pitch - The pitch of the listener in degrees.
+	 * @param source - The position of the sound (Vector3f).
+	 */
+	#[napi]
+	pub fn relative_position(listener: VectorLike, yaw: f64, pitch: f64, source: VectorLike) -> Vector3f {
+		let camera = Camera::new(listener.clone().into(), yaw, pitch, None, None, None, None);
+		let offset = source.subtract(&listener);
+
+		Vector3f::new(offset.dot(&camera.right()), offset.dot(&camera.up()), offset.dot(&camera.forward()))
+	}
+
+	/**
+	 * Calculates a stereo panning hint for a sound, from -1 (fully left) to 1 (fully right).
+	 * Only the yaw is considered, so sounds straight above or below are centered.
+	 *
+	 * @param listener - The position of the listener's head (Vector3f).
+	 * @param yaw - The yaw of the listener in degrees.
+	 * @param source - The position of the sound (Vector3f).
+	 */
+	#[napi]
+	pub fn pan(listener: VectorLike, yaw: f64, source: VectorLike) -> f64 {
+		let relative = Audio::relative_position(listener, yaw, 0.0, source);
+		let horizontal = (relative.x * relative.x + relative.z * relative.z).sqrt();
+
+		if horizontal == 0.0 { 0.0 } else { relative.x / horizontal }
+	}
+}
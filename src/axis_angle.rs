@@ -0,0 +1,115 @@
+use napi_derive::napi;
+
+use crate::mat3::Matrix3;
+use crate::quaternion::Quaternion;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const SINGULARITY: f64 = 1e-9; // Below this sine of the half angle the axis is undefined
+
+/**
+ * Represents a rotation by an angle around an axis, as used by some Bedrock
+ * attachable and animation data.
+ */
+#[napi]
+#[derive(Clone, Debug)]
+pub struct AxisAngle {
+	pub axis: Vector3f, // Axis of the rotation, normalized on construction
+	pub angle: f64 // Angle in radians, counter-clockwise looking down the axis
+}
+
+#[napi]
+impl AxisAngle {
+	/**
+	 * Constructs a rotation around an axis. A zero axis, or one that is not finite,
+	 * results in no rotation around +X.
+	 *
+	 * @param axis - The axis, normalized before use (Vector3f).
+	 * @param angle - The angle in radians.
+	 */
+	#[napi(constructor)]
+	pub fn new(axis: VectorLike, angle: f64) -> AxisAngle {
+		// hypot keeps the length of huge axes from overflowing to infinity.
+		let length = axis.x.hypot(axis.y).hypot(axis.z);
+		if length == 0.0 || !length.is_finite() { return AxisAngle { axis: Vector3f::new(1.0, 0.0, 0.0), angle: 0.0 } }
+
+		AxisAngle { axis: Vector3f::new(axis.x / length, axis.y / length, axis.z / length), angle }
+	}
+
+	/**
+	 * Extracts the axis and angle of a quaternion, with the angle in 0..PI.
+	 *
+	 * @param quaternion - The rotation, normalized before use (Quaternion).
+	 */
+	#[napi]
+	pub fn from_quaternion(quaternion: &Quaternion) -> AxisAngle {
+		let length = quaternion.length();
+		if length == 0.0 { return AxisAngle::new(Vector3f::new(1.0, 0.0, 0.0).into(), 0.0) }
+
+		// q and -q are the same rotation; pick the one with the shorter angle.
+		let sign = (if quaternion.w < 0.0 { -1.0 } else { 1.0 }) / length;
+		let (x, y, z, w) = (quaternion.x * sign, quaternion.y * sign, quaternion.z * sign, quaternion.w * sign);
+		let sine = (x * x + y * y + z * z).sqrt();
+
+		if sine < SINGULARITY { return AxisAngle::new(Vector3f::new(1.0, 0.0, 0.0).into(), 0.0) }
+
+		AxisAngle {
+			axis: Vector3f::new(x / sine, y / sine, z / sine),
+			angle: 2.0 * sine.atan2(w)
+		}
+	}
+
+	/**
+	 * Extracts the axis and angle of a rotation matrix.
+	 *
+	 * @param matrix - The rotation matrix (Matrix3).
+	 */
+	#[napi]
+	pub fn from_matrix3(matrix: &Matrix3) -> AxisAngle {
+		AxisAngle::from_quaternion(&Quaternion::from_matrix3(matrix))
+	}
+
+	/**
+	 * Converts the rotation into a quaternion.
+	 */
+	#[napi]
+	pub fn to_quaternion(&self) -> Quaternion {
+		let (sine, cosine) = (self.angle * 0.5).sin_cos();
+		Quaternion::new(self.axis.x * sine, self.axis.y * sine, self.axis.z * sine, cosine)
+	}
+
+	/**
+	 * Converts the rotation into a rotation matrix using Rodrigues' formula.
+	 */
+	#[napi]
+	pub fn to_matrix3(&self) -> Matrix3 {
+		let (s, c) = self.angle.sin_cos();
+		let t = 1.0 - c;
+		let Vector3f { x, y, z } = self.axis;
+
+		Matrix3 {
+			elements: vec![
+				t * x * x + c, t * x * y + s * z, t * x * z - s * y,
+				t * x * y - s * z, t * y * y + c, t * y * z + s * x,
+				t * x * z + s * y, t * y * z - s * x, t * z * z + c
+			]
+		}
+	}
+
+	/**
+	 * Rotates a vector around the axis.
+	 *
+	 * @param vector - The vector to rotate (Vector3f).
+	 */
+	#[napi]
+	pub fn rotate(&self, vector: VectorLike) -> Vector3f {
+		vector.rotate_around(VectorLike::from(&self.axis), self.angle)
+	}
+
+	/**
+	 * Returns the rotation undoing this one.
+	 */
+	#[napi]
+	pub fn inverse(&self) -> AxisAngle {
+		AxisAngle { axis: self.axis.clone(), angle: -self.angle }
+	}
+}
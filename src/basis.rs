@@ -0,0 +1,88 @@
+use napi_derive::napi;
+
+use crate::vec2f::Vector2f;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const VERTICAL: f64 = 1.0e-9; // Horizontal length below which a direction points straight up or down
+
+/**
+ * Represents an orthonormal basis of directions relative to a facing.
+ */
+#[napi(object)]
+#[derive(Clone)]
+pub struct BasisVectors {
+	pub right: Vector3f,
+	pub up: Vector3f,
+	pub forward: Vector3f,
+}
+
+/**
+ * Orthonormal bases for particle orientation, beam quads and local space offsets.
+ * The basis is built from the yaw and pitch of the facing rather than from cross products
+ * with a world up vector, so it stays well defined when looking straight up or down.
+ * Yaw 0 faces south (+Z) and increases clockwise, positive pitch looks down.
+ */
+#[napi]
+pub struct Basis {}
+
+#[napi]
+impl Basis {
+	/**
+	 * Builds the basis of a forward direction. Straight up or down the yaw is undefined,
+	 * so the fallback yaw decides where right points. A zero direction, or one that is not
+	 * finite, faces horizontally along the fallback yaw.
+	 *
+	 * @param forward - The forward direction, normalized before use (Vector3f).
+	 * @param fallback_yaw - The yaw in degrees used for vertical directions, defaults to 0.
+	 * @return The right, up and forward directions.
+	 */
+	#[napi]
+	pub fn from_forward(forward: VectorLike, fallback_yaw: Option<f64>) -> BasisVectors {
+		let length = forward.x.hypot(forward.y).hypot(forward.z);
+		if length == 0.0 || !length.is_finite() { return Basis::from_angles(0.0, fallback_yaw.unwrap_or(0.0).to_radians()) }
+
+		let forward = forward.multiply(1.0 / length);
+		let horizontal = (forward.x * forward.x + forward.z * forward.z).sqrt();
+
+		let yaw = if horizontal < VERTICAL { fallback_yaw.unwrap_or(0.0).to_radians() } else { (-forward.x).atan2(forward.z) };
+		let pitch = (-forward.y).atan2(horizontal);
+
+		Basis::from_angles(pitch, yaw)
+	}
+
+	/**
+	 * Builds the basis of a rotation.
+	 *
+	 * @param rotation - The rotation in degrees, x being the pitch and y the yaw (Vector2f).
+	 * @return The right, up and forward directions.
+	 */
+	#[napi]
+	pub fn from_rotation(rotation: &Vector2f) -> BasisVectors {
+		Basis::from_angles(rotation.x.to_radians(), rotation.y.to_radians())
+	}
+
+	/**
+	 * Converts an offset in a basis into a world offset.
+	 *
+	 * @param basis - The basis (BasisVectors).
+	 * @param local - The offset along right (x), up (y) and forward (z) (Vector3f).
+	 */
+	#[napi]
+	pub fn to_world(basis: BasisVectors, local: VectorLike) -> Vector3f {
+		basis.right.multiply(local.x).add(&basis.up.multiply(local.y)).add(&basis.forward.multiply(local.z))
+	}
+
+	/**
+	 * Builds a basis from a pitch and yaw in radians.
+	 */
+	pub(crate) fn from_angles(pitch: f64, yaw: f64) -> BasisVectors {
+		let (sin_yaw, cos_yaw) = yaw.sin_cos();
+		let (sin_pitch, cos_pitch) = pitch.sin_cos();
+
+		BasisVectors {
+			right: Vector3f::new(-cos_yaw, 0.0, -sin_yaw),
+			up: Vector3f::new(-sin_yaw * sin_pitch, cos_pitch, cos_yaw * sin_pitch),
+			forward: Vector3f::new(-sin_yaw * cos_pitch, -sin_pitch, cos_yaw * cos_pitch)
+		}
+	}
+}
@@ -0,0 +1,136 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::mat4::Matrix4;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Bulk operations over vectors packed as x, y, z triplets in a Float64Array, so thousands of
+ * particle or entity positions are processed in a single native call. Operations return a new
+ * array, or write into the given array when in place is set.
+ */
+#[napi]
+pub struct VectorBuffer {}
+
+#[napi]
+impl VectorBuffer {
+	/**
+	 * Adds a number to every component.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @param scalar - The number to add.
+	 * @param in_place - Whether to write into the given array, defaults to false.
+	 */
+	#[napi]
+	pub fn add_scalar(points: Float64Array, scalar: f64, in_place: Option<bool>) -> napi::Result<Float64Array> {
+		VectorBuffer::map(points, in_place, |v| v.iter_mut().for_each(|component| *component += scalar))
+	}
+
+	/**
+	 * Adds a vector to every vector.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @param offset - The vector to add (Vector3f).
+	 * @param in_place - Whether to write into the given array, defaults to false.
+	 */
+	#[napi]
+	pub fn add_vector(points: Float64Array, offset: VectorLike, in_place: Option<bool>) -> napi::Result<Float64Array> {
+		VectorBuffer::map(points, in_place, |v| {
+			v[0] += offset.x;
+			v[1] += offset.y;
+			v[2] += offset.z;
+		})
+	}
+
+	/**
+	 * Multiplies every vector by a number.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @param scalar - The number to multiply by.
+	 * @param in_place - Whether to write into the given array, defaults to false.
+	 */
+	#[napi]
+	pub fn multiply(points: Float64Array, scalar: f64, in_place: Option<bool>) -> napi::Result<Float64Array> {
+		VectorBuffer::map(points, in_place, |v| v.iter_mut().for_each(|component| *component *= scalar))
+	}
+
+	/**
+	 * Scales every vector to unit length. Zero vectors, and vectors with a length that is not finite, are left unchanged.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @param in_place - Whether to write into the given array, defaults to false.
+	 */
+	#[napi]
+	pub fn normalize(points: Float64Array, in_place: Option<bool>) -> napi::Result<Float64Array> {
+		VectorBuffer::map(points, in_place, |v| {
+			// hypot keeps the length of huge vectors from overflowing to infinity.
+			let length = v[0].hypot(v[1]).hypot(v[2]);
+			if length > 0.0 && length.is_finite() { v.iter_mut().for_each(|component| *component /= length) }
+		})
+	}
+
+	/**
+	 * Transforms every vector as a point by a matrix, including translation.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @param matrix - The transformation (Matrix4).
+	 * @param in_place - Whether to write into the given array, defaults to false.
+	 */
+	#[napi]
+	pub fn transform(points: Float64Array, matrix: &Matrix4, in_place: Option<bool>) -> napi::Result<Float64Array> {
+		VectorBuffer::map(points, in_place, |v| {
+			let point = matrix.transform_point(Vector3f::new(v[0], v[1], v[2]).into());
+			v.copy_from_slice(&[point.x, point.y, point.z]);
+		})
+	}
+
+	/**
+	 * Computes the distance from every vector to a point.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @param target - The point to measure to (Vector3f).
+	 * @return The distance of every vector, in order (Float64Array).
+	 */
+	#[napi]
+	pub fn distance_to(points: Float64Array, target: VectorLike) -> napi::Result<Float64Array> {
+		VectorBuffer::check(&points)?;
+
+		let distances = points.chunks_exact(3)
+			.map(|v| (v[0] - target.x).hypot(v[1] - target.y).hypot(v[2] - target.z))
+			.collect();
+
+		Ok(Float64Array::new(distances))
+	}
+
+	/**
+	 * Computes the length of every vector.
+	 *
+	 * @param points - The vectors, packed as x, y, z triplets (Float64Array).
+	 * @return The length of every vector, in order (Float64Array).
+	 */
+	#[napi]
+	pub fn lengths(points: Float64Array) -> napi::Result<Float64Array> {
+		VectorBuffer::distance_to(points, Vector3f::new(0.0, 0.0, 0.0).into())
+	}
+
+	/**
+	 * Applies an operation to every triplet, either in a copy or in the given array.
+	 */
+	fn map<F: FnMut(&mut [f64])>(points: Float64Array, in_place: Option<bool>, mut operation: F) -> napi::Result<Float64Array> {
+		VectorBuffer::check(&points)?;
+
+		let mut output = if in_place.unwrap_or(false) { points } else { Float64Array::new(points.to_vec()) };
+		output.chunks_exact_mut(3).for_each(&mut operation);
+
+		Ok(output)
+	}
+
+	fn check(points: &Float64Array) -> napi::Result<()> {
+		if !points.len().is_multiple_of(3) {
+			return Err(Error::new(Status::InvalidArg, "Points must be packed as x, y, z triplets".to_string()));
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,195 @@
+use napi_derive::napi;
+
+use crate::collision::frustum::Frustum;
+use crate::collision::ray::Ray;
+use crate::mat4::Matrix4;
+use crate::rotation::Rotation;
+use crate::vec3f::{Vector3f, VectorLike};
+
+#[napi]
+pub enum Projection {
+	Perspective,
+	Orthographic
+}
+
+/**
+ * Represents the size of the screen a camera renders to, in pixels.
+ */
+#[napi(object)]
+pub struct Viewport {
+	pub width: f64,
+	pub height: f64,
+}
+
+/**
+ * Represents a camera positioned and rotated using Minecraft conventions.
+ * Yaw 0 faces south (+Z) and increases clockwise, positive pitch looks down.
+ */
+#[napi]
+pub struct Camera {
+	pub position: Vector3f, // Position of the eye
+	pub yaw: f64, // Horizontal rotation in degrees
+	pub pitch: f64, // Vertical rotation in degrees
+	pub fov: f64, // Vertical field of view in degrees, or the view height in blocks for orthographic cameras
+	pub near: f64, // Distance to the near clipping plane
+	pub far: f64, // Distance to the far clipping plane
+	pub projection: Projection
+}
+
+#[napi]
+impl Camera {
+	/**
+	 * Constructs a camera at the given position and rotation.
+	 *
+	 * @param position - The eye position (Vector3f).
+	 * @param yaw - The yaw in degrees.
+	 * @param pitch - The pitch in degrees.
+	 * @param fov - The vertical field of view in degrees, defaults to 70.
+	 * @param near - The near plane distance, defaults to 0.05.
+	 * @param far - The far plane distance, defaults to 256.
+	 * @param projection - The projection kind, defaults to perspective.
+	 */
+	#[napi(constructor)]
+	pub fn new(
+		position: VectorLike,
+		yaw: f64,
+		pitch: f64,
+		fov: Option<f64>,
+		near: Option<f64>,
+		far: Option<f64>,
+		projection: Option<Projection>
+	) -> Camera {
+		Camera {
+			position: position.clone(),
+			yaw,
+			pitch,
+			fov: fov.unwrap_or(70.0),
+			near: near.unwrap_or(0.05),
+			far: far.unwrap_or(256.0),
+			projection: projection.unwrap_or(Projection::Perspective)
+		}
+	}
+
+	/**
+	 * Returns the normalized direction the camera is facing.
+	 */
+	#[napi]
+	pub fn forward(&self) -> Vector3f {
+		Rotation::direction(self.yaw, self.pitch)
+	}
+
+	/**
+	 * Returns the normalized up direction of the camera, perpendicular to forward.
+	 */
+	#[napi]
+	pub fn up(&self) -> Vector3f {
+		let (yaw, pitch) = (self.yaw.to_radians(), self.pitch.to_radians());
+
+		Vector3f::new(-yaw.sin() * pitch.sin(), pitch.cos(), yaw.cos() * pitch.sin())
+	}
+
+	/**
+	 * Returns the normalized right direction of the camera.
+	 */
+	#[napi]
+	pub fn right(&self) -> Vector3f {
+		self.forward().cross(&self.up())
+	}
+
+	/**
+	 * Builds the world to view space matrix of the camera.
+	 * The basis is derived from the rotation directly, so looking straight up or down is stable.
+	 */
+	#[napi]
+	pub fn view_matrix(&self) -> Matrix4 {
+		Matrix4::view(&self.position, &self.right(), &self.up(), &self.forward())
+	}
+
+	/**
+	 * Builds the view to clip space matrix of the camera.
+	 *
+	 * @param aspect - The viewport width divided by its height.
+	 */
+	#[napi]
+	pub fn projection_matrix(&self, aspect: f64) -> Matrix4 {
+		match self.projection {
+			Projection::Perspective => Matrix4::perspective(self.fov, aspect, self.near, self.far),
+			Projection::Orthographic => {
+				let half_height = self.fov / 2.0;
+				let half_width = half_height * aspect;
+
+				Matrix4::orthographic(-half_width, half_width, -half_height, half_height, self.near, self.far)
+			}
+		}
+	}
+
+	/**
+	 * Builds the combined world to clip space matrix (projection * view).
+	 *
+	 * @param aspect - The viewport width divided by its height.
+	 */
+	#[napi]
+	pub fn view_projection_matrix(&self, aspect: f64) -> Matrix4 {
+		self.projection_matrix(aspect).multiply(&self.view_matrix())
+	}
+
+	/**
+	 * Builds the view frustum of the camera, for culling what it cannot see.
+	 *
+	 * @param aspect - The viewport width divided by its height.
+	 */
+	#[napi]
+	pub fn frustum(&self, aspect: f64) -> Frustum {
+		Frustum::from_matrix(&self.view_projection_matrix(aspect))
+	}
+
+	/**
+	 * Projects a world position onto the screen.
+	 * Screen coordinates start at the top left corner, the z component holds the depth (-1..1).
+	 *
+	 * @param point - The world position (Vector3f).
+	 * @param viewport - The screen size in pixels (Viewport).
+	 * @return The screen position; otherwise, undefined if the point is behind the camera.
+	 */
+	#[napi]
+	pub fn world_to_screen(&self, point: VectorLike, viewport: Viewport) -> Option<Vector3f> {
+		let m = self.view_projection_matrix(viewport.width / viewport.height).elements;
+		let w = m[3] * point.x + m[7] * point.y + m[11] * point.z + m[15];
+
+		if w <= 0.0 { return None }
+		let ndc = Vector3f::new(
+			(m[0] * point.x + m[4] * point.y + m[8] * point.z + m[12]) / w,
+			(m[1] * point.x + m[5] * point.y + m[9] * point.z + m[13]) / w,
+			(m[2] * point.x + m[6] * point.y + m[10] * point.z + m[14]) / w
+		);
+
+		Some(Vector3f::new(
+			(ndc.x + 1.0) / 2.0 * viewport.width,
+			(1.0 - ndc.y) / 2.0 * viewport.height,
+			ndc.z
+		))
+	}
+
+	/**
+	 * Creates a world space ray passing through a screen position.
+	 *
+	 * @param x - The horizontal screen position in pixels.
+	 * @param y - The vertical screen position in pixels, from the top.
+	 * @param viewport - The screen size in pixels (Viewport).
+	 * @return The ray starting at the near plane; otherwise, undefined if the projection cannot be inverted.
+	 */
+	#[napi]
+	pub fn screen_to_ray(&self, x: f64, y: f64, viewport: Viewport) -> Option<Ray> {
+		let inverse = self.view_projection_matrix(viewport.width / viewport.height).inverse()?;
+
+		let ndc_x = 2.0 * x / viewport.width - 1.0;
+		let ndc_y = 1.0 - 2.0 * y / viewport.height;
+		let near = inverse.transform_point(Vector3f::new(ndc_x, ndc_y, -1.0).into());
+		let far = inverse.transform_point(Vector3f::new(ndc_x, ndc_y, 1.0).into());
+
+		Some(Ray {
+			direction: far.subtract(&near).normalize(),
+			origin: near
+		})
+	}
+}
@@ -0,0 +1,150 @@
+use napi::bindgen_prelude::Float64Array;
+use napi_derive::napi;
+
+use crate::random::java::JavaRandom;
+use crate::vec3f::Vector3f;
+
+const NOISE: i32 = -1; // Assignment of points that belong to no cluster
+
+/**
+ * Represents the outcome of a clustering.
+ */
+#[napi(object)]
+pub struct ClusterResult {
+	pub assignments: Vec<i32>, // Cluster of every point, in order, or -1 for noise
+	pub centroids: Float64Array, // Center of every cluster, packed as x, y, z triplets
+}
+
+/**
+ * Clustering of positions, for finding groups of players or mobs.
+ */
+#[napi]
+pub struct Cluster {}
+
+#[napi]
+impl Cluster {
+	/**
+	 * Partitions points into k clusters with k-means, seeded with k-means++ so the
+	 * result is deterministic for a given seed.
+	 *
+	 * @param points - The positions, packed as x, y, z triplets (Float64Array).
+	 * @param k - The number of clusters, at most the number of points.
+	 * @param max_iterations - The maximum number of refinement passes, defaults to 100.
+	 * @param seed - The seed used to pick the initial centroids, defaults to 0.
+	 * @return The cluster of every point and the centroids.
+	 */
+	#[napi]
+	pub fn kmeans(points: Float64Array, k: u32, max_iterations: Option<u32>, seed: Option<i64>) -> ClusterResult {
+		let points = Cluster::unpack(&points);
+		let k = (k as usize).min(points.len());
+		if k == 0 { return Cluster::result(vec![NOISE; points.len()], &[]) }
+
+		let mut random = JavaRandom::from_seed(seed.unwrap_or(0));
+		let mut centroids: Vec<Vector3f> = vec![points[random.next_bounded(points.len() as i32) as usize].clone()];
+
+		// Pick every further centroid with a probability proportional to its squared distance.
+		while centroids.len() < k {
+			let weights: Vec<f64> = points.iter().map(|point| Cluster::nearest(&centroids, point).1).collect();
+			let total: f64 = weights.iter().sum();
+			if total == 0.0 { break }
+
+			let mut target = random.next_double() * total;
+			let index = weights.iter().position(|weight| { target -= weight; target < 0.0 }).unwrap_or(points.len() - 1);
+			centroids.push(points[index].clone());
+		}
+
+		let mut assignments: Vec<i32> = vec![NOISE; points.len()];
+		for _ in 0..max_iterations.unwrap_or(100) {
+			let next: Vec<i32> = points.iter().map(|point| Cluster::nearest(&centroids, point).0 as i32).collect();
+			if next == assignments { break }
+			assignments = next;
+
+			for (cluster, centroid) in centroids.iter_mut().enumerate() {
+				if let Some(mean) = Cluster::mean(&points, &assignments, cluster as i32) { *centroid = mean }
+			}
+		}
+
+		Cluster::result(assignments, &centroids)
+	}
+
+	/**
+	 * Groups points that lie densely together with DBSCAN. Points with fewer than min_points
+	 * neighbors within the radius, that are not near such a point either, are noise.
+	 *
+	 * @param points - The positions, packed as x, y, z triplets (Float64Array).
+	 * @param radius - The distance within which points are neighbors.
+	 * @param min_points - The neighbors, including the point itself, needed to form a cluster.
+	 * @return The cluster of every point, -1 for noise, and the centroids.
+	 */
+	#[napi]
+	pub fn dbscan(points: Float64Array, radius: f64, min_points: u32) -> ClusterResult {
+		let points = Cluster::unpack(&points);
+		let max_distance = radius * radius;
+		let neighbors = |index: usize| -> Vec<usize> {
+			(0..points.len()).filter(|other| points[*other].subtract(&points[index]).square_length() <= max_distance).collect()
+		};
+
+		let mut assignments: Vec<i32> = vec![NOISE; points.len()];
+		let mut visited = vec![false; points.len()];
+		let mut clusters: i32 = 0;
+
+		for index in 0..points.len() {
+			if visited[index] { continue }
+			visited[index] = true;
+
+			let mut pending = neighbors(index);
+			if pending.len() < min_points as usize { continue }
+
+			assignments[index] = clusters;
+			let mut queue: Vec<usize> = Vec::new();
+			loop {
+				// Points are visited as they join the queue, so every point is queued at most once.
+				for neighbor in pending {
+					if assignments[neighbor] == NOISE { assignments[neighbor] = clusters }
+					if visited[neighbor] { continue }
+					visited[neighbor] = true;
+					queue.push(neighbor);
+				}
+
+				let Some(next) = queue.pop() else { break };
+				pending = neighbors(next);
+				if pending.len() < min_points as usize { pending.clear() }
+			}
+
+			clusters += 1;
+		}
+
+		let centroids: Vec<Vector3f> = (0..clusters).filter_map(|cluster| Cluster::mean(&points, &assignments, cluster)).collect();
+		Cluster::result(assignments, &centroids)
+	}
+
+	fn unpack(points: &Float64Array) -> Vec<Vector3f> {
+		points.chunks_exact(3).map(|p| Vector3f::new(p[0], p[1], p[2])).collect()
+	}
+
+	/**
+	 * Returns the index of the nearest centroid and its squared distance.
+	 */
+	fn nearest(centroids: &[Vector3f], point: &Vector3f) -> (usize, f64) {
+		centroids.iter()
+			.map(|centroid| centroid.subtract(point).square_length())
+			.enumerate()
+			.min_by(|a, b| a.1.total_cmp(&b.1))
+			.unwrap_or((0, 0.0))
+	}
+
+	fn mean(points: &[Vector3f], assignments: &[i32], cluster: i32) -> Option<Vector3f> {
+		let members: Vec<&Vector3f> = points.iter().zip(assignments).filter(|(_, c)| **c == cluster).map(|(p, _)| p).collect();
+		if members.is_empty() { return None }
+
+		let sum = members.iter().fold(Vector3f::new(0.0, 0.0, 0.0), |sum, point| sum.add(point));
+		Some(sum.multiply(1.0 / members.len() as f64))
+	}
+
+	fn result(assignments: Vec<i32>, centroids: &[Vector3f]) -> ClusterResult {
+		ClusterResult {
+			assignments,
+			centroids: Float64Array::new(centroids.iter().flat_map(|c| [c.x, c.y, c.z]).collect())
+		}
+	}
+}
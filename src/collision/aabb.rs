@@ -1,10 +1,12 @@
 use core::panic;
 
+use napi::bindgen_prelude::ClassInstance;
 use napi_derive::napi;
 
-use crate::vec3f::{Axis, Vector3f};
+use crate::config::MathConfig;
+use crate::vec3f::{Axis, Vector3f, VectorLike};
 
-use super::hit::HitResult;
+use super::hit::{BlockFace, HitResult, Penetration, SweepResult};
 
 /**
  * Represents an Axis-Aligned Bounding Box (AABB) in 3D space.
@@ -18,7 +20,8 @@ pub struct AABB {
 
 struct Face {
 	axis: Axis,
-	value: f64
+	value: f64,
+	face: BlockFace
 }
 
 #[napi]
@@ -30,7 +33,7 @@ impl AABB {
 	 * @param max - The maximum corner of the AABB (Vector3f).
 	 */
 	#[napi(constructor)]
-	pub fn new(min: &Vector3f, max: &Vector3f) -> AABB {
+	pub fn new(min: VectorLike, max: VectorLike) -> AABB {
 		return AABB {
 			min: min.clone(),
             max: max.clone()
@@ -45,8 +48,8 @@ impl AABB {
 	 */
 
 	#[napi]
-	pub fn translate(&self, v: &Vector3f) -> AABB {
-		return AABB::new(&self.min.add(v), &self.max.add(v));
+	pub fn translate(&self, v: VectorLike) -> AABB {
+		return AABB::new(self.min.add(&v).into(), self.max.add(&v).into());
 	}
 
 	/**
@@ -57,7 +60,7 @@ impl AABB {
 	 * @return A new AABB expanded by the position vector.
 	 */
 	#[napi]
-	pub fn expand(&mut self, v: &Vector3f) -> AABB {
+	pub fn expand(&mut self, v: VectorLike) -> AABB {
 		let mut min_clone = self.min.clone();
 		let mut max_clone = self.max.clone();
 
@@ -93,14 +96,14 @@ impl AABB {
 	 * @return True if the point is inside the AABB; otherwise, false.
 	 */
 	#[napi]
-	pub fn contains(&self, v: &Vector3f) -> bool {
+	pub fn contains(&self, v: VectorLike) -> bool {
 		return self.min.x <= v.x && v.x <= self.max.x &&
                self.min.y <= v.y && v.y <= self.max.y &&
                self.min.z <= v.z && v.z <= self.max.z;
 	}
 
 	#[napi]
-	pub fn within(&self, v: &Vector3f) -> bool {
+	pub fn within(&self, v: VectorLike) -> bool {
 		if v.x < self.min.x || v.x > self.max.x { return false }
 		if v.y < self.min.y || v.y > self.max.y { return false }
 		return v.z >= self.min.z && v.z <= self.max.z;
@@ -109,16 +112,142 @@ impl AABB {
 	#[napi]
 	pub fn grow(&self, grow_scale: f64) -> AABB {
 		let v = Vector3f::new(grow_scale, grow_scale, grow_scale);
-        AABB::new(&self.min.subtract(&v), &self.max.add(&v))
+        AABB::new(self.min.subtract(&v).into(), self.max.add(&v).into())
+	}
+
+	/**
+	 * Computes the smallest AABB enclosing both this AABB and another.
+	 *
+	 * @param other - The other AABB (AABB).
+	 * @return A new AABB enclosing both boxes, NaN along axes either box is NaN on.
+	 */
+	#[napi]
+	pub fn union(&self, other: &AABB) -> AABB {
+		// f64::min and f64::max ignore NaN, which would hide a NaN box behind the other one.
+		let lower = |a: f64, b: f64| if a.is_nan() || b.is_nan() { f64::NAN } else { a.min(b) };
+		let upper = |a: f64, b: f64| if a.is_nan() || b.is_nan() { f64::NAN } else { a.max(b) };
+
+		let min = Vector3f::new(lower(self.min.x, other.min.x), lower(self.min.y, other.min.y), lower(self.min.z, other.min.z));
+		let max = Vector3f::new(upper(self.max.x, other.max.x), upper(self.max.y, other.max.y), upper(self.max.z, other.max.z));
+
+		return AABB::new(VectorLike::from(&min), VectorLike::from(&max));
+	}
+
+	/**
+	 * Computes the overlapping region of this AABB and another.
+	 *
+	 * @param other - The other AABB (AABB).
+	 * @return The overlapping AABB; otherwise, undefined when the boxes do not overlap, only touch or have NaN corners.
+	 */
+	#[napi]
+	pub fn intersection(&self, other: &AABB) -> Option<AABB> {
+		let corners = [self.min.x, self.min.y, self.min.z, self.max.x, self.max.y, self.max.z, other.min.x, other.min.y, other.min.z, other.max.x, other.max.y, other.max.z];
+		if corners.iter().any(|c| c.is_nan()) { return None }
+
+		let min = Vector3f::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y), self.min.z.max(other.min.z));
+		let max = Vector3f::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y), self.max.z.min(other.max.z));
+
+		if min.x >= max.x || min.y >= max.y || min.z >= max.z { return None }
+
+		return Some(AABB::new(VectorLike::from(&min), VectorLike::from(&max)));
+	}
+
+	/**
+	 * Computes the smallest AABB enclosing every given box.
+	 *
+	 * @param boxes - The boxes to enclose (AABB[]).
+	 * @return The enclosing AABB, NaN along axes any box is NaN on; otherwise, undefined when no boxes are given.
+	 */
+	#[napi]
+	pub fn merge(boxes: Vec<ClassInstance<AABB>>) -> Option<AABB> {
+		let (first, rest) = boxes.split_first()?;
+		let merged = rest.iter().fold(AABB::new(VectorLike::from(&first.min), VectorLike::from(&first.max)), |merged, aabb| merged.union(aabb));
+
+		return Some(merged);
+	}
+
+	/**
+	 * Returns the volume of the AABB.
+	 */
+	#[napi]
+	pub fn volume(&self) -> f64 {
+		let size = self.size();
+		return size.x * size.y * size.z;
+	}
+
+	/**
+	 * Returns the center point of the AABB.
+	 */
+	#[napi]
+	pub fn center(&self) -> Vector3f {
+		return Vector3f::new(
+			(self.min.x + self.max.x) * 0.5,
+			(self.min.y + self.max.y) * 0.5,
+			(self.min.z + self.max.z) * 0.5
+		);
+	}
+
+	/**
+	 * Returns the extent of the AABB along each axis.
+	 */
+	#[napi]
+	pub fn size(&self) -> Vector3f {
+		return self.max.subtract(&self.min);
+	}
+
+	/**
+	 * Returns the 8 corners of the AABB. Bit 0 of the index selects the max x, bit 1 the max y
+	 * and bit 2 the max z, so the first corner is the minimum and the last is the maximum.
+	 */
+	#[napi]
+	pub fn corners(&self) -> Vec<Vector3f> {
+		return (0..8).map(|corner| Vector3f::new(
+			if corner & 1 == 0 { self.min.x } else { self.max.x },
+			if corner & 2 == 0 { self.min.y } else { self.max.y },
+			if corner & 4 == 0 { self.min.z } else { self.max.z }
+		)).collect();
 	}
 
 	#[napi]
 	pub fn intersects(&self, aabb: &AABB) -> bool {
-		const EPSILON: f64 = 1e-7;
+		let epsilon = MathConfig::current_epsilon();
+
+		if aabb.max.x - self.min.x < epsilon || self.max.x - aabb.min.x < epsilon { return false }
+		if aabb.max.y - self.min.y < epsilon || self.max.y - aabb.min.y < epsilon { return false }
+		return aabb.max.z - self.min.z > epsilon && self.max.z - aabb.min.z > epsilon;
+	}
+
+	/**
+	 * Computes how deep this AABB overlaps another, and the smallest movement that separates them,
+	 * for pushing entities apart or moving an entity out of a block it is stuck in.
+	 *
+	 * @param other - The other AABB (AABB).
+	 * @return The axis, depth and minimum translation vector; otherwise, undefined when the boxes do not intersect or have NaN corners.
+	 */
+	#[napi]
+	pub fn penetration(&self, other: &AABB) -> Option<Penetration> {
+		// A NaN depth would win or lose the comparison below depending on the axis it is on
+		let corners = [self.min.x, self.min.y, self.min.z, self.max.x, self.max.y, self.max.z, other.min.x, other.min.y, other.min.z, other.max.x, other.max.y, other.max.z];
+		if corners.iter().any(|c| c.is_nan()) || !self.intersects(other) { return None }
+
+		let mut result: Option<Penetration> = None;
+		for axis in [Axis::X, Axis::Y, Axis::Z] {
+			// Moving towards positive only has to clear the max of the other box, and towards negative its min
+			let positive = other.max.axis(axis) - self.min.axis(axis);
+			let negative = self.max.axis(axis) - other.min.axis(axis);
+			let (depth, sign) = if positive < negative { (positive, 1.0) } else { (negative, -1.0) };
+
+			if result.as_ref().is_some_and(|result| result.depth <= depth) { continue }
+
+			let mtv = Vector3f::new(
+				if axis == Axis::X { depth * sign } else { 0.0 },
+				if axis == Axis::Y { depth * sign } else { 0.0 },
+				if axis == Axis::Z { depth * sign } else { 0.0 }
+			);
+			result = Some(Penetration { axis, depth, mtv });
+		}
 
-		if aabb.max.x - self.min.x < EPSILON || self.max.x - aabb.min.x < EPSILON { return false }
-		if aabb.max.y - self.min.y < EPSILON || self.max.y - aabb.min.y < EPSILON { return false }
-		return aabb.max.z - self.min.z > EPSILON && self.max.z - aabb.min.z > EPSILON;
+		return result;
 	}
 
 	/**
@@ -133,13 +262,13 @@ impl AABB {
 	 * @return The intersection point as a Vector3f if it is valid; otherwise, undefined.
 	 */
 	#[napi]
-	pub fn on_line(axis: Axis, vec_a: &Vector3f, vec_b: &Vector3f, value: f64) -> Option<Vector3f> {
+	pub fn on_line(axis: Axis, vec_a: VectorLike, vec_b: VectorLike, value: f64) -> Option<Vector3f> {
 		let axis_a = vec_a.axis(axis);
 		let axis_b = vec_b.axis(axis);
 
 		let f = (value - axis_a) / (axis_b - axis_a);
 
-		if f < 0.0 || f > 1.0 { return None }
+		if !(0.0..=1.0).contains(&f) { return None }
 		let vector = Vector3f {
 			x: if axis == Axis::X { value } else { vec_a.x + (vec_b.x - vec_a.x) * f },
 			y: if axis == Axis::Y { value } else { vec_a.y + (vec_b.y - vec_a.y) * f },
@@ -156,7 +285,7 @@ impl AABB {
 	 * @return True if the vector is within the bounds on the specified axes; otherwise, false.
 	 */
 	#[napi]
-	pub fn within_axis(&self, axis: Vec<Axis>, vector: &Vector3f) -> bool {
+	pub fn within_axis(&self, axis: Vec<Axis>, vector: VectorLike) -> bool {
 	    if axis.len() < 2 {
 			panic!("You need to provide at least two axis!");
 		}
@@ -174,47 +303,129 @@ impl AABB {
 
 	/**
 	 * Determines if a ray defined by a start and end vector intersects with the AABB.
-	 * Returns the hit result with the intersection details.
+	 * Returns the hit result with the intersection details, where the distance is measured from the start.
 	 *
 	 * @param aabb - The AABB to check for intersection (AABB).
 	 * @param start - The start point of the ray (Vector3f).
 	 * @param end - The end point of the ray (Vector3f).
-	 * @return A HitResult if an intersection is found; otherwise, undefined.
+	 * @return A HitResult with the face that was entered if an intersection is found; otherwise, undefined.
 	 */
 	#[napi(js_name = "Intercept")]
-	pub fn intercept(aabb: &AABB, start: &Vector3f, end: &Vector3f) -> Option<HitResult> {
+	pub fn intercept(aabb: &AABB, start: VectorLike, end: VectorLike) -> Option<HitResult> {
 		let AABB { min, max } = aabb;
 		let mut min_distance: f64 = f64::INFINITY;
-        let mut hit_position: Option<Vector3f> = None;
+        let mut hit_position: Option<(Vector3f, BlockFace)> = None;
 		let faces: Vec<Face> = vec![
-			Face { axis: Axis::X, value: min.x },
-            Face { axis: Axis::X, value: max.x },
-            Face { axis: Axis::Y, value: min.y },
-            Face { axis: Axis::Y, value: max.y },
-            Face { axis: Axis::Z, value: min.z },
-            Face { axis: Axis::Z, value: max.z }
+			Face { axis: Axis::X, value: min.x, face: BlockFace::West },
+            Face { axis: Axis::X, value: max.x, face: BlockFace::East },
+            Face { axis: Axis::Y, value: min.y, face: BlockFace::Down },
+            Face { axis: Axis::Y, value: max.y, face: BlockFace::Up },
+            Face { axis: Axis::Z, value: min.z, face: BlockFace::North },
+            Face { axis: Axis::Z, value: max.z, face: BlockFace::South }
 		];
 
 		for face in faces {
-			let vector: Option<Vector3f> = AABB::on_line(face.axis, start, end, face.value);
+			let vector: Option<Vector3f> = AABB::on_line(face.axis, start.clone().into(), end.clone().into(), face.value);
 			
 			if vector.is_none() {continue};
 			let vector: Vector3f = vector.unwrap();
 
-			if !aabb.within_axis(AABB::get_axis(face.axis), &vector) {continue};
-			let vector_distance: f64 = vector.square_length();
+			if !aabb.within_axis(AABB::get_axis(face.axis), VectorLike::from(&vector)) {continue};
+			let vector_distance: f64 = vector.subtract(&start).square_length();
 
 			if vector_distance > min_distance { continue };
 			min_distance = vector_distance;
-			hit_position = Some(vector);
+			hit_position = Some((vector, face.face));
 		}
 
-		return if hit_position.is_none() { return None } else {
-			Some(HitResult {
-				distance: min_distance,
-				position: hit_position.unwrap()
-			})
+		return hit_position.map(|(position, face)| HitResult {
+			distance: min_distance.sqrt(),
+			position,
+			normal: face.normal(),
+			face
+		})
+	}
+
+	/**
+	 * Computes when this AABB, moving with a velocity, first touches another moving AABB.
+	 * Both boxes are in motion, so the test uses their relative velocity.
+	 *
+	 * @param my_velocity - The movement of this AABB during the step (Vector3f).
+	 * @param other - The other AABB (AABB).
+	 * @param other_velocity - The movement of the other AABB during the step (Vector3f).
+	 * @return The time of impact, the normal of the face hit on the other AABB and the movement of this AABB until the contact; otherwise, undefined, also for velocities that are not finite.
+	 */
+	#[napi]
+	pub fn sweep_vs_moving(&self, my_velocity: VectorLike, other: &AABB, other_velocity: VectorLike) -> Option<SweepResult> {
+		self.sweep_time(&my_velocity.subtract(&other_velocity), other).map(|hit| SweepResult {
+			displacement: my_velocity.multiply(hit.time),
+			..hit
+		})
+	}
+
+	/**
+	 * Sweeps this AABB along a velocity against a stationary AABB, finding the first contact
+	 * even when the movement would pass through the other box within a single step.
+	 *
+	 * @param other - The stationary AABB (AABB).
+	 * @param velocity - The movement of this AABB during the step (Vector3f).
+	 * @return The time of impact (0..1), the normal of the face hit and the movement until the contact; otherwise, undefined, also for velocities that are not finite.
+	 */
+	#[napi]
+	pub fn sweep(&self, other: &AABB, velocity: VectorLike) -> Option<SweepResult> {
+		self.sweep_time(&velocity, other)
+	}
+
+	/**
+	 * Computes when this AABB, moving with a velocity, first touches a stationary AABB,
+	 * using the entry and exit times of the slabs on every axis.
+	 */
+	pub(crate) fn sweep_time(&self, velocity: &Vector3f, other: &AABB) -> Option<SweepResult> {
+		// A movement that is not finite has no time of impact, nor a displacement until it.
+		if !(velocity.x.is_finite() && velocity.y.is_finite() && velocity.z.is_finite()) { return None }
+
+		if self.intersects(other) {
+			return Some(SweepResult {
+				time: 0.0,
+				normal: Vector3f::new(0.0, 0.0, 0.0),
+				displacement: Vector3f::new(0.0, 0.0, 0.0),
+				face: None
+			});
 		}
+
+		let mut entry = f64::NEG_INFINITY;
+		let mut exit = f64::INFINITY;
+		let mut normal = Vector3f::new(0.0, 0.0, 0.0);
+
+		for axis in [Axis::X, Axis::Y, Axis::Z] {
+			let delta = velocity.axis(axis);
+			let (min, max) = (self.min.axis(axis), self.max.axis(axis));
+			let (other_min, other_max) = (other.min.axis(axis), other.max.axis(axis));
+
+			let (axis_entry, axis_exit) = if delta == 0.0 {
+				// Without movement the slabs overlap either always or never.
+				if max <= other_min || min >= other_max { return None }
+				(f64::NEG_INFINITY, f64::INFINITY)
+			} else if delta > 0.0 {
+				((other_min - max) / delta, (other_max - min) / delta)
+			} else {
+				((other_max - min) / delta, (other_min - max) / delta)
+			};
+
+			if axis_entry > entry {
+				entry = axis_entry;
+				let sign = if delta > 0.0 { -1.0 } else { 1.0 };
+				normal = Vector3f::new(
+					if axis == Axis::X { sign } else { 0.0 },
+					if axis == Axis::Y { sign } else { 0.0 },
+					if axis == Axis::Z { sign } else { 0.0 }
+				);
+			}
+			exit = exit.min(axis_exit);
+		}
+
+		if entry > exit || !(0.0..=1.0).contains(&entry) { return None }
+		Some(SweepResult { time: entry, face: Some(BlockFace::from_normal(&normal)), normal, displacement: velocity.multiply(entry) })
 	}
 
 	fn get_axis(axis: Axis) -> Vec<Axis> {
@@ -0,0 +1,197 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::aabb::AABB;
+use super::hit::{BlockFace, HitResult, IndexedHitResult};
+
+/**
+ * Intersections of thick rays, where a sphere of some radius is swept along a line segment,
+ * for beam weapons and guardian-style attacks.
+ */
+#[napi]
+pub struct Beam {}
+
+#[napi]
+impl Beam {
+	/**
+	 * Sweeps a sphere along a line segment and finds where it first touches a box.
+	 * Corners and edges of the box are rounded by the radius, like the real contact shape.
+	 *
+	 * @param aabb - The box to test against (AABB).
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @param radius - The radius of the beam.
+	 * @return The center of the sphere when it touches the box, the distance travelled and the face
+	 * closest to the contact; otherwise, undefined, also for segments that are not finite.
+	 */
+	#[napi]
+	pub fn intercept(aabb: &AABB, start: VectorLike, end: VectorLike, radius: f64) -> Option<HitResult> {
+		let direction = end.subtract(&start);
+
+		Beam::sweep(aabb, &start, &direction, radius.max(0.0)).map(|t| {
+			let position = start.add(&direction.multiply(t));
+
+			// The contact normal points from the closest point of the box to the center of the sphere.
+			// Clamped with max and min, which unlike clamp tolerate inverted and NaN bounds.
+			let closest = Vector3f::new(
+				position.x.max(aabb.min.x).min(aabb.max.x),
+				position.y.max(aabb.min.y).min(aabb.max.y),
+				position.z.max(aabb.min.z).min(aabb.max.z)
+			);
+			let normal = position.subtract(&closest);
+			let normal = if normal.square_length() == 0.0 { direction.multiply(-1.0) } else { normal };
+
+			HitResult { distance: direction.length() * t, face: BlockFace::from_normal(&normal), normal: normal.normalize(), position }
+		})
+	}
+
+	/**
+	 * Sweeps a sphere along a line segment through every box it touches.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @param radius - The radius of the beam.
+	 * @param boxes - The boxes to test against (Array<AABB>).
+	 * @param limit - The maximum number of hits to return, defaults to all of them.
+	 * @return The hits sorted by distance from the start, with the index of the box that was hit.
+	 */
+	#[napi]
+	pub fn pierce(start: VectorLike, end: VectorLike, radius: f64, boxes: Vec<ClassInstance<AABB>>, limit: Option<u32>) -> Vec<IndexedHitResult> {
+		let mut hits: Vec<IndexedHitResult> = boxes.iter()
+			.enumerate()
+			.filter_map(|(index, aabb)| Beam::intercept(aabb, start.clone().into(), end.clone().into(), radius).map(|hit| IndexedHitResult {
+				index: index as u32,
+				distance: hit.distance,
+				position: hit.position,
+				face: hit.face
+			}))
+			.collect();
+
+		hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+		if let Some(limit) = limit { hits.truncate(limit as usize) }
+
+		return hits;
+	}
+
+	/**
+	 * Finds the fraction of the segment at which the sphere first touches the box.
+	 * The segment is first intersected with the box grown by the radius, then hits in the
+	 * edge and corner regions are refined against the capsules around the edges.
+	 */
+	fn sweep(aabb: &AABB, start: &Vector3f, direction: &Vector3f, radius: f64) -> Option<f64> {
+		// Contacts along a segment that is not finite could not be placed, so nothing is touched.
+		if ![start.x, start.y, start.z, direction.x, direction.y, direction.z].iter().all(|c| c.is_finite()) { return None }
+
+		if Beam::square_distance(aabb, start) <= radius * radius { return Some(0.0) }
+
+		let grown = Vector3f::new(radius, radius, radius);
+		let t = Beam::slab(&aabb.min.subtract(&grown), &aabb.max.add(&grown), start, direction)?;
+		let point = start.add(&direction.multiply(t));
+
+		// Bits of the axes on which the point lies below the box (below) or above it (above).
+		let (mut below, mut above) = (0, 0);
+		for (bit, (value, min, max)) in [(point.x, aabb.min.x, aabb.max.x), (point.y, aabb.min.y, aabb.max.y), (point.z, aabb.min.z, aabb.max.z)].into_iter().enumerate() {
+			if value < min { below |= 1 << bit }
+			if value > max { above |= 1 << bit }
+		}
+
+		let outside: u32 = below | above;
+		match outside.count_ones() {
+			0 | 1 => Some(t), // Face region, the grown box is exact
+			2 => Beam::capsule(start, direction, &Beam::corner(aabb, below ^ 7), &Beam::corner(aabb, above), radius),
+			_ => [1, 2, 4].into_iter()
+				.filter_map(|bit| Beam::capsule(start, direction, &Beam::corner(aabb, above), &Beam::corner(aabb, above ^ bit), radius))
+				.min_by(|a, b| a.total_cmp(b))
+		}
+	}
+
+	/**
+	 * Intersects the segment with a box using slabs, returning the entry fraction.
+	 */
+	fn slab(min: &Vector3f, max: &Vector3f, start: &Vector3f, direction: &Vector3f) -> Option<f64> {
+		let (mut near, mut far) = (0.0_f64, 1.0_f64);
+
+		for (origin, delta, low, high) in [(start.x, direction.x, min.x, max.x), (start.y, direction.y, min.y, max.y), (start.z, direction.z, min.z, max.z)] {
+			if delta == 0.0 {
+				if origin < low || origin > high { return None }
+				continue;
+			}
+
+			let (a, b) = ((low - origin) / delta, (high - origin) / delta);
+			near = near.max(a.min(b));
+			far = far.min(a.max(b));
+			if near > far { return None }
+		}
+
+		return Some(near);
+	}
+
+	/**
+	 * Intersects the segment with a capsule around an axis aligned edge: the cylinder
+	 * along the edge and the spheres at both of its ends.
+	 */
+	fn capsule(start: &Vector3f, direction: &Vector3f, a: &Vector3f, b: &Vector3f, radius: f64) -> Option<f64> {
+		let mut nearest = [Beam::sphere(start, direction, a, radius), Beam::sphere(start, direction, b, radius)]
+			.into_iter()
+			.flatten()
+			.min_by(|x, y| x.total_cmp(y));
+
+		// The edge runs along the one axis its corners differ on.
+		let coordinates = |v: &Vector3f| [v.x, v.y, v.z];
+		let (s, d, a, b) = (coordinates(start), coordinates(direction), coordinates(a), coordinates(b));
+		let axis = (0..3).find(|axis| a[*axis] != b[*axis]).unwrap_or(0);
+		let (i, j) = ((axis + 1) % 3, (axis + 2) % 3);
+
+		let offset = [s[i] - a[i], s[j] - a[j]];
+		let t = Beam::smallest_root(d[i] * d[i] + d[j] * d[j], 2.0 * (offset[0] * d[i] + offset[1] * d[j]), offset[0] * offset[0] + offset[1] * offset[1] - radius * radius);
+
+		if let Some(t) = t {
+			let along = s[axis] + d[axis] * t;
+			if along >= a[axis].min(b[axis]) && along <= a[axis].max(b[axis]) && nearest.is_none_or(|n| t < n) { nearest = Some(t) }
+		}
+
+		return nearest;
+	}
+
+	fn sphere(start: &Vector3f, direction: &Vector3f, center: &Vector3f, radius: f64) -> Option<f64> {
+		let offset = start.subtract(center);
+		Beam::smallest_root(direction.dot(direction), 2.0 * offset.dot(direction), offset.dot(&offset) - radius * radius)
+	}
+
+	/**
+	 * Solves a * t^2 + b * t + c = 0 for the smallest root within the segment.
+	 */
+	fn smallest_root(a: f64, b: f64, c: f64) -> Option<f64> {
+		if a == 0.0 { return None }
+
+		let discriminant = b * b - 4.0 * a * c;
+		if discriminant < 0.0 { return None }
+
+		let t = (-b - discriminant.sqrt()) / (2.0 * a);
+		if (0.0..=1.0).contains(&t) { Some(t) } else { None }
+	}
+
+	/**
+	 * Returns the corner of the box with the maximum on every axis whose bit is set.
+	 */
+	fn corner(aabb: &AABB, bits: u32) -> Vector3f {
+		Vector3f::new(
+			if bits & 1 != 0 { aabb.max.x } else { aabb.min.x },
+			if bits & 2 != 0 { aabb.max.y } else { aabb.min.y },
+			if bits & 4 != 0 { aabb.max.z } else { aabb.min.z }
+		)
+	}
+
+	fn square_distance(aabb: &AABB, point: &Vector3f) -> f64 {
+		// Clamped with max and min, which unlike clamp tolerate inverted and NaN bounds.
+		let closest = Vector3f::new(
+			point.x.max(aabb.min.x).min(aabb.max.x),
+			point.y.max(aabb.min.y).min(aabb.max.y),
+			point.z.max(aabb.min.z).min(aabb.max.z)
+		);
+
+		point.subtract(&closest).square_length()
+	}
+}
@@ -0,0 +1,234 @@
+use std::collections::{HashMap, VecDeque};
+
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::ray::Raycaster;
+
+const DEFAULT_CAPACITY: u32 = 256;
+const DEFAULT_PRECISION: f64 = 0.25; // Grid size rays are snapped to, in blocks
+const DEFAULT_TTL: u32 = 20; // Ticks a result stays valid
+
+struct Entry {
+	block: Option<Vector3f>, // First solid block along the ray, None when the line of sight is clear
+	created: u64 // Tick the result was computed at
+}
+
+/**
+ * A small cache of block raycasts, for AI that repeats nearly identical line of sight checks
+ * every tick. Rays are keyed by their start and end snapped to a grid, so rays closer than the
+ * precision share a result, and results expire after a number of ticks so block changes are picked up.
+ * When the cache is full, the result closest to expiring makes room for the new one.
+ */
+#[napi]
+pub struct RaycastCache {
+	precision: f64, // Grid size rays are snapped to, in blocks
+	ttl: u32, // Ticks a result stays valid
+	capacity: usize,
+	entries: HashMap<[i64; 6], Entry>,
+	expiry: VecDeque<(u64, [i64; 6])>, // Creation tick and key of every insert, oldest first
+	tick: u64,
+	hits: u32,
+	misses: u32
+}
+
+#[napi]
+impl RaycastCache {
+	/**
+	 * Constructs an empty cache.
+	 *
+	 * @param capacity - The maximum number of cached rays, defaults to 256.
+	 * @param precision - The grid size rays are snapped to in blocks, defaults to 0.25.
+	 * @param ttl - The number of ticks a result stays valid, defaults to 20.
+	 * Throws if the precision is not a finite number above 0.
+	 */
+	#[napi(constructor)]
+	pub fn new(capacity: Option<u32>, precision: Option<f64>, ttl: Option<u32>) -> napi::Result<RaycastCache> {
+		let mut cache = RaycastCache {
+			precision: DEFAULT_PRECISION,
+			ttl: ttl.unwrap_or(DEFAULT_TTL),
+			capacity: capacity.unwrap_or(DEFAULT_CAPACITY).max(1) as usize,
+			entries: HashMap::new(),
+			expiry: VecDeque::new(),
+			tick: 0,
+			hits: 0,
+			misses: 0
+		};
+		if let Some(precision) = precision { cache.set_precision(precision)?; }
+
+		Ok(cache)
+	}
+
+	#[napi(getter)]
+	pub fn precision(&self) -> f64 {
+		self.precision
+	}
+
+	/**
+	 * Changes the grid size rays are snapped to, dropping every cached result since their keys no longer apply.
+	 *
+	 * @param precision - The grid size in blocks, a finite number above 0.
+	 */
+	#[napi(setter, js_name = "precision")]
+	pub fn set_precision(&mut self, precision: f64) -> napi::Result<()> {
+		if !precision.is_finite() || precision <= 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Precision must be a finite number above 0, got {}", precision)));
+		}
+
+		self.precision = precision;
+		self.clear();
+		Ok(())
+	}
+
+	#[napi(getter)]
+	pub fn ttl(&self) -> u32 {
+		self.ttl
+	}
+
+	/**
+	 * Changes the number of ticks a result stays valid, which also applies to the results already cached.
+	 *
+	 * @param ttl - The number of ticks.
+	 */
+	#[napi(setter, js_name = "ttl")]
+	pub fn set_ttl(&mut self, ttl: u32) {
+		self.ttl = ttl;
+		self.expire();
+	}
+
+	/**
+	 * Advances the clock of the cache, expiring results older than the time to live.
+	 *
+	 * @param ticks - The number of ticks that passed, defaults to 1.
+	 */
+	#[napi]
+	pub fn advance(&mut self, ticks: Option<u32>) {
+		self.tick += ticks.unwrap_or(1) as u64;
+		self.expire();
+	}
+
+	/**
+	 * Checks whether the line between two points passes no solid block, reusing a recent result
+	 * of a nearby ray when there is one.
+	 *
+	 * @param start - The start of the line (Vector3f).
+	 * @param end - The end of the line (Vector3f).
+	 * @param is_solid - Returns whether the block at a position blocks the line of sight.
+	 * Throws if the line is not finite or passes through more blocks than an array can hold.
+	 */
+	#[napi(ts_args_type = "start: VectorLike, end: VectorLike, isSolid: (position: Vector3f) => boolean")]
+	pub fn line_of_sight(&mut self, start: VectorLike, end: VectorLike, is_solid: JsFunction) -> napi::Result<bool> {
+		Ok(self.first_block(start, end, is_solid)?.is_none())
+	}
+
+	/**
+	 * Finds the first solid block along a line, reusing a recent result of a nearby ray when there is one.
+	 *
+	 * @param start - The start of the line (Vector3f).
+	 * @param end - The end of the line (Vector3f).
+	 * @param is_solid - Returns whether the block at a position blocks the line.
+	 * @return The position of the first solid block; otherwise, undefined.
+	 * Throws if the line is not finite or passes through more blocks than an array can hold.
+	 */
+	#[napi(ts_args_type = "start: VectorLike, end: VectorLike, isSolid: (position: Vector3f) => boolean")]
+	pub fn first_block(&mut self, start: VectorLike, end: VectorLike, is_solid: JsFunction) -> napi::Result<Option<Vector3f>> {
+		// NaN would snap to the key of a ray through the origin, and infinite lines never finish traversing.
+		Raycaster::segment(&start, &end, None)?;
+		let key = self.key(&start, &end);
+
+		if let Some(entry) = self.entries.get(&key) {
+			if self.tick - entry.created <= self.ttl as u64 {
+				self.hits = self.hits.saturating_add(1);
+				return Ok(entry.block.clone());
+			}
+		}
+
+		self.misses = self.misses.saturating_add(1);
+		let mut block: Option<Vector3f> = None;
+		Raycaster::traverse(&start, &end, |position| {
+			if !is_solid.call1::<Vector3f, bool>(position.clone())? { return Ok(false) }
+
+			block = Some(position.clone());
+			Ok(true)
+		})?;
+
+		if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) { self.evict() }
+		self.entries.insert(key, Entry { block: block.clone(), created: self.tick });
+		self.expiry.push_back((self.tick, key));
+
+		Ok(block)
+	}
+
+	/**
+	 * Removes every cached result, for example after a large block change.
+	 */
+	#[napi]
+	pub fn clear(&mut self) {
+		self.entries.clear();
+		self.expiry.clear();
+	}
+
+	/**
+	 * Returns the number of cached rays.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.entries.len() as u32
+	}
+
+	/**
+	 * Returns the number of queries answered from the cache.
+	 */
+	#[napi]
+	pub fn hits(&self) -> u32 {
+		self.hits
+	}
+
+	/**
+	 * Returns the number of queries that had to be traced.
+	 */
+	#[napi]
+	pub fn misses(&self) -> u32 {
+		self.misses
+	}
+
+	/**
+	 * Snaps the start and end of a ray to the grid.
+	 */
+	fn key(&self, start: &Vector3f, end: &Vector3f) -> [i64; 6] {
+		[start.x, start.y, start.z, end.x, end.y, end.z].map(|value| (value / self.precision).round() as i64)
+	}
+
+	/**
+	 * Removes the entries older than the time to live, from the front of the expiry queue.
+	 */
+	fn expire(&mut self) {
+		while let Some(&(created, _)) = self.expiry.front() {
+			if self.tick - created <= self.ttl as u64 { break }
+			self.pop_oldest();
+		}
+	}
+
+	/**
+	 * Removes the entry closest to expiring.
+	 */
+	fn evict(&mut self) {
+		while !self.expiry.is_empty() {
+			if self.pop_oldest() { break }
+		}
+	}
+
+	/**
+	 * Pops the front of the expiry queue and removes its entry, unless the entry was replaced since.
+	 * Returns whether an entry was removed.
+	 */
+	fn pop_oldest(&mut self) -> bool {
+		let Some((created, key)) = self.expiry.pop_front() else { return false };
+
+		let current = self.entries.get(&key).is_some_and(|entry| entry.created == created);
+		if current { self.entries.remove(&key); }
+		current
+	}
+}
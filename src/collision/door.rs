@@ -0,0 +1,177 @@
+use napi_derive::napi;
+
+use crate::vec3f::Vector3f;
+
+use super::aabb::AABB;
+
+/**
+ * Represents a vertical panel turning around a hinge, such as a door or a fence gate leaf.
+ */
+#[napi(object)]
+pub struct DoorPanel {
+	pub hinge: Vector3f, // Bottom of the hinge edge
+	pub width: f64, // Distance from the hinge to the free edge
+	pub height: f64,
+	pub thickness: f64,
+}
+
+/**
+ * Collision of panels swinging between two angles. Angles are in degrees and follow entity yaw:
+ * at 0 the panel extends from the hinge towards south (+Z), and increasing angles turn it clockwise
+ * seen from above.
+ */
+#[napi]
+pub struct DoorSweep {}
+
+#[napi]
+impl DoorSweep {
+	/**
+	 * Finds the first angle at which a swinging panel touches a box.
+	 *
+	 * @param panel - The panel (DoorPanel).
+	 * @param from - The angle the swing starts at.
+	 * @param to - The angle the swing ends at, a full turn or more sweeps every angle.
+	 * @param aabb - The box to test, usually an entity hitbox (AABB).
+	 * @return The angle of the first contact, the start angle when they already touch; otherwise, undefined,
+	 *         also when the start angle is not finite or any other input is NaN.
+	 */
+	#[napi]
+	pub fn contact_angle(panel: DoorPanel, from: f64, to: f64, aabb: &AABB) -> Option<f64> {
+		DoorSweep::contact(&panel, from, to, aabb)
+	}
+
+	/**
+	 * Checks whether a box is in the way of a swinging panel.
+	 *
+	 * @param panel - The panel (DoorPanel).
+	 * @param from - The angle the swing starts at.
+	 * @param to - The angle the swing ends at.
+	 * @param aabb - The box to test, usually an entity hitbox (AABB).
+	 * @return Whether the box is in the way, false when the start angle is not finite or any other input is NaN.
+	 */
+	#[napi]
+	pub fn intersects(panel: DoorPanel, from: f64, to: f64, aabb: &AABB) -> bool {
+		DoorSweep::contact(&panel, from, to, aabb).is_some()
+	}
+
+	/**
+	 * Returns the box enclosing the region swept by a panel, useful to gather nearby entities first.
+	 *
+	 * @param panel - The panel (DoorPanel).
+	 * @param from - The angle the swing starts at.
+	 * @param to - The angle the swing ends at.
+	 * @return The box, with NaN corners when the start angle is not finite or the end angle is NaN.
+	 */
+	#[napi]
+	pub fn bounds(panel: DoorPanel, from: f64, to: f64) -> AABB {
+		if !from.is_finite() || to.is_nan() {
+			let nan = Vector3f::new(f64::NAN, f64::NAN, f64::NAN);
+			return AABB::new(nan.clone().into(), nan.into());
+		}
+
+		let sweep = to - from;
+		let half = panel.thickness * 0.5;
+
+		// The extremes are at the ends of the swing and at every axis direction crossed.
+		let mut angles = vec![from, to];
+		angles.extend([0.0, 90.0, 180.0, 270.0].into_iter().filter(|angle| DoorSweep::progress(*angle, from, sweep).is_some()));
+
+		let (mut min_x, mut max_x, mut min_z, mut max_z) = (0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+		for angle in angles {
+			let (x, z) = DoorSweep::direction(angle);
+			min_x = min_x.min(x * panel.width);
+			max_x = max_x.max(x * panel.width);
+			min_z = min_z.min(z * panel.width);
+			max_z = max_z.max(z * panel.width);
+		}
+
+		let hinge = &panel.hinge;
+		AABB::new(
+			Vector3f::new(hinge.x + min_x - half, hinge.y, hinge.z + min_z - half).into(),
+			Vector3f::new(hinge.x + max_x + half, hinge.y + panel.height, hinge.z + max_z + half).into()
+		)
+	}
+
+	/**
+	 * Returns the horizontal direction of a panel at an angle.
+	 */
+	fn direction(angle: f64) -> (f64, f64) {
+		let (sin, cos) = angle.to_radians().sin_cos();
+		(-sin, cos)
+	}
+
+	/**
+	 * Returns how far along a swing an angle is reached, in degrees from the start;
+	 * otherwise, None when the swing does not reach it.
+	 */
+	fn progress(angle: f64, from: f64, sweep: f64) -> Option<f64> {
+		let travelled = ((angle - from) * sweep.signum()).rem_euclid(360.0);
+		if travelled <= sweep.abs() + 1.0e-9 { Some(travelled) } else { None }
+	}
+
+	/**
+	 * Finds the first contact of the panel with the box, treated as a segment from the hinge
+	 * against the box grown by half the thickness. A segment turning around its end first touches
+	 * a convex shape either with a corner of the shape or with its tip on an edge of the shape.
+	 */
+	fn contact(panel: &DoorPanel, from: f64, to: f64, aabb: &AABB) -> Option<f64> {
+		// The slab test treats NaN bounds as unbounded, which would report a contact at the start.
+		let inputs = [panel.hinge.x, panel.hinge.y, panel.hinge.z, panel.width, panel.height, panel.thickness, to];
+		let corners = [aabb.min.x, aabb.min.y, aabb.min.z, aabb.max.x, aabb.max.y, aabb.max.z];
+		if !from.is_finite() || inputs.iter().chain(corners.iter()).any(|value| value.is_nan()) { return None }
+
+		if aabb.max.y <= panel.hinge.y || aabb.min.y >= panel.hinge.y + panel.height { return None }
+
+		let half = panel.thickness * 0.5;
+		let (x0, x1) = (aabb.min.x - panel.hinge.x - half, aabb.max.x - panel.hinge.x + half);
+		let (z0, z1) = (aabb.min.z - panel.hinge.z - half, aabb.max.z - panel.hinge.z + half);
+		let radius = panel.width;
+
+		// Already touching at the start of the swing.
+		let (dx, dz) = DoorSweep::direction(from);
+		if DoorSweep::segment_crosses(dx * radius, dz * radius, x0, x1, z0, z1) { return Some(from) }
+
+		let mut candidates: Vec<(f64, f64)> = Vec::new();
+
+		for (x, z) in [(x0, z0), (x1, z0), (x0, z1), (x1, z1)] {
+			if x * x + z * z <= radius * radius { candidates.push((x, z)) }
+		}
+
+		for (edge, low, high, vertical) in [(x0, z0, z1, true), (x1, z0, z1, true), (z0, x0, x1, false), (z1, x0, x1, false)] {
+			let squared = radius * radius - edge * edge;
+			if squared < 0.0 { continue }
+
+			for along in [squared.sqrt(), -squared.sqrt()] {
+				if along < low || along > high { continue }
+				candidates.push(if vertical { (edge, along) } else { (along, edge) });
+			}
+		}
+
+		let sweep = to - from;
+		candidates.into_iter()
+			.filter_map(|(x, z)| DoorSweep::progress((-x).atan2(z).to_degrees(), from, sweep))
+			.min_by(|a, b| a.total_cmp(b))
+			.map(|travelled| from + travelled * sweep.signum())
+	}
+
+	/**
+	 * Checks whether the segment from the hinge to (x, z) crosses a rectangle, using the slab test.
+	 */
+	fn segment_crosses(x: f64, z: f64, x0: f64, x1: f64, z0: f64, z1: f64) -> bool {
+		let (mut near, mut far) = (0.0_f64, 1.0_f64);
+
+		for (delta, low, high) in [(x, x0, x1), (z, z0, z1)] {
+			if delta == 0.0 {
+				if low > 0.0 || high < 0.0 { return false }
+				continue;
+			}
+
+			let (a, b) = (low / delta, high / delta);
+			near = near.max(a.min(b));
+			far = far.min(a.max(b));
+			if near > far { return false }
+		}
+
+		return true;
+	}
+}
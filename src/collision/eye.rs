@@ -0,0 +1,88 @@
+use napi_derive::napi;
+
+use crate::rotation::Rotation;
+use crate::vec2f::Vector2f;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * The poses of an entity that change the height of its eyes.
+ */
+#[napi]
+#[derive(PartialEq)]
+pub enum Pose {
+	Standing,
+	Sneaking,
+	Swimming,
+	Crawling,
+	Gliding, // Flying with an elytra
+	SpinAttack, // Launched by riptide
+	Sleeping
+}
+
+/**
+ * Represents the ray an entity looks along.
+ */
+#[napi(object)]
+pub struct EyeRayResult {
+	pub origin: Vector3f, // Eye position of the entity
+	pub direction: Vector3f, // Normalized direction the entity looks in
+}
+
+/**
+ * The eye ray of an entity, so every raycast starts from identical eye math. The height uses
+ * the player's vanilla eye heights per pose and ignores view bobbing, which only exists on the client.
+ */
+#[napi]
+pub struct EyeRay {}
+
+#[napi]
+impl EyeRay {
+	/**
+	 * Computes the eye position and look direction of an entity.
+	 *
+	 * @param position - The feet position of the entity (Vector3f).
+	 * @param pose - The pose of the entity (Pose).
+	 * @param rotation - The rotation of the entity in degrees, x being the pitch and y the yaw (Vector2f).
+	 * @param scale - The scale of the entity, multiplying the eye height, defaults to 1.
+	 * @return The origin and direction of the eye ray.
+	 */
+	#[napi]
+	pub fn compute(position: VectorLike, pose: Pose, rotation: &Vector2f, scale: Option<f64>) -> EyeRayResult {
+		let height = EyeRay::eye_height(pose) * scale.unwrap_or(1.0);
+
+		EyeRayResult {
+			origin: Vector3f::new(position.x, position.y + height, position.z),
+			direction: Rotation::direction(rotation.y, rotation.x)
+		}
+	}
+
+	/**
+	 * Returns the end point of the eye ray of an entity at a distance.
+	 *
+	 * @param position - The feet position of the entity (Vector3f).
+	 * @param pose - The pose of the entity (Pose).
+	 * @param rotation - The rotation of the entity in degrees, x being the pitch and y the yaw (Vector2f).
+	 * @param distance - The length of the ray.
+	 * @param scale - The scale of the entity, multiplying the eye height, defaults to 1.
+	 */
+	#[napi]
+	pub fn end(position: VectorLike, pose: Pose, rotation: &Vector2f, distance: f64, scale: Option<f64>) -> Vector3f {
+		let ray = EyeRay::compute(position, pose, rotation, scale);
+		ray.origin.add(&ray.direction.multiply(distance))
+	}
+
+	/**
+	 * Returns the height of the eyes of a player above its feet in a pose.
+	 *
+	 * @param pose - The pose of the player (Pose).
+	 */
+	#[napi]
+	pub fn eye_height(pose: Pose) -> f64 {
+		match pose {
+			Pose::Standing => 1.62,
+			Pose::Sneaking => 1.27,
+			Pose::Swimming | Pose::Crawling | Pose::Gliding | Pose::SpinAttack => 0.4,
+			Pose::Sleeping => 0.2
+		}
+	}
+}
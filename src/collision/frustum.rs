@@ -0,0 +1,104 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::mat4::Matrix4;
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::aabb::AABB;
+use super::primitive::{Plane, Sphere};
+
+/**
+ * Represents a view volume bounded by 6 planes whose normals point inwards, for culling
+ * entities and chunks a client cannot see before sending them.
+ */
+#[napi]
+pub struct Frustum {
+	planes: Vec<Plane> // Left, right, bottom, top, near and far planes
+}
+
+#[napi]
+impl Frustum {
+	/**
+	 * Constructs a frustum from its planes, with normals pointing into the volume.
+	 *
+	 * @param planes - The 6 planes, ordered left, right, bottom, top, near and far (Array<Plane>).
+	 */
+	#[napi(constructor)]
+	pub fn new(planes: Vec<ClassInstance<Plane>>) -> napi::Result<Frustum> {
+		if planes.len() != 6 {
+			return Err(Error::new(Status::InvalidArg, format!("A frustum needs 6 planes, got {}", planes.len())));
+		}
+
+		Ok(Frustum { planes: planes.iter().map(|plane| Plane::new(VectorLike::from(&plane.normal), plane.constant)).collect() })
+	}
+
+	/**
+	 * Extracts the frustum of a view projection matrix with a clip space depth of -1..1,
+	 * such as the one built by Camera.viewProjectionMatrix.
+	 *
+	 * @param matrix - The combined projection * view matrix (Matrix4).
+	 */
+	#[napi]
+	pub fn from_matrix(matrix: &Matrix4) -> Frustum {
+		let m = &matrix.elements;
+		let row = |i: usize| [m[i], m[i + 4], m[i + 8], m[i + 12]];
+		let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+
+		let plane = |sign: f64, r: [f64; 4]| Plane::new(
+			Vector3f::new(w[0] + sign * r[0], w[1] + sign * r[1], w[2] + sign * r[2]).into(),
+			w[3] + sign * r[3]
+		);
+
+		Frustum { planes: vec![plane(1.0, x), plane(-1.0, x), plane(1.0, y), plane(-1.0, y), plane(1.0, z), plane(-1.0, z)] }
+	}
+
+	/**
+	 * Returns the planes of the frustum, ordered left, right, bottom, top, near and far.
+	 */
+	#[napi]
+	pub fn planes(&self) -> Vec<Plane> {
+		self.planes.iter().map(|plane| Plane::new(VectorLike::from(&plane.normal), plane.constant)).collect()
+	}
+
+	/**
+	 * Checks if a point is inside the frustum.
+	 *
+	 * @param point - The point to check (Vector3f).
+	 */
+	#[napi]
+	pub fn contains_point(&self, point: VectorLike) -> bool {
+		self.planes.iter().all(|plane| plane.distance_to_point(point.clone().into()) >= 0.0)
+	}
+
+	/**
+	 * Checks if a sphere is at least partly inside the frustum. Like most culling tests it is
+	 * conservative, and may accept spheres just outside a corner of the frustum.
+	 *
+	 * @param sphere - The sphere to check (Sphere).
+	 */
+	#[napi]
+	pub fn contains_sphere(&self, sphere: &Sphere) -> bool {
+		self.planes.iter().all(|plane| plane.distance_to_point(VectorLike::from(&sphere.center)) >= -sphere.radius)
+	}
+
+	/**
+	 * Checks if a box is at least partly inside the frustum, by testing the corner of the box
+	 * furthest along the normal of every plane. Like most culling tests it is conservative,
+	 * and may accept boxes just outside a corner of the frustum.
+	 *
+	 * @param aabb - The box to check (AABB).
+	 */
+	#[napi(js_name = "containsAABB")]
+	pub fn contains_aabb(&self, aabb: &AABB) -> bool {
+		self.planes.iter().all(|plane| {
+			let corner = Vector3f::new(
+				if plane.normal.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+				if plane.normal.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+				if plane.normal.z >= 0.0 { aabb.max.z } else { aabb.min.z }
+			);
+
+			plane.distance_to_point(VectorLike::from(&corner)) >= 0.0
+		})
+	}
+}
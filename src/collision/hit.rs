@@ -1,9 +1,103 @@
 use napi_derive::napi;
 
-use crate::vec3f::Vector3f;
+use crate::vec3f::{Axis, Vector3f};
+
+/**
+ * The faces of a block or box, numbered like Bedrock's block face values.
+ */
+#[napi]
+#[derive(PartialEq, Debug)]
+pub enum BlockFace {
+	Down = 0, // -Y
+	Up = 1, // +Y
+	North = 2, // -Z
+	South = 3, // +Z
+	West = 4, // -X
+	East = 5 // +X
+}
+
+impl BlockFace {
+	// Returns the face on the positive or negative side of an axis.
+	pub(crate) fn from_axis(axis: Axis, positive: bool) -> BlockFace {
+		match (axis, positive) {
+			(Axis::X, false) => BlockFace::West,
+			(Axis::X, true) => BlockFace::East,
+			(Axis::Y, false) => BlockFace::Down,
+			(Axis::Y, true) => BlockFace::Up,
+			(Axis::Z, false) => BlockFace::North,
+			(Axis::Z, true) => BlockFace::South
+		}
+	}
+
+	// Returns the unit vector pointing out of the face.
+	pub(crate) fn normal(&self) -> Vector3f {
+		match self {
+			BlockFace::Down => Vector3f::new(0.0, -1.0, 0.0),
+			BlockFace::Up => Vector3f::new(0.0, 1.0, 0.0),
+			BlockFace::North => Vector3f::new(0.0, 0.0, -1.0),
+			BlockFace::South => Vector3f::new(0.0, 0.0, 1.0),
+			BlockFace::West => Vector3f::new(-1.0, 0.0, 0.0),
+			BlockFace::East => Vector3f::new(1.0, 0.0, 0.0)
+		}
+	}
+
+	// Returns the face a normal points out of, using its largest component.
+	pub(crate) fn from_normal(normal: &Vector3f) -> BlockFace {
+		let (x, y, z) = (normal.x.abs(), normal.y.abs(), normal.z.abs());
+
+		if x >= y && x >= z { BlockFace::from_axis(Axis::X, normal.x > 0.0) }
+		else if y >= z { BlockFace::from_axis(Axis::Y, normal.y > 0.0) }
+		else { BlockFace::from_axis(Axis::Z, normal.z > 0.0) }
+	}
+}
 
 #[napi(object)]
 pub struct HitResult {
 	pub distance: f64,
     pub position: Vector3f,
-}
\ No newline at end of file
+	pub face: BlockFace, // Face of the box that was hit
+	pub normal: Vector3f, // Unit normal of the surface at the hit, pointing towards the start of the ray
+}
+
+/**
+ * Represents a hit against the shape of a block.
+ */
+#[napi(object)]
+pub struct BlockHitResult {
+	pub block: Vector3f, // Position of the block that was hit
+	pub position: Vector3f, // Exact point where the shape was hit
+	pub distance: f64, // Distance from the origin of the ray to the hit
+	pub face: BlockFace, // Face of the shape that was hit
+}
+
+/**
+ * Represents a hit against one box of a list, keeping the index of the box.
+ */
+#[napi(object)]
+pub struct IndexedHitResult {
+	pub index: u32, // Index of the box that was hit
+	pub distance: f64,
+	pub position: Vector3f,
+	pub face: BlockFace, // Face of the box that was hit
+}
+
+/**
+ * Represents the first contact of a box moving towards another.
+ */
+#[napi(object)]
+pub struct SweepResult {
+	pub time: f64, // Fraction of the movement at which the boxes touch (0..1)
+	pub normal: Vector3f, // Normal of the face that was hit, zero when the boxes already overlap
+	pub displacement: Vector3f, // Movement of the box until the contact
+	pub face: Option<BlockFace>, // Face of the other box that was hit, undefined when the boxes already overlap
+}
+
+/**
+ * Represents the smallest movement that separates two overlapping boxes.
+ */
+#[napi(object)]
+pub struct Penetration {
+	pub axis: Axis, // Axis along which the boxes overlap the least
+	pub depth: f64, // Distance the boxes overlap along the axis
+	pub mtv: Vector3f, // Minimum translation vector that moves the first box out of the second
+}
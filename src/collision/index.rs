@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use napi_derive::napi;
+
+use crate::vec3f::VectorLike;
+
+use super::aabb::AABB;
+use super::hit::IndexedHitResult;
+use super::tree::DynamicAABBTree;
+
+/**
+ * A broad phase index of boxes keyed by caller chosen ids, such as entity runtime ids or packed
+ * block positions. Boxes are stored in a dynamic bounding volume tree, so box and ray queries
+ * only test the boxes near them.
+ */
+#[napi]
+pub struct SpatialIndex {
+	tree: DynamicAABBTree,
+	proxies: HashMap<u32, u32>, // Proxy in the tree of every id
+	ids: HashMap<u32, u32> // Id of every proxy in the tree
+}
+
+#[napi]
+impl SpatialIndex {
+	/**
+	 * Constructs an empty index.
+	 *
+	 * @param margin - The distance boxes are enlarged by in the tree, so small movements are cheap, defaults to 0.1.
+	 * Throws if the margin is not a finite number of at least 0.
+	 */
+	#[napi(constructor)]
+	pub fn new(margin: Option<f64>) -> napi::Result<SpatialIndex> {
+		Ok(SpatialIndex {
+			tree: DynamicAABBTree::new(margin)?,
+			proxies: HashMap::new(),
+			ids: HashMap::new()
+		})
+	}
+
+	/**
+	 * Inserts a box, replacing the box already stored with the same id.
+	 *
+	 * @param id - The id of the box.
+	 * @param aabb - The box (AABB).
+	 * @return Whether the id was new.
+	 * Throws if a corner of the box is NaN.
+	 */
+	#[napi]
+	pub fn insert(&mut self, id: u32, aabb: &AABB) -> napi::Result<bool> {
+		if self.update(id, aabb)? { return Ok(false) }
+
+		let proxy = self.tree.insert(aabb)?;
+		self.proxies.insert(id, proxy);
+		self.ids.insert(proxy, id);
+
+		return Ok(true);
+	}
+
+	/**
+	 * Removes a box.
+	 *
+	 * @param id - The id of the box.
+	 * @return Whether the id was in the index.
+	 */
+	#[napi]
+	pub fn remove(&mut self, id: u32) -> bool {
+		let Some(proxy) = self.proxies.remove(&id) else { return false };
+
+		self.ids.remove(&proxy);
+		self.tree.remove(proxy)
+	}
+
+	/**
+	 * Moves a box to new bounds.
+	 *
+	 * @param id - The id of the box.
+	 * @param aabb - The new box (AABB).
+	 * @return Whether the id was in the index.
+	 * Throws if a corner of the box is NaN.
+	 */
+	#[napi]
+	pub fn update(&mut self, id: u32, aabb: &AABB) -> napi::Result<bool> {
+		let Some(proxy) = self.proxies.get(&id) else { return Ok(false) };
+
+		self.tree.update(*proxy, aabb, None)?;
+		return Ok(true);
+	}
+
+	/**
+	 * Returns the box stored for an id; otherwise, undefined.
+	 *
+	 * @param id - The id of the box.
+	 */
+	#[napi]
+	pub fn get(&self, id: u32) -> Option<AABB> {
+		self.tree.get(*self.proxies.get(&id)?)
+	}
+
+	/**
+	 * Checks whether an id is in the index.
+	 *
+	 * @param id - The id of the box.
+	 */
+	#[napi]
+	pub fn has(&self, id: u32) -> bool {
+		self.proxies.contains_key(&id)
+	}
+
+	/**
+	 * Returns the ids of every box overlapping a box.
+	 *
+	 * @param aabb - The box to query (AABB).
+	 */
+	#[napi(js_name = "queryAABB")]
+	pub fn query_aabb(&self, aabb: &AABB) -> Vec<u32> {
+		self.tree.query(aabb).iter().filter_map(|proxy| self.ids.get(proxy).copied()).collect()
+	}
+
+	/**
+	 * Finds every box a line segment enters, nearest first.
+	 *
+	 * @param start - The start point of the ray (Vector3f).
+	 * @param end - The end point of the ray (Vector3f).
+	 * @return The hits, with the id of the box hit as the index.
+	 */
+	#[napi]
+	pub fn query_ray(&self, start: VectorLike, end: VectorLike) -> Vec<IndexedHitResult> {
+		let mut hits: Vec<IndexedHitResult> = Vec::new();
+
+		self.tree.visit_segment(&start, &end, |proxy| {
+			let (Some(id), Some(aabb)) = (self.ids.get(&(proxy as u32)), self.tree.get(proxy as u32)) else { return };
+
+			if let Some(hit) = AABB::intercept(&aabb, start.clone().into(), end.clone().into()) {
+				hits.push(IndexedHitResult { index: *id, distance: hit.distance, position: hit.position, face: hit.face });
+			}
+		});
+
+		hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+		return hits;
+	}
+
+	/**
+	 * Returns the number of boxes in the index.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.proxies.len() as u32
+	}
+
+	/**
+	 * Removes every box.
+	 */
+	#[napi]
+	pub fn clear(&mut self) {
+		self.tree = DynamicAABBTree::with_margin(self.tree.margin());
+		self.proxies.clear();
+		self.ids.clear();
+	}
+}
@@ -1,3 +1,19 @@
 pub mod aabb;
+pub mod beam;
+pub mod cache;
+pub mod door;
 pub mod ray;
-pub mod hit;
\ No newline at end of file
+pub mod eye;
+pub mod frustum;
+pub mod hit;
+pub mod index;
+pub mod order;
+pub mod picking;
+pub mod primitive;
+pub mod prune;
+pub mod resolver;
+pub mod selection;
+pub mod shape;
+pub mod sight;
+pub mod tree;
+pub mod visibility;
\ No newline at end of file
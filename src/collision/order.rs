@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use napi::bindgen_prelude::ClassInstance;
+use napi_derive::napi;
+
+use crate::vec3f::VectorLike;
+
+use super::aabb::AABB;
+
+/**
+ * Ordering of collision candidates along a movement, so resolvers visit the boxes in the order
+ * they would be hit and skip boxes the movement can never reach.
+ */
+#[napi]
+pub struct SweepOrder {}
+
+#[napi]
+impl SweepOrder {
+	/**
+	 * Sorts candidate boxes by the time a moving box first touches them. Boxes the movement does
+	 * not reach are dropped, boxes already overlapping come first, and exact duplicates, such as
+	 * the shared shapes of neighbouring blocks gathered twice, are kept only once.
+	 *
+	 * @param moving - The moving box (AABB).
+	 * @param movement - The movement of the box during the step (Vector3f).
+	 * @param candidates - The boxes that may be hit (Array<AABB>).
+	 * @return The indices of the reachable candidates, in the order they are hit; none for movements that are not finite.
+	 */
+	#[napi]
+	pub fn sort(moving: &AABB, movement: VectorLike, candidates: Vec<ClassInstance<AABB>>) -> Vec<u32> {
+		let mut seen = HashSet::new();
+		let mut hits: Vec<(u32, f64)> = Vec::new();
+
+		for (index, candidate) in candidates.iter().enumerate() {
+			let key = [candidate.min.x, candidate.min.y, candidate.min.z, candidate.max.x, candidate.max.y, candidate.max.z].map(f64::to_bits);
+			if !seen.insert(key) { continue }
+
+			if let Some(hit) = moving.sweep_time(&movement, candidate) { hits.push((index as u32, hit.time)) }
+		}
+
+		// The sort is stable, so boxes hit at the same time keep their input order.
+		hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+		hits.into_iter().map(|(index, _)| index).collect()
+	}
+
+	/**
+	 * Calculates the time a moving box first touches another, as used for the ordering.
+	 *
+	 * @param moving - The moving box (AABB).
+	 * @param movement - The movement of the box during the step (Vector3f).
+	 * @param candidate - The box that may be hit (AABB).
+	 * @return The fraction of the movement (0..1), 0 when already overlapping; otherwise, undefined, also for movements that are not finite.
+	 */
+	#[napi]
+	pub fn entry_time(moving: &AABB, movement: VectorLike, candidate: &AABB) -> Option<f64> {
+		moving.sweep_time(&movement, candidate).map(|hit| hit.time)
+	}
+}
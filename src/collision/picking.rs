@@ -0,0 +1,101 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::mat4::Matrix4;
+use crate::vec3f::{Axis, Vector3f, VectorLike};
+
+use super::hit::{BlockFace, IndexedHitResult};
+
+/**
+ * Ray picking against rotated and scaled entity hitboxes, such as display entities.
+ */
+#[napi]
+pub struct Picking {}
+
+#[napi]
+impl Picking {
+	/**
+	 * Finds the first entity hitbox hit by a line segment. Every hitbox is a box centered on
+	 * the local origin, placed in the world by its transform; the segment is moved into the
+	 * local space of each box instead of transforming the boxes.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @param transforms - The local to world matrix of every entity, 16 column-major elements each (Float64Array).
+	 * @param half_extents - The half size of every hitbox, packed as x, y, z triplets (Float64Array).
+	 * @return The closest hit with the index of the entity and the face of its hitbox in local space; otherwise, undefined.
+	 * Throws if the transform of an entity is singular, instead of silently missing it.
+	 */
+	#[napi]
+	pub fn raycast_entities(start: VectorLike, end: VectorLike, transforms: Float64Array, half_extents: Float64Array) -> napi::Result<Option<IndexedHitResult>> {
+		let count = half_extents.len() / 3;
+		if transforms.len() != count * 16 {
+			return Err(Error::new(
+				Status::InvalidArg,
+				format!("Expected {} transform elements for {} entities, got {}", count * 16, count, transforms.len())
+			));
+		}
+
+		let direction = end.subtract(&start);
+		let mut closest: Option<(usize, f64, BlockFace)> = None;
+
+		for index in 0..count {
+			let matrix = Matrix4 { elements: transforms[index * 16..index * 16 + 16].to_vec() };
+			let Some(inverse) = matrix.inverse() else {
+				return Err(Error::new(
+					Status::InvalidArg,
+					format!("The transform of entity {} is singular", index)
+				));
+			};
+
+			let extents = &half_extents[index * 3..index * 3 + 3];
+			let origin = inverse.transform_point(start.clone().into());
+			let local = inverse.transform_point(end.clone().into()).subtract(&origin);
+
+			if let Some((t, face)) = Picking::slab(&origin, &local, extents) {
+				if closest.as_ref().is_none_or(|(_, best, _)| t < *best) { closest = Some((index, t, face)) }
+			}
+		}
+
+		// The segment parameter is the same in local and world space for affine transforms.
+		Ok(closest.map(|(index, t, face)| IndexedHitResult {
+			index: index as u32,
+			distance: direction.length() * t,
+			position: start.add(&direction.multiply(t)),
+			face
+		}))
+	}
+
+	/**
+	 * Intersects the segment origin + direction * (0..1) with a box centered on the origin,
+	 * returning the parameter of the entry point and the face entered.
+	 */
+	fn slab(origin: &Vector3f, direction: &Vector3f, extents: &[f64]) -> Option<(f64, BlockFace)> {
+		let mut near = 0.0_f64;
+		let mut far = 1.0_f64;
+		let mut face = BlockFace::from_normal(&direction.multiply(-1.0));
+
+		let axes = [(Axis::X, origin.x, direction.x, extents[0]), (Axis::Y, origin.y, direction.y, extents[1]), (Axis::Z, origin.z, direction.z, extents[2])];
+
+		for (axis, o, d, h) in axes {
+			let h = h.abs();
+
+			if d.abs() < f64::EPSILON {
+				if o < -h || o > h { return None }
+				continue;
+			}
+
+			let (a, b) = ((-h - o) / d, (h - o) / d);
+			if a.min(b) > near {
+				near = a.min(b);
+				face = BlockFace::from_axis(axis, d < 0.0);
+			}
+			far = far.min(a.max(b));
+
+			if near > far { return None }
+		}
+
+		Some((near, face))
+	}
+}
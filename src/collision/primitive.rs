@@ -0,0 +1,282 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::aabb::AABB;
+use super::hit::{BlockFace, HitResult};
+
+const PARALLEL_EPSILON: f64 = 1e-12; // Below this a ray is treated as parallel to a surface
+
+/**
+ * Builds the hit at a fraction of a ray, with the normal turned towards the start of the ray.
+ */
+fn hit(start: &Vector3f, direction: &Vector3f, t: f64, normal: Vector3f) -> HitResult {
+	let normal = if normal.dot(direction) > 0.0 { normal.multiply(-1.0) } else { normal };
+
+	HitResult {
+		distance: direction.length() * t,
+		position: start.add(&direction.multiply(t)),
+		face: BlockFace::from_normal(&normal),
+		normal
+	}
+}
+
+/**
+ * Returns a normal scaled to unit length along with its length, or None when it has no direction.
+ */
+fn unit(normal: &Vector3f) -> Option<(Vector3f, f64)> {
+	// hypot keeps huge normals from overflowing to an infinite length
+	let length = normal.x.hypot(normal.y).hypot(normal.z);
+	if length == 0.0 || !length.is_finite() { return None }
+
+	Some((Vector3f::new(normal.x / length, normal.y / length, normal.z / length), length))
+}
+
+/**
+ * Represents a sphere, for approximating round hitboxes and area of effect shapes.
+ */
+#[napi]
+pub struct Sphere {
+	pub center: Vector3f, // Center of the sphere
+	pub radius: f64, // Radius of the sphere
+}
+
+#[napi]
+impl Sphere {
+	/**
+	 * Constructs a sphere.
+	 *
+	 * @param center - The center of the sphere (Vector3f).
+	 * @param radius - The radius of the sphere.
+	 */
+	#[napi(constructor)]
+	pub fn new(center: VectorLike, radius: f64) -> Sphere {
+		Sphere { center: center.clone(), radius: radius.abs() }
+	}
+
+	/**
+	 * Checks if a point is inside or on the sphere.
+	 *
+	 * @param point - The point to check (Vector3f).
+	 */
+	#[napi]
+	pub fn contains(&self, point: VectorLike) -> bool {
+		point.subtract(&self.center).square_length() <= self.radius * self.radius
+	}
+
+	/**
+	 * Checks if the sphere overlaps a box. A box with NaN corners overlaps nothing.
+	 *
+	 * @param aabb - The box to check (AABB).
+	 */
+	#[napi]
+	pub fn intersects_aabb(&self, aabb: &AABB) -> bool {
+		let corners = [aabb.min.x, aabb.min.y, aabb.min.z, aabb.max.x, aabb.max.y, aabb.max.z];
+		if corners.iter().any(|c| c.is_nan()) { return false }
+
+		// Clamped with max and min, which unlike clamp tolerate inverted and NaN bounds.
+		let closest = Vector3f::new(
+			self.center.x.max(aabb.min.x).min(aabb.max.x),
+			self.center.y.max(aabb.min.y).min(aabb.max.y),
+			self.center.z.max(aabb.min.z).min(aabb.max.z)
+		);
+
+		self.contains(VectorLike::from(&closest))
+	}
+
+	/**
+	 * Finds where a line segment first enters the sphere. A segment starting inside the sphere
+	 * hits at its start, with the normal facing back along the segment.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @return The hit, with the distance measured from the start; otherwise, undefined.
+	 */
+	#[napi]
+	pub fn raycast(&self, start: VectorLike, end: VectorLike) -> Option<HitResult> {
+		let direction = end.subtract(&start);
+		let offset = start.subtract(&self.center);
+
+		let a = direction.square_length();
+		let c = offset.square_length() - self.radius * self.radius;
+		if a < PARALLEL_EPSILON { return None }
+		if c <= 0.0 { return Some(hit(&start, &direction, 0.0, direction.normalize().multiply(-1.0))) }
+
+		let b = offset.dot(&direction);
+		let discriminant = b * b - a * c;
+		if discriminant < 0.0 { return None }
+
+		let t = (-b - discriminant.sqrt()) / a;
+		if !(0.0..=1.0).contains(&t) { return None }
+
+		let position = start.add(&direction.multiply(t));
+		Some(hit(&start, &direction, t, position.subtract(&self.center).normalize()))
+	}
+}
+
+/**
+ * Represents an infinite plane of the points p where normal · p + constant = 0.
+ * The normal is kept at unit length, so the signed distance of a point is normal · p + constant.
+ */
+#[napi]
+pub struct Plane {
+	pub normal: Vector3f, // Unit normal of the plane, pointing to its positive side
+	pub constant: f64, // Signed distance from the plane to the origin, along the normal
+}
+
+#[napi]
+impl Plane {
+	/**
+	 * Constructs a plane from a normal and a constant. The normal does not need to be normalized,
+	 * the constant is scaled along with it. A zero or non-finite normal faces up.
+	 *
+	 * @param normal - The normal of the plane (Vector3f).
+	 * @param constant - The constant of the plane equation.
+	 */
+	#[napi(constructor)]
+	pub fn new(normal: VectorLike, constant: f64) -> Plane {
+		match unit(&normal) {
+			Some((normal, length)) => Plane { normal, constant: constant / length },
+			None => Plane { normal: Vector3f::new(0.0, 1.0, 0.0), constant }
+		}
+	}
+
+	/**
+	 * Constructs the plane through a point with a normal. A zero or non-finite normal faces up.
+	 *
+	 * @param point - A point on the plane (Vector3f).
+	 * @param normal - The normal of the plane (Vector3f).
+	 */
+	#[napi]
+	pub fn from_point_normal(point: VectorLike, normal: VectorLike) -> Plane {
+		let normal = unit(&normal).map_or(Vector3f::new(0.0, 1.0, 0.0), |(normal, _)| normal);
+		Plane { constant: -normal.dot(&point), normal }
+	}
+
+	/**
+	 * Constructs the plane through three points, with the normal facing the side the points
+	 * wind counterclockwise from. Points on one line give a plane facing up.
+	 *
+	 * @param a - The first point (Vector3f).
+	 * @param b - The second point (Vector3f).
+	 * @param c - The third point (Vector3f).
+	 */
+	#[napi]
+	pub fn from_points(a: VectorLike, b: VectorLike, c: VectorLike) -> Plane {
+		Plane::from_point_normal(a.clone().into(), b.subtract(&a).cross(&c.subtract(&a)).into())
+	}
+
+	/**
+	 * Returns the signed distance from the plane to a point, positive on the side the normal points to.
+	 *
+	 * @param point - The point to measure (Vector3f).
+	 */
+	#[napi]
+	pub fn distance_to_point(&self, point: VectorLike) -> f64 {
+		self.normal.dot(&point) + self.constant
+	}
+
+	/**
+	 * Returns the closest point on the plane to a point.
+	 *
+	 * @param point - The point to project (Vector3f).
+	 */
+	#[napi]
+	pub fn project_point(&self, point: VectorLike) -> Vector3f {
+		point.subtract(&self.normal.multiply(self.distance_to_point(point.clone().into())))
+	}
+
+	/**
+	 * Finds where a line segment crosses the plane, from either side.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @return The hit, with the distance measured from the start; otherwise, undefined.
+	 */
+	#[napi]
+	pub fn raycast(&self, start: VectorLike, end: VectorLike) -> Option<HitResult> {
+		let direction = end.subtract(&start);
+		let denominator = self.normal.dot(&direction);
+		if denominator.abs() < PARALLEL_EPSILON { return None }
+
+		let t = -self.distance_to_point(start.clone().into()) / denominator;
+		if !(0.0..=1.0).contains(&t) { return None }
+
+		Some(hit(&start, &direction, t, self.normal.clone()))
+	}
+}
+
+/**
+ * Represents a triangle, for custom geometry such as entity models and slopes.
+ */
+#[napi]
+pub struct Triangle {
+	pub a: Vector3f, // First corner
+	pub b: Vector3f, // Second corner
+	pub c: Vector3f, // Third corner
+}
+
+#[napi]
+impl Triangle {
+	/**
+	 * Constructs a triangle from its corners.
+	 *
+	 * @param a - The first corner (Vector3f).
+	 * @param b - The second corner (Vector3f).
+	 * @param c - The third corner (Vector3f).
+	 */
+	#[napi(constructor)]
+	pub fn new(a: VectorLike, b: VectorLike, c: VectorLike) -> Triangle {
+		Triangle { a: a.clone(), b: b.clone(), c: c.clone() }
+	}
+
+	/**
+	 * Returns the unit normal of the triangle, facing the side the corners wind counterclockwise from.
+	 */
+	#[napi]
+	pub fn normal(&self) -> Vector3f {
+		self.b.subtract(&self.a).cross(&self.c.subtract(&self.a)).normalize()
+	}
+
+	/**
+	 * Returns the area of the triangle.
+	 */
+	#[napi]
+	pub fn area(&self) -> f64 {
+		self.b.subtract(&self.a).cross(&self.c.subtract(&self.a)).length() * 0.5
+	}
+
+	/**
+	 * Finds where a line segment crosses the triangle, from either side, using the
+	 * Möller–Trumbore algorithm.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @return The hit, with the distance measured from the start; otherwise, undefined.
+	 */
+	#[napi]
+	pub fn raycast(&self, start: VectorLike, end: VectorLike) -> Option<HitResult> {
+		let direction = end.subtract(&start);
+		let edge1 = self.b.subtract(&self.a);
+		let edge2 = self.c.subtract(&self.a);
+
+		let p = direction.cross(&edge2);
+		let determinant = edge1.dot(&p);
+		if determinant.abs() < PARALLEL_EPSILON { return None }
+
+		let inverse = 1.0 / determinant;
+		let offset = start.subtract(&self.a);
+
+		let u = offset.dot(&p) * inverse;
+		if !(0.0..=1.0).contains(&u) { return None }
+
+		let q = offset.cross(&edge1);
+		let v = direction.dot(&q) * inverse;
+		if v < 0.0 || u + v > 1.0 { return None }
+
+		let t = edge2.dot(&q) * inverse;
+		if !(0.0..=1.0).contains(&t) { return None }
+
+		Some(hit(&start, &direction, t, self.normal()))
+	}
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::Axis;
+
+use super::tree::{Bounds, OverlapPair};
+
+#[derive(Clone, Copy)]
+struct Endpoint {
+	value: f64,
+	index: usize,
+	is_min: bool
+}
+
+/**
+ * A sweep and prune broad phase over packed boxes. The endpoints of every box along one axis
+ * are kept sorted between ticks, so coherent motion only needs a few swaps to re-sort.
+ */
+#[napi]
+pub struct SweepAndPrune {
+	pub axis: Axis, // Axis the endpoints are sorted along
+	sorted_axis: Axis, // Axis the endpoints were last sorted along
+	endpoints: Vec<Endpoint>,
+	bounds: Vec<Bounds>
+}
+
+#[napi]
+impl SweepAndPrune {
+	/**
+	 * Constructs an empty broad phase.
+	 *
+	 * @param axis - The axis to sort along, ideally the one entities are most spread on, defaults to X (Axis).
+	 */
+	#[napi(constructor)]
+	pub fn new(axis: Option<Axis>) -> SweepAndPrune {
+		let axis = axis.unwrap_or(Axis::X);
+
+		SweepAndPrune {
+			axis,
+			sorted_axis: axis,
+			endpoints: Vec::new(),
+			bounds: Vec::new()
+		}
+	}
+
+	/**
+	 * Updates the boxes and returns every overlapping pair. Boxes keep their index between calls;
+	 * when the number of boxes or the axis changes the endpoints are rebuilt and fully sorted.
+	 *
+	 * @param boxes - The boxes, packed as minX, minY, minZ, maxX, maxY, maxZ (Float64Array).
+	 * @return The overlapping pairs of box indices, lower index first.
+	 */
+	#[napi]
+	pub fn update(&mut self, boxes: Float64Array) -> napi::Result<Vec<OverlapPair>> {
+		if !boxes.len().is_multiple_of(6) {
+			return Err(Error::new(Status::InvalidArg, format!("Packed boxes need 6 values each, got {} values", boxes.len())));
+		}
+
+		self.bounds = boxes.chunks_exact(6)
+			.map(|b| Bounds { min: [b[0], b[1], b[2]], max: [b[3], b[4], b[5]] })
+			.collect();
+
+		let axis = match self.axis { Axis::X => 0, Axis::Y => 1, Axis::Z => 2 };
+		let rebuild = self.endpoints.len() != self.bounds.len() * 2 || self.axis != self.sorted_axis;
+		if rebuild {
+			self.endpoints = (0..self.bounds.len())
+				.flat_map(|index| [Endpoint { value: 0.0, index, is_min: true }, Endpoint { value: 0.0, index, is_min: false }])
+				.collect();
+		}
+
+		for endpoint in self.endpoints.iter_mut() {
+			let bounds = &self.bounds[endpoint.index];
+			endpoint.value = if endpoint.is_min { bounds.min[axis] } else { bounds.max[axis] };
+		}
+
+		// A fresh set is in no particular order, only the endpoints kept from the last tick are nearly sorted.
+		if rebuild {
+			self.endpoints.sort_unstable_by(SweepAndPrune::order);
+			self.sorted_axis = self.axis;
+		} else {
+			SweepAndPrune::insertion_sort(&mut self.endpoints);
+		}
+
+		return Ok(self.sweep());
+	}
+
+	/**
+	 * Returns the number of boxes of the last update.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.bounds.len() as u32
+	}
+
+	/**
+	 * Orders endpoints along the axis. Maximums sort before minimums of the same value,
+	 * so touching boxes do not overlap.
+	 */
+	fn order(a: &Endpoint, b: &Endpoint) -> Ordering {
+		a.value.total_cmp(&b.value).then(a.is_min.cmp(&b.is_min))
+	}
+
+	/**
+	 * Sorts nearly sorted endpoints in close to linear time.
+	 */
+	fn insertion_sort(endpoints: &mut [Endpoint]) {
+		for i in 1..endpoints.len() {
+			let mut j = i;
+			while j > 0 && SweepAndPrune::order(&endpoints[j], &endpoints[j - 1]) == Ordering::Less {
+				endpoints.swap(j, j - 1);
+				j -= 1;
+			}
+		}
+	}
+
+	/**
+	 * Walks the sorted endpoints, testing every box against the boxes open along the axis.
+	 */
+	fn sweep(&self) -> Vec<OverlapPair> {
+		let mut active: Vec<usize> = Vec::new();
+		let mut pairs: Vec<OverlapPair> = Vec::new();
+
+		for endpoint in &self.endpoints {
+			if !endpoint.is_min {
+				if let Some(position) = active.iter().position(|index| *index == endpoint.index) { active.swap_remove(position); }
+				continue;
+			}
+
+			for other in &active {
+				if self.bounds[*other].overlaps(&self.bounds[endpoint.index]) {
+					let (a, b) = (endpoint.index.min(*other), endpoint.index.max(*other));
+					pairs.push(OverlapPair { a: a as u32, b: b as u32 });
+				}
+			}
+			active.push(endpoint.index);
+		}
+
+		pairs.sort_unstable_by(|x, y| x.a.cmp(&y.a).then(x.b.cmp(&y.b)));
+		return pairs;
+	}
+}
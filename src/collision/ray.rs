@@ -1,7 +1,20 @@
 use napi_derive::napi;
-use napi::{Error, JsFunction};
+use napi::bindgen_prelude::{ClassInstance, Float64Array};
+use napi::{Error, JsFunction, Status};
 
-use crate::vec3f::Vector3f;
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::aabb::AABB;
+use super::hit::IndexedHitResult;
+
+/**
+ * Represents a ray with an origin and a normalized direction.
+ */
+#[napi(object)]
+pub struct Ray {
+	pub origin: Vector3f,
+	pub direction: Vector3f,
+}
 
 #[napi]
 pub struct Raycaster {}
@@ -18,26 +31,121 @@ impl Raycaster {
 	 * @param condition - A function that takes BlockCoordinates and returns a boolean,
 	 *                    defining the condition to stop traversing when met.
 	 */
-	#[napi(ts_args_type = "start: Vector3f, end: Vector3f, condition: (position: Vector3f) => boolean")]
-	pub fn transverse_blocks(start: &Vector3f, end: &Vector3f, condition: JsFunction) {
-		if start.equals(end) { // No traversal needed if start and end are the same.
-			return;
+	#[napi(ts_args_type = "start: VectorLike, end: VectorLike, condition: (position: Vector3f) => boolean")]
+	pub fn transverse_blocks(start: VectorLike, end: VectorLike, condition: JsFunction) {
+		let _ = Raycaster::traverse(&start, &end, |position| Ok(Raycaster::check_callback(position.clone(), &condition)));
+	}
+
+	/**
+	 * Traverses blocks along a line segment entirely in native code, collecting every block
+	 * passed through instead of calling back into JavaScript for each one.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @param max_distance - The maximum distance to traverse from the start, defaults to the whole segment.
+	 * @return The positions of the blocks, in the order they are passed through.
+	 * Throws if the segment is not finite, max_distance is NaN, or the segment passes through more blocks than an array can hold.
+	 */
+	#[napi]
+	pub fn traverse_blocks_collect(start: VectorLike, end: VectorLike, max_distance: Option<f64>) -> napi::Result<Vec<Vector3f>> {
+		let (end, count) = Raycaster::segment(&start, &end, max_distance)?;
+		let mut blocks = Vec::new();
+		if blocks.try_reserve_exact(count).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} blocks", count)));
+		}
+
+		Raycaster::traverse(&start, &end, |position| {
+			blocks.push(position.clone());
+			Ok(false)
+		})?;
+
+		return Ok(blocks);
+	}
+
+	/**
+	 * Traverses blocks along a line segment like traverseBlocksCollect, returning the blocks packed
+	 * in a typed array, which avoids creating an object per block.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @param max_distance - The maximum distance to traverse from the start, defaults to the whole segment.
+	 * @return The positions of the blocks, packed as x, y, z triplets (Float64Array).
+	 * Throws if the segment is not finite, max_distance is NaN, or the segment passes through more blocks than an array can hold.
+	 */
+	#[napi]
+	pub fn traverse_blocks_packed(start: VectorLike, end: VectorLike, max_distance: Option<f64>) -> napi::Result<Float64Array> {
+		let (end, count) = Raycaster::segment(&start, &end, max_distance)?;
+		let mut blocks = Vec::new();
+		if blocks.try_reserve_exact(count * 3).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} blocks", count)));
+		}
+
+		Raycaster::traverse(&start, &end, |position| {
+			blocks.extend_from_slice(&[position.x, position.y, position.z]);
+			Ok(false)
+		})?;
+
+		Ok(Float64Array::new(blocks))
+	}
+
+	/**
+	 * Shortens a line segment to a maximum distance from its start, and counts the blocks it passes through.
+	 * Segments that are not finite would never finish traversing, so they are rejected up front.
+	 */
+	pub(crate) fn segment(start: &Vector3f, end: &Vector3f, max_distance: Option<f64>) -> napi::Result<(Vector3f, usize)> {
+		if ![start.x, start.y, start.z, end.x, end.y, end.z].iter().all(|c| c.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, format!("Segment must be finite, got ({}, {}, {}) to ({}, {}, {})", start.x, start.y, start.z, end.x, end.y, end.z)));
+		}
+		if max_distance.is_some_and(f64::is_nan) {
+			return Err(Error::new(Status::InvalidArg, "Max distance must not be NaN".to_string()));
+		}
+
+		let direction = end.subtract(start);
+		let length = direction.x.hypot(direction.y).hypot(direction.z);
+		let end = match max_distance {
+			Some(distance) if distance < length => start.add(&direction.multiply(distance.max(0.0) / length)),
+			_ => end.clone()
+		};
+
+		// Every block after the first is entered by crossing one boundary along a single axis.
+		let (from, to) = (start.floor(), end.floor());
+		let count = (to.x - from.x).abs() + (to.y - from.y).abs() + (to.z - from.z).abs() + 1.0;
+		if count > (u32::MAX / 3) as f64 {
+			return Err(Error::new(Status::InvalidArg, format!("The segment passes through more than {} blocks", u32::MAX / 3)));
+		}
+
+		return Ok((end, count as usize));
+	}
+
+	/**
+	 * Traverses the blocks along a line segment, calling visit for each block in order
+	 * until it returns true or the end of the segment is reached.
+	 *
+	 * @return Whether the traversal was stopped by visit.
+	 */
+	pub(crate) fn traverse<F: FnMut(&Vector3f) -> napi::Result<bool>>(start: &Vector3f, end: &Vector3f, mut visit: F) -> napi::Result<bool> {
+		if start.equals(end.into()) { // No traversal needed if start and end are the same.
+			return Ok(false);
 		}
 		let direction: Vector3f = end.subtract(start);
 		let mut current_position: Vector3f = start.floor();
 
 		// Check if the initial block position meets the condition.
-		if Raycaster::check_callback(current_position.clone(), &condition) { return };
+		if visit(&current_position)? { return Ok(true) };
 
 		// Determine the step sizes for each axis.
-		let step: Vector3f = Raycaster::sign(&direction);
-		let step_size: Vector3f = Raycaster::step_size(&step, &direction);
+		let step: Vector3f = Raycaster::sign(VectorLike::from(&direction));
+		let step_size: Vector3f = Raycaster::step_size(VectorLike::from(&step), VectorLike::from(&direction));
 
-		// Calculate the initial tMax values for each axis.
+		// Calculate the initial tMax values for each axis, from the position of the start within its block.
+		let initial = |step: f64, step_size: f64, start: f64| -> f64 {
+			if step == 0.0 { return f64::INFINITY }
+			step_size * if step > 0.0 { 1.0 + Raycaster::boundary(start) } else { -Raycaster::boundary(start) }
+		};
 		let mut tmax: Vector3f = Vector3f::new(
-			step_size.x * if step.x > 0.0 { 1.0 - Raycaster::boundary(direction.x) } else { Raycaster::boundary(direction.x) },
-			step_size.y * if step.y > 0.0 { 1.0 - Raycaster::boundary(direction.y) } else { Raycaster::boundary(direction.y) },
-			step_size.z * if step.z > 0.0 { 1.0 - Raycaster::boundary(direction.z) } else { Raycaster::boundary(direction.z) }
+			initial(step.x, step_size.x, start.x),
+			initial(step.y, step_size.y, start.y),
+			initial(step.z, step_size.z, start.z)
 		);
 
 		while tmax.x <= 1.0 || tmax.y <= 1.0 || tmax.z <= 1.0 {
@@ -54,8 +162,37 @@ impl Raycaster {
 			}
 
 			// Check if the current block position meets the condition.
-			if Raycaster::check_callback(current_position.clone(), &condition) { return };
+			if visit(&current_position)? { return Ok(true) };
 		}
+
+		return Ok(false);
+	}
+
+	/**
+	 * Intersects a line segment with every box it passes through, for piercing projectiles and beams.
+	 *
+	 * @param start - The starting point of the line segment (Vector3f).
+	 * @param end - The ending point of the line segment (Vector3f).
+	 * @param boxes - The boxes to test against (Array<AABB>).
+	 * @param limit - The maximum number of hits to return, defaults to all of them.
+	 * @return The hits sorted by distance from the start, with the index of the box that was hit.
+	 */
+	#[napi]
+	pub fn pierce(start: VectorLike, end: VectorLike, boxes: Vec<ClassInstance<AABB>>, limit: Option<u32>) -> Vec<IndexedHitResult> {
+		let mut hits: Vec<IndexedHitResult> = boxes.iter()
+			.enumerate()
+			.filter_map(|(index, aabb)| AABB::intercept(aabb, start.clone().into(), end.clone().into()).map(|hit| IndexedHitResult {
+				index: index as u32,
+				distance: hit.distance,
+				position: hit.position,
+				face: hit.face
+			}))
+			.collect();
+
+		hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+		if let Some(limit) = limit { hits.truncate(limit as usize) }
+
+		return hits;
 	}
 
 	/**
@@ -65,7 +202,7 @@ impl Raycaster {
 	 * @return A new Vector3f with the sign of each component.
 	 */
 	#[napi]
-	pub fn sign(vector: &Vector3f) -> Vector3f {
+	pub fn sign(vector: VectorLike) -> Vector3f {
 		Vector3f::new(
             vector.x.signum(),
             vector.y.signum(),
@@ -75,7 +212,7 @@ impl Raycaster {
 
 	
 	#[napi]
-	pub fn step_size(step: &Vector3f, direction: &Vector3f) -> Vector3f {
+	pub fn step_size(step: VectorLike, direction: VectorLike) -> Vector3f {
 		Vector3f::new(
             if step.x == 0.0 { f64::INFINITY } else { step.x / direction.x },
             if step.y == 0.0 { f64::INFINITY } else { step.y / direction.y },
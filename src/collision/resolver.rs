@@ -0,0 +1,134 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi_derive::napi;
+
+use crate::config::MathConfig;
+use crate::vec3f::{Axis, Vector3f, VectorLike};
+
+use super::aabb::AABB;
+
+/**
+ * Represents a movement after it was clipped against colliders.
+ */
+#[napi(object)]
+pub struct MovementResult {
+	pub movement: Vector3f, // Movement that can be performed without entering a collider
+	pub collided_x: bool,
+	pub collided_y: bool,
+	pub collided_z: bool,
+	pub on_ground: bool, // Whether the movement was stopped while moving down
+}
+
+/**
+ * Resolves entity movement against block and entity colliders the way vanilla entity physics does.
+ */
+#[napi]
+pub struct CollisionResolver {}
+
+#[napi]
+impl CollisionResolver {
+	/**
+	 * Clips a movement against colliders one axis at a time: Y first, then the larger of X and Z,
+	 * then the other. Each axis is moved as far as possible before the next is resolved, which
+	 * lets entities slide along walls and land on floors.
+	 *
+	 * @param entity_box - The hitbox of the entity before moving (AABB).
+	 * @param desired - The movement the entity attempts (Vector3f).
+	 * @param colliders - The boxes the entity collides with, typically gathered around the expanded hitbox (Array<AABB>).
+	 * @return The clipped movement and the axes that collided. A NaN movement along an axis is stopped, as if it collided.
+	 */
+	#[napi]
+	pub fn resolve_movement(entity_box: &AABB, desired: VectorLike, colliders: Vec<ClassInstance<AABB>>) -> MovementResult {
+		let colliders: Vec<&AABB> = colliders.iter().map(|collider| &**collider).collect();
+		CollisionResolver::resolve(entity_box, &desired, &colliders)
+	}
+
+	/**
+	 * Clips the movement of a box along a single axis against colliders.
+	 *
+	 * @param entity_box - The box before moving (AABB).
+	 * @param axis - The axis of the movement (Axis).
+	 * @param distance - The signed distance to move along the axis.
+	 * @param colliders - The boxes to collide with (Array<AABB>).
+	 * @return The signed distance that can be moved.
+	 */
+	#[napi]
+	pub fn clip_axis(entity_box: &AABB, axis: Axis, distance: f64, colliders: Vec<ClassInstance<AABB>>) -> f64 {
+		let colliders: Vec<&AABB> = colliders.iter().map(|collider| &**collider).collect();
+		CollisionResolver::clip(entity_box, axis, distance, &colliders)
+	}
+
+	/**
+	 * Resolves a movement against colliders and reports the axes that collided.
+	 */
+	pub(crate) fn resolve(entity_box: &AABB, desired: &Vector3f, colliders: &[&AABB]) -> MovementResult {
+		let movement = CollisionResolver::collide(entity_box, desired, colliders);
+
+		MovementResult {
+			collided_x: movement.x != desired.x,
+			collided_y: movement.y != desired.y,
+			collided_z: movement.z != desired.z,
+			on_ground: movement.y != desired.y && desired.y < 0.0,
+			movement
+		}
+	}
+
+	/**
+	 * Resolves a movement against colliders axis by axis, in vanilla order.
+	 */
+	pub(crate) fn collide(entity_box: &AABB, desired: &Vector3f, colliders: &[&AABB]) -> Vector3f {
+		let mut moved = AABB::new(VectorLike::from(&entity_box.min), VectorLike::from(&entity_box.max));
+		let mut result = Vector3f::new(0.0, 0.0, 0.0);
+
+		let z_first = desired.x.abs() < desired.z.abs();
+		let order = if z_first { [Axis::Y, Axis::Z, Axis::X] } else { [Axis::Y, Axis::X, Axis::Z] };
+
+		for axis in order {
+			let distance = desired.axis(axis);
+			if distance == 0.0 || distance.is_nan() { continue }
+
+			let clipped = CollisionResolver::clip(&moved, axis, distance, colliders);
+			let offset = Vector3f::new(
+				if axis == Axis::X { clipped } else { 0.0 },
+				if axis == Axis::Y { clipped } else { 0.0 },
+				if axis == Axis::Z { clipped } else { 0.0 }
+			);
+
+			moved = moved.translate(VectorLike::from(&offset));
+			result = result.add(&offset);
+		}
+
+		return result;
+	}
+
+	/**
+	 * Shortens a movement along an axis so the box stops at the first collider in the way.
+	 * Only colliders overlapping the box on the other two axes can block it.
+	 */
+	pub(crate) fn clip(entity_box: &AABB, axis: Axis, distance: f64, colliders: &[&AABB]) -> f64 {
+		let epsilon = MathConfig::current_epsilon(); // Defaults to the tolerance vanilla clips movement with
+		let mut distance = distance;
+		let others = match axis {
+			Axis::X => [Axis::Y, Axis::Z],
+			Axis::Y => [Axis::X, Axis::Z],
+			Axis::Z => [Axis::X, Axis::Y]
+		};
+
+		for collider in colliders {
+			if distance.abs() < epsilon { return 0.0 }
+
+			let overlaps = others.iter().all(|other| {
+				collider.max.axis(*other) - epsilon > entity_box.min.axis(*other) &&
+				collider.min.axis(*other) + epsilon < entity_box.max.axis(*other)
+			});
+			if !overlaps { continue }
+
+			if distance > 0.0 && collider.min.axis(axis) >= entity_box.max.axis(axis) - epsilon {
+				distance = distance.min(collider.min.axis(axis) - entity_box.max.axis(axis));
+			} else if distance < 0.0 && collider.max.axis(axis) <= entity_box.min.axis(axis) + epsilon {
+				distance = distance.max(collider.max.axis(axis) - entity_box.min.axis(axis));
+			}
+		}
+
+		return distance;
+	}
+}
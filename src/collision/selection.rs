@@ -0,0 +1,68 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::rotation::Rotation;
+use crate::vec2f::Vector2f;
+use crate::vec3f::VectorLike;
+
+use super::aabb::AABB;
+use super::hit::BlockHitResult;
+use super::ray::Raycaster;
+
+/**
+ * Block targeting that reproduces the client's picking rules, so the server agrees
+ * with the outline players see.
+ */
+#[napi]
+pub struct Selection {}
+
+#[napi]
+impl Selection {
+	/**
+	 * Picks the block an entity is looking at. Blocks are visited in the order the eye ray
+	 * passes through them, and the first block whose outline shape is hit is selected.
+	 *
+	 * The lookup returns the outline shape of a block as boxes relative to the block, or an empty
+	 * array for blocks that cannot be targeted. It is told whether liquids are selectable, and should
+	 * then return the shape of the fluid (its height) for fluid blocks, like the client does when
+	 * holding a bucket.
+	 *
+	 * @param origin - The eye position of the entity (Vector3f).
+	 * @param rotation - The rotation of the entity in degrees, x being the pitch and y the yaw (Vector2f).
+	 * @param reach - The maximum distance to pick at.
+	 * @param liquids - Whether liquid blocks can be selected.
+	 * @param block_shape_lookup - Returns the outline boxes of the block at a position.
+	 * @return The block hit and where its outline was hit; otherwise, undefined.
+	 * Throws if the reach is negative or not finite.
+	 */
+	#[napi(ts_args_type = "origin: VectorLike, rotation: Vector2f, reach: number, liquids: boolean, blockShapeLookup: (position: Vector3f, liquids: boolean) => Array<AABB>")]
+	pub fn pick_block(origin: VectorLike, rotation: &Vector2f, reach: f64, liquids: bool, block_shape_lookup: JsFunction) -> napi::Result<Option<BlockHitResult>> {
+		if !reach.is_finite() || reach < 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Reach must be a finite, non-negative distance, got {}", reach)));
+		}
+
+		let direction = Rotation::direction(rotation.y, rotation.x);
+		let end = origin.add(&direction.multiply(reach));
+
+		let mut result: Option<BlockHitResult> = None;
+
+		Raycaster::traverse(&origin, &end, |block| {
+			let shape: Vec<ClassInstance<AABB>> = block_shape_lookup.call2(block.clone(), liquids)?;
+
+			for aabb in shape.iter() {
+				let hit = match AABB::intercept(&aabb.translate(block.into()), origin.clone().into(), end.clone().into()) {
+					Some(hit) => hit,
+					None => continue
+				};
+
+				if result.as_ref().is_some_and(|nearest| nearest.distance <= hit.distance) { continue }
+				result = Some(BlockHitResult { block: block.clone(), position: hit.position, distance: hit.distance, face: hit.face });
+			}
+
+			Ok(result.is_some())
+		})?;
+
+		return Ok(result);
+	}
+}
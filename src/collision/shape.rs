@@ -0,0 +1,192 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::aabb::AABB;
+use super::hit::HitResult;
+use super::resolver::{CollisionResolver, MovementResult};
+
+/**
+ * Represents the shape of a block as a union of boxes, such as the two boxes of a stair
+ * or the post and arms of a fence. Boxes are in block space, a full block spanning 0..1.
+ */
+#[napi]
+pub struct VoxelShape {
+	boxes: Vec<AABB>
+}
+
+#[napi]
+impl VoxelShape {
+	/**
+	 * Constructs a shape from boxes.
+	 *
+	 * @param boxes - The boxes of the shape, defaults to an empty shape (Array<AABB>).
+	 */
+	#[napi(constructor)]
+	pub fn new(boxes: Option<Vec<ClassInstance<AABB>>>) -> VoxelShape {
+		VoxelShape {
+			boxes: boxes.unwrap_or_default().iter().map(|aabb| AABB::new(VectorLike::from(&aabb.min), VectorLike::from(&aabb.max))).collect()
+		}
+	}
+
+	/**
+	 * Returns a shape without any boxes, such as the shape of air.
+	 */
+	#[napi]
+	pub fn empty() -> VoxelShape {
+		VoxelShape { boxes: Vec::new() }
+	}
+
+	/**
+	 * Returns the shape of a full block.
+	 */
+	#[napi]
+	pub fn block() -> VoxelShape {
+		VoxelShape::cuboid(Vector3f::new(0.0, 0.0, 0.0).into(), Vector3f::new(1.0, 1.0, 1.0).into())
+	}
+
+	/**
+	 * Returns a shape made of a single box.
+	 *
+	 * @param min - The minimum corner of the box (Vector3f).
+	 * @param max - The maximum corner of the box (Vector3f).
+	 */
+	#[napi]
+	pub fn cuboid(min: VectorLike, max: VectorLike) -> VoxelShape {
+		VoxelShape { boxes: vec![AABB::new(min, max)] }
+	}
+
+	/**
+	 * Returns copies of the boxes of the shape.
+	 */
+	#[napi]
+	pub fn boxes(&self) -> Vec<AABB> {
+		self.boxes.iter().map(|aabb| AABB::new(VectorLike::from(&aabb.min), VectorLike::from(&aabb.max))).collect()
+	}
+
+	#[napi]
+	pub fn is_empty(&self) -> bool {
+		self.boxes.is_empty()
+	}
+
+	/**
+	 * Returns the box enclosing the whole shape; otherwise, undefined for an empty shape.
+	 */
+	#[napi]
+	pub fn bounds(&self) -> Option<AABB> {
+		let (first, rest) = self.boxes.split_first()?;
+		Some(rest.iter().fold(AABB::new(VectorLike::from(&first.min), VectorLike::from(&first.max)), |bounds, aabb| bounds.union(aabb)))
+	}
+
+	/**
+	 * Moves every box of the shape, for example to place a block shape in the world.
+	 *
+	 * @param v - The offset, usually the position of the block (Vector3f).
+	 */
+	#[napi]
+	pub fn offset(&self, v: VectorLike) -> VoxelShape {
+		VoxelShape { boxes: self.boxes.iter().map(|aabb| aabb.translate(v.clone().into())).collect() }
+	}
+
+	/**
+	 * Combines the boxes of two shapes. Boxes fully inside another box are dropped.
+	 *
+	 * @param other - The shape to combine with (VoxelShape).
+	 */
+	#[napi]
+	pub fn union(&self, other: &VoxelShape) -> VoxelShape {
+		let all: Vec<&AABB> = self.boxes.iter().chain(other.boxes.iter()).collect();
+
+		let boxes = all.iter()
+			.enumerate()
+			.filter(|(i, aabb)| !all.iter().enumerate().any(|(j, other)| {
+				// Of two identical boxes, only the first is kept.
+				*i != j && VoxelShape::encloses(other, aabb) && (j < *i || !VoxelShape::encloses(aabb, other))
+			}))
+			.map(|(_, aabb)| AABB::new(VectorLike::from(&aabb.min), VectorLike::from(&aabb.max)))
+			.collect();
+
+		VoxelShape { boxes }
+	}
+
+	/**
+	 * Returns the space covered by both shapes, as the overlaps of their boxes.
+	 * Boxes with NaN corners cover no space, so they overlap nothing.
+	 *
+	 * @param other - The shape to intersect with (VoxelShape).
+	 */
+	#[napi]
+	pub fn intersect(&self, other: &VoxelShape) -> VoxelShape {
+		let mut boxes = Vec::new();
+
+		// f64::max and f64::min ignore NaN, which would turn a NaN box into the box it is compared with.
+		let valid = |aabb: &&AABB| ![aabb.min.x, aabb.min.y, aabb.min.z, aabb.max.x, aabb.max.y, aabb.max.z].iter().any(|c| c.is_nan());
+
+		for a in self.boxes.iter().filter(valid) {
+			for b in other.boxes.iter().filter(valid) {
+				let min = Vector3f::new(a.min.x.max(b.min.x), a.min.y.max(b.min.y), a.min.z.max(b.min.z));
+				let max = Vector3f::new(a.max.x.min(b.max.x), a.max.y.min(b.max.y), a.max.z.min(b.max.z));
+
+				if min.x < max.x && min.y < max.y && min.z < max.z { boxes.push(AABB::new(VectorLike::from(&min), VectorLike::from(&max))) }
+			}
+		}
+
+		VoxelShape { boxes }
+	}
+
+	/**
+	 * Checks whether a point is inside any box of the shape.
+	 *
+	 * @param v - The point (Vector3f).
+	 */
+	#[napi]
+	pub fn contains(&self, v: VectorLike) -> bool {
+		self.boxes.iter().any(|aabb| aabb.contains(v.clone().into()))
+	}
+
+	/**
+	 * Checks whether a box overlaps any box of the shape.
+	 *
+	 * @param aabb - The box to test (AABB).
+	 */
+	#[napi]
+	pub fn intersects(&self, aabb: &AABB) -> bool {
+		self.boxes.iter().any(|other| other.intersects(aabb))
+	}
+
+	/**
+	 * Finds where a line segment first enters the shape.
+	 *
+	 * @param start - The start point of the ray (Vector3f).
+	 * @param end - The end point of the ray (Vector3f).
+	 * @return The nearest hit and the face of the box that was entered; otherwise, undefined.
+	 */
+	#[napi]
+	pub fn raycast(&self, start: VectorLike, end: VectorLike) -> Option<HitResult> {
+		self.boxes.iter()
+			.filter_map(|aabb| AABB::intercept(aabb, start.clone().into(), end.clone().into()))
+			.min_by(|a, b| a.distance.total_cmp(&b.distance))
+	}
+
+	/**
+	 * Clips the movement of a box against the shape, axis by axis like entity movement.
+	 *
+	 * @param entity_box - The box before moving (AABB).
+	 * @param movement - The movement the box attempts (Vector3f).
+	 * @return The clipped movement and the axes that collided.
+	 */
+	#[napi]
+	pub fn collide(&self, entity_box: &AABB, movement: VectorLike) -> MovementResult {
+		let colliders: Vec<&AABB> = self.boxes.iter().collect();
+		CollisionResolver::resolve(entity_box, &movement, &colliders)
+	}
+
+	/**
+	 * Checks whether the outer box fully encloses the inner box.
+	 */
+	fn encloses(outer: &AABB, inner: &AABB) -> bool {
+		outer.min.x <= inner.min.x && outer.min.y <= inner.min.y && outer.min.z <= inner.min.z
+			&& outer.max.x >= inner.max.x && outer.max.y >= inner.max.y && outer.max.z >= inner.max.z
+	}
+}
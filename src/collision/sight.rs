@@ -0,0 +1,113 @@
+use napi::JsFunction;
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::ray::Raycaster;
+use super::tree::Bounds;
+
+/**
+ * Tracks the line of sight between one observer and one target over time. The block that blocked
+ * the last check is tested first, and while it still blocks the line no traversal is needed,
+ * which makes repeated checks in static scenes cost a single lookup.
+ */
+#[napi]
+pub struct LineOfSight {
+	blocker: Option<Vector3f>, // Block that blocked the last check
+	traversals: u32, // Number of checks that needed a full traversal
+	revalidations: u32 // Number of checks answered by the remembered blocker
+}
+
+#[napi]
+impl LineOfSight {
+	/**
+	 * Constructs a tracker without a remembered blocker.
+	 */
+	#[napi(constructor)]
+	pub fn new() -> LineOfSight {
+		LineOfSight { blocker: None, traversals: 0, revalidations: 0 }
+	}
+
+	/**
+	 * Checks whether the target is visible from the observer. When the remembered blocker is still
+	 * solid and still on the line, the line is blocked without traversing it again.
+	 *
+	 * @param start - The eye position of the observer (Vector3f).
+	 * @param end - The position of the target (Vector3f).
+	 * @param is_solid - Returns whether the block at a position blocks the line of sight.
+	 * @return Whether the line of sight is clear.
+	 * Throws if the line is not finite or passes through more blocks than an array can hold.
+	 */
+	#[napi(ts_args_type = "start: VectorLike, end: VectorLike, isSolid: (position: Vector3f) => boolean")]
+	pub fn check(&mut self, start: VectorLike, end: VectorLike, is_solid: JsFunction) -> napi::Result<bool> {
+		// The slab test treats NaN lines as crossing every block, and infinite lines never finish traversing.
+		Raycaster::segment(&start, &end, None)?;
+
+		if let Some(blocker) = self.blocker.as_ref() {
+			if LineOfSight::crosses(blocker, &start, &end) && is_solid.call1::<Vector3f, bool>(blocker.clone())? {
+				self.revalidations = self.revalidations.saturating_add(1);
+				return Ok(false);
+			}
+		}
+
+		self.traversals = self.traversals.saturating_add(1);
+		self.blocker = None;
+
+		let mut blocker: Option<Vector3f> = None;
+		Raycaster::traverse(&start, &end, |position| {
+			if !is_solid.call1::<Vector3f, bool>(position.clone())? { return Ok(false) }
+
+			blocker = Some(position.clone());
+			Ok(true)
+		})?;
+
+		self.blocker = blocker;
+		Ok(self.blocker.is_none())
+	}
+
+	/**
+	 * Returns the block that blocked the last check; otherwise, undefined when it was clear.
+	 */
+	#[napi]
+	pub fn blocker(&self) -> Option<Vector3f> {
+		self.blocker.clone()
+	}
+
+	/**
+	 * Forgets the remembered blocker, for example after the observer or target teleported.
+	 */
+	#[napi]
+	pub fn reset(&mut self) {
+		self.blocker = None;
+	}
+
+	/**
+	 * Returns the number of checks that needed a full traversal.
+	 */
+	#[napi]
+	pub fn traversals(&self) -> u32 {
+		self.traversals
+	}
+
+	/**
+	 * Returns the number of checks answered by the remembered blocker.
+	 */
+	#[napi]
+	pub fn revalidations(&self) -> u32 {
+		self.revalidations
+	}
+
+	/**
+	 * Checks whether the line passes through the block.
+	 */
+	fn crosses(block: &Vector3f, start: &Vector3f, end: &Vector3f) -> bool {
+		let bounds = Bounds { min: [block.x, block.y, block.z], max: [block.x + 1.0, block.y + 1.0, block.z + 1.0] };
+		bounds.crosses([start.x, start.y, start.z], [end.x - start.x, end.y - start.y, end.z - start.z])
+	}
+}
+
+impl Default for LineOfSight {
+	fn default() -> Self {
+		LineOfSight::new()
+	}
+}
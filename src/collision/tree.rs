@@ -0,0 +1,516 @@
+use std::collections::HashSet;
+
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::aabb::AABB;
+
+/**
+ * Represents two overlapping proxies, with the lower id first.
+ */
+#[napi(object)]
+pub struct OverlapPair {
+	pub a: u32,
+	pub b: u32,
+}
+
+/**
+ * Box bounds stored without the JS class, so the tree can copy them freely.
+ */
+#[derive(Clone, Copy)]
+pub(crate) struct Bounds {
+	pub min: [f64; 3],
+	pub max: [f64; 3]
+}
+
+impl Bounds {
+	pub(crate) fn from_aabb(aabb: &AABB) -> Bounds {
+		Bounds { min: [aabb.min.x, aabb.min.y, aabb.min.z], max: [aabb.max.x, aabb.max.y, aabb.max.z] }
+	}
+
+	// Converts a box to store in the tree. NaN corners would break the comparisons that keep it searchable.
+	pub(crate) fn from_valid_aabb(aabb: &AABB) -> napi::Result<Bounds> {
+		let bounds = Bounds::from_aabb(aabb);
+		if bounds.min.iter().chain(bounds.max.iter()).any(|value| value.is_nan()) {
+			return Err(Error::new(Status::InvalidArg, format!("Box corners must not be NaN, got ({}, {}, {}) to ({}, {}, {})", bounds.min[0], bounds.min[1], bounds.min[2], bounds.max[0], bounds.max[1], bounds.max[2])));
+		}
+
+		return Ok(bounds);
+	}
+
+	pub(crate) fn to_aabb(self) -> AABB {
+		AABB::new(Vector3f::new(self.min[0], self.min[1], self.min[2]).into(), Vector3f::new(self.max[0], self.max[1], self.max[2]).into())
+	}
+
+	pub(crate) fn union(&self, other: &Bounds) -> Bounds {
+		Bounds {
+			min: [0, 1, 2].map(|axis| self.min[axis].min(other.min[axis])),
+			max: [0, 1, 2].map(|axis| self.max[axis].max(other.max[axis]))
+		}
+	}
+
+	pub(crate) fn overlaps(&self, other: &Bounds) -> bool {
+		(0..3).all(|axis| self.min[axis] < other.max[axis] && other.min[axis] < self.max[axis])
+	}
+
+	pub(crate) fn contains(&self, other: &Bounds) -> bool {
+		(0..3).all(|axis| self.min[axis] <= other.min[axis] && other.max[axis] <= self.max[axis])
+	}
+
+	// Whether the segment from start along delta (t in 0..1) passes through the bounds, using the slab test.
+	pub(crate) fn crosses(&self, start: [f64; 3], delta: [f64; 3]) -> bool {
+		let (mut near, mut far) = (0.0_f64, 1.0_f64);
+
+		for axis in 0..3 {
+			if delta[axis] == 0.0 {
+				if start[axis] < self.min[axis] || start[axis] > self.max[axis] { return false }
+				continue;
+			}
+
+			let (a, b) = ((self.min[axis] - start[axis]) / delta[axis], (self.max[axis] - start[axis]) / delta[axis]);
+			near = near.max(a.min(b));
+			far = far.min(a.max(b));
+			if near > far { return false }
+		}
+
+		return true;
+	}
+
+	// Half the surface area, the insertion cost heuristic.
+	fn area(&self) -> f64 {
+		let [x, y, z] = [0, 1, 2].map(|axis| self.max[axis] - self.min[axis]);
+		x * y + y * z + z * x
+	}
+}
+
+struct Node {
+	fat: Bounds, // Bounds of the subtree, or the enlarged bounds of a leaf
+	tight: Bounds, // Exact bounds of a leaf
+	parent: Option<usize>,
+	children: Option<(usize, usize)>, // None for leaves
+	height: i32, // 0 for leaves, -1 for free nodes
+}
+
+/**
+ * A dynamic bounding volume tree for broad phase collision between moving entities.
+ * Leaves store enlarged ("fat") boxes, so small movements do not restructure the tree,
+ * and the tree is kept balanced with rotations. Proxy ids stay valid until removed.
+ */
+#[napi]
+pub struct DynamicAABBTree {
+	margin: f64, // Distance leaves are enlarged by on every side
+	nodes: Vec<Node>,
+	free: Vec<usize>,
+	root: Option<usize>,
+	moved: HashSet<usize>
+}
+
+#[napi]
+impl DynamicAABBTree {
+	/**
+	 * Constructs an empty tree.
+	 *
+	 * @param margin - The distance leaves are enlarged by on every side, defaults to 0.1.
+	 * Throws if the margin is not a finite number of at least 0.
+	 */
+	#[napi(constructor)]
+	pub fn new(margin: Option<f64>) -> napi::Result<DynamicAABBTree> {
+		let mut tree = DynamicAABBTree::with_margin(0.1);
+		if let Some(margin) = margin { tree.set_margin(margin)?; }
+
+		Ok(tree)
+	}
+
+	/**
+	 * Constructs an empty tree with a margin that is already known to be valid.
+	 */
+	pub(crate) fn with_margin(margin: f64) -> DynamicAABBTree {
+		DynamicAABBTree {
+			margin,
+			nodes: Vec::new(),
+			free: Vec::new(),
+			root: None,
+			moved: HashSet::new()
+		}
+	}
+
+	#[napi(getter)]
+	pub fn margin(&self) -> f64 {
+		self.margin
+	}
+
+	/**
+	 * Changes the distance leaves are enlarged by, applied to leaves inserted or reinserted afterwards.
+	 *
+	 * @param margin - The distance in blocks, a finite number of at least 0 so fat boxes contain the real bounds.
+	 */
+	#[napi(setter, js_name = "margin")]
+	pub fn set_margin(&mut self, margin: f64) -> napi::Result<()> {
+		if !margin.is_finite() || margin < 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Margin must be a finite number of at least 0, got {}", margin)));
+		}
+
+		self.margin = margin;
+		Ok(())
+	}
+
+	/**
+	 * Inserts a box into the tree.
+	 *
+	 * @param aabb - The box to insert (AABB).
+	 * @return The id of the proxy.
+	 * Throws if a corner of the box is NaN.
+	 */
+	#[napi]
+	pub fn insert(&mut self, aabb: &AABB) -> napi::Result<u32> {
+		let tight = Bounds::from_valid_aabb(aabb)?;
+		let leaf = self.allocate(self.enlarge(&tight, None), tight);
+
+		self.insert_leaf(leaf);
+		self.moved.insert(leaf);
+
+		Ok(leaf as u32)
+	}
+
+	/**
+	 * Removes a proxy from the tree.
+	 *
+	 * @param id - The id of the proxy.
+	 * @return Whether the proxy existed.
+	 */
+	#[napi]
+	pub fn remove(&mut self, id: u32) -> bool {
+		let leaf = id as usize;
+		if !self.is_leaf(leaf) { return false }
+
+		self.remove_leaf(leaf);
+		self.moved.remove(&leaf);
+		self.nodes[leaf].height = -1;
+		self.free.push(leaf);
+
+		return true;
+	}
+
+	/**
+	 * Moves a proxy to a new box. The tree is only restructured when the box leaves the fat box
+	 * of the proxy, which is then enlarged in the direction of the displacement as well.
+	 *
+	 * @param id - The id of the proxy.
+	 * @param aabb - The new box of the proxy (AABB).
+	 * @param displacement - The movement expected next tick, used to predict the fat box, ignored along axes it is not finite on (Vector3f).
+	 * @return Whether the proxy was reinserted.
+	 * Throws if a corner of the box is NaN.
+	 */
+	#[napi]
+	pub fn update(&mut self, id: u32, aabb: &AABB, displacement: Option<VectorLike>) -> napi::Result<bool> {
+		let tight = Bounds::from_valid_aabb(aabb)?;
+		let leaf = id as usize;
+		if !self.is_leaf(leaf) { return Ok(false) }
+
+		self.nodes[leaf].tight = tight;
+		self.moved.insert(leaf);
+
+		if self.nodes[leaf].fat.contains(&tight) { return Ok(false) }
+
+		self.remove_leaf(leaf);
+		self.nodes[leaf].fat = self.enlarge(&tight, displacement.as_deref());
+		self.insert_leaf(leaf);
+
+		return Ok(true);
+	}
+
+	/**
+	 * Returns the exact box of a proxy; otherwise, undefined if the id is not in the tree.
+	 *
+	 * @param id - The id of the proxy.
+	 */
+	#[napi]
+	pub fn get(&self, id: u32) -> Option<AABB> {
+		if !self.is_leaf(id as usize) { return None }
+		Some(self.nodes[id as usize].tight.to_aabb())
+	}
+
+	/**
+	 * Returns the ids of every proxy overlapping a box.
+	 *
+	 * @param aabb - The box to query (AABB).
+	 */
+	#[napi]
+	pub fn query(&self, aabb: &AABB) -> Vec<u32> {
+		let mut ids: Vec<u32> = Vec::new();
+		self.visit(&Bounds::from_aabb(aabb), |leaf| ids.push(leaf as u32));
+
+		return ids;
+	}
+
+	/**
+	 * Returns every pair of overlapping proxies.
+	 */
+	#[napi]
+	pub fn overlapping_pairs(&self) -> Vec<OverlapPair> {
+		let leaves: Vec<usize> = (0..self.nodes.len()).filter(|node| self.is_leaf(*node)).collect();
+		self.pairs(&leaves)
+	}
+
+	/**
+	 * Returns the pairs of overlapping proxies involving a proxy inserted or moved since the
+	 * last call, then clears the change tracking. Calling this every tick only reports new contacts
+	 * and contacts of moving entities.
+	 */
+	#[napi]
+	pub fn moved_pairs(&mut self) -> Vec<OverlapPair> {
+		let moved: Vec<usize> = self.moved.drain().collect();
+		self.pairs(&moved)
+	}
+
+	/**
+	 * Returns the ids of the proxies inserted or moved since moved pairs were last queried.
+	 */
+	#[napi]
+	pub fn moved_proxies(&self) -> Vec<u32> {
+		let mut ids: Vec<u32> = self.moved.iter().map(|leaf| *leaf as u32).collect();
+		ids.sort_unstable();
+
+		return ids;
+	}
+
+	/**
+	 * Returns the number of proxies in the tree.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		(0..self.nodes.len()).filter(|node| self.is_leaf(*node)).count() as u32
+	}
+
+	/**
+	 * Returns the height of the tree, 0 when it holds a single proxy.
+	 */
+	#[napi]
+	pub fn height(&self) -> i32 {
+		self.root.map_or(0, |root| self.nodes[root].height)
+	}
+
+	fn is_leaf(&self, node: usize) -> bool {
+		node < self.nodes.len() && self.nodes[node].height == 0
+	}
+
+	fn enlarge(&self, tight: &Bounds, displacement: Option<&Vector3f>) -> Bounds {
+		let mut fat = Bounds {
+			min: tight.min.map(|value| value - self.margin),
+			max: tight.max.map(|value| value + self.margin)
+		};
+
+		if let Some(d) = displacement {
+			for (axis, delta) in [d.x, d.y, d.z].into_iter().enumerate() {
+				// A NaN fat box would never be found by queries again.
+				if !delta.is_finite() { continue }
+				if delta < 0.0 { fat.min[axis] += delta } else { fat.max[axis] += delta }
+			}
+		}
+
+		return fat;
+	}
+
+	fn allocate(&mut self, fat: Bounds, tight: Bounds) -> usize {
+		let node = Node { fat, tight, parent: None, children: None, height: 0 };
+
+		match self.free.pop() {
+			Some(index) => { self.nodes[index] = node; index },
+			None => { self.nodes.push(node); self.nodes.len() - 1 }
+		}
+	}
+
+	fn pairs(&self, leaves: &[usize]) -> Vec<OverlapPair> {
+		let mut seen: HashSet<(u32, u32)> = HashSet::new();
+
+		for leaf in leaves {
+			if !self.is_leaf(*leaf) { continue }
+			self.visit(&self.nodes[*leaf].tight, |other| {
+				if other != *leaf { seen.insert(((*leaf).min(other) as u32, (*leaf).max(other) as u32)); }
+			});
+		}
+
+		let mut pairs: Vec<(u32, u32)> = seen.into_iter().collect();
+		pairs.sort_unstable();
+
+		pairs.into_iter().map(|(a, b)| OverlapPair { a, b }).collect()
+	}
+
+	/**
+	 * Calls found for every leaf whose exact box overlaps the bounds.
+	 */
+	fn visit<F: FnMut(usize)>(&self, bounds: &Bounds, mut found: F) {
+		let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+		while let Some(index) = stack.pop() {
+			let node = &self.nodes[index];
+			if !node.fat.overlaps(bounds) { continue }
+
+			match node.children {
+				Some((a, b)) => { stack.push(a); stack.push(b) },
+				None => if node.tight.overlaps(bounds) { found(index) }
+			}
+		}
+	}
+
+	/**
+	 * Calls found for every leaf whose exact box is crossed by a line segment.
+	 */
+	pub(crate) fn visit_segment<F: FnMut(usize)>(&self, start: &Vector3f, end: &Vector3f, mut found: F) {
+		let origin = [start.x, start.y, start.z];
+		let delta = [end.x - start.x, end.y - start.y, end.z - start.z];
+		let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+		while let Some(index) = stack.pop() {
+			let node = &self.nodes[index];
+			if !node.fat.crosses(origin, delta) { continue }
+
+			match node.children {
+				Some((a, b)) => { stack.push(a); stack.push(b) },
+				None => if node.tight.crosses(origin, delta) { found(index) }
+			}
+		}
+	}
+
+	/**
+	 * Inserts a leaf next to the sibling that grows the tree's surface area the least.
+	 */
+	fn insert_leaf(&mut self, leaf: usize) {
+		let Some(root) = self.root else {
+			self.root = Some(leaf);
+			self.nodes[leaf].parent = None;
+			return;
+		};
+
+		let bounds = self.nodes[leaf].fat;
+		let mut index = root;
+
+		while let Some((a, b)) = self.nodes[index].children {
+			let area = self.nodes[index].fat.area();
+			let combined = self.nodes[index].fat.union(&bounds).area();
+
+			let cost = 2.0 * combined;
+			let inheritance = 2.0 * (combined - area);
+
+			let descend = |child: usize| -> f64 {
+				let node = &self.nodes[child];
+				let union = node.fat.union(&bounds).area();
+				if node.children.is_none() { union + inheritance } else { union - node.fat.area() + inheritance }
+			};
+			let (cost_a, cost_b) = (descend(a), descend(b));
+
+			if cost < cost_a && cost < cost_b { break }
+			index = if cost_a < cost_b { a } else { b };
+		}
+
+		let sibling = index;
+		let old_parent = self.nodes[sibling].parent;
+		let fat = self.nodes[sibling].fat.union(&bounds);
+		let parent = self.allocate(fat, fat);
+
+		self.nodes[parent].parent = old_parent;
+		self.nodes[parent].height = self.nodes[sibling].height + 1;
+		self.nodes[parent].children = Some((sibling, leaf));
+		self.nodes[sibling].parent = Some(parent);
+		self.nodes[leaf].parent = Some(parent);
+
+		match old_parent {
+			Some(old_parent) => self.replace_child(old_parent, sibling, parent),
+			None => self.root = Some(parent)
+		}
+
+		self.refit(self.nodes[leaf].parent);
+	}
+
+	fn remove_leaf(&mut self, leaf: usize) {
+		if self.root == Some(leaf) {
+			self.root = None;
+			return;
+		}
+
+		let Some(parent) = self.nodes[leaf].parent else { return };
+		let (a, b) = self.nodes[parent].children.unwrap_or((leaf, leaf));
+		let sibling = if a == leaf { b } else { a };
+		let grandparent = self.nodes[parent].parent;
+
+		self.nodes[sibling].parent = grandparent;
+		self.nodes[parent].height = -1;
+		self.nodes[parent].children = None;
+		self.free.push(parent);
+
+		match grandparent {
+			Some(grandparent) => {
+				self.replace_child(grandparent, parent, sibling);
+				self.refit(Some(grandparent));
+			},
+			None => self.root = Some(sibling)
+		}
+	}
+
+	fn replace_child(&mut self, parent: usize, old: usize, new: usize) {
+		if let Some((a, b)) = self.nodes[parent].children {
+			self.nodes[parent].children = Some(if a == old { (new, b) } else { (a, new) });
+		}
+	}
+
+	/**
+	 * Walks up from a node, balancing and refitting every ancestor.
+	 */
+	fn refit(&mut self, mut index: Option<usize>) {
+		while let Some(node) = index {
+			let node = self.balance(node);
+			self.fit(node);
+			index = self.nodes[node].parent;
+		}
+	}
+
+	/**
+	 * Recomputes the bounds and height of an internal node from its children.
+	 */
+	fn fit(&mut self, node: usize) {
+		if let Some((a, b)) = self.nodes[node].children {
+			self.nodes[node].fat = self.nodes[a].fat.union(&self.nodes[b].fat);
+			self.nodes[node].height = 1 + self.nodes[a].height.max(self.nodes[b].height);
+		}
+	}
+
+	/**
+	 * Rotates the taller child of a node up when the heights of its children differ by more than one.
+	 * Returns the node now at the position of the given node.
+	 */
+	fn balance(&mut self, a: usize) -> usize {
+		let Some((b, c)) = self.nodes[a].children else { return a };
+		if self.nodes[a].height < 2 { return a }
+
+		let difference = self.nodes[c].height - self.nodes[b].height;
+		let (up, other_is_first) = match difference {
+			d if d > 1 => (c, true),
+			d if d < -1 => (b, false),
+			_ => return a
+		};
+
+		let Some((f, g)) = self.nodes[up].children else { return a };
+		let parent = self.nodes[a].parent;
+
+		// The lifted node takes the place of a, and a becomes its first child.
+		self.nodes[up].parent = parent;
+		self.nodes[a].parent = Some(up);
+		match parent {
+			Some(parent) => self.replace_child(parent, a, up),
+			None => self.root = Some(up)
+		}
+
+		// The taller grandchild stays with the lifted node, the shorter one moves under a.
+		let (keep, give) = if self.nodes[f].height > self.nodes[g].height { (f, g) } else { (g, f) };
+		self.nodes[up].children = Some((a, keep));
+		self.nodes[give].parent = Some(a);
+		self.nodes[a].children = Some(if other_is_first { (b, give) } else { (give, c) });
+
+		self.fit(a);
+		self.fit(up);
+
+		return up;
+	}
+}
@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+
+use napi::bindgen_prelude::ClassInstance;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::Vector3f;
+
+use super::aabb::AABB;
+
+/**
+ * Represents the options of a visibility precomputation.
+ */
+#[napi(object)]
+pub struct VisibilityOptions {
+	pub samples: Option<u32>, // Points sampled per portal side when testing lines of sight, defaults to 4
+	pub max_depth: Option<u32>, // Maximum number of portals a line of sight may pass through, defaults to 8
+}
+
+/**
+ * A room to room potentially visible set, precomputed from room boxes and the portals
+ * (doorways and windows) between them, so interest management queries are O(1) lookups.
+ */
+#[napi]
+pub struct PotentiallyVisibleSet {
+	rooms: usize,
+	visible: Vec<bool> // Row major matrix, visible[from * rooms + to]
+}
+
+#[napi]
+impl PotentiallyVisibleSet {
+	/**
+	 * Computes which rooms can see each other. Portals are flat boxes lying on the shared faces of the rooms
+	 * they connect. A room sees another when a line passes from the first portal of a chain
+	 * through every portal of the chain, found by sampling points on the first and last portal.
+	 * Every portal is crossed at most once in each direction per first portal, so densely connected rooms
+	 * do not explode into every possible chain.
+	 *
+	 * @param rooms - The boxes of the rooms (Array<AABB>).
+	 * @param portals - The flat boxes of the portals (Array<AABB>).
+	 * @param options - The sampling precision (VisibilityOptions).
+	 * Throws if there are too many rooms for the room to room matrix or there is not enough memory for it.
+	 */
+	#[napi]
+	pub fn compute(rooms: Vec<ClassInstance<AABB>>, portals: Vec<ClassInstance<AABB>>, options: Option<VisibilityOptions>) -> napi::Result<PotentiallyVisibleSet> {
+		let samples = options.as_ref().and_then(|options| options.samples).unwrap_or(4).max(1);
+		let max_depth = options.as_ref().and_then(|options| options.max_depth).unwrap_or(8) as usize;
+		let count = rooms.len();
+
+		// The matrix grows with the square of the rooms, so its size is checked before anything is computed.
+		let cells = count.checked_mul(count).filter(|cells| *cells <= u32::MAX as usize).ok_or_else(|| {
+			Error::new(Status::InvalidArg, format!("Visible sets hold at most {} rooms, got {}", u16::MAX, count))
+		})?;
+		let mut visible: Vec<bool> = Vec::new();
+		if visible.try_reserve_exact(cells).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for a visible set of {} rooms", count)));
+		}
+		visible.resize(cells, false);
+
+		// Rooms touching every portal, and the portals of every room.
+		let connected: Vec<Vec<usize>> = portals.iter()
+			.map(|portal| (0..count).filter(|room| PotentiallyVisibleSet::touches(&rooms[*room], portal)).collect())
+			.collect();
+		let room_portals: Vec<Vec<usize>> = (0..count)
+			.map(|room| (0..portals.len()).filter(|portal| connected[*portal].contains(&room)).collect())
+			.collect();
+
+		let points: Vec<Vec<Vector3f>> = portals.iter().map(|portal| PotentiallyVisibleSet::sample_points(portal, samples)).collect();
+		for start in 0..count {
+			visible[start * count + start] = true;
+
+			// Depth first over chains of portals, keeping chains a line of sight can pass through.
+			// States are the first portal, the room entered and the portal it was entered through.
+			let mut stack: Vec<(usize, Vec<usize>)> = room_portals[start].iter().map(|portal| (start, vec![*portal])).collect();
+			let mut visited: HashSet<(usize, usize, usize)> = room_portals[start].iter().map(|portal| (*portal, start, *portal)).collect();
+			while let Some((from, chain)) = stack.pop() {
+				let last = chain[chain.len() - 1];
+
+				for room in connected[last].iter().copied().filter(|room| *room != from) {
+					visible[start * count + room] = true;
+					if chain.len() >= max_depth { continue }
+
+					for next in room_portals[room].iter().copied().filter(|portal| !chain.contains(portal)) {
+						if visited.contains(&(chain[0], room, next)) { continue }
+
+						let mut extended = chain.clone();
+						extended.push(next);
+
+						if PotentiallyVisibleSet::sees_through(&portals, &points, &extended) {
+							visited.insert((chain[0], room, next));
+							stack.push((room, extended));
+						}
+					}
+				}
+			}
+		}
+
+		Ok(PotentiallyVisibleSet { rooms: count, visible })
+	}
+
+	/**
+	 * Checks whether a room can see another.
+	 *
+	 * @param from - The index of the viewing room.
+	 * @param to - The index of the other room.
+	 */
+	#[napi]
+	pub fn is_visible(&self, from: u32, to: u32) -> bool {
+		let (from, to) = (from as usize, to as usize);
+		from < self.rooms && to < self.rooms && self.visible[from * self.rooms + to]
+	}
+
+	/**
+	 * Returns the indices of every room visible from a room, including itself.
+	 *
+	 * @param from - The index of the viewing room.
+	 */
+	#[napi]
+	pub fn visible_from(&self, from: u32) -> Vec<u32> {
+		(0..self.rooms as u32).filter(|to| self.is_visible(from, *to)).collect()
+	}
+
+	/**
+	 * Returns the number of rooms.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.rooms as u32
+	}
+
+	fn touches(room: &AABB, portal: &AABB) -> bool {
+		room.min.x <= portal.max.x && portal.min.x <= room.max.x &&
+		room.min.y <= portal.max.y && portal.min.y <= room.max.y &&
+		room.min.z <= portal.max.z && portal.min.z <= room.max.z
+	}
+
+	/**
+	 * Checks whether a sampled line from the first portal to the last passes through every portal between.
+	 */
+	fn sees_through(portals: &[ClassInstance<AABB>], points: &[Vec<Vector3f>], chain: &[usize]) -> bool {
+		if chain.len() <= 2 { return true }
+
+		let (first, last) = (chain[0], chain[chain.len() - 1]);
+		let between = &chain[1..chain.len() - 1];
+
+		points[first].iter().any(|start| points[last].iter().any(|end| {
+			between.iter().all(|portal| AABB::intercept(&portals[*portal], start.into(), end.into()).is_some())
+		}))
+	}
+
+	/**
+	 * Samples a grid of points on a portal, slightly inset from its edges. Flat axes are not subdivided.
+	 */
+	fn sample_points(portal: &AABB, samples: u32) -> Vec<Vector3f> {
+		let inset = 1e-4;
+		let steps = |extent: f64| -> Vec<f64> {
+			if extent == 0.0 || samples == 1 { return vec![extent * 0.5] }
+			(0..samples).map(|i| extent * (inset + (1.0 - 2.0 * inset) * i as f64 / (samples - 1) as f64)).collect()
+		};
+
+		let size = portal.max.subtract(&portal.min);
+		let mut points: Vec<Vector3f> = Vec::new();
+
+		for x in steps(size.x) {
+			for y in steps(size.y) {
+				for z in steps(size.z) {
+					points.push(portal.min.add(&Vector3f::new(x, y, z)));
+				}
+			}
+		}
+
+		return points;
+	}
+}
@@ -0,0 +1,145 @@
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::Axis;
+
+const DEFAULT_EPSILON: f64 = 1e-7;
+
+#[napi]
+#[derive(PartialEq)]
+pub enum AngleUnit {
+	Degrees,
+	Radians
+}
+
+/**
+ * Crate wide settings: the tolerance AABB intersection tests and collision clipping use, the unit
+ * of the Rotation conversions, and the up axis shapes and noise slices default to. Values are validated
+ * when the config is built, and a config can be installed globally to become the default of every call.
+ * Other tolerances, such as ray parallelism, are fixed by their algorithms.
+ */
+#[napi]
+#[derive(Clone, Copy)]
+pub struct MathConfig {
+	epsilon: f64,
+	angle_unit: AngleUnit,
+	up_axis: Axis
+}
+
+const DEFAULT_CONFIG: MathConfig = MathConfig { epsilon: DEFAULT_EPSILON, angle_unit: AngleUnit::Degrees, up_axis: Axis::Y };
+
+// Kept in atomics rather than behind a lock, since the epsilon is read by every intersection test.
+static EPSILON: AtomicU64 = AtomicU64::new(DEFAULT_EPSILON.to_bits());
+static ANGLE_UNIT: AtomicU8 = AtomicU8::new(AngleUnit::Degrees as u8);
+static UP_AXIS: AtomicU8 = AtomicU8::new(Axis::Y as u8);
+
+#[napi]
+impl MathConfig {
+	/**
+	 * Constructs and validates a config.
+	 *
+	 * @param epsilon - The tolerance of AABB intersections and collision clipping, defaults to 1e-7.
+	 * @param angle_unit - The unit of the Rotation conversions, defaults to degrees like Minecraft rotations (AngleUnit).
+	 * @param up_axis - The axis helices wind around and noise slices are perpendicular to by default, defaults to Y (Axis).
+	 */
+	#[napi(constructor)]
+	pub fn new(epsilon: Option<f64>, angle_unit: Option<AngleUnit>, up_axis: Option<Axis>) -> napi::Result<MathConfig> {
+		let epsilon = epsilon.unwrap_or(DEFAULT_EPSILON);
+		if !epsilon.is_finite() || epsilon < 0.0 {
+			return Err(Error::new(Status::InvalidArg, format!("Epsilon must be a finite, non-negative number, got {}", epsilon)));
+		}
+
+		Ok(MathConfig {
+			epsilon,
+			angle_unit: angle_unit.unwrap_or(AngleUnit::Degrees),
+			up_axis: up_axis.unwrap_or(Axis::Y)
+		})
+	}
+
+	/**
+	 * Returns a copy of the global config.
+	 */
+	#[napi]
+	pub fn global() -> MathConfig {
+		MathConfig::current()
+	}
+
+	/**
+	 * Installs a config as the global default.
+	 *
+	 * @param config - The config to install (MathConfig).
+	 */
+	#[napi]
+	pub fn set_global(config: &MathConfig) {
+		EPSILON.store(config.epsilon.to_bits(), Ordering::Relaxed);
+		ANGLE_UNIT.store(config.angle_unit as u8, Ordering::Relaxed);
+		UP_AXIS.store(config.up_axis as u8, Ordering::Relaxed);
+	}
+
+	/**
+	 * Restores the built in defaults.
+	 */
+	#[napi]
+	pub fn reset_global() {
+		MathConfig::set_global(&DEFAULT_CONFIG);
+	}
+
+	#[napi(getter)]
+	pub fn epsilon(&self) -> f64 {
+		self.epsilon
+	}
+
+	#[napi(getter)]
+	pub fn angle_unit(&self) -> AngleUnit {
+		self.angle_unit
+	}
+
+	#[napi(getter)]
+	pub fn up_axis(&self) -> Axis {
+		self.up_axis
+	}
+
+	/**
+	 * Converts an angle in the configured unit into degrees.
+	 *
+	 * @param angle - The angle in the configured unit.
+	 */
+	#[napi]
+	pub fn to_degrees(&self, angle: f64) -> f64 {
+		if self.angle_unit == AngleUnit::Radians { angle.to_degrees() } else { angle }
+	}
+
+	/**
+	 * Converts an angle in degrees into the configured unit.
+	 *
+	 * @param degrees - The angle in degrees.
+	 */
+	#[napi]
+	pub fn from_degrees(&self, degrees: f64) -> f64 {
+		if self.angle_unit == AngleUnit::Radians { degrees.to_radians() } else { degrees }
+	}
+
+	/**
+	 * Returns the global config.
+	 */
+	pub(crate) fn current() -> MathConfig {
+		MathConfig {
+			epsilon: MathConfig::current_epsilon(),
+			angle_unit: if ANGLE_UNIT.load(Ordering::Relaxed) == AngleUnit::Radians as u8 { AngleUnit::Radians } else { AngleUnit::Degrees },
+			up_axis: match UP_AXIS.load(Ordering::Relaxed) {
+				axis if axis == Axis::X as u8 => Axis::X,
+				axis if axis == Axis::Z as u8 => Axis::Z,
+				_ => Axis::Y
+			}
+		}
+	}
+
+	/**
+	 * Returns the global epsilon alone, the hot path of intersection tests.
+	 */
+	pub(crate) fn current_epsilon() -> f64 {
+		f64::from_bits(EPSILON.load(Ordering::Relaxed))
+	}
+}
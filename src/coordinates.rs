@@ -0,0 +1,122 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::basis::Basis;
+use crate::vec2f::Vector2f;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * How a command coordinate is interpreted.
+ */
+#[napi]
+#[derive(PartialEq, Debug)]
+pub enum CoordinateMode {
+	Absolute, // A plain number
+	Relative, // Prefixed with ~, an offset from the base position on the same axis
+	Local // Prefixed with ^, an offset along the left, up and forward directions of the rotation
+}
+
+/**
+ * Represents a single command coordinate.
+ */
+#[napi(object)]
+pub struct CoordinateValue {
+	pub mode: CoordinateMode,
+	pub value: f64,
+}
+
+/**
+ * Represents the three coordinates of a command position.
+ */
+#[napi(object)]
+pub struct CoordinateInput {
+	pub x: CoordinateValue,
+	pub y: CoordinateValue,
+	pub z: CoordinateValue,
+}
+
+/**
+ * Resolution of command coordinates with the semantics of ~ (relative) and ^ (local) notation.
+ */
+#[napi]
+pub struct Coordinates {}
+
+#[napi]
+impl Coordinates {
+	/**
+	 * Parses the three coordinates of a command, such as "~ ~1.5 ~-2" or "^ ^ ^3".
+	 *
+	 * @param input - The coordinates separated by whitespace.
+	 * @return The mode and value of every coordinate.
+	 */
+	#[napi]
+	pub fn parse(input: String) -> napi::Result<CoordinateInput> {
+		let tokens: Vec<&str> = input.split_whitespace().collect();
+		let [x, y, z] = tokens[..] else {
+			return Err(Error::new(Status::InvalidArg, format!("Expected 3 coordinates, got {}", tokens.len())));
+		};
+
+		Ok(CoordinateInput { x: Coordinates::token(x)?, y: Coordinates::token(y)?, z: Coordinates::token(z)? })
+	}
+
+	/**
+	 * Resolves command coordinates into a world position. Local coordinates must be used on all
+	 * three axes or none, like in commands; they offset the base along the left (x), up (y) and
+	 * forward (z) directions of the rotation.
+	 *
+	 * @param input - The coordinates to resolve (CoordinateInput).
+	 * @param base - The position the command runs at, the eye position for local coordinates of entities (Vector3f).
+	 * @param rotation - The rotation the command runs with in degrees, x being the pitch and y the yaw (Vector2f).
+	 * @return The resolved position.
+	 */
+	#[napi]
+	pub fn resolve(input: CoordinateInput, base: VectorLike, rotation: &Vector2f) -> napi::Result<Vector3f> {
+		let local = [&input.x, &input.y, &input.z].iter().filter(|value| value.mode == CoordinateMode::Local).count();
+
+		match local {
+			0 => Ok(Vector3f::new(
+				Coordinates::axis(&input.x, base.x),
+				Coordinates::axis(&input.y, base.y),
+				Coordinates::axis(&input.z, base.z)
+			)),
+			3 => {
+				let basis = Basis::from_rotation(rotation);
+				let left = basis.right.multiply(-input.x.value);
+
+				Ok(base.add(&left).add(&basis.up.multiply(input.y.value)).add(&basis.forward.multiply(input.z.value)))
+			},
+			_ => Err(Error::new(
+				Status::InvalidArg,
+				"Local coordinates (^) cannot be mixed with world coordinates".to_string()
+			))
+		}
+	}
+
+	/**
+	 * Resolves a single absolute or relative coordinate.
+	 */
+	fn axis(value: &CoordinateValue, base: f64) -> f64 {
+		match value.mode {
+			CoordinateMode::Relative => base + value.value,
+			_ => value.value
+		}
+	}
+
+	/**
+	 * Parses a single coordinate token, where a bare prefix means an offset of 0.
+	 */
+	fn token(token: &str) -> napi::Result<CoordinateValue> {
+		let (mode, number) = match token.chars().next() {
+			Some('~') => (CoordinateMode::Relative, &token[1..]),
+			Some('^') => (CoordinateMode::Local, &token[1..]),
+			_ => (CoordinateMode::Absolute, token)
+		};
+
+		if number.is_empty() && mode != CoordinateMode::Absolute { return Ok(CoordinateValue { mode, value: 0.0 }) }
+
+		match number.parse::<f64>() {
+			Ok(value) if value.is_finite() => Ok(CoordinateValue { mode, value }),
+			_ => Err(Error::new(Status::InvalidArg, format!("Invalid coordinate: {}", token)))
+		}
+	}
+}
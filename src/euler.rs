@@ -0,0 +1,172 @@
+use napi_derive::napi;
+
+use crate::mat3::Matrix3;
+use crate::quaternion::Quaternion;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const GIMBAL_LOCK: f64 = 0.999_999_9; // Above this the middle rotation is treated as +-90 degrees
+
+/**
+ * The order of the rotations of Euler angles. The order names intrinsic rotations,
+ * so XYZ rotates around X, then around the rotated Y, then around the twice rotated Z,
+ * resulting in the matrix Rx * Ry * Rz.
+ */
+#[napi]
+#[derive(PartialEq, Debug)]
+pub enum EulerOrder {
+	XYZ,
+	XZY,
+	YXZ,
+	YZX,
+	ZXY,
+	ZYX
+}
+
+/**
+ * Represents a rotation as three angles around the X, Y and Z axes, applied in an explicit order.
+ */
+#[napi]
+#[derive(Clone, Debug)]
+pub struct Euler {
+	pub x: f64, // Angle around X in radians
+	pub y: f64, // Angle around Y in radians
+	pub z: f64, // Angle around Z in radians
+	pub order: EulerOrder
+}
+
+#[napi]
+impl Euler {
+	/**
+	 * Constructs Euler angles.
+	 *
+	 * @param x - The angle around X in radians.
+	 * @param y - The angle around Y in radians.
+	 * @param z - The angle around Z in radians.
+	 * @param order - The order of the rotations, defaults to XYZ (EulerOrder).
+	 */
+	#[napi(constructor)]
+	pub fn new(x: f64, y: f64, z: f64, order: Option<EulerOrder>) -> Euler {
+		Euler { x, y, z, order: order.unwrap_or(EulerOrder::XYZ) }
+	}
+
+	/**
+	 * Extracts Euler angles from a rotation matrix. When the middle rotation reaches
+	 * +-90 degrees (gimbal lock) the last angle is set to zero and the first absorbs the rotation.
+	 *
+	 * @param matrix - The rotation matrix (Matrix3).
+	 * @param order - The order of the rotations, defaults to XYZ (EulerOrder).
+	 */
+	#[napi]
+	pub fn from_matrix3(matrix: &Matrix3, order: Option<EulerOrder>) -> Euler {
+		let order = order.unwrap_or(EulerOrder::XYZ);
+		let m = |row: usize, column: usize| matrix.elements[column * 3 + row];
+		let asin = |value: f64| value.clamp(-1.0, 1.0).asin();
+
+		let (x, y, z) = match order {
+			EulerOrder::XYZ => {
+				let y = asin(m(0, 2));
+				if m(0, 2).abs() < GIMBAL_LOCK { ((-m(1, 2)).atan2(m(2, 2)), y, (-m(0, 1)).atan2(m(0, 0))) }
+				else { (m(2, 1).atan2(m(1, 1)), y, 0.0) }
+			},
+			EulerOrder::XZY => {
+				let z = asin(-m(0, 1));
+				if m(0, 1).abs() < GIMBAL_LOCK { (m(2, 1).atan2(m(1, 1)), m(0, 2).atan2(m(0, 0)), z) }
+				else { ((-m(1, 2)).atan2(m(2, 2)), 0.0, z) }
+			},
+			EulerOrder::YXZ => {
+				let x = asin(-m(1, 2));
+				if m(1, 2).abs() < GIMBAL_LOCK { (x, m(0, 2).atan2(m(2, 2)), m(1, 0).atan2(m(1, 1))) }
+				else { (x, (-m(2, 0)).atan2(m(0, 0)), 0.0) }
+			},
+			EulerOrder::YZX => {
+				let z = asin(m(1, 0));
+				if m(1, 0).abs() < GIMBAL_LOCK { ((-m(1, 2)).atan2(m(1, 1)), (-m(2, 0)).atan2(m(0, 0)), z) }
+				else { (0.0, m(0, 2).atan2(m(2, 2)), z) }
+			},
+			EulerOrder::ZXY => {
+				let x = asin(m(2, 1));
+				if m(2, 1).abs() < GIMBAL_LOCK { (x, (-m(2, 0)).atan2(m(2, 2)), (-m(0, 1)).atan2(m(1, 1))) }
+				else { (x, 0.0, m(1, 0).atan2(m(0, 0))) }
+			},
+			EulerOrder::ZYX => {
+				let y = asin(-m(2, 0));
+				if m(2, 0).abs() < GIMBAL_LOCK { (m(2, 1).atan2(m(2, 2)), y, m(1, 0).atan2(m(0, 0))) }
+				else { (0.0, y, (-m(0, 1)).atan2(m(1, 1))) }
+			}
+		};
+
+		Euler { x, y, z, order }
+	}
+
+	/**
+	 * Extracts Euler angles from a quaternion.
+	 *
+	 * @param quaternion - The rotation (Quaternion).
+	 * @param order - The order of the rotations, defaults to XYZ (EulerOrder).
+	 */
+	#[napi]
+	pub fn from_quaternion(quaternion: &Quaternion, order: Option<EulerOrder>) -> Euler {
+		Euler::from_matrix3(&quaternion.to_matrix3(), order)
+	}
+
+	/**
+	 * Converts the angles into a rotation matrix.
+	 */
+	#[napi]
+	pub fn to_matrix3(&self) -> Matrix3 {
+		let (x, y, z) = (Euler::axis(0, self.x), Euler::axis(1, self.y), Euler::axis(2, self.z));
+
+		match self.order {
+			EulerOrder::XYZ => x.multiply(&y).multiply(&z),
+			EulerOrder::XZY => x.multiply(&z).multiply(&y),
+			EulerOrder::YXZ => y.multiply(&x).multiply(&z),
+			EulerOrder::YZX => y.multiply(&z).multiply(&x),
+			EulerOrder::ZXY => z.multiply(&x).multiply(&y),
+			EulerOrder::ZYX => z.multiply(&y).multiply(&x)
+		}
+	}
+
+	/**
+	 * Converts the angles into a quaternion.
+	 */
+	#[napi]
+	pub fn to_quaternion(&self) -> Quaternion {
+		Quaternion::from_euler(self)
+	}
+
+	/**
+	 * Expresses the same rotation with angles in another order.
+	 *
+	 * @param order - The new order of the rotations (EulerOrder).
+	 */
+	#[napi]
+	pub fn reorder(&self, order: EulerOrder) -> Euler {
+		if self.order == order { return self.clone() }
+		Euler::from_matrix3(&self.to_matrix3(), Some(order))
+	}
+
+	/**
+	 * Rotates a vector by the angles.
+	 *
+	 * @param vector - The vector to rotate (Vector3f).
+	 */
+	#[napi]
+	pub fn rotate(&self, vector: VectorLike) -> Vector3f {
+		self.to_matrix3().transform(vector)
+	}
+
+	/**
+	 * Builds the matrix of a counter-clockwise rotation around a single axis (0 = X, 1 = Y, 2 = Z).
+	 */
+	fn axis(axis: usize, angle: f64) -> Matrix3 {
+		let (s, c) = angle.sin_cos();
+
+		let elements = match axis {
+			0 => vec![1.0, 0.0, 0.0, 0.0, c, s, 0.0, -s, c],
+			1 => vec![c, 0.0, -s, 0.0, 1.0, 0.0, s, 0.0, c],
+			_ => vec![c, s, 0.0, -s, c, 0.0, 0.0, 0.0, 1.0]
+		};
+
+		Matrix3 { elements }
+	}
+}
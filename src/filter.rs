@@ -0,0 +1,142 @@
+use napi_derive::napi;
+
+use crate::vec2f::Vector2f;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Smooths noisy position or rotation streams with a One Euro filter: a low-pass filter
+ * whose cutoff rises with speed, removing jitter at rest while keeping fast movement responsive.
+ * With a beta of 0 it is a plain exponential moving average.
+ */
+#[napi]
+pub struct PositionFilter {
+	pub min_cutoff: f64, // Cutoff frequency at rest in Hz, lower smooths more
+	pub beta: f64, // Increase of the cutoff per unit of speed, higher lags less
+	pub derivative_cutoff: f64, // Cutoff frequency of the speed estimate in Hz
+	pub angular: bool, // Whether components are angles in degrees, wrapped at -180..180
+	value: Option<[f64; 3]>,
+	derivative: [f64; 3]
+}
+
+#[napi]
+impl PositionFilter {
+	/**
+	 * Constructs a filter without any samples yet.
+	 *
+	 * @param min_cutoff - The cutoff frequency at rest in Hz, defaults to 1.
+	 * @param beta - The increase of the cutoff per unit of speed, defaults to 0.
+	 * @param derivative_cutoff - The cutoff frequency of the speed estimate in Hz, defaults to 1.
+	 * @param angular - Whether the components are angles in degrees, defaults to false.
+	 */
+	#[napi(constructor)]
+	pub fn new(min_cutoff: Option<f64>, beta: Option<f64>, derivative_cutoff: Option<f64>, angular: Option<bool>) -> PositionFilter {
+		PositionFilter {
+			min_cutoff: min_cutoff.unwrap_or(1.0),
+			beta: beta.unwrap_or(0.0),
+			derivative_cutoff: derivative_cutoff.unwrap_or(1.0),
+			angular: angular.unwrap_or(false),
+			value: None,
+			derivative: [0.0; 3]
+		}
+	}
+
+	/**
+	 * Filters a sample. The first sample is returned unchanged, and samples that are not
+	 * finite are skipped, returning the last smoothed value instead.
+	 *
+	 * @param sample - The raw sample (Vector3f).
+	 * @param delta - The seconds elapsed since the previous sample, defaults to 0.05 (one tick).
+	 * @return The smoothed value.
+	 */
+	#[napi]
+	pub fn filter(&mut self, sample: VectorLike, delta: Option<f64>) -> Vector3f {
+		let [x, y, z] = self.step([sample.x, sample.y, sample.z], delta.unwrap_or(0.05));
+		Vector3f::new(x, y, z)
+	}
+
+	/**
+	 * Filters a rotation sample of pitch (x) and yaw (y). The filter should be angular.
+	 * Samples that are not finite are skipped like in filter.
+	 *
+	 * @param rotation - The raw rotation in degrees (Vector2f).
+	 * @param delta - The seconds elapsed since the previous sample, defaults to 0.05 (one tick).
+	 * @return The smoothed rotation.
+	 */
+	#[napi]
+	pub fn filter_rotation(&mut self, rotation: &Vector2f, delta: Option<f64>) -> Vector2f {
+		let [x, y, _] = self.step([rotation.x, rotation.y, 0.0], delta.unwrap_or(0.05));
+		Vector2f::new(x, y)
+	}
+
+	/**
+	 * Returns the last smoothed value; otherwise, undefined before the first sample.
+	 */
+	#[napi]
+	pub fn value(&self) -> Option<Vector3f> {
+		self.value.map(|[x, y, z]| Vector3f::new(x, y, z))
+	}
+
+	/**
+	 * Forgets all samples, so the next sample is returned unchanged.
+	 */
+	#[napi]
+	pub fn reset(&mut self) {
+		self.value = None;
+		self.derivative = [0.0; 3];
+	}
+
+	/**
+	 * Blends a value towards a target by a fixed factor, a single step of an exponential moving average.
+	 *
+	 * @param previous - The previous smoothed value (Vector3f).
+	 * @param sample - The new sample (Vector3f).
+	 * @param alpha - The weight of the new sample (0..1).
+	 */
+	#[napi]
+	pub fn ema(previous: VectorLike, sample: VectorLike, alpha: f64) -> Vector3f {
+		previous.lerp(&sample, alpha.clamp(0.0, 1.0))
+	}
+
+	fn step(&mut self, sample: [f64; 3], delta: f64) -> [f64; 3] {
+		// A sample that is not finite would poison every later value, so it is not taken in.
+		if sample.iter().any(|component| !component.is_finite()) { return self.value.unwrap_or(sample) }
+
+		let Some(previous) = self.value else {
+			self.value = Some(sample);
+			return sample;
+		};
+
+		let delta = delta.max(f64::EPSILON);
+		let mut result = [0.0; 3];
+
+		for axis in 0..3 {
+			let change = self.difference(sample[axis], previous[axis]);
+
+			let derivative_alpha = PositionFilter::alpha(self.derivative_cutoff, delta);
+			self.derivative[axis] += (change / delta - self.derivative[axis]) * derivative_alpha;
+
+			let cutoff = self.min_cutoff + self.beta * self.derivative[axis].abs();
+			result[axis] = self.wrap(previous[axis] + change * PositionFilter::alpha(cutoff, delta));
+		}
+
+		self.value = Some(result);
+		return result;
+	}
+
+	/**
+	 * Returns the smoothing factor of a low-pass filter with a cutoff frequency sampled after some seconds.
+	 */
+	fn alpha(cutoff: f64, delta: f64) -> f64 {
+		let tau = 1.0 / (std::f64::consts::TAU * cutoff.max(f64::EPSILON));
+		1.0 / (1.0 + tau / delta)
+	}
+
+	fn difference(&self, to: f64, from: f64) -> f64 {
+		if self.angular { self.wrap(to - from) } else { to - from }
+	}
+
+	fn wrap(&self, value: f64) -> f64 {
+		if !self.angular { return value }
+		(value + 180.0).rem_euclid(360.0) - 180.0
+	}
+}
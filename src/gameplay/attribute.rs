@@ -0,0 +1,116 @@
+use napi_derive::napi;
+
+#[napi]
+#[derive(PartialEq)]
+pub enum ModifierOperation {
+	Addition = 0, // Adds the amount to the base value
+	MultiplyBase = 1, // Adds amount * (base + additions) to the value
+	MultiplyTotal = 2, // Multiplies the value by (1 + amount)
+	Cap = 3 // Caps the value at the amount
+}
+
+/**
+ * Represents a modifier applied to an attribute.
+ */
+#[napi(object)]
+pub struct AttributeModifier {
+	pub amount: f64,
+	pub operation: ModifierOperation,
+}
+
+const MAX_PROTECTION_FACTOR: f64 = 20.0;
+
+/**
+ * Attribute and damage reduction math with vanilla's operation order.
+ */
+#[napi]
+pub struct AttributeMath {}
+
+#[napi]
+impl AttributeMath {
+	/**
+	 * Applies modifiers to a base value in vanilla order: additions first, then base
+	 * multipliers (which all scale the same added value), then total multipliers in sequence,
+	 * and finally caps. The result is clamped to the optional bounds.
+	 *
+	 * @param base - The base value of the attribute.
+	 * @param modifiers - The modifiers to apply (Array<AttributeModifier>).
+	 * @param min - The minimum value of the attribute.
+	 * @param max - The maximum value of the attribute.
+	 * @return The modified value.
+	 */
+	#[napi]
+	pub fn apply(base: f64, modifiers: Vec<AttributeModifier>, min: Option<f64>, max: Option<f64>) -> f64 {
+		let amounts = |operation: ModifierOperation| modifiers.iter()
+			.filter(move |modifier| modifier.operation == operation)
+			.map(|modifier| modifier.amount);
+
+		let added = base + amounts(ModifierOperation::Addition).sum::<f64>();
+		let mut value = added + added * amounts(ModifierOperation::MultiplyBase).sum::<f64>();
+
+		for amount in amounts(ModifierOperation::MultiplyTotal) { value *= 1.0 + amount }
+		for amount in amounts(ModifierOperation::Cap) { value = value.min(amount) }
+
+		if let Some(min) = min { value = value.max(min) }
+		if let Some(max) = max { value = value.min(max) }
+
+		return value;
+	}
+
+	/**
+	 * Calculates the enchantment protection factor (EPF) of the armor worn.
+	 * Protection counts once per level, a specialized protection matching the damage
+	 * (fire, blast or projectile protection) twice, and feather falling against falls three times.
+	 *
+	 * @param protection - The summed protection levels.
+	 * @param specialized - The summed levels of the specialized protection matching the damage.
+	 * @param feather_falling - The feather falling level, only when the damage is a fall.
+	 * @return The uncapped protection factor, saturating at u32::MAX.
+	 */
+	#[napi]
+	pub fn protection_factor(protection: u32, specialized: Option<u32>, feather_falling: Option<u32>) -> u32 {
+		protection
+			.saturating_add(specialized.unwrap_or(0).saturating_mul(2))
+			.saturating_add(feather_falling.unwrap_or(0).saturating_mul(3))
+	}
+
+	/**
+	 * Reduces damage by an enchantment protection factor, capped at 20 (80% reduction).
+	 *
+	 * @param damage - The incoming damage.
+	 * @param protection_factor - The enchantment protection factor.
+	 * @return The damage after the reduction.
+	 */
+	#[napi]
+	pub fn enchantment_reduction(damage: f64, protection_factor: f64) -> f64 {
+		damage * (1.0 - protection_factor.clamp(0.0, MAX_PROTECTION_FACTOR) / 25.0)
+	}
+
+	/**
+	 * Reduces damage by armor points and toughness.
+	 *
+	 * @param damage - The incoming damage.
+	 * @param armor - The armor points of the entity.
+	 * @param toughness - The armor toughness of the entity, defaults to 0.
+	 * @return The damage after the reduction.
+	 */
+	#[napi]
+	pub fn armor_reduction(damage: f64, armor: f64, toughness: Option<f64>) -> f64 {
+		let toughness = toughness.unwrap_or(0.0);
+		let effective = (armor - damage / (2.0 + toughness / 4.0)).max(armor / 5.0).clamp(0.0, 20.0);
+
+		damage * (1.0 - effective / 25.0)
+	}
+
+	/**
+	 * Reduces damage by the resistance effect, 20% per level.
+	 *
+	 * @param damage - The incoming damage.
+	 * @param level - The resistance level (amplifier + 1).
+	 * @return The damage after the reduction.
+	 */
+	#[napi]
+	pub fn resistance_reduction(damage: f64, level: u32) -> f64 {
+		damage * (1.0 - (level as f64 * 0.2).min(1.0))
+	}
+}
@@ -0,0 +1,111 @@
+use napi_derive::napi;
+
+pub(crate) const DAY_LENGTH: f64 = 24000.0; // Ticks in a full day
+
+/**
+ * Brightness of the moon for each of its 8 phases, starting at the full moon.
+ */
+const MOON_BRIGHTNESS: [f64; 8] = [1.0, 0.75, 0.5, 0.25, 0.0, 0.25, 0.5, 0.75];
+
+/**
+ * Day cycle formulas: celestial angle, moon phases and sky darkness, per vanilla.
+ * Times are in ticks, where 0 is sunrise and 6000 is noon.
+ */
+#[napi]
+pub struct Celestial {}
+
+#[napi]
+impl Celestial {
+	/**
+	 * Calculates the celestial angle of the sun for a time of day.
+	 * The angle is 0 at noon, 0.5 at midnight, and is eased around sunrise and sunset.
+	 *
+	 * @param time_of_day - The time of day in ticks.
+	 * @return The angle as a fraction of a full rotation (0..1).
+	 */
+	#[napi]
+	pub fn angle(time_of_day: f64) -> f64 {
+		let d = (time_of_day / DAY_LENGTH - 0.25).rem_euclid(1.0);
+		let e = 0.5 - (d * std::f64::consts::PI).cos() / 2.0;
+
+		(d * 2.0 + e) / 3.0
+	}
+
+	/**
+	 * Calculates the moon phase of a day, 0 being the full moon and 4 the new moon.
+	 *
+	 * @param day - The day number, or the total time in ticks when is_ticks is set.
+	 * @param is_ticks - Whether the value is a time in ticks, defaults to false.
+	 */
+	#[napi]
+	pub fn moon_phase(day: i64, is_ticks: Option<bool>) -> u32 {
+		let day = if is_ticks.unwrap_or(false) { day.div_euclid(DAY_LENGTH as i64) } else { day };
+		day.rem_euclid(8) as u32
+	}
+
+	/**
+	 * Returns the brightness of the moon (0..1) for a moon phase.
+	 *
+	 * @param phase - The moon phase (0..7).
+	 */
+	#[napi]
+	pub fn moon_brightness(phase: u32) -> f64 {
+		MOON_BRIGHTNESS[(phase % 8) as usize]
+	}
+
+	/**
+	 * Calculates how much the sky darkens the world, subtracted from sky light.
+	 *
+	 * @param angle - The celestial angle (0..1).
+	 * @param rain - The rain strength (0..1), defaults to 0.
+	 * @param thunder - The thunder strength (0..1), defaults to 0.
+	 * @return The sky darkness (0..11), 0 during a clear day.
+	 */
+	#[napi]
+	pub fn sky_darkness(angle: f64, rain: Option<f64>, thunder: Option<f64>) -> u32 {
+		((1.0 - Celestial::sky_brightness(angle, rain, thunder)) * 11.0) as u32
+	}
+
+	/**
+	 * Calculates the brightness of the sky (0..1), the inverse of the sky darkness before rounding.
+	 *
+	 * @param angle - The celestial angle (0..1).
+	 * @param rain - The rain strength (0..1), defaults to 0.
+	 * @param thunder - The thunder strength (0..1), defaults to 0.
+	 */
+	#[napi]
+	pub fn sky_brightness(angle: f64, rain: Option<f64>, thunder: Option<f64>) -> f64 {
+		let mut brightness = 1.0 - (1.0 - ((angle * std::f64::consts::TAU).cos() * 2.0 + 0.5)).clamp(0.0, 1.0);
+		brightness *= 1.0 - rain.unwrap_or(0.0).clamp(0.0, 1.0) * 5.0 / 16.0;
+		brightness *= 1.0 - thunder.unwrap_or(0.0).clamp(0.0, 1.0) * 5.0 / 16.0;
+
+		return brightness;
+	}
+
+	/**
+	 * Checks whether it is day, meaning the sky darkness is below 4.
+	 * Undead mobs burn and beds cannot be used during the day.
+	 *
+	 * @param time_of_day - The time of day in ticks.
+	 * @param rain - The rain strength (0..1), defaults to 0.
+	 * @param thunder - The thunder strength (0..1), defaults to 0.
+	 */
+	#[napi]
+	pub fn is_day(time_of_day: f64, rain: Option<f64>, thunder: Option<f64>) -> bool {
+		Celestial::sky_darkness(Celestial::angle(time_of_day), rain, thunder) < 4
+	}
+
+	/**
+	 * Checks whether players can sleep, which is possible at night or during a thunderstorm.
+	 *
+	 * @param time_of_day - The time of day in ticks.
+	 * @param thundering - Whether a thunderstorm is active, defaults to false.
+	 */
+	#[napi]
+	pub fn can_sleep(time_of_day: f64, thundering: Option<bool>) -> bool {
+		if thundering.unwrap_or(false) { return true }
+
+		let time = time_of_day.rem_euclid(DAY_LENGTH);
+		(12542.0..=23459.0).contains(&time)
+	}
+}
@@ -0,0 +1,52 @@
+use napi::bindgen_prelude::Float64Array;
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Target selection for attacks that jump between entities, such as chain lightning.
+ */
+#[napi]
+pub struct Chain {}
+
+#[napi]
+impl Chain {
+	/**
+	 * Computes the order an attack jumps between targets. Every jump goes to the nearest
+	 * target not yet hit, and the chain stops when no target is left within the jump radius.
+	 *
+	 * @param origin - The position the chain starts from (Vector3f).
+	 * @param targets - The candidate positions, packed as x, y, z triplets (Float64Array).
+	 * @param max_jumps - The maximum number of targets hit.
+	 * @param radius - The maximum distance of a single jump.
+	 * @return The indices of the targets hit, in order.
+	 */
+	#[napi]
+	pub fn jumps(origin: VectorLike, targets: Float64Array, max_jumps: u32, radius: f64) -> Vec<u32> {
+		let targets: Vec<Vector3f> = targets
+			.chunks_exact(3)
+			.map(|p| Vector3f::new(p[0], p[1], p[2]))
+			.collect();
+
+		let mut visited = vec![false; targets.len()];
+		let mut order: Vec<u32> = Vec::new();
+		let mut current = origin.clone();
+		let max_distance = radius * radius;
+
+		while order.len() < max_jumps as usize {
+			let nearest = targets.iter()
+				.enumerate()
+				.filter(|(index, _)| !visited[*index])
+				.map(|(index, target)| (index, target.subtract(&current).square_length()))
+				.filter(|(_, distance)| *distance <= max_distance)
+				.min_by(|a, b| a.1.total_cmp(&b.1));
+
+			let Some((index, _)) = nearest else { break };
+			visited[index] = true;
+			order.push(index as u32);
+			current = targets[index].clone();
+		}
+
+		return order;
+	}
+}
@@ -0,0 +1,100 @@
+use napi_derive::napi;
+
+pub(crate) const GRAVITY: f64 = 0.08; // Downwards acceleration of living entities per tick
+pub(crate) const SLOW_FALLING_GRAVITY: f64 = 0.01; // Gravity while under the slow falling effect
+pub(crate) const VERTICAL_DRAG: f64 = 0.98; // Vertical velocity kept per tick in air
+
+/**
+ * Modifiers applied to fall damage.
+ */
+#[napi(object)]
+pub struct FallModifiers {
+	pub jump_boost: Option<u32>, // Jump boost level (amplifier + 1), each level adds a block of safe distance
+	pub multiplier: Option<f64>, // Landing block multiplier, 0.2 for hay bales and honey, 0.5 for beds, 0 for slime
+	pub slow_falling: Option<bool>, // Slow falling negates fall damage entirely
+	pub safe_distance: Option<f64>, // Fall distance that never causes damage, defaults to 3
+}
+
+/**
+ * Vanilla damage formulas for falls and impacts. Damage is in health points (half hearts)
+ * and is applied before armor and enchantment reductions.
+ */
+#[napi]
+pub struct Damage {}
+
+#[napi]
+impl Damage {
+	/**
+	 * Calculates the damage taken from a fall.
+	 *
+	 * @param fall_distance - The distance fallen in blocks.
+	 * @param modifiers - The modifiers of the fall (FallModifiers).
+	 * @return The damage, rounded up like vanilla.
+	 */
+	#[napi]
+	pub fn fall(fall_distance: f64, modifiers: Option<FallModifiers>) -> f64 {
+		let (jump_boost, multiplier, slow_falling, safe_distance) = match modifiers {
+			Some(m) => (
+				m.jump_boost.unwrap_or(0),
+				m.multiplier.unwrap_or(1.0),
+				m.slow_falling.unwrap_or(false),
+				m.safe_distance.unwrap_or(3.0)
+			),
+			None => (0, 1.0, false, 3.0)
+		};
+
+		if slow_falling { return 0.0 }
+		((fall_distance - safe_distance - jump_boost as f64) * multiplier).ceil().max(0.0)
+	}
+
+	/**
+	 * Calculates the distance an entity has fallen to reach a vertical velocity,
+	 * simulating vanilla gravity and drag from rest.
+	 *
+	 * @param vertical_velocity - The vertical velocity at impact in blocks per tick (negative when falling).
+	 * @param slow_falling - Whether the slow falling effect was active, defaults to false.
+	 * @return The fall distance in blocks, 0 for a NaN velocity.
+	 */
+	#[napi]
+	pub fn fall_distance_from_velocity(vertical_velocity: f64, slow_falling: Option<bool>) -> f64 {
+		let gravity = if slow_falling.unwrap_or(false) { SLOW_FALLING_GRAVITY } else { GRAVITY };
+		let target = -vertical_velocity;
+		if target.is_nan() || target <= 0.0 { return 0.0 }
+
+		// Falling speed converges to gravity * drag / (1 - drag), faster impacts cannot come from falling.
+		let terminal = gravity * VERTICAL_DRAG / (1.0 - VERTICAL_DRAG);
+		let target = target.min(terminal * 0.9999);
+
+		let mut speed = 0.0;
+		let mut distance = 0.0;
+		while speed < target {
+			speed = (speed + gravity) * VERTICAL_DRAG;
+			distance += speed.min(target);
+		}
+
+		return distance;
+	}
+
+	/**
+	 * Calculates the fall damage for an impact at a vertical velocity.
+	 *
+	 * @param vertical_velocity - The vertical velocity at impact in blocks per tick (negative when falling).
+	 * @param modifiers - The modifiers of the fall (FallModifiers).
+	 */
+	#[napi]
+	pub fn impact(vertical_velocity: f64, modifiers: Option<FallModifiers>) -> f64 {
+		let slow_falling = modifiers.as_ref().and_then(|m| m.slow_falling);
+		Damage::fall(Damage::fall_distance_from_velocity(vertical_velocity, slow_falling), modifiers)
+	}
+
+	/**
+	 * Calculates the damage of flying into a wall with elytra, from the horizontal speed lost.
+	 *
+	 * @param speed_before - The horizontal speed before the collision in blocks per tick.
+	 * @param speed_after - The horizontal speed after the collision in blocks per tick.
+	 */
+	#[napi]
+	pub fn kinetic(speed_before: f64, speed_after: f64) -> f64 {
+		((speed_before - speed_after) * 10.0 - 3.0).max(0.0)
+	}
+}
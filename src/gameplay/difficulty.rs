@@ -0,0 +1,96 @@
+use napi_derive::napi;
+
+use super::celestial::Celestial;
+use super::hunger::Difficulty;
+
+const MOB_CAP_CHUNKS: f64 = 289.0; // Chunks in the 17x17 area the base mob caps are defined for
+
+/**
+ * Represents the local (regional) difficulty at a position.
+ */
+#[napi(object)]
+pub struct LocalDifficulty {
+	pub effective: f64, // Regional difficulty, 0..6.75
+	pub clamped: f64, // Clamped regional difficulty used by most mechanics, 0..1
+}
+
+/**
+ * Vanilla formulas for local difficulty and mob caps, which gate spawning and equipment chances.
+ */
+#[napi]
+pub struct DifficultyMath {}
+
+#[napi]
+impl DifficultyMath {
+	/**
+	 * Calculates the local difficulty of a chunk.
+	 *
+	 * @param difficulty - The world difficulty (Difficulty).
+	 * @param game_time - The total game time of the world in ticks.
+	 * @param inhabited_time - The ticks players have spent in the chunk.
+	 * @param moon_phase - The current moon phase (0..7), 0 being the full moon.
+	 * @return The effective and clamped local difficulty.
+	 */
+	#[napi]
+	pub fn local(difficulty: Difficulty, game_time: i64, inhabited_time: i64, moon_phase: u32) -> LocalDifficulty {
+		let effective = DifficultyMath::regional(difficulty, game_time, inhabited_time, moon_phase);
+
+		LocalDifficulty {
+			effective,
+			clamped: DifficultyMath::clamp(effective)
+		}
+	}
+
+	/**
+	 * Clamps a regional difficulty: below 2 becomes 0, above 4 becomes 1, linear in between.
+	 *
+	 * @param effective - The regional difficulty.
+	 */
+	#[napi]
+	pub fn clamp(effective: f64) -> f64 {
+		((effective - 2.0) / 2.0).clamp(0.0, 1.0)
+	}
+
+	/**
+	 * Calculates the global cap of a mob category, which scales with the chunks players can spawn mobs in.
+	 *
+	 * @param category_cap - The base cap of the category, for example 70 for monsters.
+	 * @param spawnable_chunks - The number of chunks eligible for spawning.
+	 */
+	#[napi]
+	pub fn mob_cap(category_cap: u32, spawnable_chunks: u32) -> u32 {
+		(category_cap as f64 * spawnable_chunks as f64 / MOB_CAP_CHUNKS) as u32
+	}
+
+	/**
+	 * Checks whether more mobs of a category may spawn.
+	 *
+	 * @param current - The number of mobs of the category currently loaded.
+	 * @param category_cap - The base cap of the category.
+	 * @param spawnable_chunks - The number of chunks eligible for spawning.
+	 */
+	#[napi]
+	pub fn can_spawn(current: u32, category_cap: u32, spawnable_chunks: u32) -> bool {
+		current < DifficultyMath::mob_cap(category_cap, spawnable_chunks)
+	}
+
+	fn regional(difficulty: Difficulty, game_time: i64, inhabited_time: i64, moon_phase: u32) -> f64 {
+		if difficulty == Difficulty::Peaceful { return 0.0 }
+
+		let hard = difficulty == Difficulty::Hard;
+		let time_factor = ((game_time as f64 - 72000.0) / 1440000.0).clamp(0.0, 1.0) * 0.25;
+		let mut chunk_factor = (inhabited_time as f64 / 3600000.0).clamp(0.0, 1.0) * if hard { 1.0 } else { 0.75 };
+		chunk_factor += (Celestial::moon_brightness(moon_phase) * 0.25).clamp(0.0, time_factor);
+
+		if difficulty == Difficulty::Easy { chunk_factor *= 0.5 }
+
+		let level = match difficulty {
+			Difficulty::Peaceful => 0.0,
+			Difficulty::Easy => 1.0,
+			Difficulty::Normal => 2.0,
+			Difficulty::Hard => 3.0
+		};
+
+		level * (0.75 + time_factor + chunk_factor)
+	}
+}
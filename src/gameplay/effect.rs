@@ -0,0 +1,152 @@
+use napi_derive::napi;
+
+use super::damage::{GRAVITY, SLOW_FALLING_GRAVITY, VERTICAL_DRAG};
+
+pub(crate) const JUMP_VELOCITY: f64 = 0.42; // Initial vertical velocity of a jump without effects
+const MINING_FATIGUE_MULTIPLIERS: [f64; 4] = [0.3, 0.09, 0.0027, 0.00081]; // Mining speed multipliers of mining fatigue I to IV
+
+/**
+ * Values derived from status effect levels, using the vanilla formulas.
+ * Levels are the amplifier + 1, so level 1 is "Speed I".
+ */
+#[napi]
+pub struct EffectMath {}
+
+#[napi]
+impl EffectMath {
+	/**
+	 * Calculates the movement speed multiplier of the speed and slowness effects.
+	 * Speed adds 20% per level, slowness removes 15% per level.
+	 *
+	 * @param speed - The speed level.
+	 * @param slowness - The slowness level.
+	 */
+	#[napi]
+	pub fn speed_multiplier(speed: u32, slowness: Option<u32>) -> f64 {
+		((1.0 + 0.2 * speed as f64) * (1.0 - 0.15 * slowness.unwrap_or(0) as f64)).max(0.0)
+	}
+
+	/**
+	 * Calculates the initial vertical velocity of a jump with jump boost.
+	 *
+	 * @param level - The jump boost level.
+	 */
+	#[napi]
+	pub fn jump_velocity(level: u32) -> f64 {
+		JUMP_VELOCITY + 0.1 * level as f64
+	}
+
+	/**
+	 * Calculates the peak height of a jump with jump boost, simulating gravity and drag.
+	 *
+	 * @param level - The jump boost level.
+	 * @return The height in blocks.
+	 */
+	#[napi]
+	pub fn jump_height(level: u32) -> f64 {
+		let mut velocity = EffectMath::jump_velocity(level);
+		let mut height = 0.0;
+
+		while velocity > 0.0 {
+			height += velocity;
+			velocity = (velocity - GRAVITY) * VERTICAL_DRAG;
+		}
+
+		return height;
+	}
+
+	/**
+	 * Returns the gravity applied per tick, which slow falling reduces from 0.08 to 0.01.
+	 *
+	 * @param slow_falling - Whether the slow falling effect is active.
+	 */
+	#[napi]
+	pub fn gravity(slow_falling: bool) -> f64 {
+		if slow_falling { SLOW_FALLING_GRAVITY } else { GRAVITY }
+	}
+
+	/**
+	 * Calculates the vertical velocity after a tick of levitation.
+	 * The velocity approaches 0.05 blocks per tick per level.
+	 *
+	 * @param velocity - The current vertical velocity.
+	 * @param level - The levitation level.
+	 */
+	#[napi]
+	pub fn levitation_velocity(velocity: f64, level: u32) -> f64 {
+		velocity + (0.05 * level as f64 - velocity) * 0.2
+	}
+
+	/**
+	 * Calculates the extra health granted by absorption or health boost, 4 points (2 hearts) per level.
+	 *
+	 * @param level - The effect level.
+	 */
+	#[napi]
+	pub fn bonus_health(level: u32) -> f64 {
+		4.0 * level as f64
+	}
+
+	/**
+	 * Calculates the mining speed multiplier of haste and mining fatigue.
+	 * Haste adds 20% per level, mining fatigue uses vanilla's multipliers, capped at level 4.
+	 *
+	 * @param haste - The haste (or conduit power) level.
+	 * @param mining_fatigue - The mining fatigue level.
+	 */
+	#[napi]
+	pub fn mining_speed_multiplier(haste: u32, mining_fatigue: Option<u32>) -> f64 {
+		let fatigue = mining_fatigue.unwrap_or(0);
+		let fatigue_multiplier = if fatigue == 0 { 1.0 } else { MINING_FATIGUE_MULTIPLIERS[fatigue.min(4) as usize - 1] };
+
+		(1.0 + 0.2 * haste as f64) * fatigue_multiplier
+	}
+
+	/**
+	 * Calculates the extra melee damage of strength (+3 per level) and weakness (-4 per level).
+	 *
+	 * @param strength - The strength level.
+	 * @param weakness - The weakness level.
+	 */
+	#[napi]
+	pub fn attack_bonus(strength: u32, weakness: Option<u32>) -> f64 {
+		3.0 * strength as f64 - 4.0 * weakness.unwrap_or(0) as f64
+	}
+
+	/**
+	 * Calculates the ticks between heals of regeneration.
+	 *
+	 * @param level - The regeneration level.
+	 */
+	#[napi]
+	pub fn regeneration_interval(level: u32) -> u32 {
+		EffectMath::interval(50, level)
+	}
+
+	/**
+	 * Calculates the ticks between damage ticks of poison.
+	 *
+	 * @param level - The poison level.
+	 */
+	#[napi]
+	pub fn poison_interval(level: u32) -> u32 {
+		EffectMath::interval(25, level)
+	}
+
+	/**
+	 * Calculates the ticks between damage ticks of wither.
+	 *
+	 * @param level - The wither level.
+	 */
+	#[napi]
+	pub fn wither_interval(level: u32) -> u32 {
+		EffectMath::interval(40, level)
+	}
+
+	/**
+	 * Halves the base interval for every level above the first, never dropping below a tick.
+	 */
+	fn interval(base: u32, level: u32) -> u32 {
+		base.checked_shr(level.saturating_sub(1)).unwrap_or(0).max(1)
+	}
+}
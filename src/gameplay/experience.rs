@@ -0,0 +1,131 @@
+use napi_derive::napi;
+
+/**
+ * Orb sizes used when splitting experience into orbs, largest first.
+ */
+const ORB_SIZES: [u32; 11] = [2477, 1237, 617, 307, 149, 73, 37, 17, 7, 3, 1];
+
+/**
+ * Represents a level reached with an amount of experience.
+ */
+#[napi(object)]
+pub struct LevelProgress {
+	pub level: u32, // Whole level reached
+	pub progress: f64, // Progress towards the next level (0..1)
+	pub remaining: u32, // Experience still needed for the next level
+}
+
+/**
+ * Experience and level curves, using vanilla's piecewise formulas.
+ */
+#[napi]
+pub struct Experience {}
+
+#[napi]
+impl Experience {
+	/**
+	 * Calculates the experience needed to advance from a level to the next, saturating at u32::MAX.
+	 *
+	 * @param level - The current level.
+	 */
+	#[napi]
+	pub fn xp_for_next_level(level: u32) -> u32 {
+		let level = level as u64;
+
+		let needed = match level {
+			0..=15 => 2 * level + 7,
+			16..=30 => 5 * level - 38,
+			_ => 9 * level - 158
+		};
+
+		needed.min(u32::MAX as u64) as u32
+	}
+
+	/**
+	 * Calculates the total experience needed to reach a level from zero, saturating at u32::MAX.
+	 *
+	 * @param level - The level to reach.
+	 */
+	#[napi]
+	pub fn level_to_total_xp(level: u32) -> u32 {
+		Experience::total_xp(level).min(u32::MAX as u64) as u32
+	}
+
+	/**
+	 * Calculates the total experience needed to reach a level, without saturating at u32::MAX.
+	 * The halved formulas are vanilla's fractional ones, which always land on whole numbers.
+	 */
+	fn total_xp(level: u32) -> u64 {
+		let l = level as u64;
+
+		match level {
+			0..=16 => l * l + 6 * l,
+			17..=31 => (5 * l * l + 720 - 81 * l) / 2,
+			_ => ((l * l).saturating_mul(9).saturating_add(4440) - 325 * l) / 2
+		}
+	}
+
+	/**
+	 * Converts a total amount of experience into the level it reaches.
+	 *
+	 * @param total - The total experience.
+	 * @return The level, the progress towards the next level and the experience still needed.
+	 */
+	#[napi]
+	pub fn xp_to_level(total: u32) -> LevelProgress {
+		let t = total as f64;
+
+		// Invert the quadratic of the matching segment, then correct for rounding.
+		let estimate = if total < Experience::level_to_total_xp(17) {
+			(t + 9.0).sqrt() - 3.0
+		} else if total < Experience::level_to_total_xp(32) {
+			(40.5 + (40.5 * 40.5 - 10.0 * (360.0 - t)).sqrt()) / 5.0
+		} else {
+			(162.5 + (162.5 * 162.5 - 18.0 * (2220.0 - t)).sqrt()) / 9.0
+		};
+
+		// Totals in u32 stay below level 31000, so the corrections compare unsaturated totals and end
+		let mut level = estimate.max(0.0).floor() as u32;
+		while level > 0 && Experience::total_xp(level) > total as u64 { level -= 1 }
+		while Experience::total_xp(level + 1) <= total as u64 { level += 1 }
+
+		let into = total - Experience::level_to_total_xp(level);
+		let needed = Experience::xp_for_next_level(level);
+
+		LevelProgress {
+			level,
+			progress: into as f64 / needed as f64,
+			remaining: needed - into
+		}
+	}
+
+	/**
+	 * Splits an amount of experience into orbs the way vanilla does when dropping it.
+	 *
+	 * @param amount - The experience to split.
+	 * @return The value of every orb, largest first.
+	 */
+	#[napi]
+	pub fn split_orbs(amount: u32) -> Vec<u32> {
+		let mut remaining = amount;
+		let mut orbs: Vec<u32> = Vec::new();
+
+		while remaining > 0 {
+			let orb = Experience::orb_size(remaining);
+			orbs.push(orb);
+			remaining -= orb;
+		}
+
+		return orbs;
+	}
+
+	/**
+	 * Returns the largest orb size that fits into an amount of experience.
+	 *
+	 * @param amount - The experience remaining.
+	 */
+	#[napi]
+	pub fn orb_size(amount: u32) -> u32 {
+		ORB_SIZES.iter().copied().find(|size| *size <= amount).unwrap_or(1)
+	}
+}
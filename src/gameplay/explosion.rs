@@ -0,0 +1,186 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::collision::ray::Raycaster;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const EYE_HEIGHT_RATIO: f64 = 0.85; // Default eye height of an entity relative to its height
+
+/**
+ * Represents the effect of an explosion on an entity.
+ */
+#[napi(object)]
+pub struct ExplosionImpulse {
+	pub velocity: Vector3f, // Velocity to add to the entity
+	pub damage: f64, // Damage before armor and protection, 0 when out of range
+}
+
+/**
+ * Explosion knockback and damage using vanilla's formulas. The exposure of an entity is the fraction
+ * of rays from points spread over its hitbox that reach the center of the explosion unobstructed.
+ */
+#[napi]
+pub struct Explosion {}
+
+#[napi]
+impl Explosion {
+	/**
+	 * Computes the knockback and damage an explosion deals to an entity. Entities are affected within
+	 * twice the power, and are pushed from the center towards their eyes, scaled by the distance
+	 * and the exposure.
+	 *
+	 * @param entity_aabb - The hitbox of the entity (AABB).
+	 * @param explosion_center - The center of the explosion (Vector3f).
+	 * @param power - The power of the explosion, 4 for TNT.
+	 * @param exposure - The fraction of the entity exposed to the explosion (0..1), NaN counting as 0.
+	 * @param eye_height - The eye height of the entity above its feet, defaults to 85% of the hitbox height, also when NaN.
+	 * @param knockback_resistance - The explosion knockback resistance of the entity (0..1), defaults to 0, also when NaN.
+	 * @return The velocity to add and the damage dealt, nothing when the power or the distance is NaN.
+	 */
+	#[napi]
+	pub fn impulse(entity_aabb: &AABB, explosion_center: VectorLike, power: f64, exposure: f64, eye_height: Option<f64>, knockback_resistance: Option<f64>) -> ExplosionImpulse {
+		let bounds = [entity_aabb.min.x, entity_aabb.min.y, entity_aabb.min.z, entity_aabb.max.x, entity_aabb.max.y, entity_aabb.max.z];
+		let (velocity, damage) = Explosion::compute(&bounds, &explosion_center, power, exposure, eye_height, knockback_resistance.unwrap_or(0.0));
+
+		ExplosionImpulse { velocity, damage }
+	}
+
+	/**
+	 * Computes the knockback and damage of an explosion for many entities at once.
+	 *
+	 * @param boxes - The hitboxes of the entities, packed as min x, y, z and max x, y, z (Float64Array).
+	 * @param explosion_center - The center of the explosion (Vector3f).
+	 * @param power - The power of the explosion, 4 for TNT.
+	 * @param exposures - The exposure of every entity, NaN counting as 0 (Float64Array).
+	 * @param knockback_resistance - The explosion knockback resistance of every entity, defaults to 0 for all of them and for NaN.
+	 * @return The velocity to add and the damage of every entity, packed as x, y, z, damage (Float64Array).
+	 */
+	#[napi]
+	pub fn impulse_batch(boxes: Float64Array, explosion_center: VectorLike, power: f64, exposures: Float64Array, knockback_resistance: Option<Vec<f64>>) -> napi::Result<Float64Array> {
+		if !boxes.len().is_multiple_of(6) || boxes.len() / 6 != exposures.len() {
+			return Err(Error::new(Status::InvalidArg, "Expected 6 values per box and an exposure for every box".to_string()));
+		}
+
+		let mut output = Vec::with_capacity(exposures.len() * 4);
+
+		for (index, bounds) in boxes.chunks_exact(6).enumerate() {
+			let resistance = knockback_resistance.as_ref().and_then(|resistance| resistance.get(index).copied()).unwrap_or(0.0);
+			let (velocity, damage) = Explosion::compute(bounds, &explosion_center, power, exposures[index], None, resistance);
+
+			output.extend_from_slice(&[velocity.x, velocity.y, velocity.z, damage]);
+		}
+
+		Ok(Float64Array::new(output))
+	}
+
+	/**
+	 * Returns the points vanilla casts exposure rays from, spread over the hitbox of an entity
+	 * at steps of about half a block.
+	 *
+	 * @param entity_aabb - The hitbox of the entity (AABB).
+	 * @return The points, packed as x, y, z triplets (Float64Array).
+	 * Throws if the hitbox is so large it has more points than an array can hold.
+	 */
+	#[napi]
+	pub fn exposure_samples(entity_aabb: &AABB) -> napi::Result<Float64Array> {
+		Ok(Float64Array::new(Explosion::samples(entity_aabb)?.iter().flat_map(|point| [point.x, point.y, point.z]).collect()))
+	}
+
+	/**
+	 * Computes the exposure of an entity by casting a ray from every sample point to the center
+	 * of the explosion. Blocks are treated as full cubes.
+	 *
+	 * @param entity_aabb - The hitbox of the entity (AABB).
+	 * @param explosion_center - The center of the explosion (Vector3f).
+	 * @param is_solid - Returns whether the block at a position obstructs the explosion.
+	 * @return The fraction of rays that are not obstructed (0..1).
+	 * Throws if the center is not finite, the hitbox is so large it has more points than an array can hold,
+	 * or a ray passes through more blocks than an array can hold.
+	 */
+	#[napi(ts_args_type = "entityAabb: AABB, explosionCenter: VectorLike, isSolid: (position: Vector3f) => boolean")]
+	pub fn exposure(entity_aabb: &AABB, explosion_center: VectorLike, is_solid: JsFunction) -> napi::Result<f64> {
+		// Rays towards a center that is not finite would never finish traversing.
+		if ![explosion_center.x, explosion_center.y, explosion_center.z].iter().all(|c| c.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, format!("Explosion center must be finite, got ({}, {}, {})", explosion_center.x, explosion_center.y, explosion_center.z)));
+		}
+
+		let samples = Explosion::samples(entity_aabb)?;
+		if samples.is_empty() { return Ok(0.0) }
+
+		let mut visible = 0;
+		for sample in samples.iter() {
+			Raycaster::segment(sample, &explosion_center, None)?;
+			let blocked = Raycaster::traverse(sample, &explosion_center, |block| is_solid.call1::<Vector3f, bool>(block.clone()))?;
+			if !blocked { visible += 1 }
+		}
+
+		Ok(visible as f64 / samples.len() as f64)
+	}
+
+	/**
+	 * Computes the velocity and damage from packed bounds.
+	 */
+	fn compute(bounds: &[f64], center: &Vector3f, power: f64, exposure: f64, eye_height: Option<f64>, resistance: f64) -> (Vector3f, f64) {
+		let zero = (Vector3f::new(0.0, 0.0, 0.0), 0.0);
+		let diameter = power * 2.0;
+		if diameter.is_nan() || diameter <= 0.0 { return zero }
+
+		let feet = Vector3f::new((bounds[0] + bounds[3]) * 0.5, bounds[1], (bounds[2] + bounds[5]) * 0.5);
+		let distance = feet.distance(center) / diameter;
+		if distance.is_nan() || distance > 1.0 { return zero }
+
+		let eye = feet.y + eye_height.filter(|height| !height.is_nan()).unwrap_or((bounds[4] - bounds[1]) * EYE_HEIGHT_RATIO);
+		let direction = Vector3f::new(feet.x - center.x, eye - center.y, feet.z - center.z);
+		let length = direction.length();
+		if length == 0.0 || !length.is_finite() { return zero }
+
+		// clamp passes NaN through, so NaN exposures and resistances fall back to 0.
+		let or_zero = |value: f64| if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) };
+		let impact = (1.0 - distance) * or_zero(exposure);
+		let damage = ((impact * impact + impact) / 2.0 * 7.0 * diameter + 1.0).floor();
+
+		let knockback = impact * (1.0 - or_zero(resistance));
+		(direction.multiply(knockback / length), damage)
+	}
+
+	/**
+	 * Builds the grid of exposure sample points, centered on the horizontal axes like vanilla.
+	 */
+	fn samples(aabb: &AABB) -> napi::Result<Vec<Vector3f>> {
+		let step = |min: f64, max: f64| 1.0 / ((max - min) * 2.0 + 1.0);
+		let (sx, sy, sz) = (step(aabb.min.x, aabb.max.x), step(aabb.min.y, aabb.max.y), step(aabb.min.z, aabb.max.z));
+		if !(sx > 0.0 && sy > 0.0 && sz > 0.0) { return Ok(Vec::new()) }
+
+		let offset_x = (1.0 - (1.0 / sx).floor() * sx) / 2.0;
+		let offset_z = (1.0 - (1.0 / sz).floor() * sz) / 2.0;
+		let steps = |size: f64| (1.0 / size).floor() as u32;
+
+		// Every sample is packed as three numbers, which must fit in a single array.
+		let count = (steps(sx) as f64 + 1.0) * (steps(sy) as f64 + 1.0) * (steps(sz) as f64 + 1.0);
+		if count > (u32::MAX / 3) as f64 {
+			return Err(Error::new(Status::InvalidArg, format!("The hitbox has more than {} exposure samples", u32::MAX / 3)));
+		}
+
+		let mut samples = Vec::new();
+		if samples.try_reserve_exact(count as usize).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} exposure samples", count)));
+		}
+
+		for i in 0..=steps(sx) {
+			for j in 0..=steps(sy) {
+				for k in 0..=steps(sz) {
+					let (x, y, z) = (i as f64 * sx, j as f64 * sy, k as f64 * sz);
+					samples.push(Vector3f::new(
+						aabb.min.x + (aabb.max.x - aabb.min.x) * x + offset_x,
+						aabb.min.y + (aabb.max.y - aabb.min.y) * y,
+						aabb.min.z + (aabb.max.z - aabb.min.z) * z + offset_z
+					));
+				}
+			}
+		}
+
+		return Ok(samples);
+	}
+}
@@ -0,0 +1,99 @@
+use napi::bindgen_prelude::Float64Array;
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+#[napi]
+pub enum FalloffKind {
+	Linear, // 1 - d / r
+	Quadratic, // (1 - d / r)^2
+	Smooth // Smoothstep, flat near the center and the edge
+}
+
+/**
+ * Distance falloff kernels for area of effect damage and knockback.
+ * Every kernel is 1 at the center and reaches 0 at the radius.
+ */
+#[napi]
+pub struct Falloff {}
+
+#[napi]
+impl Falloff {
+	/**
+	 * Scales linearly with the distance from the center.
+	 *
+	 * @param center - The center of the effect (Vector3f).
+	 * @param radius - The radius of the effect.
+	 * @param point - The position to scale at (Vector3f).
+	 * @return The scale (0..1).
+	 */
+	#[napi]
+	pub fn linear(center: VectorLike, radius: f64, point: VectorLike) -> f64 {
+		Falloff::kernel(FalloffKind::Linear, Falloff::fraction(&center, radius, &point))
+	}
+
+	/**
+	 * Scales with the square of the linear falloff, dropping quickly away from the center.
+	 *
+	 * @param center - The center of the effect (Vector3f).
+	 * @param radius - The radius of the effect.
+	 * @param point - The position to scale at (Vector3f).
+	 * @return The scale (0..1).
+	 */
+	#[napi]
+	pub fn quadratic(center: VectorLike, radius: f64, point: VectorLike) -> f64 {
+		Falloff::kernel(FalloffKind::Quadratic, Falloff::fraction(&center, radius, &point))
+	}
+
+	/**
+	 * Scales with a smoothstep curve, which eases out at the center and the edge.
+	 *
+	 * @param center - The center of the effect (Vector3f).
+	 * @param radius - The radius of the effect.
+	 * @param point - The position to scale at (Vector3f).
+	 * @return The scale (0..1).
+	 */
+	#[napi]
+	pub fn smooth(center: VectorLike, radius: f64, point: VectorLike) -> f64 {
+		Falloff::kernel(FalloffKind::Smooth, Falloff::fraction(&center, radius, &point))
+	}
+
+	/**
+	 * Applies a falloff to many positions at once, such as every entity caught by an explosion.
+	 *
+	 * @param center - The center of the effect (Vector3f).
+	 * @param radius - The radius of the effect.
+	 * @param points - The positions, packed as x, y, z triplets (Float64Array).
+	 * @param kind - The falloff kernel, defaults to linear (FalloffKind).
+	 * @return The scale of every position, in order (Float64Array).
+	 */
+	#[napi]
+	pub fn batch(center: VectorLike, radius: f64, points: Float64Array, kind: Option<FalloffKind>) -> Float64Array {
+		let kind = kind.unwrap_or(FalloffKind::Linear);
+
+		let scales: Vec<f64> = points
+			.chunks_exact(3)
+			.map(|p| Falloff::kernel(kind, Falloff::fraction(&center, radius, &Vector3f::new(p[0], p[1], p[2]))))
+			.collect();
+
+		Float64Array::new(scales)
+	}
+
+	/**
+	 * Returns the distance from the center as a fraction of the radius, clamped to 1.
+	 */
+	fn fraction(center: &Vector3f, radius: f64, point: &Vector3f) -> f64 {
+		if radius <= 0.0 { return 1.0 }
+		(center.distance(point) / radius).min(1.0)
+	}
+
+	fn kernel(kind: FalloffKind, fraction: f64) -> f64 {
+		let x = 1.0 - fraction;
+
+		match kind {
+			FalloffKind::Linear => x,
+			FalloffKind::Quadratic => x * x,
+			FalloffKind::Smooth => x * x * (3.0 - 2.0 * x)
+		}
+	}
+}
@@ -0,0 +1,102 @@
+use napi_derive::napi;
+
+const SECTION_VOLUME: f64 = 4096.0; // Blocks in a 16x16x16 chunk section
+
+/**
+ * Probability math for random ticks, crop growth and animal aging, so plugins and
+ * the server growth system agree on the numbers.
+ */
+#[napi]
+pub struct GrowthMath {}
+
+#[napi]
+impl GrowthMath {
+	/**
+	 * Calculates the chance that a specific block receives at least one random tick during a game tick.
+	 * Every section picks randomTickSpeed blocks per tick, with replacement.
+	 *
+	 * @param random_tick_speed - The randomTickSpeed game rule, typically 1 on Bedrock and 3 on Java.
+	 * @param section_volume - The number of blocks random ticks are spread over, defaults to 4096.
+	 */
+	#[napi]
+	pub fn random_tick_chance(random_tick_speed: u32, section_volume: Option<f64>) -> f64 {
+		let volume = section_volume.unwrap_or(SECTION_VOLUME).max(1.0);
+		1.0 - (1.0 - 1.0 / volume).powf(random_tick_speed as f64)
+	}
+
+	/**
+	 * Calculates the chance per random tick that a crop advances a stage, where the growth
+	 * factor depends on farmland moisture and neighboring crops (1..10).
+	 *
+	 * @param growth_factor - The growth factor of the crop.
+	 */
+	#[napi]
+	pub fn crop_growth_chance(growth_factor: f64) -> f64 {
+		if growth_factor <= 0.0 { return 0.0 }
+		1.0 / ((25.0 / growth_factor).floor() + 1.0)
+	}
+
+	/**
+	 * Calculates the expected number of game ticks until an event driven by random ticks happens.
+	 *
+	 * @param random_tick_speed - The randomTickSpeed game rule.
+	 * @param success_chance - The chance the event happens when the block is random ticked, defaults to 1.
+	 * @return The expected ticks; infinity when the event cannot happen.
+	 */
+	#[napi]
+	pub fn expected_ticks(random_tick_speed: u32, success_chance: Option<f64>) -> f64 {
+		let chance = GrowthMath::random_tick_chance(random_tick_speed, None) * success_chance.unwrap_or(1.0);
+		if chance <= 0.0 { f64::INFINITY } else { 1.0 / chance }
+	}
+
+	/**
+	 * Calculates the expected ticks for a crop to pass through all of its growth stages.
+	 *
+	 * @param stages - The number of stages still to grow.
+	 * @param random_tick_speed - The randomTickSpeed game rule.
+	 * @param growth_factor - The growth factor of the crop.
+	 */
+	#[napi]
+	pub fn expected_ticks_to_mature(stages: u32, random_tick_speed: u32, growth_factor: f64) -> f64 {
+		stages as f64 * GrowthMath::expected_ticks(random_tick_speed, Some(GrowthMath::crop_growth_chance(growth_factor)))
+	}
+
+	/**
+	 * Calculates the chance that an event with a fixed chance per tick happens at least once within some ticks.
+	 *
+	 * @param chance_per_tick - The chance of the event per tick.
+	 * @param ticks - The number of ticks.
+	 */
+	#[napi]
+	pub fn probability_within(chance_per_tick: f64, ticks: f64) -> f64 {
+		1.0 - (1.0 - chance_per_tick.clamp(0.0, 1.0)).powf(ticks.max(0.0))
+	}
+
+	/**
+	 * Calculates how many ticks pass before an event with a fixed chance per tick has
+	 * happened with the given probability.
+	 *
+	 * @param chance_per_tick - The chance of the event per tick.
+	 * @param probability - The desired probability (0..1).
+	 */
+	#[napi]
+	pub fn ticks_for_probability(chance_per_tick: f64, probability: f64) -> f64 {
+		if chance_per_tick <= 0.0 { return f64::INFINITY }
+		if chance_per_tick >= 1.0 { return 1.0 }
+
+		(1.0 - probability.clamp(0.0, 1.0)).ln() / (1.0 - chance_per_tick).ln()
+	}
+
+	/**
+	 * Calculates the age of a baby animal after it is fed, which skips 10% of its remaining growth time.
+	 *
+	 * @param age - The current age in ticks (negative for babies).
+	 */
+	#[napi]
+	pub fn feed_baby(age: i32) -> i32 {
+		if age >= 0 { return age }
+
+		let seconds = (-(age / 20) as f64 * 0.1) as i32;
+		(age + seconds * 20).min(0)
+	}
+}
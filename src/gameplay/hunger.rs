@@ -0,0 +1,192 @@
+use napi_derive::napi;
+
+const MAX_FOOD: u32 = 20;
+const EXHAUSTION_THRESHOLD: f64 = 4.0; // Exhaustion consumed per point of saturation or food
+const SATURATED_HEAL_INTERVAL: u32 = 10; // Ticks between heals with full food and saturation left
+const HEAL_INTERVAL: u32 = 80; // Ticks between heals or starvation damage otherwise
+
+#[napi]
+#[derive(PartialEq)]
+pub enum Difficulty {
+	Peaceful = 0,
+	Easy = 1,
+	Normal = 2,
+	Hard = 3
+}
+
+#[napi]
+pub enum HungerAction {
+	Swim, // Per block swum
+	Sprint, // Per block sprinted
+	Jump,
+	SprintJump,
+	Attack,
+	Damage, // Per hit taken
+	BreakBlock,
+	Regenerate, // Per health point healed naturally
+	HungerEffect // Per tick per level of the hunger effect
+}
+
+/**
+ * Represents the outcome of a hunger tick.
+ */
+#[napi(object)]
+pub struct HungerTick {
+	pub heal: f64, // Health restored this tick
+	pub damage: f64, // Starvation damage dealt this tick
+}
+
+/**
+ * Tracks food, saturation and exhaustion using vanilla's constants and thresholds.
+ */
+#[napi]
+pub struct Hunger {
+	pub food: u32,
+	pub saturation: f64,
+	pub exhaustion: f64,
+	pub timer: u32 // Ticks since the last heal or starvation damage
+}
+
+#[napi]
+impl Hunger {
+	/**
+	 * Constructs a full hunger state: 20 food and 5 saturation.
+	 */
+	#[napi(constructor)]
+	pub fn new(food: Option<u32>, saturation: Option<f64>) -> Hunger {
+		let food = food.unwrap_or(MAX_FOOD).min(MAX_FOOD);
+
+		Hunger {
+			food,
+			saturation: saturation.unwrap_or(5.0).clamp(0.0, food as f64),
+			exhaustion: 0.0,
+			timer: 0
+		}
+	}
+
+	/**
+	 * Returns the exhaustion caused by an action.
+	 *
+	 * @param action - The action performed (HungerAction).
+	 * @param amount - The blocks travelled, health healed or effect level, defaults to 1.
+	 */
+	#[napi]
+	pub fn action_exhaustion(action: HungerAction, amount: Option<f64>) -> f64 {
+		let amount = amount.unwrap_or(1.0);
+
+		amount * match action {
+			HungerAction::Swim => 0.01,
+			HungerAction::Sprint => 0.1,
+			HungerAction::Jump => 0.05,
+			HungerAction::SprintJump => 0.2,
+			HungerAction::Attack => 0.1,
+			HungerAction::Damage => 0.1,
+			HungerAction::BreakBlock => 0.005,
+			HungerAction::Regenerate => 6.0,
+			HungerAction::HungerEffect => 0.005
+		}
+	}
+
+	/**
+	 * Adds the exhaustion of an action.
+	 *
+	 * @param action - The action performed (HungerAction).
+	 * @param amount - The blocks travelled, health healed or effect level, defaults to 1.
+	 */
+	#[napi]
+	pub fn exhaust(&mut self, action: HungerAction, amount: Option<f64>) {
+		self.add_exhaustion(Hunger::action_exhaustion(action, amount));
+	}
+
+	/**
+	 * Adds raw exhaustion, capped at 40 like vanilla.
+	 */
+	#[napi]
+	pub fn add_exhaustion(&mut self, exhaustion: f64) {
+		self.exhaustion = (self.exhaustion + exhaustion).min(40.0);
+	}
+
+	/**
+	 * Restores food after eating. Saturation gained is nutrition * saturation modifier * 2,
+	 * never exceeding the food level.
+	 *
+	 * @param nutrition - The nutrition of the food.
+	 * @param saturation_modifier - The saturation modifier of the food.
+	 */
+	#[napi]
+	pub fn eat(&mut self, nutrition: u32, saturation_modifier: f64) {
+		self.food = (self.food + nutrition).min(MAX_FOOD);
+		self.saturation = (self.saturation + nutrition as f64 * saturation_modifier * 2.0).min(self.food as f64);
+	}
+
+	/**
+	 * Advances the hunger state by a tick: consumes exhaustion, then applies natural
+	 * regeneration or starvation.
+	 *
+	 * @param health - The current health of the entity.
+	 * @param max_health - The maximum health of the entity.
+	 * @param difficulty - The world difficulty, defaults to normal (Difficulty).
+	 * @param natural_regeneration - Whether the naturalRegeneration game rule is on, defaults to true.
+	 * @return The health restored and the starvation damage dealt this tick.
+	 */
+	#[napi]
+	pub fn tick(&mut self, health: f64, max_health: f64, difficulty: Option<Difficulty>, natural_regeneration: Option<bool>) -> HungerTick {
+		let difficulty = difficulty.unwrap_or(Difficulty::Normal);
+		let regenerate = natural_regeneration.unwrap_or(true);
+		let mut result = HungerTick { heal: 0.0, damage: 0.0 };
+
+		if self.exhaustion > EXHAUSTION_THRESHOLD {
+			self.exhaustion -= EXHAUSTION_THRESHOLD;
+
+			if self.saturation > 0.0 {
+				self.saturation = (self.saturation - 1.0).max(0.0);
+			} else if difficulty != Difficulty::Peaceful {
+				self.food = self.food.saturating_sub(1);
+			}
+		}
+
+		let hurt = health > 0.0 && health < max_health;
+
+		if regenerate && hurt && self.saturation > 0.0 && self.food >= MAX_FOOD {
+			self.timer += 1;
+			if self.timer >= SATURATED_HEAL_INTERVAL {
+				let amount = self.saturation.min(6.0);
+				result.heal = amount / 6.0;
+				self.add_exhaustion(amount);
+				self.timer = 0;
+			}
+		} else if regenerate && hurt && self.food >= 18 {
+			self.timer += 1;
+			if self.timer >= HEAL_INTERVAL {
+				result.heal = 1.0;
+				self.add_exhaustion(Hunger::action_exhaustion(HungerAction::Regenerate, None));
+				self.timer = 0;
+			}
+		} else if self.food == 0 {
+			self.timer += 1;
+			if self.timer >= HEAL_INTERVAL {
+				// Easy stops starving at 5 hearts, normal at half a heart, hard does not stop.
+				let floor = match difficulty {
+					Difficulty::Hard => 0.0,
+					Difficulty::Normal => 1.0,
+					_ => 10.0
+				};
+
+				if health > floor && difficulty != Difficulty::Peaceful { result.damage = 1.0 }
+				self.timer = 0;
+			}
+		} else {
+			self.timer = 0;
+		}
+
+		return result;
+	}
+
+	/**
+	 * Checks whether the entity can sprint, which requires more than 6 food.
+	 */
+	#[napi]
+	pub fn can_sprint(&self) -> bool {
+		self.food > 6
+	}
+}
@@ -0,0 +1,72 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+const MAX_VERTICAL: f64 = 0.4; // Vertical velocity a knockback can raise a grounded entity to
+
+/**
+ * Knockback velocities with vanilla's rules, so several hits in a tick do not stack into a launch.
+ */
+#[napi]
+pub struct Knockback {}
+
+#[napi]
+impl Knockback {
+	/**
+	 * Applies a knockback to a velocity like vanilla: the current velocity is halved and the push
+	 * away from the attacker is added horizontally. Grounded entities are also lifted, up to 0.4.
+	 *
+	 * @param velocity - The current velocity of the entity (Vector3f).
+	 * @param strength - The strength of the knockback, 0.4 for a plain hit.
+	 * @param direction_x - The x direction pointing from the entity towards the attacker.
+	 * @param direction_z - The z direction pointing from the entity towards the attacker.
+	 * @param on_ground - Whether the entity is on the ground.
+	 * @param resistance - The knockback resistance of the entity (0..1), defaults to 0.
+	 * @return The new velocity, unchanged when the strength or resistance is NaN.
+	 */
+	#[napi]
+	pub fn apply(velocity: VectorLike, strength: f64, direction_x: f64, direction_z: f64, on_ground: bool, resistance: Option<f64>) -> Vector3f {
+		let strength = strength * (1.0 - resistance.unwrap_or(0.0).clamp(0.0, 1.0));
+		if strength.is_nan() || strength <= 0.0 { return velocity.clone() }
+
+		let push = Vector3f::new(direction_x, 0.0, direction_z).normalize().multiply(strength);
+		let push = if push.x.is_nan() { Vector3f::new(0.0, 0.0, 0.0) } else { push };
+
+		Vector3f::new(
+			velocity.x / 2.0 - push.x,
+			if on_ground { MAX_VERTICAL.min(velocity.y / 2.0 + strength) } else { velocity.y },
+			velocity.z / 2.0 - push.z
+		)
+	}
+
+	/**
+	 * Combines two knockback impulses received within the same tick instead of summing them.
+	 * The stronger horizontal impulse replaces the weaker one as a whole, keeping a consistent
+	 * direction, and the vertical component is the larger of both.
+	 *
+	 * @param existing - The impulse already accumulated this tick (Vector3f).
+	 * @param incoming - The new impulse (Vector3f).
+	 * @return The combined impulse.
+	 */
+	#[napi]
+	pub fn combine(existing: VectorLike, incoming: VectorLike) -> Vector3f {
+		let horizontal = |v: &Vector3f| v.x * v.x + v.z * v.z;
+		let stronger = if horizontal(&incoming) > horizontal(&existing) { &incoming } else { &existing };
+
+		Vector3f::new(stronger.x, existing.y.max(incoming.y), stronger.z)
+	}
+
+	/**
+	 * Combines any number of knockback impulses of a tick, in order.
+	 *
+	 * @param impulses - The impulses received this tick (Array<Vector3f>).
+	 * @return The combined impulse; zero without impulses.
+	 */
+	#[napi]
+	pub fn combine_all(impulses: Vec<VectorLike>) -> Vector3f {
+		impulses.into_iter().fold(None, |combined: Option<Vector3f>, impulse| Some(match combined {
+			Some(combined) => Knockback::combine(combined.into(), impulse),
+			None => impulse.clone()
+		})).unwrap_or(Vector3f::new(0.0, 0.0, 0.0))
+	}
+}
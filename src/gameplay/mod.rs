@@ -0,0 +1,17 @@
+pub mod attribute;
+pub mod celestial;
+pub mod chain;
+pub mod damage;
+pub mod difficulty;
+pub mod effect;
+pub mod experience;
+pub mod explosion;
+pub mod falloff;
+pub mod growth;
+pub mod hunger;
+pub mod knockback;
+pub mod portal;
+pub mod safe_spot;
+pub mod ticket;
+pub mod vibration;
+pub mod weather;
\ No newline at end of file
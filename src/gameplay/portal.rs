@@ -0,0 +1,113 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const WORLD_BORDER: f64 = 29999984.0; // Furthest block coordinate inside the default world border
+
+#[napi]
+#[derive(PartialEq)]
+pub enum Dimension {
+	Overworld,
+	Nether,
+	End
+}
+
+/**
+ * Nether portal linking math: coordinate scaling between dimensions and the search
+ * for an existing portal around the scaled destination, following vanilla.
+ */
+#[napi]
+pub struct Portal {}
+
+#[napi]
+impl Portal {
+	/**
+	 * Returns how many overworld blocks a block of a dimension spans.
+	 *
+	 * @param dimension - The dimension (Dimension).
+	 */
+	#[napi]
+	pub fn coordinate_scale(dimension: Dimension) -> f64 {
+		if dimension == Dimension::Nether { 8.0 } else { 1.0 }
+	}
+
+	/**
+	 * Scales a position into another dimension, dividing by 8 into the nether and multiplying
+	 * by 8 out of it. The result is clamped inside the world border and floored to a block.
+	 *
+	 * @param position - The position in the source dimension (Vector3f).
+	 * @param from - The source dimension (Dimension).
+	 * @param to - The destination dimension (Dimension).
+	 * @param border - The furthest block coordinate inside the world border, defaults to 29999984.
+	 * @return The block position in the destination dimension, keeping the y coordinate.
+	 * Throws if the border is below 1 or NaN.
+	 */
+	#[napi]
+	pub fn scale(position: VectorLike, from: Dimension, to: Dimension, border: Option<f64>) -> napi::Result<Vector3f> {
+		let factor = Portal::coordinate_scale(from) / Portal::coordinate_scale(to);
+		let border = border.unwrap_or(WORLD_BORDER);
+		if border.is_nan() || border < 1.0 {
+			return Err(Error::new(Status::InvalidArg, format!("The world border must be at least 1, got {}", border)));
+		}
+
+		Ok(Vector3f::new(
+			(position.x * factor).clamp(-border, border - 1.0).floor(),
+			position.y.floor(),
+			(position.z * factor).clamp(-border, border - 1.0).floor()
+		))
+	}
+
+	/**
+	 * Returns the horizontal radius searched for an existing portal: 16 blocks in the nether, 128 elsewhere,
+	 * so both cover the same overworld distance.
+	 *
+	 * @param dimension - The destination dimension (Dimension).
+	 */
+	#[napi]
+	pub fn search_radius(dimension: Dimension) -> u32 {
+		if dimension == Dimension::Nether { 16 } else { 128 }
+	}
+
+	/**
+	 * Builds the box searched for existing portal blocks around a scaled destination.
+	 *
+	 * @param destination - The scaled block position (Vector3f).
+	 * @param dimension - The destination dimension (Dimension).
+	 * @param min_y - The lowest block of the dimension.
+	 * @param max_y - The highest block of the dimension.
+	 */
+	#[napi]
+	pub fn search_box(destination: VectorLike, dimension: Dimension, min_y: f64, max_y: f64) -> AABB {
+		let radius = Portal::search_radius(dimension) as f64;
+
+		AABB::new(
+			Vector3f::new(destination.x - radius, min_y, destination.z - radius).into(),
+			Vector3f::new(destination.x + radius, max_y, destination.z + radius).into()
+		)
+	}
+
+	/**
+	 * Picks the portal block a traveller links to: the closest one within the search radius,
+	 * preferring the lowest on ties.
+	 *
+	 * @param destination - The scaled block position (Vector3f).
+	 * @param dimension - The destination dimension (Dimension).
+	 * @param candidates - The portal block positions found, packed as x, y, z triplets (Float64Array).
+	 * @return The index of the chosen portal block; otherwise, undefined when a new portal is needed.
+	 */
+	#[napi]
+	pub fn closest(destination: VectorLike, dimension: Dimension, candidates: Float64Array) -> Option<u32> {
+		let radius = Portal::search_radius(dimension) as f64;
+
+		candidates.chunks_exact(3)
+			.map(|p| Vector3f::new(p[0], p[1], p[2]))
+			.enumerate()
+			.filter(|(_, p)| (p.x - destination.x).abs() <= radius && (p.z - destination.z).abs() <= radius)
+			.map(|(index, p)| (index, p.subtract(&destination).square_length(), p.y))
+			.min_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+			.map(|(index, _, _)| index as u32)
+	}
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use napi::bindgen_prelude::Uint32Array;
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::shape::volume::VolumeTransform;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const VERTICAL_RANGE: u32 = 8; // Blocks searched above and below the origin by default
+
+type Cell = (i64, i64, i64);
+
+/**
+ * Searches for positions an entity can safely stand at: a 2 block tall air gap
+ * above solid ground. Used by teleport, spawn point and respawn logic.
+ */
+#[napi]
+pub struct SafeSpot {}
+
+#[napi]
+impl SafeSpot {
+	/**
+	 * Finds the safe position closest to an origin, searching columns in rings spiraling outwards.
+	 *
+	 * @param origin - The position to search around (Vector3f).
+	 * @param search_radius - The maximum horizontal distance, in blocks, of the columns searched.
+	 * @param is_solid - Returns whether a block can be stood on and blocks movement. Called at most once per block.
+	 * @param vertical_range - The blocks searched above and below the origin, defaults to 8.
+	 * @return The block position of the feet; otherwise, undefined if no safe position was found.
+	 * Throws if the origin is not finite.
+	 */
+	#[napi(ts_args_type = "origin: VectorLike, searchRadius: number, isSolid: (position: Vector3f) => boolean, verticalRange?: number | undefined | null")]
+	pub fn find(origin: VectorLike, search_radius: u32, is_solid: JsFunction, vertical_range: Option<u32>) -> napi::Result<Option<Vector3f>> {
+		let mut cache: HashMap<Cell, bool> = HashMap::new();
+
+		SafeSpot::search(&origin, search_radius, vertical_range.unwrap_or(VERTICAL_RANGE), |cell| {
+			if let Some(solid) = cache.get(&cell) { return Ok(*solid) }
+
+			let solid: bool = is_solid.call1(Vector3f::new(cell.0 as f64, cell.1 as f64, cell.2 as f64))?;
+			cache.insert(cell, solid);
+			Ok(solid)
+		})
+	}
+
+	/**
+	 * Finds the safe position closest to an origin inside a volume of block ids, where 0 is air
+	 * and every other id is solid. Positions outside the volume are air.
+	 *
+	 * @param data - The block ids of the volume (Uint32Array).
+	 * @param size - The dimensions of the volume (Vector3f).
+	 * @param origin - The position to search around, relative to the volume (Vector3f).
+	 * @param search_radius - The maximum horizontal distance, in blocks, of the columns searched.
+	 * @param vertical_range - The blocks searched above and below the origin, defaults to 8.
+	 * @return The block position of the feet, relative to the volume; otherwise, undefined.
+	 * Throws if the origin is not finite.
+	 */
+	#[napi]
+	pub fn find_in_volume(data: Uint32Array, size: VectorLike, origin: VectorLike, search_radius: u32, vertical_range: Option<u32>) -> napi::Result<Option<Vector3f>> {
+		VolumeTransform::dimensions(&data, &size)?;
+
+		SafeSpot::search(&origin, search_radius, vertical_range.unwrap_or(VERTICAL_RANGE), |cell| {
+			let position = Vector3f::new(cell.0 as f64, cell.1 as f64, cell.2 as f64);
+			Ok(VolumeTransform::index(size.clone().into(), position.into())?.is_some_and(|index| data[index as usize] != 0))
+		})
+	}
+
+	/**
+	 * Checks rings of columns around the origin, closest rings first. A ring can only
+	 * beat the best candidate while its horizontal distance is below the candidate's distance.
+	 */
+	fn search<F: FnMut(Cell) -> napi::Result<bool>>(origin: &Vector3f, radius: u32, vertical: u32, mut solid: F) -> napi::Result<Option<Vector3f>> {
+		if !(origin.x.is_finite() && origin.y.is_finite() && origin.z.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, format!("Origin must be finite, got ({}, {}, {})", origin.x, origin.y, origin.z)));
+		}
+
+		let center: Cell = (origin.x.floor() as i64, origin.y.floor() as i64, origin.z.floor() as i64);
+		let vertical = vertical as i64;
+		let mut best: Option<(f64, Cell)> = None;
+
+		for ring in 0..=radius as i64 {
+			if best.is_some_and(|(distance, _)| distance <= (ring as f64).powi(2)) { break }
+
+			for (dx, dz) in SafeSpot::ring(ring) {
+				for dy in (0..=vertical).flat_map(|dy| if dy == 0 { vec![0] } else { vec![dy, -dy] }) {
+					let distance = (dx as f64).powi(2) + (dy as f64).powi(2) + (dz as f64).powi(2);
+					if best.is_some_and(|(best, _)| best <= distance) { continue }
+
+					// Cells at the edge of the i64 range, searched from huge origins, have no ground below or room above.
+					let (Some(x), Some(y), Some(z)) = (center.0.checked_add(dx), center.1.checked_add(dy), center.2.checked_add(dz)) else { continue };
+					if y == i64::MIN || y == i64::MAX { continue }
+
+					if solid((x, y - 1, z))? && !solid((x, y, z))? && !solid((x, y + 1, z))? {
+						best = Some((distance, (x, y, z)));
+					}
+				}
+			}
+		}
+
+		Ok(best.map(|(_, cell)| Vector3f::new(cell.0 as f64, cell.1 as f64, cell.2 as f64)))
+	}
+
+	/**
+	 * Returns the column offsets at a Chebyshev distance from the center.
+	 */
+	fn ring(ring: i64) -> Vec<(i64, i64)> {
+		if ring == 0 { return vec![(0, 0)] }
+
+		let mut offsets: Vec<(i64, i64)> = Vec::new();
+		for i in -ring..ring {
+			offsets.push((i, -ring));
+			offsets.push((ring, i));
+			offsets.push((-i, ring));
+			offsets.push((-ring, -i));
+		}
+
+		return offsets;
+	}
+}
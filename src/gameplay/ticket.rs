@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use napi::{Error, Status};
+use napi_derive::napi;
+
+const ENTITY_TICKING: u32 = 31; // Highest level at which entities tick
+const BLOCK_TICKING: u32 = 32; // Highest level at which blocks tick
+const FULL: u32 = 33; // Highest level at which a chunk is fully loaded and accessible
+const BORDER: u32 = 44; // Highest level at which a chunk is kept loaded at all
+
+#[napi]
+pub enum ChunkLoadStatus {
+	EntityTicking, // Entities, blocks and the chunk tick
+	BlockTicking, // Blocks tick, entities do not
+	Full, // Loaded and accessible, but not ticked
+	Border, // Loaded for generation of neighbors only
+	Inaccessible // Not loaded
+}
+
+/**
+ * Represents the ticket level of a chunk.
+ */
+#[napi(object)]
+pub struct ChunkLevel {
+	pub x: i32,
+	pub z: i32,
+	pub level: u32, // Lower levels have a higher priority
+}
+
+/**
+ * Propagates chunk ticket levels like vanilla's distance graph: a ticket gives its chunk
+ * its level, and the level grows by one per chunk outwards (in a square), with every chunk
+ * keeping the lowest level reaching it.
+ */
+#[napi]
+pub struct TicketGraph {
+	pub max_level: u32, // Levels above this are not propagated
+	tickets: HashMap<(i32, i32), Vec<u32>>
+}
+
+#[napi]
+impl TicketGraph {
+	/**
+	 * Constructs an empty graph.
+	 *
+	 * @param max_level - The highest level propagated, defaults to 44 (the border level).
+	 */
+	#[napi(constructor)]
+	pub fn new(max_level: Option<u32>) -> TicketGraph {
+		TicketGraph {
+			max_level: max_level.unwrap_or(BORDER),
+			tickets: HashMap::new()
+		}
+	}
+
+	/**
+	 * Returns the ticket level of a player, which makes the chunks within the simulation distance entity ticking.
+	 *
+	 * @param simulation_distance - The simulation distance in chunks.
+	 */
+	#[napi]
+	pub fn player_ticket_level(simulation_distance: u32) -> u32 {
+		ENTITY_TICKING.saturating_sub(simulation_distance)
+	}
+
+	/**
+	 * Returns what a chunk of a level is loaded and ticked for.
+	 *
+	 * @param level - The ticket level.
+	 */
+	#[napi]
+	pub fn status(level: u32) -> ChunkLoadStatus {
+		match level {
+			0..=ENTITY_TICKING => ChunkLoadStatus::EntityTicking,
+			BLOCK_TICKING => ChunkLoadStatus::BlockTicking,
+			FULL => ChunkLoadStatus::Full,
+			34..=BORDER => ChunkLoadStatus::Border,
+			_ => ChunkLoadStatus::Inaccessible
+		}
+	}
+
+	/**
+	 * Adds a ticket to a chunk. A chunk can hold several tickets, even of the same level.
+	 *
+	 * @param x - The x coordinate of the chunk.
+	 * @param z - The z coordinate of the chunk.
+	 * @param level - The level of the ticket.
+	 */
+	#[napi]
+	pub fn add_ticket(&mut self, x: i32, z: i32, level: u32) {
+		self.tickets.entry((x, z)).or_default().push(level);
+	}
+
+	/**
+	 * Removes a ticket of a level from a chunk.
+	 *
+	 * @param x - The x coordinate of the chunk.
+	 * @param z - The z coordinate of the chunk.
+	 * @param level - The level of the ticket.
+	 * @return Whether a ticket was removed.
+	 */
+	#[napi]
+	pub fn remove_ticket(&mut self, x: i32, z: i32, level: u32) -> bool {
+		let Some(levels) = self.tickets.get_mut(&(x, z)) else { return false };
+		let Some(index) = levels.iter().position(|l| *l == level) else { return false };
+
+		levels.swap_remove(index);
+		if levels.is_empty() { self.tickets.remove(&(x, z)); }
+
+		return true;
+	}
+
+	/**
+	 * Removes every ticket.
+	 */
+	#[napi]
+	pub fn clear(&mut self) {
+		self.tickets.clear();
+	}
+
+	/**
+	 * Returns the propagated level of a chunk; otherwise, undefined if no ticket reaches it.
+	 *
+	 * @param x - The x coordinate of the chunk.
+	 * @param z - The z coordinate of the chunk.
+	 */
+	#[napi]
+	pub fn level(&self, x: i32, z: i32) -> Option<u32> {
+		self.sources()
+			.map(|((tx, tz), level)| level.saturating_add(tx.abs_diff(x).max(tz.abs_diff(z))))
+			.filter(|level| *level <= self.max_level)
+			.min()
+	}
+
+	/**
+	 * Propagates every ticket and returns the level of every chunk reached.
+	 *
+	 * @param max_level - Only return chunks at or below this level, such as 33 for loaded chunks.
+	 * Throws if the tickets reach more chunks than an array can hold.
+	 */
+	#[napi]
+	pub fn compute(&self, max_level: Option<u32>) -> napi::Result<Vec<ChunkLevel>> {
+		let max_level = max_level.unwrap_or(self.max_level).min(self.max_level);
+
+		let chunks = self.sources()
+			.filter(|(_, level)| *level <= max_level)
+			.map(|(_, level)| (2 * (max_level - level) as u64 + 1).saturating_pow(2))
+			.fold(0u64, u64::saturating_add);
+		if chunks > u32::MAX as u64 {
+			return Err(Error::new(Status::InvalidArg, format!("Tickets reach more than {} chunks", u32::MAX)));
+		}
+
+		let mut levels: HashMap<(i32, i32), u32> = HashMap::new();
+		if levels.try_reserve(chunks as usize).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for the levels of {} chunks", chunks)));
+		}
+
+		for ((x, z), level) in self.sources() {
+			if level > max_level { continue }
+			let radius = (max_level - level) as i64;
+
+			for dx in -radius..=radius {
+				for dz in -radius..=radius {
+					// Chunks beyond the i32 range, around tickets at its edge, cannot be addressed.
+					let (Ok(cx), Ok(cz)) = (i32::try_from(x as i64 + dx), i32::try_from(z as i64 + dz)) else { continue };
+
+					let reached = level + dx.unsigned_abs().max(dz.unsigned_abs()) as u32;
+					let entry = levels.entry((cx, cz)).or_insert(reached);
+					if reached < *entry { *entry = reached }
+				}
+			}
+		}
+
+		let mut result: Vec<ChunkLevel> = levels.into_iter().map(|((x, z), level)| ChunkLevel { x, z, level }).collect();
+		result.sort_by(|a, b| a.level.cmp(&b.level).then(a.x.cmp(&b.x)).then(a.z.cmp(&b.z)));
+
+		return Ok(result);
+	}
+
+	/**
+	 * Returns the lowest ticket level of every chunk holding tickets.
+	 */
+	fn sources(&self) -> impl Iterator<Item = ((i32, i32), u32)> + '_ {
+		self.tickets.iter().filter_map(|(chunk, levels)| levels.iter().min().map(|level| (*chunk, *level)))
+	}
+}
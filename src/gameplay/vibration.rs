@@ -0,0 +1,83 @@
+use napi::JsFunction;
+use napi_derive::napi;
+
+use crate::collision::ray::Raycaster;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const SENSOR_RANGE: f64 = 8.0; // Listener range of a sculk sensor, calibrated sensors listen to 16
+
+/**
+ * Represents how a vibration reached a listener.
+ */
+#[napi(object)]
+pub struct VibrationResult {
+	pub distance: f64, // Straight line distance from the source to the listener
+	pub dampeners: u32, // Number of dampening blocks along the path
+	pub effective_distance: f64, // Distance after attenuation, infinite when fully occluded
+	pub received: bool, // Whether the listener perceives the vibration
+	pub strength: u32, // Signal strength (1..15) when received; otherwise, 0
+}
+
+/**
+ * Propagation of vibrations and sounds to listeners such as sculk sensors and wardens.
+ * Dampening blocks along the straight line from the source add distance, and by default
+ * fully occlude the vibration like wool does in vanilla.
+ */
+#[napi]
+pub struct Vibration {}
+
+#[napi]
+impl Vibration {
+	/**
+	 * Computes the signal strength of a vibration received at a distance, like the redstone
+	 * output of a sculk sensor.
+	 *
+	 * @param distance - The distance the vibration travelled.
+	 * @param range - The range of the listener, defaults to 8.
+	 * @return The strength (1..15); otherwise, 0 when out of range or either value is NaN.
+	 */
+	#[napi]
+	pub fn strength(distance: f64, range: Option<f64>) -> u32 {
+		let range = range.unwrap_or(SENSOR_RANGE);
+		if range.is_nan() || distance.is_nan() || range <= 0.0 || distance > range { return 0 }
+
+		(15 - (distance / range * 15.0).floor() as i32).clamp(1, 15) as u32
+	}
+
+	/**
+	 * Propagates a vibration from a source to a listener, traversing the blocks along the line
+	 * between them and counting the dampening ones.
+	 *
+	 * @param source - The position the vibration is emitted at (Vector3f).
+	 * @param listener - The position of the listener (Vector3f).
+	 * @param is_dampening - Returns whether the block at a position dampens vibrations.
+	 * @param range - The range of the listener, defaults to 8.
+	 * @param attenuation - The distance added per dampening block, defaults to fully occluding.
+	 * Throws if the line to a listener in range is not finite or passes through more blocks than an array can hold.
+	 */
+	#[napi(ts_args_type = "source: VectorLike, listener: VectorLike, isDampening: (position: Vector3f) => boolean, range?: number, attenuation?: number")]
+	pub fn propagate(source: VectorLike, listener: VectorLike, is_dampening: JsFunction, range: Option<f64>, attenuation: Option<f64>) -> napi::Result<VibrationResult> {
+		let range = range.unwrap_or(SENSOR_RANGE);
+		let distance = source.distance(&listener);
+		let mut dampeners: u32 = 0;
+
+		if distance <= range {
+			Raycaster::segment(&source, &listener, None)?;
+			Raycaster::traverse(&source, &listener, |position| {
+				if is_dampening.call1::<Vector3f, bool>(position.clone())? { dampeners = dampeners.saturating_add(1) }
+
+				// Without attenuation the first dampening block already occludes the vibration
+				Ok(dampeners > 0 && attenuation.is_none())
+			})?;
+		}
+
+		let effective_distance = match attenuation {
+			_ if dampeners == 0 => distance,
+			Some(attenuation) => distance + dampeners as f64 * attenuation.max(0.0),
+			None => f64::INFINITY
+		};
+		let strength = Vibration::strength(effective_distance, Some(range));
+
+		Ok(VibrationResult { distance, dampeners, effective_distance, received: strength > 0, strength })
+	}
+}
@@ -0,0 +1,158 @@
+use napi::JsFunction;
+use napi_derive::napi;
+
+use crate::random::java::JavaRandom;
+use crate::vec3f::Vector3f;
+
+const TRANSITION_RATE: f64 = 0.01; // Change in rain or thunder strength per tick
+const LIGHTNING_BOUND: i32 = 100_000; // Vanilla rolls nextInt(100000) per chunk per tick during a thunderstorm
+const LIGHTNING_CHANCE: f64 = 1.0 / LIGHTNING_BOUND as f64; // Chance per chunk per tick of a strike
+
+/**
+ * Weather math: rain and thunder strength transitions, thunderstorm checks and lightning strikes.
+ * Strengths range from 0 (clear) to 1 (full rain or thunder).
+ */
+#[napi]
+pub struct Weather {}
+
+#[napi]
+impl Weather {
+	/**
+	 * Moves a rain or thunder strength a tick towards its target, like vanilla does every tick.
+	 *
+	 * @param level - The current strength (0..1).
+	 * @param target - The target strength, 1 while it is raining or thundering and 0 otherwise.
+	 * @param rate - The change per tick, defaults to 0.01 when missing or not finite.
+	 */
+	#[napi]
+	pub fn step(level: f64, target: f64, rate: Option<f64>) -> f64 {
+		let rate = Weather::rate(rate);
+		let delta = (target - level).clamp(-rate, rate);
+
+		(level + delta).clamp(0.0, 1.0)
+	}
+
+	/**
+	 * Calculates the strength part way through a transition.
+	 *
+	 * @param from - The strength at the start of the transition.
+	 * @param to - The strength at the end of the transition.
+	 * @param elapsed - The ticks elapsed since the start.
+	 * @param duration - The length of the transition in ticks.
+	 */
+	#[napi]
+	pub fn interpolate(from: f64, to: f64, elapsed: f64, duration: f64) -> f64 {
+		if duration <= 0.0 { return to }
+
+		let t = (elapsed / duration).clamp(0.0, 1.0);
+		from + (to - from) * t
+	}
+
+	/**
+	 * Calculates how many ticks a transition between two strengths takes.
+	 *
+	 * @param from - The current strength.
+	 * @param to - The target strength.
+	 * @param rate - The change per tick, defaults to 0.01 when missing or not finite.
+	 */
+	#[napi]
+	pub fn transition_ticks(from: f64, to: f64, rate: Option<f64>) -> u32 {
+		let rate = Weather::rate(rate);
+		if rate == 0.0 { return 0 }
+
+		((to - from).abs() / rate - 1e-9).ceil().max(0.0) as u32
+	}
+
+	/**
+	 * Returns the effective thunder strength, which vanilla scales by the rain strength.
+	 *
+	 * @param rain - The rain strength.
+	 * @param thunder - The thunder strength.
+	 */
+	#[napi]
+	pub fn thunder_level(rain: f64, thunder: f64) -> f64 {
+		rain * thunder
+	}
+
+	/**
+	 * Checks whether it is raining, meaning the rain strength is above 0.2.
+	 *
+	 * @param rain - The rain strength.
+	 */
+	#[napi]
+	pub fn is_raining(rain: f64) -> bool {
+		rain > 0.2
+	}
+
+	/**
+	 * Checks whether there is a thunderstorm, meaning the effective thunder strength is above 0.9.
+	 *
+	 * @param rain - The rain strength.
+	 * @param thunder - The thunder strength.
+	 */
+	#[napi]
+	pub fn is_thundering(rain: f64, thunder: f64) -> bool {
+		Weather::thunder_level(rain, thunder) > 0.9
+	}
+
+	/**
+	 * Calculates the chance that lightning strikes a chunk at least once during a thunderstorm.
+	 *
+	 * @param ticks - The number of ticks the thunderstorm lasts.
+	 * @param chunks - The number of chunks considered, defaults to 1.
+	 * @param chance - The chance per chunk per tick, defaults to 1 in 100000.
+	 */
+	#[napi]
+	pub fn strike_probability(ticks: f64, chunks: Option<f64>, chance: Option<f64>) -> f64 {
+		let chance = chance.unwrap_or(LIGHTNING_CHANCE).clamp(0.0, 1.0);
+		let trials = ticks.max(0.0) * chunks.unwrap_or(1.0).max(0.0);
+
+		1.0 - (1.0 - chance).powf(trials)
+	}
+
+	/**
+	 * Calculates the expected number of lightning strikes during a thunderstorm.
+	 *
+	 * @param ticks - The number of ticks the thunderstorm lasts.
+	 * @param chunks - The number of chunks considered, defaults to 1.
+	 * @param chance - The chance per chunk per tick, defaults to 1 in 100000.
+	 */
+	#[napi]
+	pub fn expected_strikes(ticks: f64, chunks: Option<f64>, chance: Option<f64>) -> f64 {
+		ticks.max(0.0) * chunks.unwrap_or(1.0).max(0.0) * chance.unwrap_or(LIGHTNING_CHANCE).clamp(0.0, 1.0)
+	}
+
+	/**
+	 * Rolls whether lightning strikes a chunk this tick.
+	 *
+	 * @param random - The random number generator to roll with (JavaRandom).
+	 * @param rain - The rain strength.
+	 * @param thunder - The thunder strength.
+	 */
+	#[napi]
+	pub fn should_strike(random: &mut JavaRandom, rain: f64, thunder: f64) -> bool {
+		Weather::is_thundering(rain, thunder) && random.next_bounded(LIGHTNING_BOUND) == 0
+	}
+
+	/**
+	 * Picks the position of a lightning strike within a chunk: a random column snapped to its heightmap.
+	 *
+	 * @param random - The random number generator to pick the column with (JavaRandom).
+	 * @param chunk_x - The x coordinate of the chunk.
+	 * @param chunk_z - The z coordinate of the chunk.
+	 * @param heightmap - Returns the y of the highest motion blocking block in a column, plus one.
+	 * @return The block position the lightning strikes.
+	 */
+	#[napi(ts_args_type = "random: JavaRandom, chunkX: number, chunkZ: number, heightmap: (x: number, z: number) => number")]
+	pub fn lightning_position(random: &mut JavaRandom, chunk_x: i32, chunk_z: i32, heightmap: JsFunction) -> napi::Result<Vector3f> {
+		let x = chunk_x * 16 + random.next_bounded(16);
+		let z = chunk_z * 16 + random.next_bounded(16);
+		let y: f64 = heightmap.call2(x, z)?;
+
+		return Ok(Vector3f::new(x as f64, y.floor(), z as f64));
+	}
+
+	fn rate(rate: Option<f64>) -> f64 {
+		rate.filter(|rate| rate.is_finite()).unwrap_or(TRANSITION_RATE).abs()
+	}
+}
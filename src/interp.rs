@@ -0,0 +1,230 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Interpolation building blocks for upsampling noise, sampling meshes and evaluating animation curves.
+ * Corner values are named after their offsets, so c101 sits at x = 1, y = 0, z = 1.
+ */
+#[napi]
+pub struct Interp {}
+
+#[napi]
+impl Interp {
+	#[napi]
+	pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+		a + (b - a) * t
+	}
+
+	/**
+	 * Interpolates with a cubic Hermite curve between two values with tangents.
+	 *
+	 * @param p0 - The value at t = 0.
+	 * @param m0 - The tangent at t = 0.
+	 * @param p1 - The value at t = 1.
+	 * @param m1 - The tangent at t = 1.
+	 * @param t - The position along the curve (0..1).
+	 */
+	#[napi]
+	pub fn hermite(p0: f64, m0: f64, p1: f64, m1: f64, t: f64) -> f64 {
+		let (t2, t3) = (t * t, t * t * t);
+
+		(2.0 * t3 - 3.0 * t2 + 1.0) * p0
+			+ (t3 - 2.0 * t2 + t) * m0
+			+ (-2.0 * t3 + 3.0 * t2) * p1
+			+ (t3 - t2) * m1
+	}
+
+	/**
+	 * Interpolates between the four corners of a square.
+	 *
+	 * @param tx - The position along x (0..1).
+	 * @param ty - The position along y (0..1).
+	 */
+	#[napi]
+	pub fn bilinear(c00: f64, c10: f64, c01: f64, c11: f64, tx: f64, ty: f64) -> f64 {
+		Interp::lerp(Interp::lerp(c00, c10, tx), Interp::lerp(c01, c11, tx), ty)
+	}
+
+	/**
+	 * Interpolates between the eight corners of a cube.
+	 *
+	 * @param tx - The position along x (0..1).
+	 * @param ty - The position along y (0..1).
+	 * @param tz - The position along z (0..1).
+	 */
+	#[napi]
+	#[allow(clippy::too_many_arguments)]
+	pub fn trilinear(
+		c000: f64, c100: f64, c010: f64, c110: f64,
+		c001: f64, c101: f64, c011: f64, c111: f64,
+		tx: f64, ty: f64, tz: f64
+	) -> f64 {
+		let near = Interp::bilinear(c000, c100, c010, c110, tx, ty);
+		let far = Interp::bilinear(c001, c101, c011, c111, tx, ty);
+
+		Interp::lerp(near, far, tz)
+	}
+
+	/**
+	 * Calculates the barycentric coordinates of a point relative to a triangle.
+	 * The point is projected onto the plane of the triangle first.
+	 *
+	 * @param point - The point (Vector3f).
+	 * @param a - The first corner of the triangle (Vector3f).
+	 * @param b - The second corner of the triangle (Vector3f).
+	 * @param c - The third corner of the triangle (Vector3f).
+	 * @return The weights of a, b and c as x, y and z, summing to 1; NaN for degenerate triangles.
+	 */
+	#[napi]
+	pub fn barycentric(point: VectorLike, a: VectorLike, b: VectorLike, c: VectorLike) -> Vector3f {
+		let (ab, ac, ap) = (b.subtract(&a), c.subtract(&a), point.subtract(&a));
+		let (d00, d01, d11) = (ab.dot(&ab), ab.dot(&ac), ac.dot(&ac));
+		let (d20, d21) = (ap.dot(&ab), ap.dot(&ac));
+		let denominator = d00 * d11 - d01 * d01;
+
+		let v = (d11 * d20 - d01 * d21) / denominator;
+		let w = (d00 * d21 - d01 * d20) / denominator;
+
+		Vector3f::new(1.0 - v - w, v, w)
+	}
+
+	/**
+	 * Interpolates values given at the corners of a triangle at a point.
+	 *
+	 * @param point - The point (Vector3f).
+	 * @param a - The first corner of the triangle (Vector3f).
+	 * @param b - The second corner of the triangle (Vector3f).
+	 * @param c - The third corner of the triangle (Vector3f).
+	 * @param value_a - The value at a.
+	 * @param value_b - The value at b.
+	 * @param value_c - The value at c.
+	 */
+	#[napi]
+	pub fn triangle(point: VectorLike, a: VectorLike, b: VectorLike, c: VectorLike, value_a: f64, value_b: f64, value_c: f64) -> f64 {
+		let weights = Interp::barycentric(point, a, b, c);
+		weights.x * value_a + weights.y * value_b + weights.z * value_c
+	}
+}
+
+/**
+ * A natural cubic spline fitted through scalar keyframes. The curve passes through every keyframe
+ * with continuous first and second derivatives, and has no curvature at both ends.
+ */
+#[napi]
+pub struct CubicSpline {
+	times: Vec<f64>,
+	values: Vec<f64>,
+	curvatures: Vec<f64> // Second derivative of the curve at every keyframe
+}
+
+#[napi]
+impl CubicSpline {
+	/**
+	 * Fits a spline through keyframes.
+	 *
+	 * @param times - The times of the keyframes, strictly increasing.
+	 * @param values - The value at every keyframe.
+	 * Throws if a time or value is not finite, or the keyframes span more time than a number can hold.
+	 */
+	#[napi(constructor)]
+	pub fn new(times: Vec<f64>, values: Vec<f64>) -> napi::Result<Self> {
+		if times.len() != values.len() || times.len() < 2 {
+			return Err(Error::new(Status::InvalidArg, "Expected at least 2 keyframes with a value for every time".to_string()));
+		}
+
+		// A single NaN would spread through the fit to every segment of the curve.
+		if !times.iter().chain(values.iter()).all(|value| value.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, "Keyframe times and values must be finite".to_string()));
+		}
+
+		if times.windows(2).any(|pair| pair[1] <= pair[0]) {
+			return Err(Error::new(Status::InvalidArg, "Keyframe times must be strictly increasing".to_string()));
+		}
+
+		if !(times[times.len() - 1] - times[0]).is_finite() {
+			return Err(Error::new(Status::InvalidArg, "Keyframes must not span more time than a number can hold".to_string()));
+		}
+
+		let curvatures = CubicSpline::fit(&times, &values);
+		Ok(CubicSpline { times, values, curvatures })
+	}
+
+	/**
+	 * Evaluates the spline. Times outside the keyframes hold the first or last value.
+	 *
+	 * @param t - The time to evaluate at.
+	 */
+	#[napi]
+	pub fn evaluate(&self, t: f64) -> f64 {
+		let last = self.times.len() - 1;
+		if t <= self.times[0] { return self.values[0] }
+		if t >= self.times[last] { return self.values[last] }
+
+		let (i, h, a, b) = self.segment(t);
+		let (m0, m1) = (self.curvatures[i], self.curvatures[i + 1]);
+
+		a * self.values[i] + b * self.values[i + 1] + ((a * a * a - a) * m0 + (b * b * b - b) * m1) * h * h / 6.0
+	}
+
+	/**
+	 * Evaluates the slope of the spline, 0 outside the keyframes.
+	 *
+	 * @param t - The time to evaluate at.
+	 */
+	#[napi]
+	pub fn derivative(&self, t: f64) -> f64 {
+		let last = self.times.len() - 1;
+		if t < self.times[0] || t > self.times[last] { return 0.0 }
+
+		let (i, h, a, b) = self.segment(t);
+		let (m0, m1) = (self.curvatures[i], self.curvatures[i + 1]);
+
+		(self.values[i + 1] - self.values[i]) / h - (3.0 * a * a - 1.0) * h * m0 / 6.0 + (3.0 * b * b - 1.0) * h * m1 / 6.0
+	}
+
+	/**
+	 * Returns the segment containing a time, its duration and the weights of its start and end.
+	 */
+	fn segment(&self, t: f64) -> (usize, f64, f64, f64) {
+		let i = self.times.partition_point(|time| *time <= t).clamp(1, self.times.len() - 1) - 1;
+		let h = self.times[i + 1] - self.times[i];
+		let b = (t - self.times[i]) / h;
+
+		(i, h, 1.0 - b, b)
+	}
+
+	/**
+	 * Solves the tridiagonal system for the second derivatives, which are zero at both ends.
+	 */
+	fn fit(times: &[f64], values: &[f64]) -> Vec<f64> {
+		let n = times.len();
+		let mut curvatures = vec![0.0; n];
+		if n < 3 { return curvatures }
+
+		let h = |i: usize| times[i + 1] - times[i];
+		let slope = |i: usize| (values[i + 1] - values[i]) / h(i);
+
+		// Forward elimination (Thomas algorithm) over the interior keyframes.
+		let mut diagonal = vec![0.0; n];
+		let mut rhs = vec![0.0; n];
+
+		for i in 1..n - 1 {
+			diagonal[i] = 2.0 * (h(i - 1) + h(i));
+			rhs[i] = 6.0 * (slope(i) - slope(i - 1));
+
+			if i > 1 {
+				let factor = h(i - 1) / diagonal[i - 1];
+				diagonal[i] -= factor * h(i - 1);
+				rhs[i] -= factor * rhs[i - 1];
+			}
+		}
+
+		for i in (1..n - 1).rev() {
+			curvatures[i] = (rhs[i] - h(i) * curvatures[i + 1]) / diagonal[i];
+		}
+
+		return curvatures;
+	}
+}
@@ -0,0 +1,279 @@
+use napi::bindgen_prelude::ClassInstance;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::quaternion::Quaternion;
+use crate::vec3f::{Vector3f, VectorLike};
+
+#[napi]
+#[derive(PartialEq)]
+pub enum KeyframeInterpolation {
+	Linear, // Straight interpolation between keyframes, slerp for rotations
+	Step, // Holds the value of a keyframe until the next one
+	CatmullRom // Smooth curve passing through every keyframe
+}
+
+#[napi]
+#[derive(PartialEq)]
+pub enum PlaybackMode {
+	Hold, // Plays once and holds the first and last values outside the track
+	Loop, // Restarts from the first keyframe after the last one
+	PingPong // Plays forwards, then backwards
+}
+
+/**
+ * A value that can be animated by a keyframe track.
+ */
+trait Keyframe: Clone {
+	fn lerp(a: &Self, b: &Self, t: f64) -> Self;
+
+	fn catmull_rom(p0: &Self, p1: &Self, p2: &Self, p3: &Self, t: f64) -> Self;
+}
+
+impl Keyframe for f64 {
+	fn lerp(a: &f64, b: &f64, t: f64) -> f64 {
+		a + (b - a) * t
+	}
+
+	fn catmull_rom(p0: &f64, p1: &f64, p2: &f64, p3: &f64, t: f64) -> f64 {
+		let (t2, t3) = (t * t, t * t * t);
+		0.5 * (2.0 * p1 + (p2 - p0) * t + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2 + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+	}
+}
+
+impl Keyframe for Vector3f {
+	fn lerp(a: &Vector3f, b: &Vector3f, t: f64) -> Vector3f {
+		a.lerp(b, t)
+	}
+
+	fn catmull_rom(p0: &Vector3f, p1: &Vector3f, p2: &Vector3f, p3: &Vector3f, t: f64) -> Vector3f {
+		Vector3f::new(
+			f64::catmull_rom(&p0.x, &p1.x, &p2.x, &p3.x, t),
+			f64::catmull_rom(&p0.y, &p1.y, &p2.y, &p3.y, t),
+			f64::catmull_rom(&p0.z, &p1.z, &p2.z, &p3.z, t)
+		)
+	}
+}
+
+impl Keyframe for Quaternion {
+	fn lerp(a: &Quaternion, b: &Quaternion, t: f64) -> Quaternion {
+		a.slerp(b, t)
+	}
+
+	fn catmull_rom(p0: &Quaternion, p1: &Quaternion, p2: &Quaternion, p3: &Quaternion, t: f64) -> Quaternion {
+		// Align the neighbors with p1, q and -q being the same rotation, then interpolate the components.
+		let align = |q: &Quaternion| if q.dot(p1) < 0.0 { Quaternion::new(-q.x, -q.y, -q.z, -q.w) } else { q.clone() };
+		let (p0, p2, p3) = (align(p0), align(p2), align(p3));
+		let component = |f: fn(&Quaternion) -> f64| f64::catmull_rom(&f(&p0), &f(p1), &f(&p2), &f(&p3), t);
+
+		Quaternion::new(component(|q| q.x), component(|q| q.y), component(|q| q.z), component(|q| q.w)).normalize()
+	}
+}
+
+/**
+ * Keyframes sampled by time, shared by the tracks of every value type.
+ */
+struct Track<T: Keyframe> {
+	times: Vec<f64>,
+	values: Vec<T>,
+	interpolation: KeyframeInterpolation,
+	mode: PlaybackMode
+}
+
+impl<T: Keyframe> Track<T> {
+	fn new(times: Vec<f64>, values: Vec<T>, interpolation: Option<KeyframeInterpolation>, mode: Option<PlaybackMode>) -> napi::Result<Track<T>> {
+		if times.is_empty() || times.len() != values.len() {
+			return Err(Error::new(Status::InvalidArg, "Expected at least 1 keyframe with a value for every time".to_string()));
+		}
+
+		if !times.iter().all(|time| time.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, "Keyframe times must be finite".to_string()));
+		}
+
+		if times.windows(2).any(|pair| pair[1] <= pair[0]) {
+			return Err(Error::new(Status::InvalidArg, "Keyframe times must be strictly increasing".to_string()));
+		}
+
+		if !(times[times.len() - 1] - times[0]).is_finite() {
+			return Err(Error::new(Status::InvalidArg, "Keyframes must not span more time than a number can hold".to_string()));
+		}
+
+		Ok(Track {
+			times,
+			values,
+			interpolation: interpolation.unwrap_or(KeyframeInterpolation::Linear),
+			mode: mode.unwrap_or(PlaybackMode::Hold)
+		})
+	}
+
+	fn duration(&self) -> f64 {
+		self.times[self.times.len() - 1] - self.times[0]
+	}
+
+	// Maps a playback time onto the time range of the keyframes.
+	fn local_time(&self, time: f64) -> f64 {
+		let (start, duration) = (self.times[0], self.duration());
+		if duration <= 0.0 { return start }
+
+		// NaN, and infinite times in looping modes, have no place on the track.
+		let elapsed = time - start;
+		if elapsed.is_nan() || (elapsed.is_infinite() && self.mode != PlaybackMode::Hold) { return start }
+
+		start + match self.mode {
+			PlaybackMode::Hold => elapsed.clamp(0.0, duration),
+			PlaybackMode::Loop => elapsed.rem_euclid(duration),
+			PlaybackMode::PingPong => {
+				let phase = elapsed.rem_euclid(duration * 2.0);
+				if phase > duration { duration * 2.0 - phase } else { phase }
+			}
+		}
+	}
+
+	fn sample(&self, time: f64) -> T {
+		let time = self.local_time(time);
+		let last = self.times.len() - 1;
+
+		let i = self.times.partition_point(|keyframe| *keyframe <= time).clamp(1, last.max(1)) - 1;
+		if i >= last { return self.values[last].clone() }
+
+		let t = ((time - self.times[i]) / (self.times[i + 1] - self.times[i])).clamp(0.0, 1.0);
+
+		match self.interpolation {
+			KeyframeInterpolation::Step => self.values[if t >= 1.0 { i + 1 } else { i }].clone(),
+			KeyframeInterpolation::Linear => T::lerp(&self.values[i], &self.values[i + 1], t),
+			KeyframeInterpolation::CatmullRom => {
+				let previous = &self.values[i.saturating_sub(1)];
+				let next = &self.values[(i + 2).min(last)];
+				T::catmull_rom(previous, &self.values[i], &self.values[i + 1], next, t)
+			}
+		}
+	}
+}
+
+/**
+ * Samples an animated number, such as a scale factor or an opacity, by time.
+ */
+#[napi]
+pub struct NumberTrack {
+	track: Track<f64>
+}
+
+#[napi]
+impl NumberTrack {
+	/**
+	 * Constructs a track from keyframes.
+	 *
+	 * @param times - The times of the keyframes, finite and strictly increasing.
+	 * @param values - The value at every keyframe.
+	 * @param interpolation - How values between keyframes are computed, defaults to linear (KeyframeInterpolation).
+	 * @param mode - What happens past the last keyframe, defaults to hold (PlaybackMode).
+	 */
+	#[napi(constructor)]
+	pub fn new(times: Vec<f64>, values: Vec<f64>, interpolation: Option<KeyframeInterpolation>, mode: Option<PlaybackMode>) -> napi::Result<Self> {
+		Ok(NumberTrack { track: Track::new(times, values, interpolation, mode)? })
+	}
+
+	/**
+	 * Samples the value of the track at a time.
+	 *
+	 * @param time - The playback time, sampling the first keyframe when it is NaN.
+	 */
+	#[napi]
+	pub fn sample(&self, time: f64) -> f64 {
+		self.track.sample(time)
+	}
+
+	/**
+	 * Returns the time between the first and the last keyframe.
+	 */
+	#[napi]
+	pub fn duration(&self) -> f64 {
+		self.track.duration()
+	}
+}
+
+/**
+ * Samples an animated vector, such as a position or a scale, by time.
+ */
+#[napi]
+pub struct VectorTrack {
+	track: Track<Vector3f>
+}
+
+#[napi]
+impl VectorTrack {
+	/**
+	 * Constructs a track from keyframes.
+	 *
+	 * @param times - The times of the keyframes, finite and strictly increasing.
+	 * @param values - The vector at every keyframe (Array<Vector3f>).
+	 * @param interpolation - How values between keyframes are computed, defaults to linear (KeyframeInterpolation).
+	 * @param mode - What happens past the last keyframe, defaults to hold (PlaybackMode).
+	 */
+	#[napi(constructor)]
+	pub fn new(times: Vec<f64>, values: Vec<VectorLike>, interpolation: Option<KeyframeInterpolation>, mode: Option<PlaybackMode>) -> napi::Result<Self> {
+		let values = values.iter().map(|value| (**value).clone()).collect();
+		Ok(VectorTrack { track: Track::new(times, values, interpolation, mode)? })
+	}
+
+	/**
+	 * Samples the vector of the track at a time.
+	 *
+	 * @param time - The playback time, sampling the first keyframe when it is NaN.
+	 */
+	#[napi]
+	pub fn sample(&self, time: f64) -> Vector3f {
+		self.track.sample(time)
+	}
+
+	/**
+	 * Returns the time between the first and the last keyframe.
+	 */
+	#[napi]
+	pub fn duration(&self) -> f64 {
+		self.track.duration()
+	}
+}
+
+/**
+ * Samples an animated rotation by time. Linear interpolation uses slerp.
+ */
+#[napi]
+pub struct QuaternionTrack {
+	track: Track<Quaternion>
+}
+
+#[napi]
+impl QuaternionTrack {
+	/**
+	 * Constructs a track from keyframes.
+	 *
+	 * @param times - The times of the keyframes, finite and strictly increasing.
+	 * @param values - The rotation at every keyframe (Array<Quaternion>).
+	 * @param interpolation - How values between keyframes are computed, defaults to linear (KeyframeInterpolation).
+	 * @param mode - What happens past the last keyframe, defaults to hold (PlaybackMode).
+	 */
+	#[napi(constructor)]
+	pub fn new(times: Vec<f64>, values: Vec<ClassInstance<Quaternion>>, interpolation: Option<KeyframeInterpolation>, mode: Option<PlaybackMode>) -> napi::Result<Self> {
+		let values = values.iter().map(|value| (**value).clone()).collect();
+		Ok(QuaternionTrack { track: Track::new(times, values, interpolation, mode)? })
+	}
+
+	/**
+	 * Samples the rotation of the track at a time.
+	 *
+	 * @param time - The playback time, sampling the first keyframe when it is NaN.
+	 */
+	#[napi]
+	pub fn sample(&self, time: f64) -> Quaternion {
+		self.track.sample(time)
+	}
+
+	/**
+	 * Returns the time between the first and the last keyframe.
+	 */
+	#[napi]
+	pub fn duration(&self) -> f64 {
+		self.track.duration()
+	}
+}
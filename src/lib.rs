@@ -1,2 +1,38 @@
+#![allow(clippy::needless_return)]
+
+pub mod vec2f;
 pub mod vec3f;
-pub mod collision;
\ No newline at end of file
+pub mod vec3i;
+pub mod mat3;
+pub mod mat4;
+pub mod matrix_stack;
+pub mod quaternion;
+pub mod rect;
+pub mod rot2;
+pub mod ai;
+pub mod axis_angle;
+pub mod audio;
+pub mod basis;
+pub mod buffer;
+pub mod camera;
+pub mod cluster;
+pub mod collision;
+pub mod config;
+pub mod coordinates;
+pub mod euler;
+pub mod filter;
+pub mod gameplay;
+pub mod interp;
+pub mod keyframe;
+pub mod network;
+pub mod noise;
+pub mod optimize;
+pub mod physics;
+pub mod points;
+pub mod pool;
+pub mod random;
+pub mod rotation;
+pub mod shape;
+pub mod spline;
+pub mod tick;
+pub mod ui;
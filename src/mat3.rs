@@ -0,0 +1,207 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::mat4::Matrix4;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Represents a 3x3 matrix, typically a rotation or a linear transform without translation.
+ * Elements are stored in column-major order, like Matrix4.
+ */
+#[napi(js_name = "Matrix3")]
+#[derive(Clone, Debug)]
+pub struct Matrix3 {
+	pub(crate) elements: Vec<f64> // Always 9 elements, enforced by the constructor and setter
+}
+
+#[napi]
+impl Matrix3 {
+	/**
+	 * Constructs a matrix from 9 column-major elements.
+	 *
+	 * @param elements - The matrix elements, defaults to the identity matrix.
+	 */
+	#[napi(constructor)]
+	pub fn new(elements: Option<Vec<f64>>) -> napi::Result<Self> {
+		match elements {
+			None => Ok(Matrix3::identity()),
+			Some(elements) if elements.len() == 9 => Ok(Matrix3 { elements }),
+			Some(elements) => Err(Error::new(
+				Status::InvalidArg,
+				format!("Matrix3 requires 9 elements, got {}", elements.len())
+			))
+		}
+	}
+
+	#[napi(getter)]
+	pub fn elements(&self) -> Vec<f64> {
+		self.elements.clone()
+	}
+
+	/**
+	 * Replaces the elements of the matrix.
+	 *
+	 * @param elements - The 9 column-major elements.
+	 */
+	#[napi(setter, js_name = "elements")]
+	pub fn set_elements(&mut self, elements: Vec<f64>) -> napi::Result<()> {
+		*self = Matrix3::new(Some(elements))?;
+		Ok(())
+	}
+
+	/**
+	 * Creates the identity matrix.
+	 */
+	#[napi]
+	pub fn identity() -> Matrix3 {
+		Matrix3 {
+			elements: vec![
+				1.0, 0.0, 0.0,
+				0.0, 1.0, 0.0,
+				0.0, 0.0, 1.0
+			]
+		}
+	}
+
+	/**
+	 * Creates a matrix from its three columns.
+	 *
+	 * @param x - The first column, the image of the X axis (Vector3f).
+	 * @param y - The second column, the image of the Y axis (Vector3f).
+	 * @param z - The third column, the image of the Z axis (Vector3f).
+	 */
+	#[napi]
+	pub fn from_columns(x: VectorLike, y: VectorLike, z: VectorLike) -> Matrix3 {
+		Matrix3 { elements: vec![x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z] }
+	}
+
+	/**
+	 * Extracts the upper left 3x3 block of a 4x4 matrix.
+	 *
+	 * @param matrix - The matrix to extract from (Matrix4).
+	 */
+	#[napi]
+	pub fn from_matrix4(matrix: &Matrix4) -> Matrix3 {
+		let m = &matrix.elements;
+		Matrix3 { elements: vec![m[0], m[1], m[2], m[4], m[5], m[6], m[8], m[9], m[10]] }
+	}
+
+	/**
+	 * Expands this matrix into a 4x4 matrix without translation.
+	 */
+	#[napi]
+	pub fn to_matrix4(&self) -> Matrix4 {
+		let m = &self.elements;
+
+		Matrix4 {
+			elements: vec![
+				m[0], m[1], m[2], 0.0,
+				m[3], m[4], m[5], 0.0,
+				m[6], m[7], m[8], 0.0,
+				0.0, 0.0, 0.0, 1.0
+			]
+		}
+	}
+
+	/**
+	 * Returns an element of the matrix.
+	 *
+	 * @param row - The row (0..3).
+	 * @param column - The column (0..3).
+	 * Throws if the row or column is out of range.
+	 */
+	#[napi]
+	pub fn get(&self, row: u32, column: u32) -> napi::Result<f64> {
+		if row >= 3 || column >= 3 {
+			return Err(Error::new(Status::InvalidArg, format!("Element ({}, {}) is outside of a 3x3 matrix", row, column)));
+		}
+
+		Ok(self.elements[(column * 3 + row) as usize])
+	}
+
+	/**
+	 * Multiplies this matrix with another matrix.
+	 *
+	 * @param other - The right hand side matrix (Matrix3).
+	 * @return The product of both matrices.
+	 */
+	#[napi]
+	pub fn multiply(&self, other: &Matrix3) -> Matrix3 {
+		let a = &self.elements;
+		let b = &other.elements;
+		let mut elements = vec![0.0; 9];
+
+		for column in 0..3 {
+			for row in 0..3 {
+				elements[column * 3 + row] = (0..3).map(|k| a[k * 3 + row] * b[column * 3 + k]).sum();
+			}
+		}
+
+		Matrix3 { elements }
+	}
+
+	/**
+	 * Transforms a vector by this matrix.
+	 *
+	 * @param v - The vector to transform (Vector3f).
+	 * @return The transformed vector.
+	 */
+	#[napi]
+	pub fn transform(&self, v: VectorLike) -> Vector3f {
+		let m = &self.elements;
+
+		Vector3f::new(
+			m[0] * v.x + m[3] * v.y + m[6] * v.z,
+			m[1] * v.x + m[4] * v.y + m[7] * v.z,
+			m[2] * v.x + m[5] * v.y + m[8] * v.z
+		)
+	}
+
+	/**
+	 * Returns the transpose of this matrix, which is the inverse of a rotation matrix.
+	 */
+	#[napi]
+	pub fn transpose(&self) -> Matrix3 {
+		let m = &self.elements;
+		Matrix3 { elements: vec![m[0], m[3], m[6], m[1], m[4], m[7], m[2], m[5], m[8]] }
+	}
+
+	/**
+	 * Calculates the determinant of this matrix.
+	 */
+	#[napi]
+	pub fn determinant(&self) -> f64 {
+		let m = &self.elements;
+
+		m[0] * (m[4] * m[8] - m[7] * m[5])
+			- m[3] * (m[1] * m[8] - m[7] * m[2])
+			+ m[6] * (m[1] * m[5] - m[4] * m[2])
+	}
+
+	/**
+	 * Calculates the inverse of this matrix.
+	 *
+	 * @return The inverse matrix; otherwise, undefined if the matrix is singular.
+	 */
+	#[napi]
+	pub fn inverse(&self) -> Option<Matrix3> {
+		let determinant = self.determinant();
+		let m = &self.elements;
+
+		// Only an exactly zero determinant is singular, any tolerance would depend on the scale
+		if determinant == 0.0 || !determinant.is_finite() { return None }
+
+		let adjugate = [
+			m[4] * m[8] - m[7] * m[5], m[7] * m[2] - m[1] * m[8], m[1] * m[5] - m[4] * m[2],
+			m[6] * m[5] - m[3] * m[8], m[0] * m[8] - m[6] * m[2], m[3] * m[2] - m[0] * m[5],
+			m[3] * m[7] - m[6] * m[4], m[6] * m[1] - m[0] * m[7], m[0] * m[4] - m[3] * m[1]
+		];
+
+		Some(Matrix3 { elements: adjugate.iter().map(|c| c / determinant).collect() })
+	}
+
+	#[napi]
+	pub fn equals(&self, other: &Matrix3) -> bool {
+		self.elements == other.elements
+	}
+}
@@ -0,0 +1,398 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::mat3::Matrix3;
+use crate::quaternion::Quaternion;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Represents a transform split into its translation, rotation and scale.
+ */
+#[napi(object, object_from_js = false)]
+pub struct Decomposition {
+	pub translation: Vector3f,
+	pub rotation: Quaternion,
+	pub scale: Vector3f,
+}
+
+/**
+ * Represents a 4x4 transformation matrix.
+ * Elements are stored in column-major order, matching WebGL and glTF.
+ */
+#[napi(js_name = "Matrix4")]
+#[derive(Clone, Debug)]
+pub struct Matrix4 {
+	pub(crate) elements: Vec<f64> // Always 16 elements, enforced by the constructor and setter
+}
+
+#[napi]
+impl Matrix4 {
+	/**
+	 * Constructs a matrix from 16 column-major elements.
+	 *
+	 * @param elements - The matrix elements, defaults to the identity matrix.
+	 */
+	#[napi(constructor)]
+	pub fn new(elements: Option<Vec<f64>>) -> napi::Result<Self> {
+		match elements {
+			None => Ok(Matrix4::identity()),
+			Some(elements) if elements.len() == 16 => Ok(Matrix4 { elements }),
+			Some(elements) => Err(Error::new(
+				Status::InvalidArg,
+				format!("Matrix4 requires 16 elements, got {}", elements.len())
+			))
+		}
+	}
+
+	#[napi(getter)]
+	pub fn elements(&self) -> Vec<f64> {
+		self.elements.clone()
+	}
+
+	/**
+	 * Replaces the elements of the matrix.
+	 *
+	 * @param elements - The 16 column-major elements.
+	 */
+	#[napi(setter, js_name = "elements")]
+	pub fn set_elements(&mut self, elements: Vec<f64>) -> napi::Result<()> {
+		*self = Matrix4::new(Some(elements))?;
+		Ok(())
+	}
+
+	/**
+	 * Creates the identity matrix.
+	 */
+	#[napi]
+	pub fn identity() -> Matrix4 {
+		Matrix4 {
+			elements: vec![
+				1.0, 0.0, 0.0, 0.0,
+				0.0, 1.0, 0.0, 0.0,
+				0.0, 0.0, 1.0, 0.0,
+				0.0, 0.0, 0.0, 1.0
+			]
+		}
+	}
+
+	/**
+	 * Creates a translation matrix.
+	 *
+	 * @param v - The translation (Vector3f).
+	 */
+	#[napi]
+	pub fn translation(v: VectorLike) -> Matrix4 {
+		let mut matrix = Matrix4::identity();
+		matrix.elements[12] = v.x;
+		matrix.elements[13] = v.y;
+		matrix.elements[14] = v.z;
+
+		return matrix;
+	}
+
+	/**
+	 * Creates a scale matrix.
+	 *
+	 * @param v - The scale along each axis (Vector3f).
+	 */
+	#[napi]
+	pub fn scaling(v: VectorLike) -> Matrix4 {
+		let mut matrix = Matrix4::identity();
+		matrix.elements[0] = v.x;
+		matrix.elements[5] = v.y;
+		matrix.elements[10] = v.z;
+
+		return matrix;
+	}
+
+	/**
+	 * Creates a right-handed perspective projection matrix with a clip space depth of -1..1.
+	 *
+	 * @param fov - The vertical field of view in degrees.
+	 * @param aspect - The viewport width divided by its height.
+	 * @param near - The distance to the near clipping plane.
+	 * @param far - The distance to the far clipping plane.
+	 */
+	#[napi]
+	pub fn perspective(fov: f64, aspect: f64, near: f64, far: f64) -> Matrix4 {
+		let f = 1.0 / (fov.to_radians() / 2.0).tan();
+		let range = near - far;
+
+		Matrix4 {
+			elements: vec![
+				f / aspect, 0.0, 0.0, 0.0,
+				0.0, f, 0.0, 0.0,
+				0.0, 0.0, (far + near) / range, -1.0,
+				0.0, 0.0, 2.0 * far * near / range, 0.0
+			]
+		}
+	}
+
+	/**
+	 * Creates a right-handed orthographic projection matrix with a clip space depth of -1..1.
+	 */
+	#[napi]
+	pub fn orthographic(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Matrix4 {
+		let width = right - left;
+		let height = top - bottom;
+		let depth = far - near;
+
+		Matrix4 {
+			elements: vec![
+				2.0 / width, 0.0, 0.0, 0.0,
+				0.0, 2.0 / height, 0.0, 0.0,
+				0.0, 0.0, -2.0 / depth, 0.0,
+				-(right + left) / width, -(top + bottom) / height, -(far + near) / depth, 1.0
+			]
+		}
+	}
+
+	/**
+	 * Creates a view matrix for an eye looking at a target point.
+	 *
+	 * @param eye - The position of the eye (Vector3f).
+	 * @param target - The point being looked at (Vector3f).
+	 * @param up - The world up direction (Vector3f).
+	 */
+	#[napi]
+	pub fn look_at(eye: VectorLike, target: VectorLike, up: VectorLike) -> Matrix4 {
+		let forward = target.subtract(&eye).normalize();
+		let right = forward.cross(&up).normalize();
+		let up = right.cross(&forward);
+
+		Matrix4::view(&eye, &right, &up, &forward)
+	}
+
+	/**
+	 * Multiplies this matrix by another matrix (this * other).
+	 *
+	 * @param other - The right hand side matrix (Matrix4).
+	 * @return The product of both matrices.
+	 */
+	#[napi]
+	pub fn multiply(&self, other: &Matrix4) -> Matrix4 {
+		let a = &self.elements;
+		let b = &other.elements;
+		let mut elements = vec![0.0; 16];
+
+		for column in 0..4 {
+			for row in 0..4 {
+				elements[column * 4 + row] = (0..4).map(|k| a[k * 4 + row] * b[column * 4 + k]).sum();
+			}
+		}
+
+		Matrix4 { elements }
+	}
+
+	/**
+	 * Transforms a point by this matrix, including translation and the perspective divide.
+	 *
+	 * @param v - The point to transform (Vector3f).
+	 * @return The transformed point.
+	 */
+	#[napi]
+	pub fn transform_point(&self, v: VectorLike) -> Vector3f {
+		let m = &self.elements;
+		let w = m[3] * v.x + m[7] * v.y + m[11] * v.z + m[15];
+		let w = if w == 0.0 { 1.0 } else { w };
+
+		Vector3f::new(
+			(m[0] * v.x + m[4] * v.y + m[8] * v.z + m[12]) / w,
+			(m[1] * v.x + m[5] * v.y + m[9] * v.z + m[13]) / w,
+			(m[2] * v.x + m[6] * v.y + m[10] * v.z + m[14]) / w
+		)
+	}
+
+	/**
+	 * Transforms a direction by this matrix, ignoring translation.
+	 *
+	 * @param v - The direction to transform (Vector3f).
+	 * @return The transformed direction.
+	 */
+	#[napi]
+	pub fn transform_direction(&self, v: VectorLike) -> Vector3f {
+		let m = &self.elements;
+
+		Vector3f::new(
+			m[0] * v.x + m[4] * v.y + m[8] * v.z,
+			m[1] * v.x + m[5] * v.y + m[9] * v.z,
+			m[2] * v.x + m[6] * v.y + m[10] * v.z
+		)
+	}
+
+	/**
+	 * Calculates the world bounds of a box transformed by this matrix, from all 8 transformed corners.
+	 * The result encloses rotated or projected boxes so they can feed AABB based broad phases.
+	 *
+	 * @param aabb - The box to transform (AABB).
+	 * @return The axis-aligned bounds of the transformed box, NaN along axes any corner is NaN on.
+	 */
+	#[napi]
+	pub fn transform_aabb(&self, aabb: &AABB) -> AABB {
+		let mut min = Vector3f::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+		let mut max = Vector3f::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+
+		// Unlike f64::min and f64::max, a NaN corner spreads to the bounds instead of being skipped.
+		let lower = |a: f64, b: f64| if a.is_nan() || b.is_nan() { f64::NAN } else { a.min(b) };
+		let upper = |a: f64, b: f64| if a.is_nan() || b.is_nan() { f64::NAN } else { a.max(b) };
+
+		for corner in aabb.corners() {
+			let point = self.transform_point(VectorLike::from(&corner));
+
+			min = Vector3f::new(lower(min.x, point.x), lower(min.y, point.y), lower(min.z, point.z));
+			max = Vector3f::new(upper(max.x, point.x), upper(max.y, point.y), upper(max.z, point.z));
+		}
+
+		AABB::new(VectorLike::from(&min), VectorLike::from(&max))
+	}
+
+	/**
+	 * Returns the transpose of this matrix.
+	 */
+	#[napi]
+	pub fn transpose(&self) -> Matrix4 {
+		let mut elements = vec![0.0; 16];
+
+		for column in 0..4 {
+			for row in 0..4 {
+				elements[row * 4 + column] = self.elements[column * 4 + row];
+			}
+		}
+
+		Matrix4 { elements }
+	}
+
+	/**
+	 * Calculates the determinant of this matrix.
+	 */
+	#[napi]
+	pub fn determinant(&self) -> f64 {
+		let cofactors = self.cofactors();
+		let m = &self.elements;
+
+		m[0] * cofactors[0] + m[1] * cofactors[4] + m[2] * cofactors[8] + m[3] * cofactors[12]
+	}
+
+	/**
+	 * Calculates the inverse of this matrix.
+	 *
+	 * @return The inverse matrix; otherwise, undefined if the matrix is singular.
+	 */
+	#[napi]
+	pub fn inverse(&self) -> Option<Matrix4> {
+		let cofactors = self.cofactors();
+		let m = &self.elements;
+		let determinant = m[0] * cofactors[0] + m[1] * cofactors[4] + m[2] * cofactors[8] + m[3] * cofactors[12];
+
+		// Only an exactly zero determinant is singular, any tolerance would depend on the scale and translation
+		if determinant == 0.0 || !determinant.is_finite() { return None }
+
+		Some(Matrix4 { elements: cofactors.iter().map(|c| c / determinant).collect() })
+	}
+
+	/**
+	 * Creates a matrix applying a scale, then a rotation, then a translation.
+	 *
+	 * @param translation - The translation (Vector3f).
+	 * @param rotation - The rotation (Quaternion).
+	 * @param scale - The scale along each axis (Vector3f).
+	 */
+	#[napi]
+	pub fn compose(translation: VectorLike, rotation: &Quaternion, scale: VectorLike) -> Matrix4 {
+		let mut elements = rotation.to_matrix3().to_matrix4().elements;
+
+		for (column, factor) in [scale.x, scale.y, scale.z].into_iter().enumerate() {
+			for row in 0..3 { elements[column * 4 + row] *= factor }
+		}
+
+		elements[12] = translation.x;
+		elements[13] = translation.y;
+		elements[14] = translation.z;
+
+		Matrix4 { elements }
+	}
+
+	/**
+	 * Splits an affine matrix into translation, rotation and scale, the inverse of compose.
+	 * A mirroring matrix results in a negative X scale. Shear cannot be represented and is lost.
+	 * An axis scaled to zero is rebuilt from the other two, and with more the rotation is the identity.
+	 *
+	 * @return The translation, rotation and scale of the matrix.
+	 */
+	#[napi]
+	pub fn decompose(&self) -> Decomposition {
+		let m = &self.elements;
+		let column = |index: usize| Vector3f::new(m[index * 4], m[index * 4 + 1], m[index * 4 + 2]);
+		let (x, y, z) = (column(0), column(1), column(2));
+
+		let mut scale = Vector3f::new(x.length(), y.length(), z.length());
+		if x.cross(&y).dot(&z) < 0.0 { scale.x = -scale.x }
+
+		let unscale = |axis: &Vector3f, factor: f64| if factor == 0.0 { Vector3f::new(0.0, 0.0, 0.0) } else { axis.multiply(1.0 / factor) };
+		let (mut rx, mut ry, mut rz) = (unscale(&x, scale.x), unscale(&y, scale.y), unscale(&z, scale.z));
+
+		// A single axis scaled to zero is rebuilt from the other two, which still define the rotation.
+		match (scale.x == 0.0, scale.y == 0.0, scale.z == 0.0) {
+			(true, false, false) => rx = ry.cross(&rz),
+			(false, true, false) => ry = rz.cross(&rx),
+			(false, false, true) => rz = rx.cross(&ry),
+			_ => {}
+		}
+
+		// With fewer than two axes left, the rotation cannot be recovered at all.
+		let flat = [scale.x, scale.y, scale.z].iter().filter(|factor| **factor == 0.0).count() > 1;
+		let rotation = if flat { Quaternion::identity() } else { Quaternion::from_matrix3(&Matrix3::from_columns(rx.into(), ry.into(), rz.into())) };
+
+		Decomposition {
+			translation: Vector3f::new(m[12], m[13], m[14]),
+			rotation,
+			scale
+		}
+	}
+
+	#[napi]
+	pub fn equals(&self, other: &Matrix4) -> bool {
+		self.elements == other.elements
+	}
+
+	/**
+	 * Builds a view matrix from an eye position and an orthonormal camera basis.
+	 */
+	pub(crate) fn view(eye: &Vector3f, right: &Vector3f, up: &Vector3f, forward: &Vector3f) -> Matrix4 {
+		Matrix4 {
+			elements: vec![
+				right.x, up.x, -forward.x, 0.0,
+				right.y, up.y, -forward.y, 0.0,
+				right.z, up.z, -forward.z, 0.0,
+				-right.dot(eye), -up.dot(eye), forward.dot(eye), 1.0
+			]
+		}
+	}
+
+	/**
+	 * Computes the transposed cofactor matrix (the adjugate), used by inverse and determinant.
+	 */
+	fn cofactors(&self) -> Vec<f64> {
+		let m = &self.elements;
+
+		vec![
+			m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15] + m[9] * m[7] * m[14] + m[13] * m[6] * m[11] - m[13] * m[7] * m[10],
+			-m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15] - m[9] * m[3] * m[14] - m[13] * m[2] * m[11] + m[13] * m[3] * m[10],
+			m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15] + m[5] * m[3] * m[14] + m[13] * m[2] * m[7] - m[13] * m[3] * m[6],
+			-m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11] - m[5] * m[3] * m[10] - m[9] * m[2] * m[7] + m[9] * m[3] * m[6],
+			-m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15] - m[8] * m[7] * m[14] - m[12] * m[6] * m[11] + m[12] * m[7] * m[10],
+			m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15] + m[8] * m[3] * m[14] + m[12] * m[2] * m[11] - m[12] * m[3] * m[10],
+			-m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15] - m[4] * m[3] * m[14] - m[12] * m[2] * m[7] + m[12] * m[3] * m[6],
+			m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11] + m[4] * m[3] * m[10] + m[8] * m[2] * m[7] - m[8] * m[3] * m[6],
+			m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15] + m[8] * m[7] * m[13] + m[12] * m[5] * m[11] - m[12] * m[7] * m[9],
+			-m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15] - m[8] * m[3] * m[13] - m[12] * m[1] * m[11] + m[12] * m[3] * m[9],
+			m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15] + m[4] * m[3] * m[13] + m[12] * m[1] * m[7] - m[12] * m[3] * m[5],
+			-m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11] - m[4] * m[3] * m[9] - m[8] * m[1] * m[7] + m[8] * m[3] * m[5],
+			-m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14] - m[8] * m[6] * m[13] - m[12] * m[5] * m[10] + m[12] * m[6] * m[9],
+			m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14] + m[8] * m[2] * m[13] + m[12] * m[1] * m[10] - m[12] * m[2] * m[9],
+			-m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14] - m[4] * m[2] * m[13] - m[12] * m[1] * m[6] + m[12] * m[2] * m[5],
+			m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10] + m[4] * m[2] * m[9] + m[8] * m[1] * m[6] - m[8] * m[2] * m[5]
+		]
+	}
+}
@@ -0,0 +1,159 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::axis_angle::AxisAngle;
+use crate::mat4::{Decomposition, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * A stack of transforms for building nested arrangements, such as display entities
+ * attached to each other. Every operation is applied in the local space of the current
+ * transform, like the matrix stacks of OpenGL and the Java renderer.
+ */
+#[napi]
+pub struct MatrixStack {
+	stack: Vec<Matrix4> // Saved transforms, the last one being the current transform
+}
+
+#[napi]
+impl MatrixStack {
+	/**
+	 * Constructs a stack holding a single transform.
+	 *
+	 * @param base - The initial transform, defaults to the identity matrix (Matrix4).
+	 */
+	#[napi(constructor)]
+	pub fn new(base: Option<&Matrix4>) -> MatrixStack {
+		MatrixStack {
+			stack: vec![base.cloned().unwrap_or(Matrix4::identity())]
+		}
+	}
+
+	/**
+	 * Saves the current transform, so it can be restored with pop.
+	 */
+	#[napi]
+	pub fn push(&mut self) {
+		let current = self.current();
+		self.stack.push(current);
+	}
+
+	/**
+	 * Restores the transform saved by the last push.
+	 *
+	 * @return An error when there is no saved transform to restore.
+	 */
+	#[napi]
+	pub fn pop(&mut self) -> napi::Result<()> {
+		if self.stack.len() <= 1 {
+			return Err(Error::new(Status::GenericFailure, "Cannot pop the base transform of a MatrixStack".to_string()));
+		}
+
+		self.stack.pop();
+		Ok(())
+	}
+
+	/**
+	 * Returns the number of saved transforms, 0 when only the base transform is left.
+	 */
+	#[napi]
+	pub fn depth(&self) -> u32 {
+		(self.stack.len() - 1) as u32
+	}
+
+	/**
+	 * Returns a copy of the current transform.
+	 */
+	#[napi]
+	pub fn current(&self) -> Matrix4 {
+		self.stack[self.stack.len() - 1].clone()
+	}
+
+	/**
+	 * Translates the current transform.
+	 *
+	 * @param v - The translation in local space (Vector3f).
+	 */
+	#[napi]
+	pub fn translate(&mut self, v: VectorLike) {
+		self.apply(&Matrix4::translation(v));
+	}
+
+	/**
+	 * Rotates the current transform by a quaternion.
+	 *
+	 * @param rotation - The rotation (Quaternion).
+	 */
+	#[napi]
+	pub fn rotate(&mut self, rotation: &Quaternion) {
+		self.apply(&rotation.to_matrix3().to_matrix4());
+	}
+
+	/**
+	 * Rotates the current transform around an axis.
+	 *
+	 * @param axis - The axis to rotate around (Vector3f).
+	 * @param angle - The angle in radians, counterclockwise when looking down the axis.
+	 */
+	#[napi]
+	pub fn rotate_axis(&mut self, axis: VectorLike, angle: f64) {
+		self.apply(&AxisAngle::new(axis, angle).to_matrix3().to_matrix4());
+	}
+
+	/**
+	 * Scales the current transform.
+	 *
+	 * @param v - The scale on every local axis (Vector3f).
+	 */
+	#[napi]
+	pub fn scale(&mut self, v: VectorLike) {
+		self.apply(&Matrix4::scaling(v));
+	}
+
+	/**
+	 * Applies an arbitrary transform in the local space of the current transform.
+	 *
+	 * @param matrix - The transform to apply (Matrix4).
+	 */
+	#[napi]
+	pub fn multiply(&mut self, matrix: &Matrix4) {
+		self.apply(matrix);
+	}
+
+	/**
+	 * Replaces the current transform with the identity matrix, keeping saved transforms.
+	 */
+	#[napi]
+	pub fn load_identity(&mut self) {
+		let last = self.stack.len() - 1;
+		self.stack[last] = Matrix4::identity();
+	}
+
+	/**
+	 * Transforms a point from local space into the space of the base transform.
+	 *
+	 * @param v - The point to transform (Vector3f).
+	 */
+	#[napi]
+	pub fn transform_point(&self, v: VectorLike) -> Vector3f {
+		self.stack[self.stack.len() - 1].transform_point(v)
+	}
+
+	/**
+	 * Splits the current transform into its translation, rotation and scale,
+	 * ready to be sent as the transformation of a display entity.
+	 */
+	#[napi]
+	pub fn decompose(&self) -> Decomposition {
+		self.stack[self.stack.len() - 1].decompose()
+	}
+
+	/**
+	 * Post-multiplies the current transform, so the transform is applied in local space.
+	 */
+	fn apply(&mut self, matrix: &Matrix4) {
+		let last = self.stack.len() - 1;
+		self.stack[last] = self.stack[last].multiply(matrix);
+	}
+}
@@ -0,0 +1,137 @@
+use napi::bindgen_prelude::{Float64Array, Uint8Array};
+use napi::{Error, Status};
+use napi_derive::napi;
+
+const HAS_X: u32 = 0x01;
+const HAS_Y: u32 = 0x02;
+const HAS_Z: u32 = 0x04;
+const HAS_PITCH: u32 = 0x08;
+const HAS_YAW: u32 = 0x10;
+const HAS_HEAD_YAW: u32 = 0x20;
+const ON_GROUND: u32 = 0x40;
+
+const ANGLE_STEP: f64 = 360.0 / 256.0; // Degrees per step of a byte encoded angle
+
+/**
+ * Represents the movement updates of a batch of entities, ready to be written to MoveActorDelta packets.
+ */
+#[napi(object)]
+pub struct MoveDeltaBatch {
+	pub flags: Vec<u32>, // MoveActorDelta header flags of every entity; 0 or only the ground flag means nothing to send
+	pub positions: Float64Array, // Positions the client knows after the update, rounded to float precision; the last sent value on axes that are not sent
+	pub deltas: Float64Array, // Movement since the previous positions, zero on axes that are not sent
+	pub rotations: Uint8Array, // Pitch, yaw and head yaw of every entity quantized to byte angles
+}
+
+/**
+ * Batch encoding of entity movement for network broadcasts using MoveActorDelta semantics:
+ * every position axis and rotation is flagged and sent only when it changed.
+ */
+#[napi]
+pub struct MoveDelta {}
+
+#[napi]
+impl MoveDelta {
+	/**
+	 * Compares the previous and current state of many entities and computes which fields changed.
+	 * Positions are compared after rounding to float precision and rotations after quantizing to
+	 * byte angles, so nothing is flagged that the client could not observe. The returned positions
+	 * keep the last sent value on axes that are not sent, so feeding them back as the previous
+	 * positions lets slow drift accumulate until it passes the threshold.
+	 *
+	 * @param previous_positions - The positions last sent, packed as x, y, z triplets (Float64Array).
+	 * @param positions - The current positions, packed as x, y, z triplets (Float64Array).
+	 * @param previous_rotations - The rotations last sent, packed as pitch, yaw, head yaw triplets in degrees (Float64Array).
+	 * @param rotations - The current rotations, packed as pitch, yaw, head yaw triplets in degrees (Float64Array).
+	 * @param on_ground - Whether every entity is on the ground, sets the ground flag.
+	 * @param threshold - The movement on an axis below which it is not sent, defaults to 0.
+	 * @return The flags, positions, deltas and quantized rotations of every entity.
+	 */
+	#[napi]
+	pub fn compute(
+		previous_positions: Float64Array,
+		positions: Float64Array,
+		previous_rotations: Float64Array,
+		rotations: Float64Array,
+		on_ground: Option<Vec<bool>>,
+		threshold: Option<f64>
+	) -> napi::Result<MoveDeltaBatch> {
+		if !positions.len().is_multiple_of(3) {
+			return Err(Error::new(Status::InvalidArg, format!("Positions must be packed in triplets, got {} values", positions.len())));
+		}
+
+		let count = positions.len() / 3;
+
+		for (name, length) in [("previous positions", previous_positions.len()), ("previous rotations", previous_rotations.len()), ("rotations", rotations.len())] {
+			if length != count * 3 {
+				return Err(Error::new(Status::InvalidArg, format!("Expected {} {} values, got {}", count * 3, name, length)));
+			}
+		}
+
+		let threshold = threshold.unwrap_or(0.0).max(0.0);
+		let mut flags = vec![0u32; count];
+		let mut current = vec![0.0; count * 3];
+		let mut deltas = vec![0.0; count * 3];
+		let mut angles = vec![0u8; count * 3];
+
+		for (index, flag) in flags.iter_mut().enumerate() {
+			for axis in 0..3 {
+				let i = index * 3 + axis;
+				let (before, after) = (previous_positions[i] as f32 as f64, positions[i] as f32 as f64);
+				current[i] = before;
+
+				if (after - before).abs() > threshold {
+					current[i] = after;
+					deltas[i] = after - before;
+					*flag |= [HAS_X, HAS_Y, HAS_Z][axis];
+				}
+
+				let (before, after) = (MoveDelta::quantize_angle(previous_rotations[i]), MoveDelta::quantize_angle(rotations[i]));
+				angles[i] = after;
+
+				if before != after { *flag |= [HAS_PITCH, HAS_YAW, HAS_HEAD_YAW][axis] }
+			}
+
+			if on_ground.as_ref().is_some_and(|ground| ground.get(index).copied().unwrap_or(false)) {
+				*flag |= ON_GROUND;
+			}
+		}
+
+		Ok(MoveDeltaBatch {
+			flags,
+			positions: Float64Array::new(current),
+			deltas: Float64Array::new(deltas),
+			rotations: Uint8Array::new(angles)
+		})
+	}
+
+	/**
+	 * Quantizes an angle to a byte, 256 steps per full turn.
+	 *
+	 * @param degrees - The angle in degrees.
+	 */
+	#[napi]
+	pub fn quantize_angle(degrees: f64) -> u8 {
+		((degrees / ANGLE_STEP).round() as i64).rem_euclid(256) as u8
+	}
+
+	/**
+	 * Converts a byte angle back to degrees (0..360).
+	 *
+	 * @param angle - The byte angle.
+	 */
+	#[napi]
+	pub fn dequantize_angle(angle: u8) -> f64 {
+		angle as f64 * ANGLE_STEP
+	}
+
+	/**
+	 * Checks whether the flags of an entity carry any movement or rotation to send.
+	 *
+	 * @param flags - The MoveActorDelta header flags.
+	 */
+	#[napi]
+	pub fn has_changes(flags: u32) -> bool {
+		flags & !ON_GROUND != 0
+	}
+}
@@ -0,0 +1,120 @@
+use std::collections::{HashMap, HashSet};
+
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+const CELL_SIZE: f64 = 16.0; // Size of the grid cells entities are bucketed in, one chunk
+
+/**
+ * Computes which entities are relevant to every player for broadcasts, with hysteresis:
+ * an entity enters the set of a player within the view distance, and only leaves it beyond
+ * the view distance plus the margin, so entities on the edge do not flicker in and out.
+ * Distances are horizontal, in blocks.
+ */
+#[napi]
+pub struct InterestManager {
+	pub margin: f64, // Extra distance an entity must move beyond the view distance to leave a set
+	relevant: Vec<HashSet<u32>> // Entities relevant to every player after the last compute
+}
+
+#[napi]
+impl InterestManager {
+	/**
+	 * Constructs an interest manager without any relevant entities.
+	 *
+	 * @param margin - The hysteresis margin in blocks, defaults to 8.
+	 */
+	#[napi(constructor)]
+	pub fn new(margin: Option<f64>) -> InterestManager {
+		InterestManager { margin: margin.unwrap_or(8.0).max(0.0), relevant: Vec::new() }
+	}
+
+	/**
+	 * Computes the relevant entities of every player. Players and entities are identified by their
+	 * index, so the order must stay stable between calls for the hysteresis to apply.
+	 *
+	 * @param players - The players, packed as x, y, z, view distance quadruplets (Float64Array).
+	 * @param entities - The entity positions, packed as x, y, z triplets (Float64Array).
+	 * @return The indices of the entities relevant to every player, sorted.
+	 */
+	#[napi]
+	pub fn compute(&mut self, players: Float64Array, entities: Float64Array) -> napi::Result<Vec<Vec<u32>>> {
+		if !players.len().is_multiple_of(4) || !entities.len().is_multiple_of(3) {
+			return Err(Error::new(
+				Status::InvalidArg,
+				"Players must be packed in quadruplets and entities in triplets".to_string()
+			));
+		}
+
+		let mut grid: HashMap<(i64, i64), Vec<u32>> = HashMap::new();
+		for (index, entity) in entities.chunks_exact(3).enumerate() {
+			grid.entry(InterestManager::cell(entity[0], entity[2])).or_default().push(index as u32);
+		}
+
+		let count = players.len() / 4;
+		self.relevant.resize_with(count, HashSet::new);
+
+		let mut result = Vec::with_capacity(count);
+
+		for (player, previous) in players.chunks_exact(4).zip(self.relevant.iter_mut()) {
+			let (x, z, view) = (player[0], player[2], player[3].max(0.0));
+			let reach = view + self.margin;
+			let (min, max) = (InterestManager::cell(x - reach, z - reach), InterestManager::cell(x + reach, z + reach));
+			let mut current = HashSet::new();
+
+			// Large or infinite distances span more cells than are occupied, so filter the occupied buckets instead
+			let cells = (max.0 as f64 - min.0 as f64 + 1.0) * (max.1 as f64 - min.1 as f64 + 1.0);
+			let mut buckets: Vec<&Vec<u32>> = Vec::new();
+			if cells > grid.len() as f64 {
+				let in_range = |cell: &(i64, i64)| cell.0 >= min.0 && cell.0 <= max.0 && cell.1 >= min.1 && cell.1 <= max.1;
+				buckets.extend(grid.iter().filter(|(cell, _)| in_range(cell)).map(|(_, bucket)| bucket));
+			} else {
+				for cx in min.0..=max.0 {
+					for cz in min.1..=max.1 { buckets.extend(grid.get(&(cx, cz))) }
+				}
+			}
+
+			for bucket in buckets {
+				for &index in bucket {
+					let entity = &entities[index as usize * 3..index as usize * 3 + 3];
+					let distance = (entity[0] - x).powi(2) + (entity[2] - z).powi(2);
+					let limit = if previous.contains(&index) { reach } else { view };
+
+					if distance <= limit * limit { current.insert(index); }
+				}
+			}
+
+			let mut sorted: Vec<u32> = current.iter().copied().collect();
+			sorted.sort_unstable();
+			result.push(sorted);
+
+			*previous = current;
+		}
+
+		return Ok(result);
+	}
+
+	/**
+	 * Checks whether an entity was relevant to a player after the last compute.
+	 *
+	 * @param player - The index of the player.
+	 * @param entity - The index of the entity.
+	 */
+	#[napi]
+	pub fn is_relevant(&self, player: u32, entity: u32) -> bool {
+		self.relevant.get(player as usize).is_some_and(|set| set.contains(&entity))
+	}
+
+	/**
+	 * Forgets all relevant sets, so the next compute applies no hysteresis.
+	 */
+	#[napi]
+	pub fn reset(&mut self) {
+		self.relevant.clear();
+	}
+
+	fn cell(x: f64, z: f64) -> (i64, i64) {
+		((x / CELL_SIZE).floor() as i64, (z / CELL_SIZE).floor() as i64)
+	}
+}
@@ -0,0 +1,105 @@
+use napi::bindgen_prelude::Uint8Array;
+use napi::{Error, JsFunction, Status};
+use napi_derive::napi;
+
+use crate::config::MathConfig;
+use crate::rect::Rect;
+use crate::shape::generator::ShapeGen;
+use crate::vec2f::Vector2f;
+use crate::vec3f::{Axis, Vector3f};
+
+use super::perlin::PerlinNoise;
+
+/**
+ * Represents the slice of noise an image is rendered from.
+ */
+#[napi(object)]
+pub struct NoiseSlice {
+	pub axis: Option<Axis>, // Axis perpendicular to the slice, defaults to the configured up axis (Y, the x, z plane)
+	pub depth: Option<f64>, // Coordinate of the slice along the axis, defaults to 0
+	pub min: Option<f64>, // Noise value rendered black, defaults to -1
+	pub max: Option<f64>, // Noise value rendered white, defaults to 1
+}
+
+/**
+ * Renders slices of noise into grayscale images, to inspect world generation noise from JS tooling.
+ * Images are row major with one byte per pixel: the first row is the top of the region.
+ */
+#[napi]
+pub struct NoiseDebug {}
+
+#[napi]
+impl NoiseDebug {
+	/**
+	 * Renders a region of Perlin noise. The region spans the plane perpendicular to the slice axis,
+	 * mapped like ShapeGen planes: Y uses (x, z), X uses (z, y) and Z uses (x, y).
+	 *
+	 * @param noise - The noise to sample (PerlinNoise).
+	 * @param region - The region of the plane to render, in noise coordinates (Rect).
+	 * @param resolution - The width and height of the image in pixels (Vector2f).
+	 * @param slice - The slice and value range to render (NoiseSlice).
+	 * @return The grayscale pixels (Uint8Array).
+	 * Throws if the resolution is below one pixel or holds more pixels than a typed array.
+	 */
+	#[napi]
+	pub fn render(noise: &PerlinNoise, region: &Rect, resolution: &Vector2f, slice: Option<NoiseSlice>) -> napi::Result<Uint8Array> {
+		let pixels = NoiseDebug::sample(region, resolution, slice, |position| Ok(noise.sample_vector(position.into())))?;
+		Ok(Uint8Array::new(pixels))
+	}
+
+	/**
+	 * Renders a region of any noise, sampled by a function.
+	 *
+	 * @param sampler - Returns the noise value at a position.
+	 * @param region - The region of the plane to render, in noise coordinates (Rect).
+	 * @param resolution - The width and height of the image in pixels (Vector2f).
+	 * @param slice - The slice and value range to render (NoiseSlice).
+	 * @return The grayscale pixels (Uint8Array).
+	 * Throws if the resolution is below one pixel or holds more pixels than a typed array.
+	 */
+	#[napi(ts_args_type = "sampler: (position: Vector3f) => number, region: Rect, resolution: Vector2f, slice?: NoiseSlice | undefined | null")]
+	pub fn render_with(sampler: JsFunction, region: &Rect, resolution: &Vector2f, slice: Option<NoiseSlice>) -> napi::Result<Uint8Array> {
+		let pixels = NoiseDebug::sample(region, resolution, slice, |position| sampler.call1(position.clone()))?;
+		Ok(Uint8Array::new(pixels))
+	}
+
+	/**
+	 * Samples the center of every pixel and maps the values to bytes.
+	 */
+	fn sample<F: FnMut(&Vector3f) -> napi::Result<f64>>(region: &Rect, resolution: &Vector2f, slice: Option<NoiseSlice>, mut sampler: F) -> napi::Result<Vec<u8>> {
+		let (width, height) = (resolution.x.floor(), resolution.y.floor());
+		if !(width.is_finite() && height.is_finite() && width >= 1.0 && height >= 1.0) {
+			return Err(Error::new(Status::InvalidArg, format!("Resolution must be at least 1x1 pixels, got {}x{}", resolution.x, resolution.y)));
+		}
+
+		// The pixels must still fit in a typed array.
+		let (width, height) = (width.min(u32::MAX as f64) as usize, height.min(u32::MAX as f64) as usize);
+		let count = width.checked_mul(height).filter(|count| *count <= u32::MAX as usize).ok_or_else(|| {
+			Error::new(Status::InvalidArg, format!("Images hold at most {} pixels, got {}x{}", u32::MAX, width, height))
+		})?;
+
+		let axis = slice.as_ref().and_then(|slice| slice.axis).unwrap_or_else(|| MathConfig::current().up_axis());
+		let depth = slice.as_ref().and_then(|slice| slice.depth).unwrap_or(0.0);
+		let min = slice.as_ref().and_then(|slice| slice.min).unwrap_or(-1.0);
+		let max = slice.as_ref().and_then(|slice| slice.max).unwrap_or(1.0);
+
+		let origin = Vector3f::new(0.0, 0.0, 0.0);
+		let mut pixels: Vec<u8> = Vec::new();
+		if pixels.try_reserve_exact(count).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for an image of {} pixels", count)));
+		}
+
+		for row in 0..height {
+			for column in 0..width {
+				let u = region.x + (column as f64 + 0.5) / width as f64 * region.width;
+				let v = region.y + (row as f64 + 0.5) / height as f64 * region.height;
+				let value = sampler(&ShapeGen::plane_point(&origin, axis, u, v, depth))?;
+
+				let normalized = if max == min { 0.0 } else { (value - min) / (max - min) };
+				pixels.push((normalized.clamp(0.0, 1.0) * 255.0).round() as u8);
+			}
+		}
+
+		return Ok(pixels);
+	}
+}
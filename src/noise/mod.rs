@@ -0,0 +1,3 @@
+pub mod debug;
+pub mod perlin;
+pub mod worm;
\ No newline at end of file
@@ -0,0 +1,112 @@
+use napi_derive::napi;
+
+use crate::random::java::JavaRandom;
+use crate::vec3f::VectorLike;
+
+const GRADIENT: [[f64; 3]; 16] = [
+	[1.0, 1.0, 0.0], [-1.0, 1.0, 0.0], [1.0, -1.0, 0.0], [-1.0, -1.0, 0.0],
+	[1.0, 0.0, 1.0], [-1.0, 0.0, 1.0], [1.0, 0.0, -1.0], [-1.0, 0.0, -1.0],
+	[0.0, 1.0, 1.0], [0.0, -1.0, 1.0], [0.0, 1.0, -1.0], [0.0, -1.0, -1.0],
+	[1.0, 1.0, 0.0], [0.0, -1.0, 1.0], [-1.0, 1.0, 0.0], [0.0, -1.0, -1.0]
+];
+
+/**
+ * Seeded 3D Perlin noise, equivalent to vanilla's ImprovedNoise.
+ * Samples are roughly within -1..1 and are zero on integer lattice points of the offset space.
+ */
+#[napi(js_name = "PerlinNoise")]
+#[derive(Clone)]
+pub struct PerlinNoise {
+	pub xo: f64, // Offset applied to sampled x coordinates
+	pub yo: f64, // Offset applied to sampled y coordinates
+	pub zo: f64, // Offset applied to sampled z coordinates
+	permutation: Vec<u8>
+}
+
+#[napi]
+impl PerlinNoise {
+	/**
+	 * Constructs the noise from a seed, consuming a JavaRandom the same way vanilla does.
+	 *
+	 * @param seed - The seed of the noise.
+	 */
+	#[napi(constructor)]
+	pub fn new(seed: i64) -> PerlinNoise {
+		PerlinNoise::from_random(&mut JavaRandom::from_seed(seed))
+	}
+
+	/**
+	 * Samples the noise at a position.
+	 */
+	#[napi]
+	pub fn sample(&self, x: f64, y: f64, z: f64) -> f64 {
+		let (x, y, z) = (x + self.xo, y + self.yo, z + self.zo);
+		let (xi, yi, zi) = (x.floor(), y.floor(), z.floor());
+		let (xf, yf, zf) = (x - xi, y - yi, z - zi);
+		// The lattice repeats every 256 cells, so coordinates are masked first to keep the sums below from overflowing
+		let (xi, yi, zi) = (xi as i32 & 255, yi as i32 & 255, zi as i32 & 255);
+
+		let a = self.hash(xi);
+		let b = self.hash(xi + 1);
+		let aa = self.hash(a + yi);
+		let ab = self.hash(a + yi + 1);
+		let ba = self.hash(b + yi);
+		let bb = self.hash(b + yi + 1);
+
+		let d0 = PerlinNoise::grad(self.hash(aa + zi), xf, yf, zf);
+		let d1 = PerlinNoise::grad(self.hash(ba + zi), xf - 1.0, yf, zf);
+		let d2 = PerlinNoise::grad(self.hash(ab + zi), xf, yf - 1.0, zf);
+		let d3 = PerlinNoise::grad(self.hash(bb + zi), xf - 1.0, yf - 1.0, zf);
+		let d4 = PerlinNoise::grad(self.hash(aa + zi + 1), xf, yf, zf - 1.0);
+		let d5 = PerlinNoise::grad(self.hash(ba + zi + 1), xf - 1.0, yf, zf - 1.0);
+		let d6 = PerlinNoise::grad(self.hash(ab + zi + 1), xf, yf - 1.0, zf - 1.0);
+		let d7 = PerlinNoise::grad(self.hash(bb + zi + 1), xf - 1.0, yf - 1.0, zf - 1.0);
+
+		let (u, v, w) = (PerlinNoise::fade(xf), PerlinNoise::fade(yf), PerlinNoise::fade(zf));
+		let lower = PerlinNoise::lerp(v, PerlinNoise::lerp(u, d0, d1), PerlinNoise::lerp(u, d2, d3));
+		let upper = PerlinNoise::lerp(v, PerlinNoise::lerp(u, d4, d5), PerlinNoise::lerp(u, d6, d7));
+
+		PerlinNoise::lerp(w, lower, upper)
+	}
+
+	/**
+	 * Samples the noise at a position given as a vector.
+	 *
+	 * @param v - The position to sample (Vector3f).
+	 */
+	#[napi]
+	pub fn sample_vector(&self, v: VectorLike) -> f64 {
+		self.sample(v.x, v.y, v.z)
+	}
+
+	pub(crate) fn from_random(random: &mut JavaRandom) -> PerlinNoise {
+		let xo = random.next_double() * 256.0;
+		let yo = random.next_double() * 256.0;
+		let zo = random.next_double() * 256.0;
+		let mut permutation: Vec<u8> = (0..=255).collect();
+
+		for i in 0..256 {
+			let j = random.next_bounded(256 - i as i32) as usize;
+			permutation.swap(i, i + j);
+		}
+
+		PerlinNoise { xo, yo, zo, permutation }
+	}
+
+	fn hash(&self, i: i32) -> i32 {
+		self.permutation[(i & 255) as usize] as i32
+	}
+
+	fn grad(hash: i32, x: f64, y: f64, z: f64) -> f64 {
+		let g = GRADIENT[(hash & 15) as usize];
+		g[0] * x + g[1] * y + g[2] * z
+	}
+
+	fn fade(t: f64) -> f64 {
+		t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+	}
+
+	fn lerp(t: f64, a: f64, b: f64) -> f64 {
+		a + t * (b - a)
+	}
+}
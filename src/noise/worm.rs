@@ -0,0 +1,100 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::noise::perlin::PerlinNoise;
+use crate::random::java::JavaRandom;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Options controlling the shape of a noise worm.
+ */
+#[napi(object)]
+pub struct WormOptions {
+	pub length: u32, // Number of nodes in the path
+	pub step: Option<f64>, // Distance between nodes in blocks, defaults to 1
+	pub radius: Option<f64>, // Base radius of the worm, defaults to 2
+	pub radius_variation: Option<f64>, // Relative radius change driven by noise (0..1), defaults to 0.5
+	pub turn_strength: Option<f64>, // Maximum yaw change per node in degrees, defaults to 20
+	pub max_pitch: Option<f64>, // Maximum pitch in degrees, 0 produces flat rivers, defaults to 45 when missing or not finite
+	pub frequency: Option<f64>, // Frequency of the steering noise, defaults to 0.05
+}
+
+/**
+ * Represents the generated path of a worm: packed x, y, z node positions and a radius per node.
+ */
+#[napi(object)]
+pub struct WormPath {
+	pub points: Float64Array,
+	pub radii: Float64Array,
+}
+
+/**
+ * Generates "noise worms", seeded random walks steered by Perlin noise, used to carve tunnels and rivers.
+ * The same seed and options always produce the same path.
+ */
+#[napi]
+pub struct NoiseWorm {}
+
+#[napi]
+impl NoiseWorm {
+	/**
+	 * Generates the path of a worm.
+	 *
+	 * @param seed - The seed of the worm.
+	 * @param start - The position of the first node (Vector3f).
+	 * @param options - The shape options (WormOptions).
+	 * @return The node positions and radii.
+	 * Throws if the path is too long to fit in a typed array or there is not enough memory.
+	 */
+	#[napi]
+	pub fn generate(seed: i64, start: VectorLike, options: WormOptions) -> napi::Result<WormPath> {
+		if options.length as u64 * 3 > u32::MAX as u64 {
+			return Err(Error::new(Status::InvalidArg, format!("Worms have at most {} nodes, got {}", u32::MAX / 3, options.length)));
+		}
+
+		let step = options.step.unwrap_or(1.0);
+		let radius = options.radius.unwrap_or(2.0);
+		let radius_variation = options.radius_variation.unwrap_or(0.5).clamp(0.0, 1.0);
+		let turn_strength = options.turn_strength.unwrap_or(20.0).to_radians();
+		let max_pitch = options.max_pitch.filter(|pitch| pitch.is_finite()).unwrap_or(45.0).abs().to_radians();
+		let frequency = options.frequency.unwrap_or(0.05);
+
+		let mut random = JavaRandom::from_seed(seed);
+		let yaw_noise = PerlinNoise::from_random(&mut random);
+		let pitch_noise = PerlinNoise::from_random(&mut random);
+		let radius_noise = PerlinNoise::from_random(&mut random);
+
+		let mut yaw = random.next_double() * std::f64::consts::TAU;
+		let mut pitch = (random.next_double() * 2.0 - 1.0) * max_pitch * 0.5;
+		let mut position = start.clone();
+
+		let count = options.length as usize;
+		let (mut points, mut radii): (Vec<f64>, Vec<f64>) = (Vec::new(), Vec::new());
+		if points.try_reserve_exact(count * 3).is_err() || radii.try_reserve_exact(count).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for a worm of {} nodes", count)));
+		}
+
+		for i in 0..count {
+			let (x, y, z) = (position.x * frequency, position.y * frequency, position.z * frequency);
+
+			points.extend_from_slice(&[position.x, position.y, position.z]);
+			radii.push((radius * (1.0 + radius_variation * radius_noise.sample(x, y, i as f64 * frequency))).max(0.0));
+
+			// Steer using the noise at the current node, then advance.
+			yaw += yaw_noise.sample(x, y, z) * turn_strength;
+			pitch = (pitch * 0.75 + pitch_noise.sample(x, y, z) * max_pitch).clamp(-max_pitch, max_pitch);
+
+			position = position.add(&Vector3f::new(
+				-yaw.sin() * pitch.cos() * step,
+				-pitch.sin() * step,
+				yaw.cos() * pitch.cos() * step
+			));
+		}
+
+		Ok(WormPath {
+			points: Float64Array::new(points),
+			radii: Float64Array::new(radii)
+		})
+	}
+}
@@ -0,0 +1,108 @@
+use napi::JsFunction;
+use napi_derive::napi;
+
+/**
+ * Represents the outcome of a minimization.
+ */
+#[napi(object)]
+pub struct MinimizeResult {
+	pub x: f64, // Argument of the minimum found
+	pub value: f64, // Function value at the minimum
+	pub iterations: u32, // Number of iterations performed
+}
+
+const INV_PHI: f64 = 0.618_033_988_749_894_9; // 1 / golden ratio
+
+/**
+ * Small numeric optimizers for tuning angles, timings and similar scalar parameters.
+ */
+#[napi]
+pub struct Optimizer {}
+
+#[napi]
+impl Optimizer {
+	/**
+	 * Finds the minimum of a function on an interval using a golden-section search,
+	 * refined by a Newton step on the final bracket. The function should be unimodal on the interval.
+	 * The function is only ever evaluated inside the interval.
+	 *
+	 * @param f - The function to minimize.
+	 * @param lower - The lower bound of the interval.
+	 * @param upper - The upper bound of the interval.
+	 * @param tolerance - The width of the bracket at which the search stops, defaults to 1e-6.
+	 * @param max_iterations - The maximum number of iterations, defaults to 100.
+	 * @return The position and value of the minimum.
+	 */
+	#[napi(ts_args_type = "f: (x: number) => number, lower: number, upper: number, tolerance?: number, maxIterations?: number")]
+	pub fn minimize_1d(
+		f: JsFunction,
+		lower: f64,
+		upper: f64,
+		tolerance: Option<f64>,
+		max_iterations: Option<u32>
+	) -> napi::Result<MinimizeResult> {
+		let evaluate = |x: f64| -> napi::Result<f64> { f.call1(x) };
+
+		Optimizer::golden_section(
+			evaluate,
+			lower.min(upper),
+			lower.max(upper),
+			tolerance.unwrap_or(1e-6),
+			max_iterations.unwrap_or(100)
+		)
+	}
+
+	/**
+	 * Golden-section search shared by the exposed optimizers.
+	 */
+	pub(crate) fn golden_section<F>(f: F, lower: f64, upper: f64, tolerance: f64, max_iterations: u32) -> napi::Result<MinimizeResult>
+	where F: Fn(f64) -> napi::Result<f64> {
+		let (mut a, mut b) = (lower, upper);
+		let mut c = b - (b - a) * INV_PHI;
+		let mut d = a + (b - a) * INV_PHI;
+		let mut fc = f(c)?;
+		let mut fd = f(d)?;
+		let mut iterations: u32 = 0;
+
+		while (b - a).abs() > tolerance && iterations < max_iterations {
+			if fc < fd {
+				b = d;
+				d = c;
+				fd = fc;
+				c = b - (b - a) * INV_PHI;
+				fc = f(c)?;
+			} else {
+				a = c;
+				c = d;
+				fc = fd;
+				d = a + (b - a) * INV_PHI;
+				fd = f(d)?;
+			}
+			iterations += 1;
+		}
+
+		let (mut x, mut value) = if fc < fd { (c, fc) } else { (d, fd) };
+
+		// Newton step using central differences, kept only when it improves the result.
+		// Skipped near the bounds, so the function is never probed outside the interval.
+		let h = ((b - a).abs() / 2.0).max(tolerance).max(f64::EPSILON.sqrt() * x.abs().max(1.0));
+		if x - h >= lower && x + h <= upper {
+			let (before, after) = (f(x - h)?, f(x + h)?);
+			let second = (after - 2.0 * value + before) / (h * h);
+
+			if second > 0.0 {
+				let candidate = x - (after - before) / (2.0 * h) / second;
+
+				if candidate.is_finite() && candidate >= lower && candidate <= upper {
+					let candidate_value = f(candidate)?;
+					if candidate_value < value {
+						x = candidate;
+						value = candidate_value;
+					}
+				}
+			}
+		}
+
+		Ok(MinimizeResult { x, value, iterations })
+	}
+}
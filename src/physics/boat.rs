@@ -0,0 +1,147 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+// Vanilla declares these as float literals widened to double, so they are widened the same way.
+const BOAT_HEIGHT: f64 = 0.5625; // Height of the boat hitbox
+const BUOYANCY: f64 = 0.061_538_46_f32 as f64; // Upwards acceleration per unit of submersion
+const GRAVITY: f64 = -0.04_f32 as f64; // Downwards acceleration per tick
+const UNDERWATER_BUOYANCY: f64 = 0.01_f32 as f64; // Submersion of a boat under still water
+const WATER_FRICTION: f64 = 0.9_f32 as f64; // Velocity kept per tick on water and in air
+const UNDERWATER_FRICTION: f64 = 0.45_f32 as f64; // Velocity kept per tick under still water
+const FORWARD_FORCE: f64 = 0.04_f32 as f64; // Acceleration per tick of paddling forward
+const TURN_FORCE: f64 = 0.005_f32 as f64; // Acceleration per tick of paddling backward or turning in place
+
+/**
+ * Where a boat is relative to water and ground, as determined by the caller from the blocks around it.
+ */
+#[napi]
+#[derive(PartialEq)]
+pub enum BoatStatus {
+	InWater, // Floating on a water surface
+	UnderWater, // Fully submerged in still water
+	UnderFlowingWater, // Fully submerged in flowing water
+	OnLand,
+	InAir
+}
+
+/**
+ * The keys held by the player paddling a boat.
+ */
+#[napi(object)]
+pub struct BoatInput {
+	pub forward: Option<bool>,
+	pub backward: Option<bool>,
+	pub left: Option<bool>,
+	pub right: Option<bool>,
+}
+
+/**
+ * Represents a boat, stepped with vanilla's buoyancy, friction and paddling math.
+ * Block collisions are left to the caller, the step only moves the boat by its velocity.
+ */
+#[napi]
+pub struct Boat {
+	pub position: Vector3f,
+	pub velocity: Vector3f,
+	pub yaw: f64, // Yaw in degrees, 0 faces south (+Z) and increases clockwise
+	pub delta_rotation: f64 // Turning speed in degrees per tick
+}
+
+#[napi]
+impl Boat {
+	/**
+	 * Constructs a boat at rest.
+	 *
+	 * @param position - The position of the boat (Vector3f).
+	 * @param yaw - The yaw of the boat in degrees, defaults to 0.
+	 */
+	#[napi(constructor)]
+	pub fn new(position: VectorLike, yaw: Option<f64>) -> Boat {
+		Boat {
+			position: position.clone(),
+			velocity: Vector3f::new(0.0, 0.0, 0.0),
+			yaw: yaw.unwrap_or(0.0),
+			delta_rotation: 0.0
+		}
+	}
+
+	/**
+	 * Advances the boat by a tick: applies gravity, buoyancy and friction for its status,
+	 * then the paddle input, then moves it by its velocity.
+	 *
+	 * @param status - Where the boat is relative to water and ground (BoatStatus).
+	 * @param input - The keys held by the paddling player, if any (BoatInput).
+	 * @param water_level - The height of the water surface, needed while in water.
+	 * @param land_friction - The averaged friction of the blocks below while on land, defaults to 0.6.
+	 * @param no_gravity - Whether the boat ignores gravity, defaults to false.
+	 */
+	#[napi]
+	pub fn step(&mut self, status: BoatStatus, input: Option<BoatInput>, water_level: Option<f64>, land_friction: Option<f64>, no_gravity: Option<bool>) {
+		let mut gravity = if no_gravity.unwrap_or(false) { 0.0 } else { GRAVITY };
+		let mut buoyancy = 0.0;
+
+		let friction = match status {
+			BoatStatus::InWater => {
+				buoyancy = water_level.map_or(0.0, |level| (level - self.position.y) / BOAT_HEIGHT);
+				WATER_FRICTION
+			},
+			BoatStatus::UnderFlowingWater => {
+				gravity = -7.0e-4;
+				WATER_FRICTION
+			},
+			BoatStatus::UnderWater => {
+				buoyancy = UNDERWATER_BUOYANCY;
+				UNDERWATER_FRICTION
+			},
+			BoatStatus::InAir => WATER_FRICTION,
+			// Vanilla halves the land friction of a controlled boat, but only after it was applied, so it never affects motion
+			BoatStatus::OnLand => land_friction.unwrap_or(0.6)
+		};
+
+		self.velocity = Vector3f::new(self.velocity.x * friction, self.velocity.y + gravity, self.velocity.z * friction);
+		self.delta_rotation *= friction;
+
+		if buoyancy > 0.0 {
+			self.velocity.y = (self.velocity.y + buoyancy * BUOYANCY) * 0.75;
+		}
+
+		if let Some(input) = input { self.paddle(input) }
+		self.position = self.position.add(&self.velocity);
+	}
+
+	/**
+	 * Applies a tick of paddle input: turning, and forward force along the yaw.
+	 * Turning in place also moves the boat forward slightly.
+	 *
+	 * @param input - The keys held by the paddling player (BoatInput).
+	 */
+	#[napi]
+	pub fn paddle(&mut self, input: BoatInput) {
+		let (forward, backward) = (input.forward.unwrap_or(false), input.backward.unwrap_or(false));
+		let (left, right) = (input.left.unwrap_or(false), input.right.unwrap_or(false));
+		let mut force = 0.0;
+
+		if left { self.delta_rotation -= 1.0 }
+		if right { self.delta_rotation += 1.0 }
+		if right != left && !forward && !backward { force += TURN_FORCE }
+
+		self.yaw += self.delta_rotation;
+
+		if forward { force += FORWARD_FORCE }
+		if backward { force -= TURN_FORCE }
+
+		let (sin, cos) = self.yaw.to_radians().sin_cos();
+		self.velocity = self.velocity.add(&Vector3f::new(-sin * force, 0.0, cos * force));
+	}
+
+	/**
+	 * Calculates the height a boat settles at when it lands on water, just above the surface.
+	 *
+	 * @param water_level - The height of the water surface.
+	 */
+	#[napi]
+	pub fn surface_height(water_level: f64) -> f64 {
+		water_level - BOAT_HEIGHT + 0.101
+	}
+}
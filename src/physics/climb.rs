@@ -0,0 +1,110 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const DETECTION_DEPTH: f64 = 0.1; // Thickness of the region checked in front of an entity
+
+/**
+ * Regions checked for climbable blocks and interactions around an entity, so every consumer
+ * uses the same offsets. Positions are at the feet of the entity, hitboxes are width x height x width.
+ * Yaw is in degrees, 0 faces south (+Z) and increases clockwise.
+ */
+#[napi]
+pub struct Climbing {}
+
+#[napi]
+impl Climbing {
+	/**
+	 * Calculates the thin region directly in front of the face of the hitbox the entity looks towards.
+	 * The yaw is snapped to the nearest cardinal direction, like the facing of the entity.
+	 *
+	 * @param position - The feet position of the entity (Vector3f).
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @param width - The width of the hitbox.
+	 * @param height - The height of the hitbox.
+	 * @param depth - The thickness of the region, defaults to 0.1.
+	 * @return The region in front of the entity.
+	 */
+	#[napi]
+	pub fn front_region(position: VectorLike, yaw: f64, width: f64, height: f64, depth: Option<f64>) -> AABB {
+		let depth = depth.unwrap_or(DETECTION_DEPTH).max(0.0);
+		let (dx, dz) = Climbing::facing(yaw);
+		let half = width * 0.5;
+
+		// Across the facing the region spans the hitbox; along it, it starts at the face.
+		let span = |d: f64, center: f64| match d {
+			d if d > 0.0 => (center + half, center + half + depth),
+			d if d < 0.0 => (center - half - depth, center - half),
+			_ => (center - half, center + half)
+		};
+
+		let (min_x, max_x) = span(dx, position.x);
+		let (min_z, max_z) = span(dz, position.z);
+
+		AABB::new(Vector3f::new(min_x, position.y, min_z).into(), Vector3f::new(max_x, position.y + height, max_z).into())
+	}
+
+	/**
+	 * Returns the block checked for a climbable block at the feet of an entity, like vanilla's onClimbable.
+	 *
+	 * @param position - The feet position of the entity (Vector3f).
+	 */
+	#[napi]
+	pub fn feet_block(position: VectorLike) -> Vector3f {
+		position.floor()
+	}
+
+	/**
+	 * Returns the blocks the region in front of an entity touches, to test for climbable blocks.
+	 *
+	 * @param position - The feet position of the entity (Vector3f).
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @param width - The width of the hitbox.
+	 * @param height - The height of the hitbox.
+	 * @param depth - The thickness of the region, defaults to 0.1.
+	 * @return The positions of the blocks, lowest first.
+	 * Throws if the region is not finite or spans more blocks than an array can hold.
+	 */
+	#[napi]
+	pub fn front_blocks(position: VectorLike, yaw: f64, width: f64, height: f64, depth: Option<f64>) -> napi::Result<Vec<Vector3f>> {
+		let region = Climbing::front_region(position, yaw, width, height, depth);
+		if ![region.min.x, region.min.y, region.min.z, region.max.x, region.max.y, region.max.z].iter().all(|c| c.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, "The region in front of the entity must be finite".to_string()));
+		}
+
+		let (min, max) = (region.min.floor(), region.max.subtract(&Vector3f::new(1e-7, 1e-7, 1e-7)).floor());
+		let count = (max.x - min.x + 1.0).max(0.0) * (max.y - min.y + 1.0).max(0.0) * (max.z - min.z + 1.0).max(0.0);
+		if count > u32::MAX as f64 {
+			return Err(Error::new(Status::InvalidArg, format!("The region in front of the entity spans more than {} blocks", u32::MAX)));
+		}
+
+		let mut blocks = Vec::new();
+		if blocks.try_reserve_exact(count as usize).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} blocks", count)));
+		}
+
+		for y in min.y as i64..=max.y as i64 {
+			for x in min.x as i64..=max.x as i64 {
+				for z in min.z as i64..=max.z as i64 {
+					blocks.push(Vector3f::new(x as f64, y as f64, z as f64));
+				}
+			}
+		}
+
+		return Ok(blocks);
+	}
+
+	/**
+	 * Snaps a yaw to the nearest cardinal direction, as x and z steps.
+	 */
+	fn facing(yaw: f64) -> (f64, f64) {
+		match ((yaw / 90.0).round() as i64).rem_euclid(4) {
+			0 => (0.0, 1.0), // South
+			1 => (-1.0, 0.0), // West
+			2 => (0.0, -1.0), // North
+			_ => (1.0, 0.0) // East
+		}
+	}
+}
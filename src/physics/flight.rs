@@ -0,0 +1,107 @@
+use napi_derive::napi;
+
+use crate::gameplay::damage::GRAVITY;
+use crate::rotation::Rotation;
+use crate::vec3f::{Vector3f, VectorLike};
+
+// Vanilla declares these as float literals widened to double, so they are widened the same way.
+const RIPTIDE_LIFT: f64 = 1.199_999_9_f32 as f64; // Blocks a grounded entity is moved up when launched by riptide
+const GLIDE_HORIZONTAL_DRAG: f64 = 0.99_f32 as f64; // Horizontal velocity kept per tick of gliding
+const GLIDE_VERTICAL_DRAG: f64 = 0.98_f32 as f64; // Vertical velocity kept per tick of gliding
+
+/**
+ * Elytra gliding and riptide launches with vanilla's formulas, for movement simulation and validation.
+ * Rotations are in degrees, yaw 0 faces south (+Z) and increases clockwise, positive pitch looks down.
+ */
+#[napi]
+pub struct Flight {}
+
+#[napi]
+impl Flight {
+	/**
+	 * Advances the velocity of a gliding entity by a tick. Looking down trades height for speed,
+	 * looking up trades speed for height, and the velocity is steered towards the look direction.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param pitch - The pitch of the entity in degrees.
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @param gravity - The gravity per tick, defaults to 0.08.
+	 * @return The velocity after the tick.
+	 */
+	#[napi]
+	pub fn glide(velocity: VectorLike, pitch: f64, yaw: f64, gravity: Option<f64>) -> Vector3f {
+		let gravity = gravity.unwrap_or(GRAVITY);
+		let look = Flight::look(pitch, yaw);
+		let radians = pitch.to_radians();
+
+		let horizontal_look = (look.x * look.x + look.z * look.z).sqrt();
+		let horizontal_speed = (velocity.x * velocity.x + velocity.z * velocity.z).sqrt();
+		let lift = radians.cos().powi(2) * (look.length() / 0.4).min(1.0);
+
+		let mut v = velocity.add(&Vector3f::new(0.0, gravity * (-1.0 + lift * 0.75), 0.0));
+
+		// Falling converts vertical speed into forward speed.
+		if v.y < 0.0 && horizontal_look > 0.0 {
+			let converted = v.y * -0.1 * lift;
+			v = v.add(&Vector3f::new(look.x * converted / horizontal_look, converted, look.z * converted / horizontal_look));
+		}
+
+		// Looking up converts forward speed into height.
+		if radians < 0.0 && horizontal_look > 0.0 {
+			let climb = horizontal_speed * -radians.sin() * 0.04;
+			v = v.add(&Vector3f::new(-look.x * climb / horizontal_look, climb * 3.2, -look.z * climb / horizontal_look));
+		}
+
+		if horizontal_look > 0.0 {
+			v = v.add(&Vector3f::new(
+				(look.x / horizontal_look * horizontal_speed - v.x) * 0.1,
+				0.0,
+				(look.z / horizontal_look * horizontal_speed - v.z) * 0.1
+			));
+		}
+
+		Vector3f::new(v.x * GLIDE_HORIZONTAL_DRAG, v.y * GLIDE_VERTICAL_DRAG, v.z * GLIDE_HORIZONTAL_DRAG)
+	}
+
+	/**
+	 * Applies a tick of firework rocket boost to a gliding entity, which pulls the velocity
+	 * towards 1.5 blocks per tick in the look direction.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param pitch - The pitch of the entity in degrees.
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @return The boosted velocity.
+	 */
+	#[napi]
+	pub fn firework_boost(velocity: VectorLike, pitch: f64, yaw: f64) -> Vector3f {
+		let look = Flight::look(pitch, yaw);
+		velocity.add(&look.multiply(0.1)).add(&look.multiply(1.5).subtract(&velocity).multiply(0.5))
+	}
+
+	/**
+	 * Calculates the velocity added by a riptide launch, 3 * (1 + level) / 4 blocks per tick in
+	 * the look direction. Grounded entities are additionally moved up by riptideLift.
+	 *
+	 * @param pitch - The pitch of the entity in degrees.
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @param level - The riptide level.
+	 * @return The velocity to add.
+	 */
+	#[napi]
+	pub fn riptide_launch(pitch: f64, yaw: f64, level: u32) -> Vector3f {
+		let speed = 3.0 * ((1.0 + level as f64) / 4.0);
+		Flight::look(pitch, yaw).multiply(speed)
+	}
+
+	/**
+	 * Returns the blocks a grounded entity is moved up when launched by riptide.
+	 */
+	#[napi]
+	pub fn riptide_lift() -> f64 {
+		RIPTIDE_LIFT
+	}
+
+	fn look(pitch: f64, yaw: f64) -> Vector3f {
+		Rotation::direction(yaw, pitch)
+	}
+}
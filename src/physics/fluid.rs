@@ -0,0 +1,166 @@
+use napi_derive::napi;
+
+use crate::gameplay::damage::GRAVITY;
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::movement::{Movement, MovementInput};
+
+const WATER_DRAG: f64 = 0.8; // Velocity kept per tick in water
+const SPRINT_WATER_DRAG: f64 = 0.9; // Horizontal velocity kept per tick while sprint swimming
+const DEPTH_STRIDER_DRAG: f64 = 0.546_000_06; // Horizontal drag approached with depth strider III
+const DOLPHINS_GRACE_DRAG: f64 = 0.96; // Horizontal drag with dolphin's grace
+const WATER_ACCELERATION: f64 = 0.02; // Movement input acceleration per tick in fluids
+const WATER_PUSH: f64 = 0.014; // Push of flowing water per tick
+const LAVA_PUSH: f64 = 0.007 / 3.0; // Push of flowing lava per tick outside the nether
+const MIN_PUSH: f64 = 0.0045; // Smallest push applied to an entity at rest
+
+/**
+ * Flags of an entity moving through water.
+ */
+#[napi(object)]
+pub struct SwimOptions {
+	pub sprinting: Option<bool>,
+	pub on_ground: Option<bool>,
+	pub depth_strider: Option<u32>, // Level of depth strider on the boots (0..3)
+	pub dolphins_grace: Option<bool>,
+	pub movement_speed: Option<f64>, // Movement speed attribute, defaults to 0.1
+	pub no_gravity: Option<bool>,
+}
+
+/**
+ * Per tick velocity updates of entities in fluids and bubble columns, with vanilla's constants.
+ * Movement input is applied before the drag of the tick, collisions are left to the caller.
+ * Rotations are in degrees, yaw 0 faces south (+Z) and increases clockwise.
+ */
+#[napi]
+pub struct FluidMotion {}
+
+#[napi]
+impl FluidMotion {
+	/**
+	 * Advances the velocity of an entity in water by a tick: input acceleration, drag and the
+	 * reduced gravity of fluids.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param input - The movement input (MovementInput).
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @param options - The flags of the entity (SwimOptions).
+	 * @return The velocity after the tick.
+	 */
+	#[napi]
+	pub fn water_step(velocity: VectorLike, input: MovementInput, yaw: f64, options: Option<SwimOptions>) -> Vector3f {
+		let options = options.unwrap_or(SwimOptions {
+			sprinting: None, on_ground: None, depth_strider: None, dolphins_grace: None, movement_speed: None, no_gravity: None
+		});
+		let sprinting = options.sprinting.unwrap_or(false);
+
+		let mut drag = if sprinting { SPRINT_WATER_DRAG } else { WATER_DRAG };
+		let mut acceleration = WATER_ACCELERATION;
+
+		let mut strider = options.depth_strider.unwrap_or(0).min(3) as f64;
+		if !options.on_ground.unwrap_or(false) { strider *= 0.5 }
+
+		if strider > 0.0 {
+			drag += (DEPTH_STRIDER_DRAG - drag) * strider / 3.0;
+			acceleration += (options.movement_speed.unwrap_or(0.1) - acceleration) * strider / 3.0;
+		}
+
+		if options.dolphins_grace.unwrap_or(false) { drag = DOLPHINS_GRACE_DRAG }
+
+		let v = velocity.add(&Movement::relative(input, yaw, acceleration));
+		let mut v = Vector3f::new(v.x * drag, v.y * WATER_DRAG, v.z * drag);
+
+		if !options.no_gravity.unwrap_or(false) && !sprinting {
+			v.y -= GRAVITY / 16.0;
+		}
+
+		return v;
+	}
+
+	/**
+	 * Advances the velocity of an entity in lava by a tick.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param input - The movement input (MovementInput).
+	 * @param yaw - The yaw of the entity in degrees.
+	 * @param gravity - The gravity per tick, defaults to 0.08.
+	 * @return The velocity after the tick.
+	 */
+	#[napi]
+	pub fn lava_step(velocity: VectorLike, input: MovementInput, yaw: f64, gravity: Option<f64>) -> Vector3f {
+		let v = velocity.add(&Movement::relative(input, yaw, WATER_ACCELERATION)).multiply(0.5);
+		Vector3f::new(v.x, v.y - gravity.unwrap_or(GRAVITY) / 4.0, v.z)
+	}
+
+	/**
+	 * Steers the vertical velocity of a sprint swimming player towards the look direction.
+	 * Looking down pulls harder (0.085) than looking up (0.06), and looking up only has an
+	 * effect while jumping or with fluid above the head.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param pitch - The pitch of the player in degrees.
+	 * @param jumping - Whether the player is holding jump.
+	 * @param fluid_above - Whether there is fluid above the head of the player.
+	 * @return The velocity with the vertical component adjusted.
+	 */
+	#[napi]
+	pub fn swim_vertical(velocity: VectorLike, pitch: f64, jumping: bool, fluid_above: bool) -> Vector3f {
+		let look = -pitch.to_radians().sin();
+		let pull = if look < -0.2 { 0.085 } else { 0.06 };
+
+		if look > 0.0 && !jumping && !fluid_above { return velocity.clone() }
+		Vector3f::new(velocity.x, velocity.y + (look - velocity.y) * pull, velocity.z)
+	}
+
+	/**
+	 * Returns the vertical velocity gained per tick by jumping in a fluid.
+	 */
+	#[napi]
+	pub fn jump_in_fluid(velocity: VectorLike) -> Vector3f {
+		Vector3f::new(velocity.x, velocity.y + 0.04, velocity.z)
+	}
+
+	/**
+	 * Pushes an entity along the flow of a fluid. Entities at rest are pushed at least 0.0045 blocks per tick.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param flow - The summed flow of the fluid blocks the entity touches (Vector3f).
+	 * @param lava - Whether the fluid is lava, defaults to false.
+	 * @return The pushed velocity; otherwise, the velocity unchanged for a flow that is zero or not finite.
+	 */
+	#[napi]
+	pub fn fluid_push(velocity: VectorLike, flow: VectorLike, lava: Option<bool>) -> Vector3f {
+		let length = flow.length();
+		if length == 0.0 || !length.is_finite() { return velocity.clone() }
+
+		let mut push = flow.normalize().multiply(if lava.unwrap_or(false) { LAVA_PUSH } else { WATER_PUSH });
+
+		if velocity.x.abs() < 0.003 && velocity.z.abs() < 0.003 && push.length() < MIN_PUSH {
+			push = push.normalize().multiply(MIN_PUSH);
+		}
+
+		velocity.add(&push)
+	}
+
+	/**
+	 * Applies a tick of a bubble column. Inside the column, magma columns drag down to at most 0.3
+	 * blocks per tick and soul sand columns lift up to 0.7; at the surface just above the column
+	 * the limits rise to 0.9 and 1.8, which launches entities out of the water.
+	 *
+	 * @param velocity - The current velocity (Vector3f).
+	 * @param drag_down - Whether the column drags down (magma) instead of up (soul sand).
+	 * @param surface - Whether the entity is in the air just above the column rather than inside it.
+	 * @return The velocity after the tick.
+	 */
+	#[napi]
+	pub fn bubble_column(velocity: VectorLike, drag_down: bool, surface: bool) -> Vector3f {
+		let y = match (drag_down, surface) {
+			(true, false) => (velocity.y - 0.03).max(-0.3),
+			(true, true) => (velocity.y - 0.03).max(-0.9),
+			(false, false) => (velocity.y + 0.06).min(0.7),
+			(false, true) => (velocity.y + 0.1).min(1.8)
+		};
+
+		Vector3f::new(velocity.x, y, velocity.z)
+	}
+}
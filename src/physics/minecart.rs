@@ -0,0 +1,138 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+use super::rail::{RailPath, RailShape};
+
+const GRAVITY: f64 = 0.04; // Downwards acceleration of minecarts per tick
+const SLOPE_ACCELERATION: f64 = 0.007_812_5; // Acceleration down ascending rails per tick
+const POWERED_BOOST: f64 = 0.06; // Acceleration of an active powered rail per tick
+const MAX_SPEED: f64 = 0.4; // Maximum movement per tick on rails
+const MAX_ALIGNED_SPEED: f64 = 2.0; // Maximum speed kept when aligning the velocity with a rail
+
+/**
+ * Represents the rail a minecart is on.
+ */
+#[napi(object)]
+pub struct MinecartRail {
+	pub shape: RailShape,
+	pub powered_rail: Option<bool>, // Whether the rail is a powered rail
+	pub active: Option<bool>, // Whether a powered rail is receiving power
+}
+
+/**
+ * Flags of a minecart.
+ */
+#[napi(object)]
+pub struct MinecartOptions {
+	pub occupied: Option<bool>, // Whether something rides the minecart, which keeps more speed
+	pub in_water: Option<bool>, // Whether the minecart is in water, which halves its top speed
+	pub on_ground: Option<bool>, // Whether a derailed minecart rests on the ground
+}
+
+/**
+ * Represents the state of a minecart after a step.
+ */
+#[napi(object)]
+pub struct MinecartStep {
+	pub position: Vector3f,
+	pub velocity: Vector3f,
+	pub on_rail: bool,
+}
+
+/**
+ * Minecart movement with vanilla's rail snapping, slope acceleration and powered rails.
+ * Block collisions are left to the caller; derailed carts only fall and slow down.
+ */
+#[napi]
+pub struct Minecart {}
+
+#[napi]
+impl Minecart {
+	/**
+	 * Advances a minecart by a tick.
+	 *
+	 * @param position - The position of the minecart (Vector3f).
+	 * @param velocity - The velocity of the minecart (Vector3f).
+	 * @param rail - The rail in the block at the position; undefined when derailed (MinecartRail).
+	 * @param options - The flags of the minecart (MinecartOptions).
+	 * @return The new position and velocity, and whether the minecart moved along a rail. A NaN velocity stops a minecart on a rail.
+	 */
+	#[napi]
+	pub fn step(position: VectorLike, velocity: VectorLike, rail: Option<MinecartRail>, options: Option<MinecartOptions>) -> MinecartStep {
+		let occupied = options.as_ref().and_then(|options| options.occupied).unwrap_or(false);
+		let in_water = options.as_ref().and_then(|options| options.in_water).unwrap_or(false);
+		let on_ground = options.as_ref().and_then(|options| options.on_ground).unwrap_or(false);
+		let max_speed = if in_water { MAX_SPEED / 2.0 } else { MAX_SPEED };
+
+		let Some(rail) = rail else { return Minecart::derail(&position, &velocity, max_speed, on_ground) };
+
+		let block = position.floor();
+		let shape = rail.shape;
+		let powered = rail.powered_rail.unwrap_or(false);
+		let active = powered && rail.active.unwrap_or(false);
+		let mut v = Vector3f::new(velocity.x, 0.0, velocity.z);
+
+		// Ascending rails pull the minecart towards their lower end.
+		match shape {
+			RailShape::AscendingEast => v.x -= SLOPE_ACCELERATION,
+			RailShape::AscendingWest => v.x += SLOPE_ACCELERATION,
+			RailShape::AscendingNorth => v.z += SLOPE_ACCELERATION,
+			RailShape::AscendingSouth => v.z -= SLOPE_ACCELERATION,
+			_ => {}
+		}
+
+		// The velocity is turned along the rail, keeping its horizontal speed.
+		let (a, b) = RailPath::exit_offsets(shape);
+		let mut direction = Vector3f::new(b.0 - a.0, 0.0, b.2 - a.2).normalize();
+		if v.x * direction.x + v.z * direction.z < 0.0 { direction = direction.multiply(-1.0) }
+
+		// A NaN speed stops the minecart rather than reaching the cap, which f64::min would pick.
+		let speed = (v.x * v.x + v.z * v.z).sqrt();
+		let speed = if speed.is_nan() { 0.0 } else { speed.min(MAX_ALIGNED_SPEED) };
+		v = direction.multiply(speed);
+
+		// Unpowered powered rails brake and stop slow minecarts.
+		if powered && !active {
+			v = if speed < 0.03 { Vector3f::new(0.0, 0.0, 0.0) } else { v.multiply(0.5) };
+		}
+
+		let snapped = RailPath::evaluate(VectorLike::from(&block), shape, RailPath::project(VectorLike::from(&block), shape, position), None).position;
+		let factor = if occupied { 0.75 } else { 1.0 };
+		let mut moved = Vector3f::new(
+			snapped.x + (v.x * factor).clamp(-max_speed, max_speed),
+			snapped.y,
+			snapped.z + (v.z * factor).clamp(-max_speed, max_speed)
+		);
+
+		// The height follows the rail, and leaving an ascending rail at its top moves up a block.
+		moved.y = RailPath::evaluate(VectorLike::from(&block), shape, RailPath::project(VectorLike::from(&block), shape, VectorLike::from(&moved)), None).position.y;
+
+		let drag = if occupied { 0.997 } else { 0.96 };
+		v = Vector3f::new(v.x * drag, 0.0, v.z * drag);
+
+		if active {
+			let speed = (v.x * v.x + v.z * v.z).sqrt();
+			if speed > 0.01 { v = v.add(&v.multiply(POWERED_BOOST / speed)) }
+		}
+
+		MinecartStep { position: moved, velocity: v, on_rail: true }
+	}
+
+	/**
+	 * Moves a minecart without a rail: it falls, its speed is capped, and it is slowed by the ground or air.
+	 */
+	fn derail(position: &Vector3f, velocity: &Vector3f, max_speed: f64, on_ground: bool) -> MinecartStep {
+		let mut v = Vector3f::new(
+			velocity.x.clamp(-max_speed, max_speed),
+			velocity.y - GRAVITY,
+			velocity.z.clamp(-max_speed, max_speed)
+		);
+
+		if on_ground { v = v.multiply(0.5) }
+		let position = position.add(&v);
+		if !on_ground { v = v.multiply(0.95) }
+
+		MinecartStep { position, velocity: v, on_rail: false }
+	}
+}
@@ -0,0 +1,10 @@
+pub mod boat;
+pub mod climb;
+pub mod flight;
+pub mod fluid;
+pub mod minecart;
+pub mod movement;
+pub mod platform;
+pub mod push;
+pub mod rail;
+pub mod state;
\ No newline at end of file
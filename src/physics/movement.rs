@@ -0,0 +1,89 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Represents movement input relative to the facing of an entity, as Bedrock encodes it.
+ * Positive forward moves in the facing direction, positive strafe moves to the left.
+ */
+#[napi(object)]
+pub struct MovementInput {
+	pub forward: f64,
+	pub strafe: f64,
+}
+
+/**
+ * Conversions between world space movement and yaw relative movement input.
+ * Yaw is in degrees, 0 faces south (+Z) and increases clockwise.
+ */
+#[napi]
+pub struct Movement {}
+
+#[napi]
+impl Movement {
+	/**
+	 * Decomposes a horizontal velocity into forward and strafe components relative to a yaw.
+	 *
+	 * @param velocity - The world space velocity, the y component is ignored (Vector3f).
+	 * @param yaw - The yaw the movement is relative to, in degrees.
+	 * @return The forward and strafe components.
+	 */
+	#[napi]
+	pub fn decompose(velocity: VectorLike, yaw: f64) -> MovementInput {
+		let (sin, cos) = yaw.to_radians().sin_cos();
+
+		MovementInput {
+			forward: velocity.z * cos - velocity.x * sin,
+			strafe: velocity.x * cos + velocity.z * sin
+		}
+	}
+
+	/**
+	 * Composes a horizontal world space velocity from forward and strafe input.
+	 * This is the inverse of decompose.
+	 *
+	 * @param input - The forward and strafe components (MovementInput).
+	 * @param yaw - The yaw the input is relative to, in degrees.
+	 * @return The world space velocity, with a y component of 0.
+	 */
+	#[napi]
+	pub fn compose(input: MovementInput, yaw: f64) -> Vector3f {
+		let (sin, cos) = yaw.to_radians().sin_cos();
+
+		Vector3f::new(
+			input.strafe * cos - input.forward * sin,
+			0.0,
+			input.forward * cos + input.strafe * sin
+		)
+	}
+
+	/**
+	 * Applies movement input the way vanilla does each tick: input longer than 1 is
+	 * normalized before being scaled by the speed and rotated by the yaw.
+	 *
+	 * @param input - The raw movement input (MovementInput).
+	 * @param yaw - The yaw of the entity, in degrees.
+	 * @param speed - The movement speed of the entity.
+	 * @return The velocity to add for this tick.
+	 */
+	#[napi]
+	pub fn relative(input: MovementInput, yaw: f64, speed: f64) -> Vector3f {
+		let length_squared = input.forward * input.forward + input.strafe * input.strafe;
+		if length_squared < 1.0e-7 { return Vector3f::new(0.0, 0.0, 0.0) }
+
+		let scale = if length_squared > 1.0 { speed / length_squared.sqrt() } else { speed };
+		Movement::compose(MovementInput { forward: input.forward * scale, strafe: input.strafe * scale }, yaw)
+	}
+
+	/**
+	 * Calculates the signed difference between a body and head yaw, wrapped to -180..180.
+	 *
+	 * @param body_yaw - The body yaw in degrees.
+	 * @param head_yaw - The head yaw in degrees.
+	 */
+	#[napi]
+	pub fn yaw_difference(body_yaw: f64, head_yaw: f64) -> f64 {
+		let difference = (head_yaw - body_yaw).rem_euclid(360.0);
+		if difference >= 180.0 { difference - 360.0 } else { difference }
+	}
+}
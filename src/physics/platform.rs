@@ -0,0 +1,90 @@
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const STANDING_TOLERANCE: f64 = 0.05; // Gap between the feet and the platform still counted as standing on it
+
+/**
+ * Represents how a platform carries a rider during a step.
+ */
+#[napi(object)]
+pub struct PlatformCarry {
+	pub displacement: Vector3f, // Movement to apply to the rider, zero when it was not standing on the platform
+	pub standing: bool, // Whether the rider was standing on the platform before the step
+	pub supported: bool, // Whether the rider still stands on the platform after being carried
+}
+
+/**
+ * Kinematic platforms such as elevators and moving floors, which carry the entities standing on them.
+ */
+#[napi]
+pub struct Platform {}
+
+#[napi]
+impl Platform {
+	/**
+	 * Computes how far a rider is carried by a platform moving from one box to another, optionally
+	 * turning around its center. The feet of the rider follow the top of the platform, and turning
+	 * swings the rider around the center of the platform.
+	 *
+	 * @param old_box - The box of the platform before the step (AABB).
+	 * @param new_box - The box of the platform after the step (AABB).
+	 * @param rider - The hitbox of the rider before the step (AABB).
+	 * @param rotation - The yaw the platform turned by during the step in degrees, clockwise like entity yaw, defaults to 0.
+	 *                   A rotation that is not finite is ignored.
+	 * @param tolerance - The gap between the feet and the platform still counted as standing, defaults to 0.05.
+	 * @return The displacement of the rider, whether it was standing and whether it is still supported.
+	 */
+	#[napi]
+	pub fn carry(old_box: &AABB, new_box: &AABB, rider: &AABB, rotation: Option<f64>, tolerance: Option<f64>) -> PlatformCarry {
+		let tolerance = tolerance.unwrap_or(STANDING_TOLERANCE);
+
+		if !Platform::stands_on(old_box, rider, tolerance) {
+			return PlatformCarry { displacement: Vector3f::new(0.0, 0.0, 0.0), standing: false, supported: false };
+		}
+
+		let (old_center, new_center) = (old_box.min.add(&old_box.max).multiply(0.5), new_box.min.add(&new_box.max).multiply(0.5));
+		let feet = Vector3f::new((rider.min.x + rider.max.x) * 0.5, rider.min.y, (rider.min.z + rider.max.z) * 0.5);
+
+		// Swing the feet around the old center, then follow the platform to its new place.
+		let (sin, cos) = rotation.filter(|rotation| rotation.is_finite()).unwrap_or(0.0).to_radians().sin_cos();
+		let (x, z) = (feet.x - old_center.x, feet.z - old_center.z);
+		let carried = Vector3f::new(
+			new_center.x + x * cos - z * sin,
+			feet.y + new_box.max.y - old_box.max.y,
+			new_center.z + x * sin + z * cos
+		);
+
+		let displacement = carried.subtract(&feet);
+		let moved = rider.translate(VectorLike::from(&displacement));
+
+		PlatformCarry {
+			supported: Platform::stands_on(new_box, &moved, tolerance),
+			displacement,
+			standing: true
+		}
+	}
+
+	/**
+	 * Checks whether a rider stands on top of a platform: its feet are at the top of the platform
+	 * and its footprint overlaps the platform.
+	 *
+	 * @param platform - The box of the platform (AABB).
+	 * @param rider - The hitbox of the rider (AABB).
+	 * @param tolerance - The gap between the feet and the platform still counted as standing, defaults to 0.05.
+	 */
+	#[napi]
+	pub fn is_standing(platform: &AABB, rider: &AABB, tolerance: Option<f64>) -> bool {
+		Platform::stands_on(platform, rider, tolerance.unwrap_or(STANDING_TOLERANCE))
+	}
+
+	/**
+	 * Checks the feet of the rider against the top face of the platform.
+	 */
+	fn stands_on(platform: &AABB, rider: &AABB, tolerance: f64) -> bool {
+		(rider.min.y - platform.max.y).abs() <= tolerance
+			&& rider.min.x < platform.max.x && rider.max.x > platform.min.x
+			&& rider.min.z < platform.max.z && rider.max.z > platform.min.z
+	}
+}
@@ -0,0 +1,177 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * The shapes a rail block can take, numbered like the rail_direction block state.
+ */
+#[napi]
+#[derive(PartialEq)]
+pub enum RailShape {
+	NorthSouth = 0,
+	EastWest = 1,
+	AscendingEast = 2,
+	AscendingWest = 3,
+	AscendingNorth = 4,
+	AscendingSouth = 5,
+	SouthEast = 6,
+	SouthWest = 7,
+	NorthWest = 8,
+	NorthEast = 9
+}
+
+/**
+ * Represents a point on a rail path.
+ */
+#[napi(object)]
+pub struct RailPoint {
+	pub position: Vector3f, // World position on the rail
+	pub direction: Vector3f, // Normalized direction of travel at the position
+}
+
+/**
+ * Evaluates the path a minecart follows along a rail block.
+ * Progress 0 is the first exit of the shape and 1 the second exit; positions are
+ * relative to the block origin unless a block position is added.
+ */
+#[napi]
+pub struct RailPath {}
+
+#[napi]
+impl RailPath {
+	/**
+	 * Returns the two exits of a rail shape as block offsets, like vanilla's exit table.
+	 * An exit with a y of -1 marks the lower end of an ascending rail.
+	 *
+	 * @param shape - The rail shape (RailShape).
+	 */
+	#[napi]
+	pub fn exits(shape: RailShape) -> Vec<Vector3f> {
+		let ((ax, ay, az), (bx, by, bz)) = RailPath::exit_offsets(shape);
+		vec![Vector3f::new(ax, ay, az), Vector3f::new(bx, by, bz)]
+	}
+
+	/**
+	 * Evaluates the position and direction on a rail for a progress value.
+	 * Curved rails follow the straight chord between their exits like vanilla minecarts,
+	 * or a quarter circle when smooth is set.
+	 *
+	 * @param block - The position of the rail block (Vector3f).
+	 * @param shape - The rail shape (RailShape).
+	 * @param t - The progress along the rail from the first to the second exit (0..1).
+	 * @param smooth - Whether curved rails follow an arc, defaults to false.
+	 * @return The position and direction of travel.
+	 */
+	#[napi]
+	pub fn evaluate(block: VectorLike, shape: RailShape, t: f64, smooth: Option<bool>) -> RailPoint {
+		let t = t.clamp(0.0, 1.0);
+		let (start, end) = RailPath::endpoints(shape);
+		let origin = block.floor();
+
+		if smooth.unwrap_or(false) && RailPath::is_curved(shape) {
+			// The arc is centered on the corner shared by both exits.
+			let (a, b) = RailPath::exit_offsets(shape);
+			let corner = Vector3f::new(0.5 + (a.0 + b.0) * 0.5, 0.0, 0.5 + (a.2 + b.2) * 0.5);
+			let from = start.subtract(&corner);
+			let to = end.subtract(&corner);
+
+			let start_angle = from.z.atan2(from.x);
+			let mut sweep = to.z.atan2(to.x) - start_angle;
+			if sweep > std::f64::consts::PI { sweep -= std::f64::consts::TAU }
+			if sweep < -std::f64::consts::PI { sweep += std::f64::consts::TAU }
+
+			let angle = start_angle + sweep * t;
+			let (sin, cos) = angle.sin_cos();
+			let position = Vector3f::new(corner.x + cos * 0.5, 0.0, corner.z + sin * 0.5);
+			let direction = Vector3f::new(-sin * sweep.signum(), 0.0, cos * sweep.signum());
+
+			return RailPoint { position: position.add(&origin), direction };
+		}
+
+		RailPoint {
+			position: start.lerp(&end, t).add(&origin),
+			direction: end.subtract(&start).normalize()
+		}
+	}
+
+	/**
+	 * Calculates the length of the path along a rail block.
+	 *
+	 * @param shape - The rail shape (RailShape).
+	 * @param smooth - Whether curved rails follow an arc, defaults to false.
+	 */
+	#[napi]
+	pub fn length(shape: RailShape, smooth: Option<bool>) -> f64 {
+		if smooth.unwrap_or(false) && RailPath::is_curved(shape) {
+			return std::f64::consts::FRAC_PI_4;
+		}
+
+		let (start, end) = RailPath::endpoints(shape);
+		start.distance(&end)
+	}
+
+	/**
+	 * Projects a position onto the straight path of a rail block, returning the progress
+	 * of the closest point. This matches how vanilla snaps minecarts onto rails.
+	 *
+	 * @param block - The position of the rail block (Vector3f).
+	 * @param shape - The rail shape (RailShape).
+	 * @param position - The position to project (Vector3f).
+	 * @return The progress of the closest point on the rail (0..1).
+	 */
+	#[napi]
+	pub fn project(block: VectorLike, shape: RailShape, position: VectorLike) -> f64 {
+		let (start, end) = RailPath::endpoints(shape);
+		let origin = block.floor();
+		let start = start.add(&origin);
+		let segment = end.add(&origin).subtract(&start);
+
+		// Only the horizontal position determines progress, the height follows the rail.
+		let horizontal = Vector3f::new(segment.x, 0.0, segment.z);
+		let length = horizontal.square_length();
+		if length == 0.0 { return 0.0 }
+
+		let offset = position.subtract(&start);
+		((offset.x * horizontal.x + offset.z * horizontal.z) / length).clamp(0.0, 1.0)
+	}
+
+	#[napi]
+	pub fn is_ascending(shape: RailShape) -> bool {
+		matches!(shape, RailShape::AscendingEast | RailShape::AscendingWest | RailShape::AscendingNorth | RailShape::AscendingSouth)
+	}
+
+	#[napi]
+	pub fn is_curved(shape: RailShape) -> bool {
+		matches!(shape, RailShape::SouthEast | RailShape::SouthWest | RailShape::NorthWest | RailShape::NorthEast)
+	}
+
+	pub(crate) fn exit_offsets(shape: RailShape) -> ((f64, f64, f64), (f64, f64, f64)) {
+		match shape {
+			RailShape::NorthSouth => ((0.0, 0.0, -1.0), (0.0, 0.0, 1.0)),
+			RailShape::EastWest => ((-1.0, 0.0, 0.0), (1.0, 0.0, 0.0)),
+			RailShape::AscendingEast => ((-1.0, -1.0, 0.0), (1.0, 0.0, 0.0)),
+			RailShape::AscendingWest => ((-1.0, 0.0, 0.0), (1.0, -1.0, 0.0)),
+			RailShape::AscendingNorth => ((0.0, 0.0, -1.0), (0.0, -1.0, 1.0)),
+			RailShape::AscendingSouth => ((0.0, -1.0, -1.0), (0.0, 0.0, 1.0)),
+			RailShape::SouthEast => ((0.0, 0.0, 1.0), (1.0, 0.0, 0.0)),
+			RailShape::SouthWest => ((0.0, 0.0, 1.0), (-1.0, 0.0, 0.0)),
+			RailShape::NorthWest => ((0.0, 0.0, -1.0), (-1.0, 0.0, 0.0)),
+			RailShape::NorthEast => ((0.0, 0.0, -1.0), (1.0, 0.0, 0.0))
+		}
+	}
+
+	/**
+	 * Returns the block relative points where the path meets the edges of the block.
+	 * Ascending rails rise from the bottom of the block at their lower exit to the top at the other.
+	 */
+	pub(crate) fn endpoints(shape: RailShape) -> (Vector3f, Vector3f) {
+		let (a, b) = RailPath::exit_offsets(shape);
+		let ascending = RailPath::is_ascending(shape);
+		let height = |dy: f64| if ascending && dy == 0.0 { 1.0 } else { 0.0 };
+
+		(
+			Vector3f::new(0.5 + a.0 * 0.5, height(a.1), 0.5 + a.2 * 0.5),
+			Vector3f::new(0.5 + b.0 * 0.5, height(b.1), 0.5 + b.2 * 0.5)
+		)
+	}
+}
@@ -0,0 +1,277 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::gameplay::damage::{GRAVITY, VERTICAL_DRAG};
+use crate::vec3f::{Vector3f, VectorLike};
+
+const HORIZONTAL_DRAG: f64 = 0.91; // Horizontal velocity kept per tick in air
+
+/**
+ * Stores the positions, velocities and hitbox sizes of many entities in packed arrays,
+ * with kernels running a tick of simple motion for all of them in a few calls.
+ * Positions are at the feet of the entity, hitboxes are width x height x width like vanilla.
+ */
+#[napi]
+pub struct EntityStateBuffer {
+	positions: Vec<f64>, // x, y, z per entity
+	velocities: Vec<f64>, // x, y, z per entity
+	sizes: Vec<f64>, // Width and height per entity
+	bounds: Vec<f64>, // Min x, y, z and max x, y, z per entity
+	snapshot: Vec<f64> // Positions at the last dirty check
+}
+
+#[napi]
+impl EntityStateBuffer {
+	/**
+	 * Constructs a buffer of entities at the origin, at rest and without a hitbox.
+	 *
+	 * @param count - The number of entities.
+	 */
+	#[napi(constructor)]
+	pub fn new(count: u32) -> napi::Result<EntityStateBuffer> {
+		let mut buffer = EntityStateBuffer {
+			positions: Vec::new(),
+			velocities: Vec::new(),
+			sizes: Vec::new(),
+			bounds: Vec::new(),
+			snapshot: Vec::new()
+		};
+
+		buffer.resize(count)?;
+		Ok(buffer)
+	}
+
+	/**
+	 * Returns the number of entities.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		(self.positions.len() / 3) as u32
+	}
+
+	/**
+	 * Changes the number of entities, appending entities at the origin or dropping the last ones.
+	 * Returns an error, leaving the buffer unchanged, when the count is too large or there is not enough memory.
+	 *
+	 * @param count - The new number of entities.
+	 */
+	#[napi]
+	pub fn resize(&mut self, count: u32) -> napi::Result<()> {
+		// The widest array holds 6 values per entity and must still fit in a typed array.
+		if count as u64 * 6 > u32::MAX as u64 {
+			return Err(Error::new(Status::InvalidArg, format!("Buffers hold at most {} entities, got {}", u32::MAX / 6, count)));
+		}
+
+		let count = count as usize;
+		let mut arrays = [(&mut self.positions, 3), (&mut self.velocities, 3), (&mut self.sizes, 2), (&mut self.bounds, 6), (&mut self.snapshot, 3)];
+
+		// Reserve every array before resizing any, so a failed allocation leaves the buffer unchanged.
+		for (values, width) in arrays.iter_mut() {
+			if values.try_reserve_exact((count * *width).saturating_sub(values.len())).is_err() {
+				return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} entities", count)));
+			}
+		}
+
+		for (values, width) in arrays { values.resize(count * width, 0.0) }
+		Ok(())
+	}
+
+	/**
+	 * Returns the positions, packed as x, y, z triplets (Float64Array).
+	 */
+	#[napi]
+	pub fn positions(&self) -> Float64Array {
+		Float64Array::new(self.positions.clone())
+	}
+
+	/**
+	 * Replaces the positions of all entities.
+	 *
+	 * @param positions - The positions, packed as x, y, z triplets (Float64Array).
+	 */
+	#[napi]
+	pub fn set_positions(&mut self, positions: Float64Array) -> napi::Result<()> {
+		EntityStateBuffer::assign(&mut self.positions, &positions, "positions")
+	}
+
+	/**
+	 * Returns the velocities, packed as x, y, z triplets (Float64Array).
+	 */
+	#[napi]
+	pub fn velocities(&self) -> Float64Array {
+		Float64Array::new(self.velocities.clone())
+	}
+
+	/**
+	 * Replaces the velocities of all entities.
+	 *
+	 * @param velocities - The velocities, packed as x, y, z triplets (Float64Array).
+	 */
+	#[napi]
+	pub fn set_velocities(&mut self, velocities: Float64Array) -> napi::Result<()> {
+		EntityStateBuffer::assign(&mut self.velocities, &velocities, "velocities")
+	}
+
+	/**
+	 * Replaces the hitbox sizes of all entities.
+	 *
+	 * @param sizes - The sizes, packed as width, height pairs (Float64Array).
+	 */
+	#[napi]
+	pub fn set_sizes(&mut self, sizes: Float64Array) -> napi::Result<()> {
+		EntityStateBuffer::assign(&mut self.sizes, &sizes, "sizes")
+	}
+
+	/**
+	 * Returns the hitboxes as computed by the last recomputeBounds call.
+	 *
+	 * @return The boxes, packed as min x, y, z and max x, y, z (Float64Array).
+	 */
+	#[napi]
+	pub fn bounds(&self) -> Float64Array {
+		Float64Array::new(self.bounds.clone())
+	}
+
+	/**
+	 * Returns the position of an entity.
+	 *
+	 * @param index - The index of the entity.
+	 */
+	#[napi]
+	pub fn get_position(&self, index: u32) -> Option<Vector3f> {
+		let i = index as usize * 3;
+		self.positions.get(i..i + 3).map(|p| Vector3f::new(p[0], p[1], p[2]))
+	}
+
+	/**
+	 * Sets the position of an entity. Out of range indices are ignored.
+	 *
+	 * @param index - The index of the entity.
+	 * @param position - The new position (Vector3f).
+	 */
+	#[napi]
+	pub fn set_position(&mut self, index: u32, position: VectorLike) {
+		let i = index as usize * 3;
+		if let Some(p) = self.positions.get_mut(i..i + 3) { p.copy_from_slice(&[position.x, position.y, position.z]) }
+	}
+
+	/**
+	 * Returns the velocity of an entity.
+	 *
+	 * @param index - The index of the entity.
+	 */
+	#[napi]
+	pub fn get_velocity(&self, index: u32) -> Option<Vector3f> {
+		let i = index as usize * 3;
+		self.velocities.get(i..i + 3).map(|v| Vector3f::new(v[0], v[1], v[2]))
+	}
+
+	/**
+	 * Sets the velocity of an entity. Out of range indices are ignored.
+	 *
+	 * @param index - The index of the entity.
+	 * @param velocity - The new velocity (Vector3f).
+	 */
+	#[napi]
+	pub fn set_velocity(&mut self, index: u32, velocity: VectorLike) {
+		let i = index as usize * 3;
+		if let Some(v) = self.velocities.get_mut(i..i + 3) { v.copy_from_slice(&[velocity.x, velocity.y, velocity.z]) }
+	}
+
+	/**
+	 * Moves every entity by its velocity.
+	 *
+	 * @param delta - The number of ticks to move for, defaults to 1.
+	 */
+	#[napi]
+	pub fn integrate(&mut self, delta: Option<f64>) {
+		let delta = delta.unwrap_or(1.0);
+
+		for (position, velocity) in self.positions.iter_mut().zip(&self.velocities) {
+			*position += velocity * delta;
+		}
+	}
+
+	/**
+	 * Applies a tick of gravity and air drag to every velocity, in vanilla order:
+	 * gravity is subtracted first, then the vertical and horizontal drag are applied.
+	 *
+	 * @param gravity - The downwards acceleration per tick, defaults to 0.08.
+	 * @param vertical_drag - The vertical velocity kept per tick, defaults to 0.98.
+	 * @param horizontal_drag - The horizontal velocity kept per tick, defaults to 0.91.
+	 */
+	#[napi]
+	pub fn apply_gravity(&mut self, gravity: Option<f64>, vertical_drag: Option<f64>, horizontal_drag: Option<f64>) {
+		let gravity = gravity.unwrap_or(GRAVITY);
+		let vertical = vertical_drag.unwrap_or(VERTICAL_DRAG);
+		let horizontal = horizontal_drag.unwrap_or(HORIZONTAL_DRAG);
+
+		for velocity in self.velocities.chunks_exact_mut(3) {
+			velocity[0] *= horizontal;
+			velocity[1] = (velocity[1] - gravity) * vertical;
+			velocity[2] *= horizontal;
+		}
+	}
+
+	/**
+	 * Recomputes the hitbox of every entity from its position and size.
+	 *
+	 * @return The boxes, packed as min x, y, z and max x, y, z (Float64Array).
+	 */
+	#[napi]
+	pub fn recompute_bounds(&mut self) -> Float64Array {
+		let entities = self.positions.chunks_exact(3).zip(self.sizes.chunks_exact(2));
+
+		for (bounds, (position, size)) in self.bounds.chunks_exact_mut(6).zip(entities) {
+			let half = size[0] * 0.5;
+
+			bounds.copy_from_slice(&[
+				position[0] - half, position[1], position[2] - half,
+				position[0] + half, position[1] + size[1], position[2] + half
+			]);
+		}
+
+		return self.bounds();
+	}
+
+	/**
+	 * Finds the entities that moved further than a threshold since their last reported movement,
+	 * then remembers their positions for the next check.
+	 *
+	 * @param threshold - The distance an entity must move to be dirty, defaults to 0.
+	 * @return The indices of the dirty entities.
+	 */
+	#[napi]
+	pub fn compute_dirty(&mut self, threshold: Option<f64>) -> Vec<u32> {
+		let threshold = threshold.unwrap_or(0.0).max(0.0);
+		let mut dirty = Vec::new();
+
+		for (index, (position, last)) in self.positions.chunks_exact(3).zip(self.snapshot.chunks_exact_mut(3)).enumerate() {
+			let (dx, dy, dz) = (position[0] - last[0], position[1] - last[1], position[2] - last[2]);
+			let moved = dx * dx + dy * dy + dz * dz;
+
+			if moved > threshold * threshold {
+				last.copy_from_slice(position);
+				dirty.push(index as u32);
+			}
+		}
+
+		return dirty;
+	}
+
+	/**
+	 * Copies packed values into a store, requiring the same length.
+	 */
+	fn assign(target: &mut [f64], values: &[f64], name: &str) -> napi::Result<()> {
+		if values.len() != target.len() {
+			return Err(Error::new(
+				Status::InvalidArg,
+				format!("Expected {} {} values, got {}", target.len(), name, values.len())
+			));
+		}
+
+		target.copy_from_slice(values);
+		Ok(())
+	}
+}
@@ -0,0 +1,98 @@
+use napi::bindgen_prelude::Float64Array;
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+const COINCIDENT: f64 = 1e-12; // Distance below which the median estimate sits on a point
+
+/**
+ * Central points of groups of positions, for gathering points, spawn anchors and AI group targets.
+ */
+#[napi]
+pub struct Points {}
+
+#[napi]
+impl Points {
+	/**
+	 * Calculates the centroid (mean position) of points.
+	 *
+	 * @param points - The positions, packed as x, y, z triplets (Float64Array).
+	 * @param weights - The weight of every point, defaults to 1 each.
+	 * @return The centroid; otherwise, undefined without points or with a total weight of 0.
+	 */
+	#[napi]
+	pub fn centroid(points: Float64Array, weights: Option<Vec<f64>>) -> Option<Vector3f> {
+		let points = Points::unpack(&points);
+		let weight = |index: usize| weights.as_ref().map_or(1.0, |weights| weights.get(index).copied().unwrap_or(0.0));
+
+		let mut sum = Vector3f::new(0.0, 0.0, 0.0);
+		let mut total = 0.0;
+
+		for (index, point) in points.iter().enumerate() {
+			sum = sum.add(&point.multiply(weight(index)));
+			total += weight(index);
+		}
+
+		if total == 0.0 { None } else { Some(sum.multiply(1.0 / total)) }
+	}
+
+	/**
+	 * Approximates the geometric median of points with Weiszfeld's algorithm: the position with the
+	 * smallest summed distance to all points. Unlike the centroid it is not dragged away by outliers.
+	 *
+	 * @param points - The positions, packed as x, y, z triplets (Float64Array).
+	 * @param max_iterations - The maximum number of refinement steps, defaults to 100.
+	 * @param tolerance - The movement of the estimate below which it is considered converged, defaults to 1e-6.
+	 * @return The geometric median; otherwise, undefined without points.
+	 */
+	#[napi]
+	pub fn geometric_median(points: Float64Array, max_iterations: Option<u32>, tolerance: Option<f64>) -> Option<Vector3f> {
+		let points = Points::unpack(&points);
+		let tolerance = tolerance.unwrap_or(1e-6);
+
+		if points.is_empty() { return None }
+
+		let mut median = points.iter()
+			.fold(Vector3f::new(0.0, 0.0, 0.0), |sum, point| sum.add(point))
+			.multiply(1.0 / points.len() as f64);
+
+		for _ in 0..max_iterations.unwrap_or(100) {
+			let mut sum = Vector3f::new(0.0, 0.0, 0.0);
+			let mut total = 0.0;
+
+			// Points the estimate sits on are skipped, their inverse distance is unbounded.
+			for point in &points {
+				let distance = point.distance(&median);
+				if distance < COINCIDENT { continue }
+
+				sum = sum.add(&point.multiply(1.0 / distance));
+				total += 1.0 / distance;
+			}
+
+			if total == 0.0 { break }
+
+			let next = sum.multiply(1.0 / total);
+			let moved = next.distance(&median);
+			median = next;
+
+			if moved < tolerance { break }
+		}
+
+		return Some(median);
+	}
+
+	/**
+	 * Sums the distances from a position to all points, the quantity the geometric median minimizes.
+	 *
+	 * @param points - The positions, packed as x, y, z triplets (Float64Array).
+	 * @param position - The position to measure from (Vector3f).
+	 */
+	#[napi]
+	pub fn total_distance(points: Float64Array, position: VectorLike) -> f64 {
+		Points::unpack(&points).iter().map(|point| point.distance(&position)).sum()
+	}
+
+	fn unpack(points: &Float64Array) -> Vec<Vector3f> {
+		points.chunks_exact(3).map(|p| Vector3f::new(p[0], p[1], p[2])).collect()
+	}
+}
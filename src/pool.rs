@@ -0,0 +1,134 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use napi::bindgen_prelude::{FromNapiRef, Reference};
+use napi::{Env, JsFunction, JsUnknown, NapiRaw, ValueType};
+use napi_derive::napi;
+
+use crate::vec3f::Vector3f;
+
+/**
+ * Hands out reusable Vector3f instances, so code running per entity per tick does not
+ * allocate thousands of short lived vectors. Pooled vectors are kept alive by the pool,
+ * and must not be used after they are released.
+ */
+#[napi]
+pub struct VectorPool {
+	free: RefCell<Vec<Reference<Vector3f>>>,
+	pooled: RefCell<HashSet<usize>>, // Addresses of the vectors in free, so a vector is never pooled twice
+	scopes: RefCell<Vec<Vec<Reference<Vector3f>>>> // Vectors acquired by every active scope
+}
+
+#[napi]
+impl VectorPool {
+	/**
+	 * Constructs an empty pool.
+	 */
+	#[napi(constructor)]
+	pub fn new() -> VectorPool {
+		VectorPool {
+			free: RefCell::new(Vec::new()),
+			pooled: RefCell::new(HashSet::new()),
+			scopes: RefCell::new(Vec::new())
+		}
+	}
+
+	/**
+	 * Takes a vector from the pool, or creates one when the pool is empty. The vector is reset to zero.
+	 */
+	#[napi(ts_return_type = "Vector3f")]
+	pub fn acquire(&self, env: Env) -> napi::Result<Reference<Vector3f>> {
+		let pooled = self.free.borrow_mut().pop();
+		let mut vector = match pooled {
+			Some(vector) => {
+				self.pooled.borrow_mut().remove(&VectorPool::address(&vector));
+				vector
+			},
+			None => Vector3f::into_reference(Vector3f::new(0.0, 0.0, 0.0), env)?
+		};
+
+		vector.x = 0.0;
+		vector.y = 0.0;
+		vector.z = 0.0;
+
+		if let Some(scope) = self.scopes.borrow_mut().last_mut() { scope.push(vector.clone(env)?) }
+
+		return Ok(vector);
+	}
+
+	/**
+	 * Returns a vector to the pool. Releasing a vector which is already pooled does nothing.
+	 *
+	 * @param vector - The vector to return (Vector3f).
+	 */
+	#[napi(ts_args_type = "vector: Vector3f")]
+	pub fn release(&self, vector: Reference<Vector3f>) {
+		self.recycle(vector);
+	}
+
+	/**
+	 * Calls a function and releases every vector acquired while it runs once it returns, even if it throws.
+	 * A vector returned by the function stays with the caller and is not released.
+	 *
+	 * @param callback - The function to run.
+	 * @return The return value of the function.
+	 */
+	#[napi(ts_args_type = "callback: () => unknown", ts_return_type = "unknown")]
+	pub fn scoped(&self, env: Env, callback: JsFunction) -> napi::Result<JsUnknown> {
+		self.scopes.borrow_mut().push(Vec::new());
+		let result = callback.call_without_args(None);
+
+		let returned = match &result {
+			Ok(value) => value.get_type().map(|kind| match kind {
+				// Only the address is compared, objects of other classes or plain objects never match a pooled vector
+				ValueType::Object => unsafe { Vector3f::from_napi_ref(env.raw(), value.raw()) }.ok().map(|vector| vector as *const Vector3f as usize),
+				_ => None
+			}),
+			Err(_) => Ok(None)
+		};
+
+		// The scope is popped before a failed type check is propagated, so it never outlives the call
+		let acquired = self.scopes.borrow_mut().pop().unwrap_or_default();
+		let kept = returned.as_ref().ok().copied().flatten();
+		for vector in acquired {
+			if Some(VectorPool::address(&vector)) != kept { self.recycle(vector) }
+		}
+
+		returned?;
+		return result;
+	}
+
+	/**
+	 * Returns the number of vectors waiting in the pool.
+	 */
+	#[napi]
+	pub fn size(&self) -> u32 {
+		self.free.borrow().len() as u32
+	}
+
+	/**
+	 * Drops every pooled vector, letting them be garbage collected.
+	 */
+	#[napi]
+	pub fn clear(&self) {
+		self.free.borrow_mut().clear();
+		self.pooled.borrow_mut().clear();
+	}
+
+	/**
+	 * Puts a vector back into the free list, unless it is already there.
+	 */
+	fn recycle(&self, vector: Reference<Vector3f>) {
+		if self.pooled.borrow_mut().insert(VectorPool::address(&vector)) { self.free.borrow_mut().push(vector) }
+	}
+
+	fn address(vector: &Reference<Vector3f>) -> usize {
+		&**vector as *const Vector3f as usize
+	}
+}
+
+impl Default for VectorPool {
+	fn default() -> Self {
+		VectorPool::new()
+	}
+}
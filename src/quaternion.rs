@@ -0,0 +1,207 @@
+use napi_derive::napi;
+
+use crate::axis_angle::AxisAngle;
+use crate::euler::{Euler, EulerOrder};
+use crate::mat3::Matrix3;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Represents a rotation as a unit quaternion, with the vector part in x, y, z and the scalar part in w.
+ */
+#[napi]
+#[derive(Clone, Debug)]
+pub struct Quaternion {
+	pub x: f64,
+	pub y: f64,
+	pub z: f64,
+	pub w: f64
+}
+
+#[napi]
+impl Quaternion {
+	#[napi(constructor)]
+	pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+		Quaternion { x, y, z, w }
+	}
+
+	/**
+	 * Returns the quaternion of no rotation.
+	 */
+	#[napi]
+	pub fn identity() -> Quaternion {
+		Quaternion::new(0.0, 0.0, 0.0, 1.0)
+	}
+
+	/**
+	 * Constructs a rotation from Euler angles, composing the rotation of every axis in the order of the angles.
+	 *
+	 * @param euler - The angles to convert (Euler).
+	 */
+	#[napi]
+	pub fn from_euler(euler: &Euler) -> Quaternion {
+		let axis = |x: f64, y: f64, z: f64, angle: f64| Quaternion::from_axis_angle(Vector3f::new(x, y, z).into(), angle);
+		let (x, y, z) = (axis(1.0, 0.0, 0.0, euler.x), axis(0.0, 1.0, 0.0, euler.y), axis(0.0, 0.0, 1.0, euler.z));
+
+		match euler.order {
+			EulerOrder::XYZ => x.multiply(&y).multiply(&z),
+			EulerOrder::XZY => x.multiply(&z).multiply(&y),
+			EulerOrder::YXZ => y.multiply(&x).multiply(&z),
+			EulerOrder::YZX => y.multiply(&z).multiply(&x),
+			EulerOrder::ZXY => z.multiply(&x).multiply(&y),
+			EulerOrder::ZYX => z.multiply(&y).multiply(&x)
+		}
+	}
+
+	/**
+	 * Constructs a rotation around an axis.
+	 *
+	 * @param axis - The axis to rotate around, it does not need to be normalized (Vector3f).
+	 * @param angle - The angle in radians, counterclockwise when looking down the axis.
+	 */
+	#[napi]
+	pub fn from_axis_angle(axis: VectorLike, angle: f64) -> Quaternion {
+		AxisAngle::new(axis, angle).to_quaternion()
+	}
+
+	/**
+	 * Extracts the rotation of a rotation matrix, using the largest diagonal term for stability.
+	 *
+	 * @param matrix - The rotation matrix (Matrix3).
+	 */
+	#[napi]
+	pub fn from_matrix3(matrix: &Matrix3) -> Quaternion {
+		let m = |row: usize, column: usize| matrix.elements[column * 3 + row];
+		let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+		if trace > 0.0 {
+			let s = (trace + 1.0).sqrt() * 2.0;
+			Quaternion::new((m(2, 1) - m(1, 2)) / s, (m(0, 2) - m(2, 0)) / s, (m(1, 0) - m(0, 1)) / s, 0.25 * s)
+		} else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+			let s = (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * 2.0;
+			Quaternion::new(0.25 * s, (m(0, 1) + m(1, 0)) / s, (m(0, 2) + m(2, 0)) / s, (m(2, 1) - m(1, 2)) / s)
+		} else if m(1, 1) > m(2, 2) {
+			let s = (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * 2.0;
+			Quaternion::new((m(0, 1) + m(1, 0)) / s, 0.25 * s, (m(1, 2) + m(2, 1)) / s, (m(0, 2) - m(2, 0)) / s)
+		} else {
+			let s = (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * 2.0;
+			Quaternion::new((m(0, 2) + m(2, 0)) / s, (m(1, 2) + m(2, 1)) / s, 0.25 * s, (m(1, 0) - m(0, 1)) / s)
+		}
+	}
+
+	/**
+	 * Converts the rotation into a rotation matrix. The quaternion is normalized first.
+	 */
+	#[napi]
+	pub fn to_matrix3(&self) -> Matrix3 {
+		let Quaternion { x, y, z, w } = self.normalize();
+
+		Matrix3 {
+			elements: vec![
+				1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y + z * w), 2.0 * (x * z - y * w),
+				2.0 * (x * y - z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z + x * w),
+				2.0 * (x * z + y * w), 2.0 * (y * z - x * w), 1.0 - 2.0 * (x * x + y * y)
+			]
+		}
+	}
+
+	/**
+	 * Combines two rotations. The result applies the other rotation first, then this one,
+	 * matching the order of matrix multiplication.
+	 *
+	 * @param other - The rotation applied first (Quaternion).
+	 */
+	#[napi]
+	pub fn multiply(&self, other: &Quaternion) -> Quaternion {
+		Quaternion::new(
+			self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+			self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+			self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+			self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z
+		)
+	}
+
+	/**
+	 * Spherically interpolates towards another rotation at a constant angular speed,
+	 * taking the shortest path.
+	 *
+	 * @param other - The rotation to interpolate towards (Quaternion).
+	 * @param t - The interpolation factor (0..1).
+	 */
+	#[napi]
+	pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+		let mut cosine = self.dot(other);
+
+		// Flip the target when the rotations are more than 180 degrees apart, q and -q being the same rotation.
+		let target = if cosine < 0.0 {
+			cosine = -cosine;
+			Quaternion::new(-other.x, -other.y, -other.z, -other.w)
+		} else {
+			other.clone()
+		};
+
+		// Nearly identical rotations fall back to a normalized linear interpolation.
+		let (a, b) = if cosine > 0.9995 { (1.0 - t, t) } else {
+			let angle = cosine.min(1.0).acos();
+			let sine = angle.sin();
+			(((1.0 - t) * angle).sin() / sine, (t * angle).sin() / sine)
+		};
+
+		Quaternion::new(
+			self.x * a + target.x * b,
+			self.y * a + target.y * b,
+			self.z * a + target.z * b,
+			self.w * a + target.w * b
+		).normalize()
+	}
+
+	/**
+	 * Returns the quaternion scaled to unit length, or the identity when it has no length
+	 * or a length that is not finite.
+	 */
+	#[napi]
+	pub fn normalize(&self) -> Quaternion {
+		let length = self.length();
+		if length == 0.0 || !length.is_finite() { return Quaternion::identity() }
+
+		Quaternion::new(self.x / length, self.y / length, self.z / length, self.w / length)
+	}
+
+	/**
+	 * Returns the conjugate, which is the inverse rotation for unit quaternions.
+	 */
+	#[napi]
+	pub fn conjugate(&self) -> Quaternion {
+		Quaternion::new(-self.x, -self.y, -self.z, self.w)
+	}
+
+	/**
+	 * Rotates a vector by the rotation. The quaternion is normalized first.
+	 *
+	 * @param v - The vector to rotate (Vector3f).
+	 */
+	#[napi]
+	pub fn rotate_vector(&self, v: VectorLike) -> Vector3f {
+		let q = self.normalize();
+		let axis = Vector3f::new(q.x, q.y, q.z);
+
+		// v + 2w(u x v) + 2u x (u x v), which avoids building the full rotation matrix.
+		let t = axis.cross(&v).multiply(2.0);
+		v.add(&t.multiply(q.w)).add(&axis.cross(&t))
+	}
+
+	#[napi]
+	pub fn dot(&self, other: &Quaternion) -> f64 {
+		self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+	}
+
+	#[napi]
+	pub fn length(&self) -> f64 {
+		// hypot keeps the length of huge quaternions from overflowing to infinity.
+		self.x.hypot(self.y).hypot(self.z).hypot(self.w)
+	}
+
+	#[napi]
+	pub fn equals(&self, other: &Quaternion) -> bool {
+		self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+	}
+}
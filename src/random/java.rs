@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use napi::bindgen_prelude::Either;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use super::seed::Seed;
+
+const MULTIPLIER: i64 = 0x5DEECE66D;
+const ADDEND: i64 = 0xB;
+const MASK: i64 = (1 << 48) - 1;
+
+/**
+ * A seedable random number generator producing the exact sequence of java.util.Random,
+ * which vanilla world generation and loot rolls are built on.
+ */
+#[napi(js_name = "JavaRandom")]
+#[derive(Clone, Debug)]
+pub struct JavaRandom {
+	seed: i64,
+	next_next_gaussian: Option<f64> // Second value of the last gaussian pair, returned by the next call
+}
+
+#[napi]
+impl JavaRandom {
+	/**
+	 * Constructs a generator from a seed, scrambled the same way Java does.
+	 *
+	 * @param seed - The seed as a number, or a decimal string beyond 2^53; only the lower 48 bits are used.
+	 * Throws if the seed is not a 64-bit integer.
+	 */
+	#[napi(constructor)]
+	pub fn new(seed: Either<f64, String>) -> napi::Result<JavaRandom> {
+		Ok(JavaRandom::from_seed(Seed::parse(seed)?))
+	}
+
+	/**
+	 * Resets the generator to a new seed.
+	 *
+	 * @param seed - The seed as a number, or a decimal string beyond 2^53; only the lower 48 bits are used.
+	 * Throws if the seed is not a 64-bit integer.
+	 */
+	#[napi]
+	pub fn set_seed(&mut self, seed: Either<f64, String>) -> napi::Result<()> {
+		*self = JavaRandom::from_seed(Seed::parse(seed)?);
+		Ok(())
+	}
+
+	/**
+	 * Returns a uniformly distributed int, bounded to [0, bound) when a bound is given.
+	 * Like Java, a bound that is not positive is an error.
+	 *
+	 * @param bound - The exclusive upper bound, must be positive.
+	 */
+	#[napi]
+	pub fn next_int(&mut self, bound: Option<i32>) -> napi::Result<i32> {
+		match bound {
+			None => Ok(self.next(32)),
+			Some(bound) if bound > 0 => Ok(self.next_bounded(bound)),
+			Some(bound) => Err(Error::new(Status::InvalidArg, format!("Bound must be positive, got {}", bound)))
+		}
+	}
+
+	/**
+	 * Returns a uniformly distributed 64-bit integer. Values beyond 2^53 lose precision once they reach JS.
+	 */
+	#[napi]
+	pub fn next_long(&mut self) -> i64 {
+		((self.next(32) as i64) << 32).wrapping_add(self.next(32) as i64)
+	}
+
+	#[napi]
+	pub fn next_boolean(&mut self) -> bool {
+		self.next(1) != 0
+	}
+
+	/**
+	 * Returns a uniformly distributed float in [0, 1), with 24 bits of precision.
+	 */
+	#[napi]
+	pub fn next_float(&mut self) -> f64 {
+		self.next(24) as f64 / (1 << 24) as f64
+	}
+
+	/**
+	 * Returns a uniformly distributed double in [0, 1), with 53 bits of precision.
+	 */
+	#[napi]
+	pub fn next_double(&mut self) -> f64 {
+		(((self.next(26) as i64) << 27) + self.next(27) as i64) as f64 * (1.0 / (1i64 << 53) as f64)
+	}
+
+	/**
+	 * Returns a normally distributed double with a mean of 0 and a standard deviation of 1,
+	 * using the polar method. Values are generated in pairs, so every other call draws nothing.
+	 */
+	#[napi]
+	pub fn next_gaussian(&mut self) -> f64 {
+		if let Some(gaussian) = self.next_next_gaussian.take() { return gaussian }
+
+		let (gaussian, next) = polar_gaussian(|| self.next_double(), strict_log);
+		self.next_next_gaussian = Some(next);
+
+		return gaussian;
+	}
+
+	/**
+	 * Splits off an independent generator seeded from this one, like vanilla's fork.
+	 */
+	#[napi]
+	pub fn fork(&mut self) -> JavaRandom {
+		JavaRandom::from_seed(self.next_long())
+	}
+
+	/**
+	 * Shuffles values in place with Fisher-Yates, in the same order as Java's Collections.shuffle.
+	 *
+	 * @param values - The values to shuffle, such as indices into a list.
+	 * @return The shuffled values.
+	 */
+	#[napi]
+	pub fn shuffle(&mut self, values: Vec<u32>) -> Vec<u32> {
+		let mut values = values;
+
+		for i in (1..values.len()).rev() {
+			let j = self.next_bounded(i as i32 + 1) as usize;
+			values.swap(i, j);
+		}
+
+		return values;
+	}
+
+	/**
+	 * Picks k distinct indices out of n, in the order they were drawn.
+	 * Memory grows with k only, so small samples of huge ranges are cheap.
+	 *
+	 * @param n - The number of indices to pick from, at most 2^31 - 1 like a Java int bound.
+	 * @param k - The number of indices to pick, at most n.
+	 * @return The picked indices.
+	 */
+	#[napi]
+	pub fn sample(&mut self, n: u32, k: u32) -> napi::Result<Vec<u32>> {
+		if n > i32::MAX as u32 {
+			return Err(Error::new(Status::InvalidArg, format!("Can only sample from at most {} indices, got {}", i32::MAX, n)));
+		}
+
+		let k = k.min(n);
+		let mut picked: Vec<u32> = Vec::new();
+		if picked.try_reserve_exact(k as usize).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory to sample {} indices", k)));
+		}
+
+		// A partial Fisher-Yates shuffle, drawing each pick from the indices left.
+		// Only the slots moved by a swap are stored; every other slot still holds its own index.
+		let mut swapped: HashMap<u32, u32> = HashMap::new();
+		for i in 0..k {
+			let j = i + self.next_bounded((n - i) as i32) as u32;
+			let value = swapped.get(&j).copied().unwrap_or(j);
+
+			swapped.insert(j, swapped.get(&i).copied().unwrap_or(i));
+			picked.push(value);
+		}
+
+		return Ok(picked);
+	}
+
+	/**
+	 * Constructs a generator from a seed, scrambled the same way Java does.
+	 */
+	pub(crate) fn from_seed(seed: i64) -> JavaRandom {
+		JavaRandom { seed: (seed ^ MULTIPLIER) & MASK, next_next_gaussian: None }
+	}
+
+	/**
+	 * Returns a uniformly distributed int in [0, bound), the bound must be positive.
+	 */
+	pub(crate) fn next_bounded(&mut self, bound: i32) -> i32 {
+		// Powers of two take the high bits directly.
+		if bound & -bound == bound {
+			return ((bound as i64 * self.next(31) as i64) >> 31) as i32;
+		}
+
+		loop {
+			let bits = self.next(31);
+			let value = bits % bound;
+			if bits.wrapping_sub(value).wrapping_add(bound - 1) >= 0 { return value }
+		}
+	}
+
+	/**
+	 * Advances the generator and returns the requested number of high bits.
+	 */
+	pub(crate) fn next(&mut self, bits: u32) -> i32 {
+		self.seed = (self.seed.wrapping_mul(MULTIPLIER).wrapping_add(ADDEND)) & MASK;
+		(self.seed >> (48 - bits)) as i32
+	}
+}
+
+/**
+ * Draws a pair of normally distributed values with Marsaglia's polar method, in the order
+ * java.util.Random and vanilla consume their uniform doubles.
+ */
+pub(crate) fn polar_gaussian<F: FnMut() -> f64>(mut next_double: F, log: fn(f64) -> f64) -> (f64, f64) {
+	loop {
+		let v1 = 2.0 * next_double() - 1.0;
+		let v2 = 2.0 * next_double() - 1.0;
+		let s = v1 * v1 + v2 * v2;
+		if s >= 1.0 || s == 0.0 { continue }
+
+		let multiplier = (-2.0 * log(s) / s).sqrt();
+		return (v1 * multiplier, v2 * multiplier);
+	}
+}
+
+/**
+ * The natural logarithm of fdlibm, which Java's StrictMath.log is specified to return bit for bit.
+ * The platform logarithm can differ in the last bit, which would break the gaussian sequence.
+ * Only positive normal numbers are handled, which is all the polar method passes in.
+ */
+fn strict_log(x: f64) -> f64 {
+	const LN2_HI: f64 = f64::from_bits(0x3FE62E42FEE00000);
+	const LN2_LO: f64 = f64::from_bits(0x3DEA39EF35793C76);
+	const LG: [f64; 7] = [
+		f64::from_bits(0x3FE5555555555593), f64::from_bits(0x3FD999999997FA04), f64::from_bits(0x3FD2492494229359),
+		f64::from_bits(0x3FCC71C51D8E78AF), f64::from_bits(0x3FC7466496CB03DE), f64::from_bits(0x3FC39A09D078C69F),
+		f64::from_bits(0x3FC2F112DF3E5244)
+	];
+
+	if !(x.is_normal() && x > 0.0) { return x.ln() }
+
+	let bits = x.to_bits();
+	let high = (bits >> 32) as i32;
+	let mut k = (high >> 20) - 1023;
+	let high = high & 0x000FFFFF;
+
+	// Normalize x into [sqrt(2)/2, sqrt(2)) and move the exponent into k.
+	let i = (high + 0x95F64) & 0x100000;
+	let x = f64::from_bits((((high | (i ^ 0x3FF00000)) as u64) << 32) | (bits & 0xFFFFFFFF));
+	k += i >> 20;
+
+	let f = x - 1.0;
+	let dk = k as f64;
+
+	if (0x000FFFFF & (2 + high)) < 3 {
+		if f == 0.0 { return if k == 0 { 0.0 } else { dk * LN2_HI + dk * LN2_LO } }
+
+		let r = f * f * (0.5 - (1.0 / 3.0) * f);
+		return if k == 0 { f - r } else { dk * LN2_HI - ((r - dk * LN2_LO) - f) };
+	}
+
+	let s = f / (2.0 + f);
+	let z = s * s;
+	let w = z * z;
+	let t1 = w * (LG[1] + w * (LG[3] + w * LG[5]));
+	let t2 = z * (LG[0] + w * (LG[2] + w * (LG[4] + w * LG[6])));
+	let r = t2 + t1;
+
+	if ((high - 0x6147A) | (0x6B851 - high)) > 0 {
+		let hfsq = 0.5 * f * f;
+		if k == 0 { f - (hfsq - s * (hfsq + r)) } else { dk * LN2_HI - ((hfsq - (s * (hfsq + r) + dk * LN2_LO)) - f) }
+	} else if k == 0 {
+		f - s * (f - r)
+	} else {
+		dk * LN2_HI - ((s * (f - r) - dk * LN2_LO) - f)
+	}
+}
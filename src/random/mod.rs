@@ -0,0 +1,3 @@
+pub mod seed;
+pub mod java;
+pub mod xoroshiro;
\ No newline at end of file
@@ -0,0 +1,116 @@
+use napi::bindgen_prelude::Either;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+/**
+ * Utilities for converting user supplied text into world seeds.
+ * The conversions mirror vanilla so the same input always produces the same world.
+ */
+#[napi]
+pub struct Seed {}
+
+#[napi]
+impl Seed {
+	/**
+	 * Converts a seed entered as text into a numeric seed, like vanilla's world creation screen.
+	 * Numeric input, including 0, is parsed as a 64-bit integer, anything else is hashed using
+	 * Java's String.hashCode. The seed is returned as a decimal string, since 64-bit seeds
+	 * lose precision as JS numbers.
+	 *
+	 * @param s - The seed text.
+	 * @return The resulting seed, or "0" for empty input.
+	 */
+	#[napi]
+	pub fn from_string(s: String) -> String {
+		// Like Java's String.trim, only control characters and spaces are trimmed.
+		let trimmed = s.trim_matches(|c: char| c <= ' ');
+		if trimmed.is_empty() { return "0".to_string() }
+
+		match trimmed.parse::<i64>() {
+			Ok(value) => value.to_string(),
+			Err(_) => Seed::hash_code(trimmed.to_string()).to_string()
+		}
+	}
+
+	/**
+	 * Computes Java's String.hashCode over the UTF-16 code units of a string.
+	 *
+	 * @param s - The string to hash.
+	 * @return The 32-bit hash, wrapping exactly like Java int arithmetic.
+	 */
+	#[napi]
+	pub fn hash_code(s: String) -> i32 {
+		s.encode_utf16().fold(0i32, |hash, unit| hash.wrapping_mul(31).wrapping_add(unit as i32))
+	}
+
+	/**
+	 * Computes the 64-bit variant of String.hashCode, using the same polynomial
+	 * with Java long arithmetic.
+	 *
+	 * @param s - The string to hash.
+	 * @return The 64-bit hash as a decimal string, since it may exceed the precision of JS numbers.
+	 */
+	#[napi]
+	pub fn hash_code64(s: String) -> String {
+		s.encode_utf16().fold(0i64, |hash, unit| hash.wrapping_mul(31).wrapping_add(unit as i64)).to_string()
+	}
+
+	/**
+	 * Converts a UUID string into a 64-bit seed by XORing its most and least significant halves.
+	 * This is the first step of Java's UUID.hashCode, which then folds the result down to 32 bits;
+	 * the seed keeps all 64 bits.
+	 *
+	 * @param uuid - The UUID in its canonical 8-4-4-4-12 form.
+	 * @return The seed derived from the UUID, as a decimal string.
+	 */
+	#[napi]
+	pub fn from_uuid(uuid: String) -> napi::Result<String> {
+		let hex: String = uuid.trim().chars().filter(|c| *c != '-').collect();
+
+		if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+			return Err(Error::new(Status::InvalidArg, format!("Invalid UUID: {}", uuid)));
+		}
+
+		let most = u64::from_str_radix(&hex[..16], 16).unwrap_or(0) as i64;
+		let least = u64::from_str_radix(&hex[16..], 16).unwrap_or(0) as i64;
+
+		Ok((most ^ least).to_string())
+	}
+
+	/**
+	 * Computes the seed vanilla derives from a block position, which positional generators mix
+	 * into their own seed so every block gets a reproducible sequence.
+	 *
+	 * @param x - The x coordinate of the block.
+	 * @param y - The y coordinate of the block.
+	 * @param z - The z coordinate of the block.
+	 * @return The seed of the position.
+	 */
+	#[napi]
+	pub fn positional(x: i32, y: i32, z: i32) -> i64 {
+		let seed = (x.wrapping_mul(3129871) as i64) ^ (z as i64).wrapping_mul(116129781) ^ y as i64;
+		seed.wrapping_mul(seed).wrapping_mul(42317861).wrapping_add(seed.wrapping_mul(11)) >> 16
+	}
+
+	/**
+	 * Reads a 64-bit seed passed from JS, either as a number or as a decimal string
+	 * for seeds beyond the precision of JS numbers, such as the strings returned by fromString.
+	 * Numbers are read as doubles, so NaN, infinite and fractional seeds are rejected instead of truncated.
+	 */
+	pub(crate) fn parse(seed: Either<f64, String>) -> napi::Result<i64> {
+		match seed {
+			Either::A(seed) => {
+				// -2^63 is exact as a double, and 2^63 is the first double past i64::MAX
+				if seed.fract() != 0.0 || !(i64::MIN as f64..-(i64::MIN as f64)).contains(&seed) {
+					return Err(Error::new(Status::InvalidArg, format!("Seeds must be 64-bit integers, got {}", seed)));
+				}
+
+				Ok(seed as i64)
+			}
+			Either::B(text) => text.trim().parse::<i64>().map_err(|_| Error::new(
+				Status::InvalidArg,
+				format!("Seeds must be 64-bit integers, got {}", text)
+			))
+		}
+	}
+}
@@ -0,0 +1,225 @@
+use napi::bindgen_prelude::Either;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use super::java::polar_gaussian;
+use super::seed::Seed;
+
+const GOLDEN_RATIO: i64 = 0x9E3779B97F4A7C15u64 as i64;
+const SILVER_RATIO: i64 = 0x6A09E667F3BCC909;
+const FLOAT_UNIT: f32 = 5.9604645e-8; // Scale of 24 random bits, as a float like vanilla
+const DOUBLE_UNIT: f32 = 1.110223e-16; // Scale of 53 random bits, vanilla's float literal is slightly below 2^-53
+
+/**
+ * Mixes a 64-bit value with Stafford's variant 13 of the SplitMix64 finalizer.
+ */
+fn mix_stafford13(value: i64) -> i64 {
+	let mut value = value as u64;
+	value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+	value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+	(value ^ (value >> 31)) as i64
+}
+
+/**
+ * A seedable random number generator producing the exact sequence of vanilla's Xoroshiro128++
+ * random source, which modern world generation uses instead of java.util.Random.
+ */
+#[napi(js_name = "Xoroshiro128PlusPlus")]
+#[derive(Clone, Debug)]
+pub struct Xoroshiro128PlusPlus {
+	low: i64,
+	high: i64,
+	next_next_gaussian: Option<f64> // Second value of the last gaussian pair, returned by the next call
+}
+
+#[napi]
+impl Xoroshiro128PlusPlus {
+	/**
+	 * Constructs a generator from a 64-bit seed, expanded to 128 bits the same way vanilla does.
+	 *
+	 * @param seed - The seed as a number, or a decimal string beyond 2^53.
+	 * Throws if the seed is not a 64-bit integer.
+	 */
+	#[napi(constructor)]
+	pub fn new(seed: Either<f64, String>) -> napi::Result<Xoroshiro128PlusPlus> {
+		Ok(Xoroshiro128PlusPlus::from_seed(Seed::parse(seed)?))
+	}
+
+	/**
+	 * Constructs a generator from its raw 128-bit state. An all zero state is replaced
+	 * by a fixed one, since it would only produce zeros.
+	 *
+	 * @param low - The lower 64 bits of the state, as a number or a decimal string beyond 2^53.
+	 * @param high - The upper 64 bits of the state, as a number or a decimal string beyond 2^53.
+	 * Throws if the state is not made of 64-bit integers.
+	 */
+	#[napi]
+	pub fn from_state(low: Either<f64, String>, high: Either<f64, String>) -> napi::Result<Xoroshiro128PlusPlus> {
+		Ok(Xoroshiro128PlusPlus::with_state(Seed::parse(low)?, Seed::parse(high)?))
+	}
+
+	/**
+	 * Resets the generator to a new seed.
+	 *
+	 * @param seed - The seed as a number, or a decimal string beyond 2^53.
+	 * Throws if the seed is not a 64-bit integer.
+	 */
+	#[napi]
+	pub fn set_seed(&mut self, seed: Either<f64, String>) -> napi::Result<()> {
+		*self = Xoroshiro128PlusPlus::from_seed(Seed::parse(seed)?);
+		Ok(())
+	}
+
+	/**
+	 * Returns a uniformly distributed 64-bit integer. Values beyond 2^53 lose precision once they reach JS.
+	 */
+	#[napi]
+	pub fn next_long(&mut self) -> i64 {
+		let (low, mut high) = (self.low, self.high);
+		let value = low.wrapping_add(high).rotate_left(17).wrapping_add(low);
+
+		high ^= low;
+		self.low = low.rotate_left(49) ^ high ^ (high << 21);
+		self.high = high.rotate_left(28);
+
+		return value;
+	}
+
+	/**
+	 * Returns a uniformly distributed int, bounded to [0, bound) when a bound is given.
+	 * Bounded values use Lemire's multiply and reject method, like vanilla, and a bound that
+	 * is not positive is an error.
+	 *
+	 * @param bound - The exclusive upper bound, must be positive.
+	 */
+	#[napi]
+	pub fn next_int(&mut self, bound: Option<i32>) -> napi::Result<i32> {
+		match bound {
+			None => Ok(self.next_long() as i32),
+			Some(bound) if bound > 0 => Ok(self.next_bounded(bound)),
+			Some(bound) => Err(Error::new(Status::InvalidArg, format!("Bound must be positive, got {}", bound)))
+		}
+	}
+
+	#[napi]
+	pub fn next_boolean(&mut self) -> bool {
+		self.next_long() & 1 != 0
+	}
+
+	/**
+	 * Returns a uniformly distributed float in [0, 1), with 24 bits of precision.
+	 */
+	#[napi]
+	pub fn next_float(&mut self) -> f64 {
+		(self.next_bits(24) as f32 * FLOAT_UNIT) as f64
+	}
+
+	/**
+	 * Returns a uniformly distributed double in [0, 1), with 53 bits of precision.
+	 */
+	#[napi]
+	pub fn next_double(&mut self) -> f64 {
+		self.next_bits(53) as f64 * DOUBLE_UNIT as f64
+	}
+
+	/**
+	 * Returns a normally distributed double with a mean of 0 and a standard deviation of 1,
+	 * using the polar method. Values are generated in pairs, so every other call draws nothing.
+	 * Vanilla takes the logarithm with Math.log here, which the JVM only guarantees to within
+	 * one ulp, so rare values can differ from a given JVM in the last bit.
+	 */
+	#[napi]
+	pub fn next_gaussian(&mut self) -> f64 {
+		if let Some(gaussian) = self.next_next_gaussian.take() { return gaussian }
+
+		let (gaussian, next) = polar_gaussian(|| self.next_double(), f64::ln);
+		self.next_next_gaussian = Some(next);
+
+		return gaussian;
+	}
+
+	/**
+	 * Skips a number of values, as if next long had been called that many times.
+	 *
+	 * @param count - The number of values to skip.
+	 */
+	#[napi]
+	pub fn consume(&mut self, count: u32) {
+		for _ in 0..count { self.next_long(); }
+	}
+
+	/**
+	 * Splits off an independent generator whose state is drawn from this one, like vanilla's fork.
+	 */
+	#[napi]
+	pub fn fork(&mut self) -> Xoroshiro128PlusPlus {
+		let low = self.next_long();
+		let high = self.next_long();
+
+		Xoroshiro128PlusPlus::with_state(low, high)
+	}
+
+	/**
+	 * Returns the generator of a block position, using this generator as a positional factory
+	 * without advancing it. Forking first and then calling this for every position matches
+	 * vanilla's forkPositional().at(x, y, z).
+	 *
+	 * @param x - The x coordinate of the block.
+	 * @param y - The y coordinate of the block.
+	 * @param z - The z coordinate of the block.
+	 */
+	#[napi]
+	pub fn at(&self, x: i32, y: i32, z: i32) -> Xoroshiro128PlusPlus {
+		Xoroshiro128PlusPlus::with_state(Seed::positional(x, y, z) ^ self.low, self.high)
+	}
+
+	/**
+	 * Constructs a generator from a 64-bit seed, expanded to 128 bits the same way vanilla does.
+	 */
+	pub(crate) fn from_seed(seed: i64) -> Xoroshiro128PlusPlus {
+		let (low, high) = Xoroshiro128PlusPlus::expand(seed);
+		Xoroshiro128PlusPlus::with_state(low, high)
+	}
+
+	/**
+	 * Constructs a generator from its raw state, replacing an all zero state.
+	 */
+	fn with_state(low: i64, high: i64) -> Xoroshiro128PlusPlus {
+		let (low, high) = if low == 0 && high == 0 { (GOLDEN_RATIO, SILVER_RATIO) } else { (low, high) };
+		Xoroshiro128PlusPlus { low, high, next_next_gaussian: None }
+	}
+
+	/**
+	 * Returns a uniformly distributed int in [0, bound) with Lemire's method, the bound must be positive.
+	 */
+	fn next_bounded(&mut self, bound: i32) -> i32 {
+		let bound = bound as u64;
+		let mut product = (self.next_long() as u32 as u64) * bound;
+		if product & 0xFFFFFFFF < bound {
+			// Values from the low end of the range would make some results more likely.
+			let threshold = (bound as u32).wrapping_neg() as u64 % bound;
+			while product & 0xFFFFFFFF < threshold {
+				product = (self.next_long() as u32 as u64) * bound;
+			}
+		}
+
+		(product >> 32) as i32
+	}
+
+	/**
+	 * Expands a 64-bit seed into a mixed 128-bit state.
+	 */
+	fn expand(seed: i64) -> (i64, i64) {
+		let low = seed ^ SILVER_RATIO;
+		let high = low.wrapping_add(GOLDEN_RATIO);
+
+		(mix_stafford13(low), mix_stafford13(high))
+	}
+
+	/**
+	 * Returns the requested number of high bits of the next value.
+	 */
+	fn next_bits(&mut self, bits: u32) -> u64 {
+		(self.next_long() as u64) >> (64 - bits)
+	}
+}
@@ -0,0 +1,62 @@
+use napi_derive::napi;
+
+use crate::vec2f::Vector2f;
+
+/**
+ * Represents an axis aligned rectangle in 2D space.
+ * The rectangle is defined by its top left corner and its size, with y growing downwards.
+ */
+#[napi(js_name = "Rect")]
+#[derive(Clone, Debug)]
+pub struct Rect {
+	pub x: f64, // Left edge of the rectangle
+	pub y: f64, // Top edge of the rectangle
+	pub width: f64,
+	pub height: f64
+}
+
+#[napi]
+impl Rect {
+	#[napi(constructor)]
+	pub fn new(x: f64, y: f64, width: f64, height: f64) -> Rect {
+		Rect { x, y, width, height }
+	}
+
+	/**
+	 * Constructs a rectangle from a position and a size.
+	 *
+	 * @param position - The top left corner (Vector2f).
+	 * @param size - The width and height (Vector2f).
+	 */
+	#[napi]
+	pub fn from_position_size(position: &Vector2f, size: &Vector2f) -> Rect {
+		Rect::new(position.x, position.y, size.x, size.y)
+	}
+
+	#[napi]
+	pub fn position(&self) -> Vector2f {
+		Vector2f::new(self.x, self.y)
+	}
+
+	#[napi]
+	pub fn size(&self) -> Vector2f {
+		Vector2f::new(self.width, self.height)
+	}
+
+	#[napi]
+	pub fn center(&self) -> Vector2f {
+		Vector2f::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+	}
+
+	#[napi]
+	pub fn contains(&self, point: &Vector2f) -> bool {
+		point.x >= self.x && point.x <= self.x + self.width &&
+		point.y >= self.y && point.y <= self.y + self.height
+	}
+
+	#[napi]
+	pub fn intersects(&self, other: &Rect) -> bool {
+		self.x < other.x + other.width && other.x < self.x + self.width &&
+		self.y < other.y + other.height && other.y < self.y + self.height
+	}
+}
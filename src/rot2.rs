@@ -0,0 +1,131 @@
+use napi_derive::napi;
+
+use crate::vec2f::Vector2f;
+
+/**
+ * A 2D rotation stored as a cosine and sine pair, like a unit complex number.
+ * Composing and applying rotations needs no trigonometry, so repeated rotations stay cheap and exact.
+ */
+#[napi]
+#[derive(Clone, Debug)]
+pub struct Rot2 {
+	pub cos: f64,
+	pub sin: f64
+}
+
+#[napi]
+impl Rot2 {
+	/**
+	 * Constructs a rotation from a cosine and sine pair, normalized to unit length.
+	 * A zero pair, or one that is not finite, results in the identity.
+	 *
+	 * @param cos - The cosine of the angle.
+	 * @param sin - The sine of the angle.
+	 */
+	#[napi(constructor)]
+	pub fn new(cos: f64, sin: f64) -> Rot2 {
+		let length = cos.hypot(sin);
+		if length == 0.0 || !length.is_finite() { return Rot2::identity() }
+
+		Rot2 { cos: cos / length, sin: sin / length }
+	}
+
+	/**
+	 * Returns the rotation by zero radians.
+	 */
+	#[napi]
+	pub fn identity() -> Rot2 {
+		Rot2 { cos: 1.0, sin: 0.0 }
+	}
+
+	/**
+	 * Constructs a rotation from an angle, counterclockwise from +X toward +Y.
+	 *
+	 * @param angle - The angle in radians.
+	 */
+	#[napi]
+	pub fn from_angle(angle: f64) -> Rot2 {
+		let (sin, cos) = angle.sin_cos();
+		Rot2 { cos, sin }
+	}
+
+	/**
+	 * Constructs the rotation turning one direction onto another.
+	 *
+	 * @param from - The starting direction (Vector2f).
+	 * @param to - The target direction (Vector2f).
+	 */
+	#[napi]
+	pub fn between(from: &Vector2f, to: &Vector2f) -> Rot2 {
+		Rot2::new(from.dot(to), from.x * to.y - from.y * to.x)
+	}
+
+	/**
+	 * Returns the angle of the rotation in radians (-PI..PI).
+	 */
+	#[napi]
+	pub fn to_angle(&self) -> f64 {
+		self.sin.atan2(self.cos)
+	}
+
+	/**
+	 * Combines two rotations, rotating by this rotation and then by the other.
+	 *
+	 * @param other - The rotation applied after this one (Rot2).
+	 */
+	#[napi]
+	pub fn compose(&self, other: &Rot2) -> Rot2 {
+		Rot2 {
+			cos: self.cos * other.cos - self.sin * other.sin,
+			sin: self.sin * other.cos + self.cos * other.sin
+		}
+	}
+
+	/**
+	 * Returns the rotation undoing this one.
+	 */
+	#[napi]
+	pub fn inverse(&self) -> Rot2 {
+		Rot2 { cos: self.cos, sin: -self.sin }
+	}
+
+	/**
+	 * Rotates a vector.
+	 *
+	 * @param vector - The vector to rotate (Vector2f).
+	 */
+	#[napi]
+	pub fn rotate(&self, vector: &Vector2f) -> Vector2f {
+		Vector2f::new(self.cos * vector.x - self.sin * vector.y, self.sin * vector.x + self.cos * vector.y)
+	}
+
+	/**
+	 * Rotates a vector by the inverse of this rotation.
+	 *
+	 * @param vector - The vector to rotate (Vector2f).
+	 */
+	#[napi]
+	pub fn unrotate(&self, vector: &Vector2f) -> Vector2f {
+		self.inverse().rotate(vector)
+	}
+
+	/**
+	 * Interpolates between two rotations along the shorter arc.
+	 *
+	 * @param other - The target rotation (Rot2).
+	 * @param t - The interpolation factor (0..1).
+	 */
+	#[napi]
+	pub fn slerp(&self, other: &Rot2, t: f64) -> Rot2 {
+		let delta = self.inverse().compose(other).to_angle();
+		self.compose(&Rot2::from_angle(delta * t))
+	}
+
+	/**
+	 * Renormalizes the pair, removing drift after many compositions.
+	 */
+	#[napi]
+	pub fn normalize(&self) -> Rot2 {
+		Rot2::new(self.cos, self.sin)
+	}
+}
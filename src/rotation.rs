@@ -0,0 +1,123 @@
+use napi_derive::napi;
+
+use crate::config::MathConfig;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Represents a look rotation in the configured angle unit, degrees by default.
+ */
+#[napi(object)]
+pub struct YawPitch {
+	pub yaw: f64, // Yaw (-180..180 degrees), 0 faces south (+Z) and increases clockwise
+	pub pitch: f64, // Pitch (-90..90 degrees), positive looks down
+}
+
+/**
+ * Conversions between look rotations and directions using Minecraft's conventions: yaw 0 faces
+ * south (+Z), 90 degrees faces west (-X), and positive pitch looks down. Angles are in the angle unit
+ * of the global MathConfig, degrees by default, except for wrapDegrees which always takes degrees.
+ */
+#[napi]
+pub struct Rotation {}
+
+#[napi]
+impl Rotation {
+	/**
+	 * Returns the unit direction an entity with a rotation looks in.
+	 *
+	 * @param yaw - The yaw in the configured unit.
+	 * @param pitch - The pitch in the configured unit.
+	 */
+	#[napi]
+	pub fn direction_from_rotation(yaw: f64, pitch: f64) -> Vector3f {
+		let config = MathConfig::current();
+		Rotation::direction(config.to_degrees(yaw), config.to_degrees(pitch))
+	}
+
+	/**
+	 * Returns the rotation that looks along a direction. Straight up or down directions keep a yaw of 0,
+	 * and a zero vector gives a zero rotation.
+	 *
+	 * @param v - The direction, it does not need to be normalized (Vector3f).
+	 */
+	#[napi]
+	pub fn rotation_from_direction(v: VectorLike) -> YawPitch {
+		let config = MathConfig::current();
+		let rotation = Rotation::rotation(&v);
+
+		YawPitch { yaw: config.from_degrees(rotation.yaw), pitch: config.from_degrees(rotation.pitch) }
+	}
+
+	/**
+	 * Returns the rotation to look from one position at another.
+	 *
+	 * @param from - The eye position (Vector3f).
+	 * @param to - The position to look at (Vector3f).
+	 */
+	#[napi]
+	pub fn look_at(from: VectorLike, to: VectorLike) -> YawPitch {
+		Rotation::rotation_from_direction(to.subtract(&from).into())
+	}
+
+	/**
+	 * Wraps an angle into -180..180 like vanilla, with 180 wrapping to -180.
+	 *
+	 * @param degrees - The angle in degrees.
+	 */
+	#[napi]
+	pub fn wrap_degrees(degrees: f64) -> f64 {
+		let wrapped = degrees.rem_euclid(360.0);
+		if wrapped >= 180.0 { wrapped - 360.0 } else { wrapped }
+	}
+
+	/**
+	 * Returns the shortest signed turn from one angle to another.
+	 *
+	 * @param from - The current angle in the configured unit.
+	 * @param to - The target angle in the configured unit.
+	 * @return The turn (-180..180 degrees) in the configured unit, positive turning clockwise.
+	 */
+	#[napi]
+	pub fn difference(from: f64, to: f64) -> f64 {
+		let config = MathConfig::current();
+		config.from_degrees(Rotation::wrap_degrees(config.to_degrees(to - from)))
+	}
+
+	/**
+	 * Turns an angle towards a target by at most a step, taking the shortest way around,
+	 * like mobs turning their heads.
+	 *
+	 * @param from - The current angle in the configured unit.
+	 * @param to - The target angle in the configured unit.
+	 * @param max_step - The largest turn allowed in the configured unit, a NaN step leaves the angle unchanged.
+	 */
+	#[napi]
+	pub fn approach(from: f64, to: f64, max_step: f64) -> f64 {
+		let step = max_step.abs();
+		if step.is_nan() { return from }
+
+		from + Rotation::difference(from, to).clamp(-step, step)
+	}
+
+	/**
+	 * Returns the unit direction of a rotation in degrees, for callers that are not affected by the config.
+	 */
+	pub(crate) fn direction(yaw: f64, pitch: f64) -> Vector3f {
+		let (sin_yaw, cos_yaw) = yaw.to_radians().sin_cos();
+		let (sin_pitch, cos_pitch) = pitch.to_radians().sin_cos();
+
+		Vector3f::new(-sin_yaw * cos_pitch, -sin_pitch, cos_yaw * cos_pitch)
+	}
+
+	/**
+	 * Returns the rotation in degrees that looks along a direction.
+	 */
+	pub(crate) fn rotation(v: &Vector3f) -> YawPitch {
+		// hypot keeps huge directions from overflowing to an infinite horizontal length
+		let horizontal = v.x.hypot(v.z);
+		let yaw = if horizontal == 0.0 { 0.0 } else { (-v.x).atan2(v.z).to_degrees() };
+		let pitch = if horizontal == 0.0 && v.y == 0.0 { 0.0 } else { (-v.y).atan2(horizontal).to_degrees() };
+
+		YawPitch { yaw: Rotation::wrap_degrees(yaw), pitch }
+	}
+}
@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::config::MathConfig;
+use crate::vec3f::{Axis, Vector3f, VectorLike};
+
+/**
+ * Generates block positions for common building shapes.
+ * All shapes are rasterized around the block containing the center position.
+ */
+#[napi]
+pub struct ShapeGen {}
+
+#[napi]
+impl ShapeGen {
+	/**
+	 * Generates the block positions of a circle, ring or arc lying in a plane.
+	 * The plane is perpendicular to the given axis: Y uses the (x, z) plane, X uses (z, y) and Z uses (x, y).
+	 * Angles are measured in degrees from the first plane axis towards the second.
+	 *
+	 * @param center - The center of the circle (Vector3f).
+	 * @param radius - The radius in blocks.
+	 * @param axis - The axis perpendicular to the plane of the circle (Axis).
+	 * @param thickness - The thickness of the ring in blocks, defaults to 1.
+	 * @param filled - Whether the circle is filled, defaults to false.
+	 * @param start_angle - The start angle of the arc in degrees, defaults to 0.
+	 * @param end_angle - The end angle of the arc in degrees, defaults to 360.
+	 * @return The positions of all blocks in the shape.
+	 * Throws if the circle spans too many blocks to generate.
+	 */
+	#[napi]
+	pub fn circle(
+		center: VectorLike,
+		radius: f64,
+		axis: Axis,
+		thickness: Option<f64>,
+		filled: Option<bool>,
+		start_angle: Option<f64>,
+		end_angle: Option<f64>
+	) -> napi::Result<Vec<Vector3f>> {
+		let thickness = thickness.unwrap_or(1.0).max(0.0);
+		let filled = filled.unwrap_or(false);
+		let start_angle = start_angle.unwrap_or(0.0);
+		let end_angle = end_angle.unwrap_or(360.0);
+
+		let origin = center.floor();
+		let outer = radius + 0.5;
+		let inner = outer - thickness;
+		let extent = outer.ceil().max(0.0);
+		ShapeGen::check_points((2.0 * extent + 1.0).powi(2), "Circle")?;
+
+		let extent = extent as i64;
+		let mut positions: Vec<Vector3f> = Vec::new();
+
+		for u in -extent..=extent {
+			for v in -extent..=extent {
+				let (u, v) = (u as f64, v as f64);
+				let distance = (u * u + v * v).sqrt();
+
+				if distance > outer || (!filled && distance <= inner) { continue };
+				if !ShapeGen::within_arc(v.atan2(u).to_degrees(), start_angle, end_angle) { continue };
+
+				ShapeGen::push(&mut positions, ShapeGen::plane_point(&origin, axis, u, v, 0.0))?;
+			}
+		}
+
+		return Ok(positions);
+	}
+
+	/**
+	 * Generates the block positions of a helix winding around an axis.
+	 *
+	 * @param center - The center of the helix base (Vector3f).
+	 * @param radius - The radius in blocks.
+	 * @param height - The height of the helix along the axis, may be negative.
+	 * @param turns - The number of full turns.
+	 * @param axis - The axis the helix winds around, defaults to the up axis of the global MathConfig (Axis).
+	 * @param thickness - The thickness of the curve in blocks, defaults to 1.
+	 * @return The connected block positions, ordered from the base to the top.
+	 * Throws if the thickened curve covers too many blocks to generate.
+	 */
+	#[napi]
+	pub fn helix(
+		center: VectorLike,
+		radius: f64,
+		height: f64,
+		turns: f64,
+		axis: Option<Axis>,
+		thickness: Option<f64>
+	) -> napi::Result<Vec<Vector3f>> {
+		let axis = axis.unwrap_or_else(|| MathConfig::current().up_axis());
+		let origin = Vector3f::new(center.x.floor() + 0.5, center.y.floor() + 0.5, center.z.floor() + 0.5);
+
+		// Sample densely enough that consecutive samples are less than a block apart.
+		let length = (turns * std::f64::consts::TAU * radius).hypot(height);
+		let samples = (length * 2.0).ceil().max(1.0);
+		if samples.is_nan() || samples >= u32::MAX as f64 {
+			return Err(Error::new(Status::InvalidArg, format!("Helix of length {} is too long", length)));
+		}
+
+		let samples = samples as usize;
+		let mut points: Vec<Vector3f> = Vec::new();
+		if points.try_reserve_exact(samples + 1).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for a helix of {} samples", samples)));
+		}
+
+		for i in 0..=samples {
+			let t = i as f64 / samples as f64;
+			let angle = t * turns * std::f64::consts::TAU;
+
+			points.push(ShapeGen::plane_point(&origin, axis, radius * angle.cos(), radius * angle.sin(), height * t));
+		}
+
+		ShapeGen::rasterize(&points, thickness.unwrap_or(1.0))
+	}
+
+	/**
+	 * Rasterizes a parametric curve, given as sampled points, into a connected set of blocks.
+	 * Consecutive samples are joined by straight segments, so any f(t) can be sampled in JS.
+	 *
+	 * @param points - The sampled points, packed as x, y, z triplets (Float64Array).
+	 * @param thickness - The thickness of the curve in blocks, defaults to 1.
+	 * @return The connected block positions, ordered along the curve.
+	 * Throws if the thickened curve covers too many blocks to generate.
+	 */
+	#[napi]
+	pub fn curve(points: Float64Array, thickness: Option<f64>) -> napi::Result<Vec<Vector3f>> {
+		if !points.len().is_multiple_of(3) {
+			return Err(Error::new(Status::InvalidArg, format!("Points must be packed in triplets, got {} values", points.len())));
+		}
+
+		let points: Vec<Vector3f> = points
+			.chunks_exact(3)
+			.map(|p| Vector3f::new(p[0], p[1], p[2]))
+			.collect();
+
+		ShapeGen::rasterize(&points, thickness.unwrap_or(1.0))
+	}
+
+	/**
+	 * Joins consecutive points with voxel lines and thickens them, removing duplicates.
+	 * Returns an error when the lines and brush together cover too many blocks.
+	 */
+	fn rasterize(points: &[Vector3f], thickness: f64) -> napi::Result<Vec<Vector3f>> {
+		let brush = ShapeGen::brush(thickness)?;
+
+		// Every line visits one block per block boundary crossed, plus its first block.
+		let cells: f64 = points.windows(2).map(|segment| {
+			let (start, end) = (&segment[0], &segment[1]);
+			(end.x.floor() - start.x.floor()).abs() + (end.y.floor() - start.y.floor()).abs() + (end.z.floor() - start.z.floor()).abs() + 1.0
+		}).sum::<f64>().max(1.0);
+		ShapeGen::check_points(cells * brush.len() as f64, "Curve")?;
+
+		let mut visited: HashSet<(i64, i64, i64)> = HashSet::new();
+		let mut positions: Vec<Vector3f> = Vec::new();
+
+		let mut add = |cell: (i64, i64, i64)| -> napi::Result<()> {
+			for offset in &brush {
+				let key = (cell.0 + offset.0, cell.1 + offset.1, cell.2 + offset.2);
+				if visited.len() == visited.capacity() && visited.try_reserve(visited.len().max(64)).is_err() {
+					return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} positions", visited.len() + 1)));
+				}
+
+				if visited.insert(key) {
+					ShapeGen::push(&mut positions, Vector3f::new(key.0 as f64, key.1 as f64, key.2 as f64))?;
+				}
+			}
+
+			Ok(())
+		};
+
+		match points {
+			[] => {},
+			[point] => add(ShapeGen::cell(point))?,
+			_ => for segment in points.windows(2) {
+				for cell in ShapeGen::line(&segment[0], &segment[1]) { add(cell)? }
+			}
+		}
+
+		return Ok(positions);
+	}
+
+	/**
+	 * Walks the blocks crossed by a segment, stepping one face at a time so the result is face connected.
+	 */
+	fn line(start: &Vector3f, end: &Vector3f) -> Vec<(i64, i64, i64)> {
+		let mut cell = ShapeGen::cell(start);
+		let target = ShapeGen::cell(end);
+		let direction = end.subtract(start);
+		let mut cells = vec![cell];
+
+		let step = |d: f64| if d > 0.0 { 1 } else if d < 0.0 { -1 } else { 0 };
+		let delta = |d: f64| if d == 0.0 { f64::INFINITY } else { 1.0 / d.abs() };
+		let initial = |p: f64, d: f64| {
+			if d > 0.0 { (p.floor() + 1.0 - p) / d } else if d < 0.0 { (p - p.floor()) / -d } else { f64::INFINITY }
+		};
+
+		let steps = (step(direction.x), step(direction.y), step(direction.z));
+		let deltas = (delta(direction.x), delta(direction.y), delta(direction.z));
+		let mut tmax = (initial(start.x, direction.x), initial(start.y, direction.y), initial(start.z, direction.z));
+
+		while cell != target {
+			if tmax.0 <= tmax.1 && tmax.0 <= tmax.2 {
+				if tmax.0 > 1.0 { break }
+				cell.0 += steps.0;
+				tmax.0 += deltas.0;
+			} else if tmax.1 <= tmax.2 {
+				if tmax.1 > 1.0 { break }
+				cell.1 += steps.1;
+				tmax.1 += deltas.1;
+			} else {
+				if tmax.2 > 1.0 { break }
+				cell.2 += steps.2;
+				tmax.2 += deltas.2;
+			}
+
+			cells.push(cell);
+		}
+
+		return cells;
+	}
+
+	/**
+	 * Builds the block offsets of a sphere with the given diameter, used to thicken curves.
+	 */
+	fn brush(thickness: f64) -> napi::Result<Vec<(i64, i64, i64)>> {
+		let radius = ((thickness - 1.0) / 2.0).max(0.0);
+		ShapeGen::check_points((2.0 * radius.ceil() + 1.0).powi(3), "Brush")?;
+
+		let extent = radius.ceil() as i64;
+		let mut offsets = Vec::new();
+
+		for x in -extent..=extent {
+			for y in -extent..=extent {
+				for z in -extent..=extent {
+					if ((x * x + y * y + z * z) as f64).sqrt() <= radius + 0.5 { ShapeGen::push(&mut offsets, (x, y, z))? }
+				}
+			}
+		}
+
+		return Ok(offsets);
+	}
+
+	/**
+	 * Rejects shapes covering 2^32 - 1 blocks or more, before looping over them.
+	 */
+	fn check_points(count: f64, shape: &str) -> napi::Result<()> {
+		if count.is_nan() || count >= u32::MAX as f64 {
+			return Err(Error::new(Status::InvalidArg, format!("{} of {} blocks is too large", shape, count)));
+		}
+
+		Ok(())
+	}
+
+	/**
+	 * Pushes a position, returning an error instead of aborting when there is not enough memory.
+	 */
+	fn push<T>(values: &mut Vec<T>, value: T) -> napi::Result<()> {
+		if values.len() == values.capacity() && values.try_reserve(values.len().max(64)).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} positions", values.len() + 1)));
+		}
+
+		values.push(value);
+		Ok(())
+	}
+
+	fn cell(point: &Vector3f) -> (i64, i64, i64) {
+		(point.x.floor() as i64, point.y.floor() as i64, point.z.floor() as i64)
+	}
+
+	/**
+	 * Checks whether an angle lies on the arc from start to end, travelling in the positive direction.
+	 */
+	pub(crate) fn within_arc(angle: f64, start: f64, end: f64) -> bool {
+		if (end - start).abs() >= 360.0 { return true }
+
+		let span = (end - start).rem_euclid(360.0);
+		(angle - start).rem_euclid(360.0) <= span
+	}
+
+	/**
+	 * Maps plane coordinates to a world position, where w is the offset along the axis.
+	 */
+	pub(crate) fn plane_point(origin: &Vector3f, axis: Axis, u: f64, v: f64, w: f64) -> Vector3f {
+		match axis {
+			Axis::X => Vector3f::new(origin.x + w, origin.y + v, origin.z + u),
+			Axis::Y => Vector3f::new(origin.x + u, origin.y + w, origin.z + v),
+			Axis::Z => Vector3f::new(origin.x + u, origin.y + v, origin.z + w)
+		}
+	}
+}
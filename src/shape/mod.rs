@@ -0,0 +1,3 @@
+pub mod generator;
+pub mod structure;
+pub mod volume;
\ No newline at end of file
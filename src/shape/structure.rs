@@ -0,0 +1,166 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::collision::aabb::AABB;
+use crate::vec3f::{Vector3f, VectorLike};
+
+const MAX_WIDTH: f64 = 64.0; // Bedrock structure block limit on the X and Z axes
+const MAX_HEIGHT: f64 = 384.0; // Bedrock structure block limit on the Y axis, the full world height
+
+/**
+ * Represents a structure selection as its minimum block and its size in blocks.
+ */
+#[napi(object)]
+pub struct StructureRegion {
+	pub origin: Vector3f, // Minimum block of the selection
+	pub size: Vector3f, // Size of the selection in blocks, at least 1 on every axis
+}
+
+/**
+ * Helpers for structure blocks and world edit selections: normalizing two corners into
+ * an origin and size, enforcing Bedrock's size limits and checking for overlaps.
+ */
+#[napi]
+pub struct StructureBounds {}
+
+#[napi]
+impl StructureBounds {
+	/**
+	 * Normalizes two arbitrary corner blocks into the minimum block and the size of the selection.
+	 * Both corners are included, so equal corners select a single block.
+	 *
+	 * @param corner_a - The first corner block (Vector3f).
+	 * @param corner_b - The second corner block (Vector3f).
+	 */
+	#[napi]
+	pub fn normalize(corner_a: VectorLike, corner_b: VectorLike) -> StructureRegion {
+		let (a, b) = (corner_a.floor(), corner_b.floor());
+
+		StructureRegion {
+			origin: Vector3f::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+			size: Vector3f::new((a.x - b.x).abs() + 1.0, (a.y - b.y).abs() + 1.0, (a.z - b.z).abs() + 1.0)
+		}
+	}
+
+	/**
+	 * Returns the inclusive corner block opposite to the origin of a selection.
+	 *
+	 * @param region - The selection (StructureRegion).
+	 */
+	#[napi]
+	pub fn max_corner(region: StructureRegion) -> Vector3f {
+		region.origin.add(&region.size).subtract(&Vector3f::new(1.0, 1.0, 1.0))
+	}
+
+	/**
+	 * Checks whether a size fits within the structure size limits.
+	 *
+	 * @param size - The size in blocks (Vector3f).
+	 * @param limit - The maximum size, defaults to Bedrock's 64x384x64 (Vector3f).
+	 * Throws if the limit is below 1 or NaN on any axis.
+	 */
+	#[napi]
+	pub fn fits(size: VectorLike, limit: Option<VectorLike>) -> napi::Result<bool> {
+		let limit = StructureBounds::limit(limit.as_deref())?;
+		Ok(size.x >= 1.0 && size.y >= 1.0 && size.z >= 1.0 && size.x <= limit.x && size.y <= limit.y && size.z <= limit.z)
+	}
+
+	/**
+	 * Shrinks a selection to fit within the structure size limits, keeping its origin.
+	 *
+	 * @param region - The selection (StructureRegion).
+	 * @param limit - The maximum size, defaults to Bedrock's 64x384x64 (Vector3f).
+	 * Throws if the limit is below 1 or NaN on any axis.
+	 */
+	#[napi]
+	pub fn clamp(region: StructureRegion, limit: Option<VectorLike>) -> napi::Result<StructureRegion> {
+		let limit = StructureBounds::limit(limit.as_deref())?;
+
+		Ok(StructureRegion {
+			size: Vector3f::new(region.size.x.clamp(1.0, limit.x), region.size.y.clamp(1.0, limit.y), region.size.z.clamp(1.0, limit.z)),
+			origin: region.origin
+		})
+	}
+
+	/**
+	 * Normalizes two corner blocks and checks the selection against the structure size limits.
+	 *
+	 * @param corner_a - The first corner block (Vector3f).
+	 * @param corner_b - The second corner block (Vector3f).
+	 * @param limit - The maximum size, defaults to Bedrock's 64x384x64 (Vector3f).
+	 * @return The selection; otherwise, an error naming the size when it is too large or the limit when it is below 1.
+	 */
+	#[napi]
+	pub fn validate(corner_a: VectorLike, corner_b: VectorLike, limit: Option<VectorLike>) -> napi::Result<StructureRegion> {
+		let region = StructureBounds::normalize(corner_a, corner_b);
+		let limit = StructureBounds::limit(limit.as_deref())?;
+
+		if !StructureBounds::fits(VectorLike::from(&region.size), Some(VectorLike::from(&limit)))? {
+			let message = format!("Structure size {}x{}x{} exceeds the limit of {}x{}x{}", region.size.x, region.size.y, region.size.z, limit.x, limit.y, limit.z);
+			return Err(Error::new(Status::InvalidArg, message));
+		}
+
+		Ok(region)
+	}
+
+	/**
+	 * Converts a selection into the AABB covering its blocks.
+	 *
+	 * @param region - The selection (StructureRegion).
+	 */
+	#[napi]
+	pub fn to_aabb(region: StructureRegion) -> AABB {
+		AABB::new(VectorLike::from(&region.origin), region.origin.add(&region.size).into())
+	}
+
+	/**
+	 * Checks whether two selections share at least one block.
+	 *
+	 * @param a - The first selection (StructureRegion).
+	 * @param b - The second selection (StructureRegion).
+	 */
+	#[napi]
+	pub fn overlaps(a: StructureRegion, b: StructureRegion) -> bool {
+		StructureBounds::intersect(&a, &b)
+	}
+
+	/**
+	 * Finds the regions a selection must not intersect that it overlaps, such as protected areas
+	 * or other pending structures.
+	 *
+	 * @param region - The selection (StructureRegion).
+	 * @param forbidden - The regions the selection must not intersect (Array<StructureRegion>).
+	 * @return The indices of the overlapped regions, empty when the selection is allowed.
+	 */
+	#[napi]
+	pub fn find_overlaps(region: StructureRegion, forbidden: Vec<StructureRegion>) -> Vec<u32> {
+		forbidden.iter()
+			.enumerate()
+			.filter(|(_, other)| StructureBounds::intersect(&region, other))
+			.map(|(index, _)| index as u32)
+			.collect()
+	}
+
+	/**
+	 * Checks whether two selections overlap, treating the sizes as exclusive block ranges.
+	 */
+	fn intersect(a: &StructureRegion, b: &StructureRegion) -> bool {
+		a.origin.x < b.origin.x + b.size.x && b.origin.x < a.origin.x + a.size.x
+			&& a.origin.y < b.origin.y + b.size.y && b.origin.y < a.origin.y + a.size.y
+			&& a.origin.z < b.origin.z + b.size.z && b.origin.z < a.origin.z + a.size.z
+	}
+
+	/**
+	 * Returns the size limit to use, defaulting to Bedrock's, or an error when it is below a single block.
+	 */
+	fn limit(limit: Option<&Vector3f>) -> napi::Result<Vector3f> {
+		let limit = limit.cloned().unwrap_or(Vector3f::new(MAX_WIDTH, MAX_HEIGHT, MAX_WIDTH));
+
+		if [limit.x, limit.y, limit.z].iter().any(|size| size.is_nan() || *size < 1.0) {
+			let message = format!("Structure size limits must be at least 1 on every axis, got {}x{}x{}", limit.x, limit.y, limit.z);
+			return Err(Error::new(Status::InvalidArg, message));
+		}
+
+		Ok(limit)
+	}
+}
@@ -0,0 +1,254 @@
+use napi::bindgen_prelude::Uint32Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::{Axis, Vector3f, VectorLike};
+
+/**
+ * Represents a volume of block ids together with its dimensions.
+ */
+#[napi(object)]
+pub struct Volume {
+	pub data: Uint32Array,
+	pub size: Vector3f,
+}
+
+/**
+ * Transforms volumes of block ids, such as structure and clipboard data.
+ * Volumes are stored in ZYX order like .mcstructure files: the index of (x, y, z)
+ * is (x * sizeY + y) * sizeZ + z.
+ */
+#[napi]
+pub struct VolumeTransform {}
+
+#[napi]
+impl VolumeTransform {
+	/**
+	 * Rotates a volume around the Y axis in steps of 90 degrees clockwise, seen from above.
+	 *
+	 * @param data - The block ids of the volume (Uint32Array).
+	 * @param size - The dimensions of the volume (Vector3f).
+	 * @param times - The number of 90 degree steps, negative values rotate counterclockwise.
+	 * @return The rotated volume and its new dimensions.
+	 */
+	#[napi]
+	pub fn rotate90(data: Uint32Array, size: VectorLike, times: i32) -> napi::Result<Volume> {
+		let (sx, sy, sz) = VolumeTransform::dimensions(&data, &size)?;
+		let times = times.rem_euclid(4);
+
+		let (nx, nz) = if times % 2 == 0 { (sx, sz) } else { (sz, sx) };
+		let mut output = vec![0u32; data.len()];
+
+		for x in 0..sx {
+			for y in 0..sy {
+				for z in 0..sz {
+					let (tx, tz) = match times {
+						1 => (sz - 1 - z, x),
+						2 => (sx - 1 - x, sz - 1 - z),
+						3 => (z, sx - 1 - x),
+						_ => (x, z)
+					};
+
+					output[(tx * sy + y) * nz + tz] = data[(x * sy + y) * sz + z];
+				}
+			}
+		}
+
+		Ok(Volume {
+			data: Uint32Array::new(output),
+			size: Vector3f::new(nx as f64, sy as f64, nz as f64)
+		})
+	}
+
+	/**
+	 * Mirrors a volume along an axis, flipping the order of blocks on that axis.
+	 *
+	 * @param data - The block ids of the volume (Uint32Array).
+	 * @param size - The dimensions of the volume (Vector3f).
+	 * @param axis - The axis to flip (Axis).
+	 * @return The mirrored volume, its dimensions are unchanged.
+	 */
+	#[napi]
+	pub fn mirror(data: Uint32Array, size: VectorLike, axis: Axis) -> napi::Result<Volume> {
+		let (sx, sy, sz) = VolumeTransform::dimensions(&data, &size)?;
+		let mut output = vec![0u32; data.len()];
+
+		for x in 0..sx {
+			for y in 0..sy {
+				for z in 0..sz {
+					let (tx, ty, tz) = match axis {
+						Axis::X => (sx - 1 - x, y, z),
+						Axis::Y => (x, sy - 1 - y, z),
+						Axis::Z => (x, y, sz - 1 - z)
+					};
+
+					output[(tx * sy + ty) * sz + tz] = data[(x * sy + y) * sz + z];
+				}
+			}
+		}
+
+		Ok(Volume {
+			data: Uint32Array::new(output),
+			size: Vector3f::new(sx as f64, sy as f64, sz as f64)
+		})
+	}
+
+	/**
+	 * Scales a volume up by an integer factor, repeating every block (nearest neighbor).
+	 *
+	 * @param data - The block ids of the volume (Uint32Array).
+	 * @param size - The dimensions of the volume (Vector3f).
+	 * @param factor - The integer scale factor, at least 1.
+	 * @return The scaled volume and its new dimensions.
+	 */
+	#[napi]
+	pub fn upscale(data: Uint32Array, size: VectorLike, factor: u32) -> napi::Result<Volume> {
+		let (sx, sy, sz) = VolumeTransform::dimensions(&data, &size)?;
+		let factor = VolumeTransform::factor(factor)?;
+
+		let (nx, ny, nz) = match (sx.checked_mul(factor), sy.checked_mul(factor), sz.checked_mul(factor)) {
+			(Some(nx), Some(ny), Some(nz)) => (nx, ny, nz),
+			_ => return Err(Error::new(Status::InvalidArg, format!("Upscaling by {} makes the volume too large", factor)))
+		};
+		let mut output = VolumeTransform::allocate(VolumeTransform::block_count(nx, ny, nz)?)?;
+
+		for x in 0..nx {
+			for y in 0..ny {
+				for z in 0..nz {
+					output[(x * ny + y) * nz + z] = data[((x / factor) * sy + y / factor) * sz + z / factor];
+				}
+			}
+		}
+
+		Ok(Volume {
+			data: Uint32Array::new(output),
+			size: Vector3f::new(nx as f64, ny as f64, nz as f64)
+		})
+	}
+
+	/**
+	 * Scales a volume down by an integer factor. Every output block takes the most common
+	 * id of the cell it covers, ties keep the id found first. Cells on the far edges may be partial.
+	 *
+	 * @param data - The block ids of the volume (Uint32Array).
+	 * @param size - The dimensions of the volume (Vector3f).
+	 * @param factor - The integer scale factor, at least 1.
+	 * @param ignore - An id (usually air) that only wins when a cell contains nothing else.
+	 * @return The scaled volume and its new dimensions.
+	 */
+	#[napi]
+	pub fn downsample(data: Uint32Array, size: VectorLike, factor: u32, ignore: Option<u32>) -> napi::Result<Volume> {
+		let (sx, sy, sz) = VolumeTransform::dimensions(&data, &size)?;
+		let factor = VolumeTransform::factor(factor)?;
+
+		let (nx, ny, nz) = (sx.div_ceil(factor), sy.div_ceil(factor), sz.div_ceil(factor));
+		let mut output = vec![0u32; nx * ny * nz];
+		let mut counts: Vec<(u32, usize)> = Vec::new();
+
+		for x in 0..nx {
+			for y in 0..ny {
+				for z in 0..nz {
+					counts.clear();
+
+					for cx in x * factor..((x + 1) * factor).min(sx) {
+						for cy in y * factor..((y + 1) * factor).min(sy) {
+							for cz in z * factor..((z + 1) * factor).min(sz) {
+								let id = data[(cx * sy + cy) * sz + cz];
+
+								match counts.iter_mut().find(|(value, _)| *value == id) {
+									Some((_, count)) => *count += 1,
+									None => counts.push((id, 1))
+								}
+							}
+						}
+					}
+
+					let mut best: Option<(u32, usize)> = None;
+					for (id, count) in counts.iter() {
+						if Some(*id) == ignore { continue };
+						if best.is_none_or(|(_, best_count)| *count > best_count) { best = Some((*id, *count)) }
+					}
+
+					output[(x * ny + y) * nz + z] = best.map(|(id, _)| id)
+						.or(ignore)
+						.unwrap_or(0);
+				}
+			}
+		}
+
+		Ok(Volume {
+			data: Uint32Array::new(output),
+			size: Vector3f::new(nx as f64, ny as f64, nz as f64)
+		})
+	}
+
+	/**
+	 * Calculates the index of a position inside a volume of the given size.
+	 *
+	 * @param size - The dimensions of the volume (Vector3f).
+	 * @param position - The position inside the volume (Vector3f).
+	 * @return The index; otherwise, undefined if the position is outside the volume.
+	 */
+	#[napi]
+	pub fn index(size: VectorLike, position: VectorLike) -> napi::Result<Option<u32>> {
+		VolumeTransform::block_count(size.x as usize, size.y as usize, size.z as usize)?;
+
+		let (sx, sy, sz) = (size.x as i64, size.y as i64, size.z as i64);
+		let (x, y, z) = (position.x.floor() as i64, position.y.floor() as i64, position.z.floor() as i64);
+
+		if x < 0 || y < 0 || z < 0 || x >= sx || y >= sy || z >= sz { return Ok(None) }
+		Ok(Some(((x * sy + y) * sz + z) as u32))
+	}
+
+	/**
+	 * Validates a volume and returns its dimensions.
+	 */
+	pub(crate) fn dimensions(data: &[u32], size: &Vector3f) -> napi::Result<(usize, usize, usize)> {
+		if size.x < 0.0 || size.y < 0.0 || size.z < 0.0 {
+			return Err(Error::new(Status::InvalidArg, "Volume size cannot be negative".to_string()));
+		}
+
+		let (sx, sy, sz) = (size.x as usize, size.y as usize, size.z as usize);
+		let blocks = VolumeTransform::block_count(sx, sy, sz)?;
+		if blocks != data.len() {
+			return Err(Error::new(
+				Status::InvalidArg,
+				format!("Volume of size {}x{}x{} requires {} blocks, got {}", sx, sy, sz, blocks, data.len())
+			));
+		}
+
+		Ok((sx, sy, sz))
+	}
+
+	/**
+	 * Counts the blocks of a volume, rejecting sizes whose indices do not fit in a u32.
+	 * Empty axes count as 1 in the check, so the loops over the other axes stay bounded too.
+	 */
+	fn block_count(sx: usize, sy: usize, sz: usize) -> napi::Result<usize> {
+		match [sx, sy, sz].iter().try_fold(1usize, |blocks, axis| blocks.checked_mul((*axis).max(1))) {
+			Some(blocks) if blocks <= u32::MAX as usize => Ok(sx * sy * sz),
+			_ => Err(Error::new(Status::InvalidArg, format!("Volume of size {}x{}x{} is too large", sx, sy, sz)))
+		}
+	}
+
+	/**
+	 * Allocates the blocks of an output volume, returning an error instead of aborting when memory runs out.
+	 */
+	fn allocate(blocks: usize) -> napi::Result<Vec<u32>> {
+		let mut output: Vec<u32> = Vec::new();
+		if output.try_reserve_exact(blocks).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for a volume of {} blocks", blocks)));
+		}
+
+		output.resize(blocks, 0);
+		Ok(output)
+	}
+
+	fn factor(factor: u32) -> napi::Result<usize> {
+		if factor == 0 {
+			return Err(Error::new(Status::InvalidArg, "Scale factor must be at least 1".to_string()));
+		}
+
+		Ok(factor as usize)
+	}
+}
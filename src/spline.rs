@@ -0,0 +1,175 @@
+use napi::bindgen_prelude::Float64Array;
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::vec3f::Vector3f;
+
+const MAX_SUBDIVISIONS: u32 = 12; // Depth limit of the adaptive arc length sampling
+
+/**
+ * A Catmull-Rom spline passing through its control points, for camera paths and cinematics.
+ * The arc length is sampled adaptively on construction, so points can be looked up by the
+ * distance travelled and paths can be followed at constant speed.
+ */
+#[napi]
+pub struct Spline {
+	points: Vec<Vector3f>,
+	closed: bool,
+	table: Vec<(f64, f64)> // Parameter and distance travelled at every sample, in order
+}
+
+#[napi]
+impl Spline {
+	/**
+	 * Constructs a spline through control points.
+	 *
+	 * @param points - The control points, packed as x, y, z triplets (Float64Array).
+	 * @param closed - Whether the spline loops back to its first point, defaults to false.
+	 * @param tolerance - The maximum error of the arc length per segment, defaults to 0.001 blocks.
+	 * Throws if there are fewer than 2 control points or a coordinate is not finite.
+	 */
+	#[napi(constructor)]
+	pub fn new(points: Float64Array, closed: Option<bool>, tolerance: Option<f64>) -> napi::Result<Spline> {
+		if let Some(value) = points.iter().find(|value| !value.is_finite()) {
+			return Err(Error::new(Status::InvalidArg, format!("Control points must be finite, got {}", value)));
+		}
+
+		let points: Vec<Vector3f> = points.chunks_exact(3).map(|p| Vector3f::new(p[0], p[1], p[2])).collect();
+		if points.len() < 2 {
+			return Err(Error::new(Status::InvalidArg, "A spline needs at least 2 control points".to_string()));
+		}
+
+		let mut spline = Spline { points, closed: closed.unwrap_or(false), table: vec![(0.0, 0.0)] };
+		let tolerance = tolerance.unwrap_or(0.001).max(1e-9);
+		let segments = spline.segments();
+
+		for segment in 0..segments {
+			let (start, end) = (segment as f64 / segments as f64, (segment + 1) as f64 / segments as f64);
+			spline.subdivide(start, end, tolerance, 0);
+		}
+
+		return Ok(spline);
+	}
+
+	/**
+	 * Returns the point at a parameter, where 0 is the start and 1 the end of the spline.
+	 * Equal steps of the parameter do not cover equal distances, see pointAtDistance.
+	 *
+	 * @param t - The parameter (0..1).
+	 */
+	#[napi]
+	pub fn point_at(&self, t: f64) -> Vector3f {
+		let segments = self.segments();
+		let scaled = t.clamp(0.0, 1.0) * segments as f64;
+		let segment = (scaled.floor() as usize).min(segments - 1);
+		let local = scaled - segment as f64;
+
+		let [p0, p1, p2, p3] = [segment as i64 - 1, segment as i64, segment as i64 + 1, segment as i64 + 2].map(|index| self.control(index));
+		let (t2, t3) = (local * local, local * local * local);
+
+		// Uniform Catmull-Rom basis.
+		let blend = |a: f64, b: f64, c: f64, d: f64| 0.5 * (
+			2.0 * b + (c - a) * local + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2 + (3.0 * b - a - 3.0 * c + d) * t3
+		);
+
+		Vector3f::new(blend(p0.x, p1.x, p2.x, p3.x), blend(p0.y, p1.y, p2.y, p3.y), blend(p0.z, p1.z, p2.z, p3.z))
+	}
+
+	/**
+	 * Returns the length of the spline.
+	 */
+	#[napi]
+	pub fn total_length(&self) -> f64 {
+		self.table.last().map_or(0.0, |(_, distance)| *distance)
+	}
+
+	/**
+	 * Returns the parameter reached after travelling a distance along the spline.
+	 *
+	 * @param distance - The distance from the start, clamped to the length.
+	 */
+	#[napi]
+	pub fn t_at_distance(&self, distance: f64) -> f64 {
+		let distance = distance.clamp(0.0, self.total_length());
+		let index = self.table.partition_point(|(_, d)| *d < distance);
+
+		if index == 0 { return 0.0 }
+		if index >= self.table.len() { return 1.0 }
+
+		let ((t0, d0), (t1, d1)) = (self.table[index - 1], self.table[index]);
+		if d1 == d0 { t1 } else { t0 + (t1 - t0) * (distance - d0) / (d1 - d0) }
+	}
+
+	/**
+	 * Returns the point reached after travelling a distance along the spline, for constant speed movement.
+	 *
+	 * @param distance - The distance from the start, clamped to the length.
+	 */
+	#[napi]
+	pub fn point_at_distance(&self, distance: f64) -> Vector3f {
+		self.point_at(self.t_at_distance(distance))
+	}
+
+	/**
+	 * Samples points spaced evenly along the spline.
+	 *
+	 * @param count - The number of points, including both ends.
+	 * @return The points, packed as x, y, z triplets (Float64Array).
+	 * Throws if the points do not fit in a typed array or there is not enough memory.
+	 */
+	#[napi]
+	pub fn sample_evenly(&self, count: u32) -> napi::Result<Float64Array> {
+		if count as u64 * 3 > u32::MAX as u64 {
+			return Err(Error::new(Status::InvalidArg, format!("Splines are sampled at most {} times, got {}", u32::MAX / 3, count)));
+		}
+
+		let mut values: Vec<f64> = Vec::new();
+		if values.try_reserve_exact(count as usize * 3).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} samples", count)));
+		}
+
+		let length = self.total_length();
+		let step = if count > 1 { length / (count - 1) as f64 } else { 0.0 };
+
+		for i in 0..count {
+			let point = self.point_at_distance(step * i as f64);
+			values.extend([point.x, point.y, point.z]);
+		}
+
+		Ok(Float64Array::new(values))
+	}
+
+	fn segments(&self) -> usize {
+		if self.closed { self.points.len() } else { self.points.len() - 1 }
+	}
+
+	/**
+	 * Returns a control point, wrapping around closed splines and clamping open ones.
+	 */
+	fn control(&self, index: i64) -> &Vector3f {
+		let count = self.points.len() as i64;
+		let index = if self.closed { index.rem_euclid(count) } else { index.clamp(0, count - 1) };
+
+		&self.points[index as usize]
+	}
+
+	/**
+	 * Splits a parameter range until its chord matches the length of its two halves,
+	 * appending the samples of the range to the table.
+	 */
+	fn subdivide(&mut self, start: f64, end: f64, tolerance: f64, depth: u32) {
+		let middle = (start + end) * 0.5;
+		let (a, b, c) = (self.point_at(start), self.point_at(middle), self.point_at(end));
+		let (chord, halves) = (a.distance(&c), a.distance(&b) + b.distance(&c));
+
+		if depth < MAX_SUBDIVISIONS && halves - chord > tolerance {
+			self.subdivide(start, middle, tolerance, depth + 1);
+			self.subdivide(middle, end, tolerance, depth + 1);
+			return;
+		}
+
+		let travelled = self.total_length();
+		self.table.push((middle, travelled + a.distance(&b)));
+		self.table.push((end, travelled + halves));
+	}
+}
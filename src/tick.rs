@@ -0,0 +1,248 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::random::java::JavaRandom;
+use crate::vec2f::Vector2f;
+
+const INV_PHI: f64 = 0.618_033_988_749_894_9; // 1 / golden ratio
+
+/**
+ * Helpers distributing recurring tasks across the ticks of a period, so that
+ * mass updates are spread out instead of all running on the same tick.
+ */
+#[napi]
+pub struct TickSchedule {}
+
+#[napi]
+impl TickSchedule {
+	/**
+	 * Distributes tasks using the golden-ratio sequence. Every prefix of the sequence
+	 * is close to evenly spread, so tasks can be added over time without rebalancing.
+	 *
+	 * @param count - The number of tasks.
+	 * @param period - The number of ticks between runs of a task.
+	 * @param start - The index of the first task in the sequence, defaults to 0.
+	 * @return The tick offset (0..period) of every task.
+	 * Throws if there is not enough memory for the offsets.
+	 */
+	#[napi]
+	pub fn golden_ratio_offsets(count: u32, period: u32, start: Option<u32>) -> napi::Result<Vec<u32>> {
+		let start = start.unwrap_or(0);
+		let mut offsets = TickSchedule::allocate(count, "offsets")?;
+		if period == 0 { offsets.resize(count as usize, 0); return Ok(offsets) }
+
+		// Indices are widened so a start near u32::MAX still yields count offsets instead of overflowing
+		offsets.extend((start as u64..start as u64 + count as u64)
+			.map(|i| (((i as f64 * INV_PHI).fract() * period as f64) as u32).min(period - 1)));
+
+		Ok(offsets)
+	}
+
+	/**
+	 * Distributes tasks evenly by splitting the period into one stratum per task,
+	 * placing each task at a jittered position inside its stratum.
+	 *
+	 * @param count - The number of tasks.
+	 * @param period - The number of ticks between runs of a task.
+	 * @param seed - The seed for the jitter; without a seed tasks sit in the middle of their stratum.
+	 * @return The tick offset (0..period) of every task.
+	 * Throws if there is not enough memory for the offsets.
+	 */
+	#[napi]
+	pub fn stratified_offsets(count: u32, period: u32, seed: Option<i64>) -> napi::Result<Vec<u32>> {
+		let mut offsets = TickSchedule::allocate(count, "offsets")?;
+		if period == 0 || count == 0 { offsets.resize(count as usize, 0); return Ok(offsets) }
+
+		let mut random = seed.map(JavaRandom::from_seed);
+		let stratum = period as f64 / count as f64;
+
+		offsets.extend((0..count)
+			.map(|i| {
+				let jitter = random.as_mut().map_or(0.5, |random| random.next_double());
+				(((i as f64 + jitter) * stratum) as u32).min(period - 1)
+			}));
+
+		Ok(offsets)
+	}
+
+	/**
+	 * Counts how many tasks run on every tick of the period.
+	 *
+	 * @param offsets - The tick offsets of the tasks.
+	 * @param period - The number of ticks in the period.
+	 * @return The number of tasks per tick.
+	 * Throws if there is not enough memory for the period.
+	 */
+	#[napi]
+	pub fn load(offsets: Vec<u32>, period: u32) -> napi::Result<Vec<u32>> {
+		let mut load = TickSchedule::allocate(period, "ticks")?;
+		load.resize(period as usize, 0);
+		if period == 0 { return Ok(load) }
+
+		for offset in offsets { load[(offset % period) as usize] += 1 }
+		return Ok(load);
+	}
+
+	/**
+	 * Checks whether a task with the given offset runs on a tick.
+	 *
+	 * @param tick - The current tick.
+	 * @param offset - The offset of the task.
+	 * @param period - The number of ticks between runs of the task.
+	 */
+	#[napi]
+	pub fn is_due(tick: i64, offset: u32, period: u32) -> bool {
+		period != 0 && tick.rem_euclid(period as i64) == offset as i64 % period as i64
+	}
+
+	/**
+	 * Reserves room for a number of values, returning an error instead of aborting when there is not enough memory.
+	 */
+	fn allocate(count: u32, values: &str) -> napi::Result<Vec<u32>> {
+		let mut allocated: Vec<u32> = Vec::new();
+		if allocated.try_reserve_exact(count as usize).is_err() {
+			return Err(Error::new(Status::GenericFailure, format!("Not enough memory for {} {}", count, values)));
+		}
+
+		Ok(allocated)
+	}
+}
+
+const DEFAULT_TICK_RATE: f64 = 20.0; // Ticks per second of a vanilla server
+
+/**
+ * Conversions between ticks, milliseconds and partial ticks, so systems interpolating
+ * between ticks share the same math.
+ */
+#[napi]
+pub struct TickTime {}
+
+#[napi]
+impl TickTime {
+	/**
+	 * Returns the duration of a tick in milliseconds.
+	 *
+	 * @param tick_rate - The ticks per second, defaults to 20.
+	 */
+	#[napi]
+	pub fn tick_duration(tick_rate: Option<f64>) -> f64 {
+		1000.0 / tick_rate.unwrap_or(DEFAULT_TICK_RATE)
+	}
+
+	/**
+	 * Converts ticks into milliseconds.
+	 *
+	 * @param ticks - The ticks, fractions included.
+	 * @param tick_rate - The ticks per second, defaults to 20.
+	 */
+	#[napi]
+	pub fn ticks_to_ms(ticks: f64, tick_rate: Option<f64>) -> f64 {
+		ticks * TickTime::tick_duration(tick_rate)
+	}
+
+	/**
+	 * Converts milliseconds into ticks, fractions included.
+	 *
+	 * @param ms - The milliseconds.
+	 * @param tick_rate - The ticks per second, defaults to 20.
+	 */
+	#[napi]
+	pub fn ms_to_ticks(ms: f64, tick_rate: Option<f64>) -> f64 {
+		ms / TickTime::tick_duration(tick_rate)
+	}
+
+	/**
+	 * Calculates the partial tick alpha, the progress from the last tick towards the next.
+	 *
+	 * @param ms_since_tick - The milliseconds elapsed since the last tick.
+	 * @param tick_rate - The ticks per second, defaults to 20.
+	 * @return The alpha (0..1).
+	 */
+	#[napi]
+	pub fn alpha(ms_since_tick: f64, tick_rate: Option<f64>) -> f64 {
+		TickTime::ms_to_ticks(ms_since_tick, tick_rate).clamp(0.0, 1.0)
+	}
+
+	/**
+	 * Splits a time into the whole tick it falls in and the alpha within that tick.
+	 *
+	 * @param ms - The milliseconds since tick 0.
+	 * @param tick_rate - The ticks per second, defaults to 20.
+	 * @return The tick (x) and the alpha (y).
+	 */
+	#[napi]
+	pub fn split(ms: f64, tick_rate: Option<f64>) -> Vector2f {
+		let ticks = TickTime::ms_to_ticks(ms, tick_rate);
+		Vector2f::new(ticks.floor(), ticks - ticks.floor())
+	}
+}
+
+/**
+ * Accumulates frame time and hands out fixed ticks, carrying the remainder over so no
+ * time is lost. The alpha of the remainder interpolates rendering between the last two ticks.
+ */
+#[napi]
+pub struct FixedTimestepAccumulator {
+	pub tick_rate: f64,
+	pub max_steps: u32, // Maximum ticks run per advance, so a stall does not spiral
+	pub accumulated: f64, // Milliseconds not yet consumed by a tick
+	pub ticks: i64 // Ticks run since construction or reset
+}
+
+#[napi]
+impl FixedTimestepAccumulator {
+	/**
+	 * Constructs an empty accumulator.
+	 *
+	 * @param tick_rate - The ticks per second, defaults to 20.
+	 * @param max_steps - The maximum ticks run per advance, defaults to 10.
+	 */
+	#[napi(constructor)]
+	pub fn new(tick_rate: Option<f64>, max_steps: Option<u32>) -> FixedTimestepAccumulator {
+		FixedTimestepAccumulator {
+			tick_rate: tick_rate.unwrap_or(DEFAULT_TICK_RATE),
+			max_steps: max_steps.unwrap_or(10),
+			accumulated: 0.0,
+			ticks: 0
+		}
+	}
+
+	/**
+	 * Adds elapsed time and consumes it in whole ticks. Time beyond the step limit is dropped,
+	 * and a tick rate that is not positive never ticks.
+	 *
+	 * @param delta_ms - The milliseconds elapsed since the last advance.
+	 * @return The number of ticks to run.
+	 */
+	#[napi]
+	pub fn advance(&mut self, delta_ms: f64) -> u32 {
+		let duration = TickTime::tick_duration(Some(self.tick_rate));
+		self.accumulated += delta_ms.max(0.0);
+
+		// Counted at once rather than tick by tick, so huge deltas and step limits cannot stall.
+		let steps = if duration > 0.0 { (self.accumulated / duration).floor().min(self.max_steps as f64) as u32 } else { 0 };
+		self.accumulated -= steps as f64 * duration;
+
+		if steps == self.max_steps { self.accumulated = self.accumulated.min(duration) }
+		self.ticks = self.ticks.saturating_add(steps as i64);
+
+		return steps;
+	}
+
+	/**
+	 * Returns the alpha of the remaining time, for interpolating between the last two ticks.
+	 */
+	#[napi]
+	pub fn alpha(&self) -> f64 {
+		TickTime::alpha(self.accumulated, Some(self.tick_rate))
+	}
+
+	/**
+	 * Clears the accumulated time and the tick count.
+	 */
+	#[napi]
+	pub fn reset(&mut self) {
+		self.accumulated = 0.0;
+		self.ticks = 0;
+	}
+}
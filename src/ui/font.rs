@@ -0,0 +1,205 @@
+use napi_derive::napi;
+
+/**
+ * Character advances of the vanilla default font in pixels, including the 1 pixel spacing.
+ * Characters not listed advance by the default glyph width.
+ */
+const ADVANCES: [(char, u32); 27] = [
+	(' ', 4), ('!', 2), ('"', 4), ('\'', 2), ('(', 4), (')', 4), ('*', 5), (',', 2), ('.', 2),
+	(':', 2), (';', 2), ('<', 5), ('>', 5), ('@', 7), ('I', 4), ('[', 4), (']', 4), ('`', 3),
+	('f', 5), ('i', 2), ('k', 5), ('l', 3), ('t', 4), ('{', 4), ('|', 2), ('}', 4), ('~', 7)
+];
+
+const DEFAULT_ADVANCE: u32 = 6;
+const FORMAT_PREFIX: char = '§';
+
+/**
+ * Text measurement using the vanilla font metrics, so chat, scoreboard and
+ * form content can be aligned without guessing.
+ * Formatting codes (for example "§l") are zero width, bold text advances one extra pixel per character.
+ */
+#[napi]
+pub struct FontMetrics {}
+
+#[napi]
+impl FontMetrics {
+	/**
+	 * Returns the advance of a single character in pixels.
+	 *
+	 * @param character - The character to measure; only the first character is used.
+	 * @param bold - Whether the character is rendered bold.
+	 */
+	#[napi]
+	pub fn char_width(character: String, bold: Option<bool>) -> u32 {
+		match character.chars().next() {
+			Some(c) => FontMetrics::advance(c, bold.unwrap_or(false)),
+			None => 0
+		}
+	}
+
+	/**
+	 * Measures the width of a line of text in pixels.
+	 *
+	 * @param text - The text to measure, may contain formatting codes.
+	 * @return The width of the widest line when the text spans multiple lines.
+	 */
+	#[napi]
+	pub fn measure(text: String) -> u32 {
+		text.split('\n').map(FontMetrics::measure_line).max().unwrap_or(0)
+	}
+
+	/**
+	 * Wraps text into lines no wider than the given width. Lines are broken at spaces,
+	 * words wider than a full line are split. Active formatting codes are carried onto
+	 * the following lines like vanilla chat does.
+	 *
+	 * @param text - The text to wrap, may contain formatting codes and line breaks.
+	 * @param max_width - The maximum line width in pixels.
+	 * @return The wrapped lines.
+	 */
+	#[napi]
+	pub fn wrap(text: String, max_width: u32) -> Vec<String> {
+		let mut lines: Vec<String> = Vec::new();
+		let mut formatting = String::new();
+
+		for paragraph in text.split('\n') {
+			let mut line = formatting.clone();
+			let mut line_width: u32 = 0;
+
+			for (index, word) in paragraph.split(' ').enumerate() {
+				// Measure the joined line, so the word picks up the formatting active before it.
+				let candidate = if index == 0 { format!("{}{}", line, word) } else { format!("{} {}", line, word) };
+				let candidate_width = FontMetrics::measure_line(&candidate);
+
+				if candidate_width <= max_width {
+					line = candidate;
+					line_width = candidate_width;
+					continue;
+				}
+
+				if line_width > 0 {
+					formatting = FontMetrics::active_formatting(&line);
+					lines.push(line);
+					line = formatting.clone();
+					line_width = 0;
+				}
+
+				// Split words that do not fit on a line of their own.
+				let mut bold = FontMetrics::is_bold(&line);
+				let mut chars = word.chars().peekable();
+				while let Some(c) = chars.next() {
+					if c == FORMAT_PREFIX {
+						line.push(c);
+						if let Some(code) = chars.next() {
+							line.push(code);
+							bold = FontMetrics::apply_code(code, bold);
+						}
+						continue;
+					}
+
+					let width = FontMetrics::advance(c, bold);
+					if line_width + width > max_width && line_width > 0 {
+						formatting = FontMetrics::active_formatting(&line);
+						lines.push(line);
+						line = formatting.clone();
+						line_width = 0;
+					}
+
+					line.push(c);
+					line_width += width;
+				}
+			}
+
+			formatting = FontMetrics::active_formatting(&line);
+			lines.push(line);
+		}
+
+		return lines;
+	}
+
+	/**
+	 * Calculates how many spaces pad a text to the given width, for aligning columns.
+	 *
+	 * @param text - The text being padded.
+	 * @param width - The target width in pixels.
+	 * @return The number of spaces which keeps the text closest to, without exceeding, the width.
+	 */
+	#[napi]
+	pub fn padding(text: String, width: u32) -> u32 {
+		let remaining = width.saturating_sub(FontMetrics::measure_line(&text));
+		remaining / FontMetrics::advance(' ', false)
+	}
+
+	fn measure_line(line: &str) -> u32 {
+		let mut width: u32 = 0;
+		let mut bold = false;
+		let mut chars = line.chars();
+
+		while let Some(c) = chars.next() {
+			if c == FORMAT_PREFIX {
+				if let Some(code) = chars.next() { bold = FontMetrics::apply_code(code, bold) }
+				continue;
+			}
+
+			width += FontMetrics::advance(c, bold);
+		}
+
+		return width;
+	}
+
+	fn advance(c: char, bold: bool) -> u32 {
+		let base = ADVANCES.iter()
+			.find(|(character, _)| *character == c)
+			.map(|(_, advance)| *advance)
+			.unwrap_or(DEFAULT_ADVANCE);
+
+		if bold { base + 1 } else { base }
+	}
+
+	/**
+	 * Updates the bold state for a formatting code. Colors and resets clear bold, like vanilla.
+	 */
+	fn apply_code(code: char, bold: bool) -> bool {
+		match code.to_ascii_lowercase() {
+			'l' => true,
+			'r' | '0'..='9' | 'a'..='f' | 'g' => false,
+			_ => bold
+		}
+	}
+
+	fn is_bold(line: &str) -> bool {
+		let mut bold = false;
+		let mut chars = line.chars();
+
+		while let Some(c) = chars.next() {
+			if c == FORMAT_PREFIX {
+				if let Some(code) = chars.next() { bold = FontMetrics::apply_code(code, bold) }
+			}
+		}
+
+		return bold;
+	}
+
+	/**
+	 * Collects the formatting codes still active at the end of a line.
+	 */
+	fn active_formatting(line: &str) -> String {
+		let mut color: Option<char> = None;
+		let mut formats: Vec<char> = Vec::new();
+		let mut chars = line.chars();
+
+		while let Some(c) = chars.next() {
+			if c != FORMAT_PREFIX { continue }
+			let Some(code) = chars.next() else { break };
+
+			match code.to_ascii_lowercase() {
+				'r' => { color = None; formats.clear(); },
+				'0'..='9' | 'a'..='g' => { color = Some(code); formats.clear(); },
+				'k'..='o' if !formats.contains(&code) => formats.push(code),
+				_ => {}
+			}
+		}
+
+		color.into_iter().chain(formats).flat_map(|code| [FORMAT_PREFIX, code]).collect()
+	}
+}
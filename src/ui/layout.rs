@@ -0,0 +1,119 @@
+use napi_derive::napi;
+
+use crate::rect::Rect;
+use crate::vec2f::Vector2f;
+
+#[napi]
+pub enum FitMode {
+	Contain, // Scale uniformly so the content fits entirely inside the container
+	Cover, // Scale uniformly so the content covers the whole container
+	Stretch // Scale each axis independently to match the container
+}
+
+/**
+ * Layout helpers for server driven user interfaces.
+ * All positions use screen conventions: the origin is the top left corner and y grows downwards.
+ */
+#[napi]
+pub struct Layout {}
+
+#[napi]
+impl Layout {
+	/**
+	 * Resolves the rectangle of an element anchored inside a parent rectangle.
+	 *
+	 * @param parent - The parent rectangle (Rect).
+	 * @param size - The size of the element (Vector2f).
+	 * @param anchor - The normalized point of the parent the element is attached to, (0, 0) is top left (Vector2f).
+	 * @param pivot - The normalized point of the element placed on the anchor (Vector2f).
+	 * @param offset - The offset applied after anchoring, defaults to no offset (Vector2f).
+	 * @return The resolved element rectangle.
+	 */
+	#[napi]
+	pub fn anchor(parent: &Rect, size: &Vector2f, anchor: &Vector2f, pivot: &Vector2f, offset: Option<&Vector2f>) -> Rect {
+		let (offset_x, offset_y) = offset.map(|o| (o.x, o.y)).unwrap_or((0.0, 0.0));
+
+		Rect::new(
+			parent.x + parent.width * anchor.x - size.x * pivot.x + offset_x,
+			parent.y + parent.height * anchor.y - size.y * pivot.y + offset_y,
+			size.x,
+			size.y
+		)
+	}
+
+	/**
+	 * Splits a rectangle into the nine regions of a 9-slice.
+	 * When the borders do not fit inside the rectangle they are scaled down proportionally.
+	 *
+	 * @param rect - The rectangle to split (Rect).
+	 * @param left - The width of the left border.
+	 * @param top - The height of the top border.
+	 * @param right - The width of the right border.
+	 * @param bottom - The height of the bottom border.
+	 * @return The nine regions, row by row from the top left to the bottom right corner.
+	 */
+	#[napi]
+	pub fn nine_slice(rect: &Rect, left: f64, top: f64, right: f64, bottom: f64) -> Vec<Rect> {
+		let (left, right) = Layout::fit_borders(rect.width, left.max(0.0), right.max(0.0));
+		let (top, bottom) = Layout::fit_borders(rect.height, top.max(0.0), bottom.max(0.0));
+
+		let columns = [
+			(rect.x, left),
+			(rect.x + left, rect.width - left - right),
+			(rect.x + rect.width - right, right)
+		];
+		let rows = [
+			(rect.y, top),
+			(rect.y + top, rect.height - top - bottom),
+			(rect.y + rect.height - bottom, bottom)
+		];
+
+		let mut slices = Vec::with_capacity(9);
+		for (y, height) in rows {
+			for (x, width) in columns {
+				slices.push(Rect::new(x, y, width, height));
+			}
+		}
+
+		return slices;
+	}
+
+	/**
+	 * Fits content of a given size into a container, preserving the aspect ratio
+	 * unless stretching. The result is centered inside the container.
+	 *
+	 * @param content - The natural size of the content (Vector2f).
+	 * @param container - The container rectangle (Rect).
+	 * @param mode - How the content is scaled (FitMode).
+	 * @return The rectangle the content occupies.
+	 */
+	#[napi]
+	pub fn fit(content: &Vector2f, container: &Rect, mode: FitMode) -> Rect {
+		if content.x <= 0.0 || content.y <= 0.0 {
+			return Rect::new(container.x + container.width / 2.0, container.y + container.height / 2.0, 0.0, 0.0);
+		}
+
+		let scale_x = container.width / content.x;
+		let scale_y = container.height / content.y;
+		let (width, height) = match mode {
+			FitMode::Contain => { let s = scale_x.min(scale_y); (content.x * s, content.y * s) },
+			FitMode::Cover => { let s = scale_x.max(scale_y); (content.x * s, content.y * s) },
+			FitMode::Stretch => (container.width, container.height)
+		};
+
+		Rect::new(
+			container.x + (container.width - width) / 2.0,
+			container.y + (container.height - height) / 2.0,
+			width,
+			height
+		)
+	}
+
+	fn fit_borders(length: f64, start: f64, end: f64) -> (f64, f64) {
+		let total = start + end;
+		if total <= length || total == 0.0 { return (start, end) }
+
+		let scale = length.max(0.0) / total;
+		(start * scale, end * scale)
+	}
+}
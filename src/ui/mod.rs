@@ -0,0 +1,2 @@
+pub mod layout;
+pub mod font;
\ No newline at end of file
@@ -0,0 +1,137 @@
+use napi_derive::napi;
+
+use crate::vec3f::{Vector3f, VectorLike};
+
+#[napi(js_name = "Vector2f")]
+#[derive(Clone, Debug)]
+pub struct Vector2f {
+	pub x: f64,
+	pub y: f64
+}
+
+#[napi]
+impl Vector2f {
+
+	#[napi(constructor)]
+	pub fn new(x: f64, y: f64) -> Self {
+		Vector2f { x, y }
+	}
+
+	#[napi]
+	pub fn dot(&self, other: &Vector2f) -> f64 {
+		self.x * other.x + self.y * other.y
+	}
+
+	#[napi]
+	pub fn length(&self) -> f64 {
+		// hypot keeps the length of huge vectors from overflowing to infinity.
+		self.x.hypot(self.y)
+	}
+
+	#[napi]
+	pub fn square_length(&self) -> f64 {
+		self.dot(self)
+	}
+
+	/**
+	 * Returns the vector scaled to unit length, or a zero vector when it has no length
+	 * or a length that is not finite.
+	 */
+	#[napi]
+	pub fn normalize(&self) -> Vector2f {
+		let length = self.length();
+		if length == 0.0 || !length.is_finite() { return Vector2f::new(0.0, 0.0) }
+
+		Vector2f::new(self.x / length, self.y / length)
+	}
+
+	#[napi]
+	pub fn add(&self, other: &Vector2f) -> Vector2f {
+		Vector2f::new(self.x + other.x, self.y + other.y)
+	}
+
+	#[napi]
+	pub fn subtract(&self, other: &Vector2f) -> Vector2f {
+		Vector2f::new(self.x - other.x, self.y - other.y)
+	}
+
+	#[napi]
+	pub fn multiply(&self, scalar: f64) -> Vector2f {
+		Vector2f::new(self.x * scalar, self.y * scalar)
+	}
+
+	#[napi]
+	pub fn negate(&self) -> Vector2f {
+		Vector2f::new(-self.x, -self.y)
+	}
+
+	#[napi]
+	pub fn lerp(&self, other: &Vector2f, t: f64) -> Vector2f {
+		Vector2f::new(self.x + (other.x - self.x) * t, self.y + (other.y - self.y) * t)
+	}
+
+	#[napi]
+	pub fn distance(&self, other: &Vector2f) -> f64 {
+		self.subtract(other).length()
+	}
+
+	/**
+	 * Returns the z component of the 3D cross product, positive when other is counterclockwise from this vector.
+	 */
+	#[napi]
+	pub fn cross(&self, other: &Vector2f) -> f64 {
+		self.x * other.y - self.y * other.x
+	}
+
+	/**
+	 * Rotates the vector counterclockwise, from +x towards +y.
+	 *
+	 * @param angle - The angle in radians.
+	 */
+	#[napi]
+	pub fn rotate(&self, angle: f64) -> Vector2f {
+		let (sin, cos) = angle.sin_cos();
+		Vector2f::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+	}
+
+	/**
+	 * Returns the vector rotated by 90 degrees counterclockwise.
+	 */
+	#[napi]
+	pub fn perpendicular(&self) -> Vector2f {
+		Vector2f::new(-self.y, self.x)
+	}
+
+	/**
+	 * Returns the angle of the vector from +x towards +y in radians (-PI..PI).
+	 */
+	#[napi]
+	pub fn angle(&self) -> f64 {
+		self.y.atan2(self.x)
+	}
+
+	/**
+	 * Returns the horizontal part of a vector, with x as x and z as y.
+	 *
+	 * @param v - The vector to flatten (Vector3f).
+	 */
+	#[napi(js_name = "fromVector3f")]
+	pub fn from_vector3f(v: VectorLike) -> Vector2f {
+		Vector2f::new(v.x, v.z)
+	}
+
+	/**
+	 * Lifts the vector into the horizontal plane, with x as x and y as z.
+	 *
+	 * @param y - The height of the result, defaults to 0.
+	 */
+	#[napi(js_name = "toVector3f")]
+	pub fn to_vector3f(&self, y: Option<f64>) -> Vector3f {
+		Vector3f::new(self.x, y.unwrap_or(0.0), self.y)
+	}
+
+	#[napi]
+	pub fn equals(&self, other: &Vector2f) -> bool {
+		self.x == other.x && self.y == other.y
+	}
+}
@@ -1,6 +1,10 @@
-use napi::bindgen_prelude::FromNapiValue;
+use std::ops::Deref;
+
+use napi::bindgen_prelude::{Either, FromNapiValue, Reference, TypeName, ValueType};
+use napi::{Error, JsNumber, JsObject, Status};
 use napi_derive::napi;
 
+use crate::vec2f::Vector2f;
 
 #[derive(PartialEq)]
 #[napi]
@@ -18,6 +22,16 @@ pub struct Vector3f {
 	pub z: f64
 }
 
+/**
+ * Represents a position in spherical coordinates, with Y as the up axis.
+ */
+#[napi(object)]
+pub struct Spherical {
+	pub radius: f64, // Distance from the origin
+	pub theta: f64, // Polar angle from +Y in radians (0..PI)
+	pub phi: f64, // Azimuth around Y from +X towards +Z in radians (-PI..PI)
+}
+
 #[napi]
 impl Vector3f {
 
@@ -26,7 +40,6 @@ impl Vector3f {
         Vector3f { x, y, z }
     }
 
-	#[napi]
 	pub fn dot(&self, other: &Vector3f) -> f64 {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
@@ -41,13 +54,11 @@ impl Vector3f {
 		self.dot(self)
 	}
 
-	#[napi]
 	pub fn normalize(&self) -> Self {
         let length = self.length();
         Vector3f::new(self.x / length, self.y / length, self.z / length)
     }
 
-	#[napi]
 	pub fn lerp(&self, other: &Vector3f, t: f64) -> Vector3f {
 		Vector3f::new(
             self.x + (other.x - self.x) * t,
@@ -56,22 +67,18 @@ impl Vector3f {
         )
 	}
 
-	#[napi]
 	pub fn add(&self, other: &Vector3f) -> Vector3f {
 		Vector3f::new(self.x + other.x, self.y + other.y, self.z + other.z)
 	}
 
-	#[napi]
 	pub fn subtract(&self, other: &Vector3f) -> Vector3f {
         Vector3f::new(self.x - other.x, self.y - other.y, self.z - other.z)
     }
 
-	#[napi]
 	pub fn multiply(&self, scalar: f64) -> Vector3f {
         Vector3f::new(self.x * scalar, self.y * scalar, self.z * scalar)
     }
 
-	#[napi]
 	pub fn cross(&self, other: &Vector3f) -> Vector3f {
 		Vector3f::new(
             self.y * other.z - self.z * other.y,
@@ -85,7 +92,6 @@ impl Vector3f {
 		Vector3f::new(self.x.abs(), self.y.abs(), self.z.abs())
 	}
 
-	#[napi]
 	pub fn distance(&self, other: &Vector3f) -> f64 {
 		let diff = self.subtract(other);
 
@@ -108,8 +114,8 @@ impl Vector3f {
 	}
 
 	#[napi]
-	pub fn slerp(&self, other: &Vector3f, t: f64) -> Vector3f {
-		let dot: f64 = self.dot(other);
+	pub fn slerp(&self, other: VectorLike, t: f64) -> Vector3f {
+		let dot: f64 = self.dot(&other);
 		let theta: f64 = dot.acos();
 		let sin_theta: f64 = theta.sin();
 		let a: f64 = ((1.0 - t) * theta).sin() / sin_theta;
@@ -118,8 +124,186 @@ impl Vector3f {
 		return self.multiply(a).add(&other.multiply(b))
 	}
 
+	/**
+	 * Rotates the vector around an axis using Rodrigues' formula.
+	 * The rotation is counter-clockwise when looking from the tip of the axis towards the origin.
+	 *
+	 * @param axis - The axis to rotate around, normalized before use; a zero axis leaves the vector unchanged (Vector3f).
+	 * @param angle - The angle in radians.
+	 */
+	#[napi]
+	pub fn rotate_around(&self, axis: VectorLike, angle: f64) -> Vector3f {
+		if axis.length() == 0.0 { return self.clone() }
+
+		let k = axis.normalize();
+		let (sin, cos) = angle.sin_cos();
+
+		self.multiply(cos)
+			.add(&k.cross(self).multiply(sin))
+			.add(&k.multiply(k.dot(self) * (1.0 - cos)))
+	}
+
+	/**
+	 * Rotates the vector around the X axis.
+	 * The rotation is counter-clockwise when looking from +X towards the origin.
+	 *
+	 * @param angle - The angle in radians.
+	 */
+	#[napi]
+	pub fn rotate_x(&self, angle: f64) -> Vector3f {
+		let (sin, cos) = angle.sin_cos();
+		Vector3f::new(self.x, self.y * cos - self.z * sin, self.y * sin + self.z * cos)
+	}
+
+	/**
+	 * Rotates the vector around the Y axis.
+	 * The rotation is counter-clockwise when looking from +Y towards the origin.
+	 *
+	 * @param angle - The angle in radians.
+	 */
+	#[napi]
+	pub fn rotate_y(&self, angle: f64) -> Vector3f {
+		let (sin, cos) = angle.sin_cos();
+		Vector3f::new(self.x * cos + self.z * sin, self.y, -self.x * sin + self.z * cos)
+	}
+
+	/**
+	 * Rotates the vector around the Z axis.
+	 * The rotation is counter-clockwise when looking from +Z towards the origin.
+	 *
+	 * @param angle - The angle in radians.
+	 */
+	#[napi]
+	pub fn rotate_z(&self, angle: f64) -> Vector3f {
+		let (sin, cos) = angle.sin_cos();
+		Vector3f::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos, self.z)
+	}
+
+	/**
+	 * Rotates the vector around the Y axis by an angle in degrees, like a yaw change.
+	 * Positive angles turn clockwise when seen from above, matching Minecraft's yaw.
+	 *
+	 * @param degrees - The angle in degrees.
+	 */
+	#[napi]
+	pub fn rotate_yaw(&self, degrees: f64) -> Vector3f {
+		self.rotate_y(-degrees.to_radians())
+	}
+
+	/**
+	 * Converts spherical coordinates into a vector, with Y as the up axis.
+	 *
+	 * @param radius - The distance from the origin.
+	 * @param theta - The polar angle from +Y in radians.
+	 * @param phi - The azimuth around Y from +X towards +Z in radians.
+	 */
+	#[napi]
+	pub fn from_spherical(radius: f64, theta: f64, phi: f64) -> Vector3f {
+		let (sin_theta, cos_theta) = theta.sin_cos();
+		let (sin_phi, cos_phi) = phi.sin_cos();
+
+		Vector3f::new(radius * sin_theta * cos_phi, radius * cos_theta, radius * sin_theta * sin_phi)
+	}
+
+	/**
+	 * Converts the vector into spherical coordinates, the inverse of fromSpherical.
+	 * The zero vector has all angles at 0.
+	 */
+	#[napi]
+	pub fn to_spherical(&self) -> Spherical {
+		let radius = self.length();
+		if radius == 0.0 { return Spherical { radius, theta: 0.0, phi: 0.0 } }
+
+		Spherical {
+			radius,
+			theta: (self.y / radius).clamp(-1.0, 1.0).acos(),
+			phi: self.z.atan2(self.x)
+		}
+	}
+
+	/**
+	 * Maps the direction of the vector onto an equirectangular image, as used for sky textures.
+	 *
+	 * @return The texture coordinates (0..1), u following the azimuth and v going from +Y (0) to -Y (1).
+	 */
+	#[napi]
+	pub fn to_equirectangular(&self) -> Vector2f {
+		let spherical = self.to_spherical();
+
+		Vector2f::new(
+			(spherical.phi / std::f64::consts::TAU).rem_euclid(1.0),
+			spherical.theta / std::f64::consts::PI
+		)
+	}
+
+	/**
+	 * Converts equirectangular texture coordinates back into a unit direction.
+	 *
+	 * @param uv - The texture coordinates (Vector2f).
+	 */
+	#[napi]
+	pub fn from_equirectangular(uv: &Vector2f) -> Vector3f {
+		Vector3f::from_spherical(1.0, uv.y * std::f64::consts::PI, uv.x * std::f64::consts::TAU)
+	}
+
+	// Dot and distance are used throughout the crate, so JS gets them through wrappers taking any vector.
+	#[napi(js_name = "dot")]
+	pub fn dot_like(&self, other: VectorLike) -> f64 {
+		self.dot(&other)
+	}
+
+	#[napi(js_name = "distance")]
+	pub fn distance_like(&self, other: VectorLike) -> f64 {
+		self.distance(&other)
+	}
+
+	// The arithmetic methods are exposed to JS with an optional out vector. When given, the result
+	// is written into it and it is returned, so hot code can reuse pooled vectors instead of allocating.
+	#[napi(js_name = "normalize", ts_args_type = "out?: Vector3f", ts_return_type = "Vector3f")]
+	pub fn normalize_out(&self, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		Vector3f::output(self.normalize(), out)
+	}
+
+	#[napi(js_name = "lerp", ts_args_type = "other: VectorLike, t: number, out?: Vector3f", ts_return_type = "Vector3f")]
+	pub fn lerp_out(&self, other: VectorLike, t: f64, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		Vector3f::output(self.lerp(&other, t), out)
+	}
+
+	#[napi(js_name = "add", ts_args_type = "other: VectorLike, out?: Vector3f", ts_return_type = "Vector3f")]
+	pub fn add_out(&self, other: VectorLike, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		Vector3f::output(self.add(&other), out)
+	}
+
+	#[napi(js_name = "subtract", ts_args_type = "other: VectorLike, out?: Vector3f", ts_return_type = "Vector3f")]
+	pub fn subtract_out(&self, other: VectorLike, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		Vector3f::output(self.subtract(&other), out)
+	}
+
+	#[napi(js_name = "multiply", ts_args_type = "scalar: number, out?: Vector3f", ts_return_type = "Vector3f")]
+	pub fn multiply_out(&self, scalar: f64, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		Vector3f::output(self.multiply(scalar), out)
+	}
+
+	#[napi(js_name = "cross", ts_args_type = "other: VectorLike, out?: Vector3f", ts_return_type = "Vector3f")]
+	pub fn cross_out(&self, other: VectorLike, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		Vector3f::output(self.cross(&other), out)
+	}
+
+	/**
+	 * Writes a result into the out vector if one was given; otherwise, returns it as a new vector.
+	 */
+	fn output(value: Vector3f, out: Option<Reference<Vector3f>>) -> Either<Vector3f, Reference<Vector3f>> {
+		match out {
+			Some(mut out) => {
+				*out = value;
+				Either::B(out)
+			},
+			None => Either::A(value)
+		}
+	}
+
 	#[napi]
-	pub fn equals(&self, other: &Vector3f) -> bool {
+	pub fn equals(&self, other: VectorLike) -> bool {
 		self.x == other.x && self.y == other.y && self.z == other.z
 	}
 
@@ -134,10 +318,68 @@ impl Vector3f {
 }
 
 
-impl FromNapiValue for Vector3f {
+/**
+ * A vector argument from JavaScript, which may be a Vector3f instance, a plain { x, y, z } object
+ * or an [x, y, z] array. Methods take vectors as VectorLike so literals can be passed anywhere,
+ * and it dereferences to the converted Vector3f. The matching TypeScript alias is declared
+ * by the dts header of the build scripts in package.json.
+ */
+pub struct VectorLike(Vector3f);
+
+impl Deref for VectorLike {
+	type Target = Vector3f;
+
+	fn deref(&self) -> &Vector3f {
+		&self.0
+	}
+}
+
+impl From<&Vector3f> for VectorLike {
+	fn from(v: &Vector3f) -> Self {
+		VectorLike(v.clone())
+	}
+}
+
+impl From<Vector3f> for VectorLike {
+	fn from(v: Vector3f) -> Self {
+		VectorLike(v)
+	}
+}
+
+impl TypeName for VectorLike {
+	fn type_name() -> &'static str {
+		"Vector3f"
+	}
+
+	fn value_type() -> ValueType {
+		ValueType::Object
+	}
+}
+
+impl FromNapiValue for VectorLike {
 	unsafe fn from_napi_value(env: napi::sys::napi_env, napi_val: napi::sys::napi_value) -> napi::Result<Self> {
-		println!("{:?}", f64::from_napi_value(env, napi_val));
-		Ok(Vector3f::new(f64::from_napi_value(env, napi_val)?, 0.0, 0.0)) // Assuming x, y, and z are always 0.0 for now
+		Ok(VectorLike(Vector3f::from_napi_value(env, napi_val)?))
 	}
 }
 
+/**
+ * Converts a JavaScript value into a Vector3f, accepting Vector3f instances,
+ * plain { x, y, z } objects and [x, y, z] arrays.
+ */
+impl FromNapiValue for Vector3f {
+	unsafe fn from_napi_value(env: napi::sys::napi_env, napi_val: napi::sys::napi_value) -> napi::Result<Self> {
+		let object = JsObject::from_napi_value(env, napi_val)?;
+
+		if object.is_array()? {
+			if object.get_array_length()? < 3 {
+				return Err(Error::new(Status::InvalidArg, "Expected an array of 3 numbers for a Vector3f".to_string()));
+			}
+
+			let element = |index: u32| object.get_element::<JsNumber>(index)?.get_double();
+			return Ok(Vector3f::new(element(0)?, element(1)?, element(2)?));
+		}
+
+		// Class instances expose their fields through getters, so they read the same as plain objects.
+		Ok(Vector3f::new(object.get_named_property::<f64>("x")?, object.get_named_property::<f64>("y")?, object.get_named_property::<f64>("z")?))
+	}
+}
@@ -0,0 +1,163 @@
+use napi::{Error, Status};
+use napi_derive::napi;
+
+use crate::collision::hit::BlockFace;
+use crate::vec3f::{Vector3f, VectorLike};
+
+/**
+ * Represents an integer block position. Unlike Vector3f, components are always whole numbers,
+ * so positions compare exactly and make stable map keys.
+ */
+#[napi(js_name = "Vector3i")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vector3i {
+	pub x: i32,
+	pub y: i32,
+	pub z: i32
+}
+
+#[napi]
+impl Vector3i {
+	#[napi(constructor)]
+	pub fn new(x: i32, y: i32, z: i32) -> Self {
+		Vector3i { x, y, z }
+	}
+
+	/**
+	 * Returns the block containing a position, flooring every component.
+	 *
+	 * @param v - The position (Vector3f).
+	 * Throws if a component is NaN or the block is outside the range of a 32-bit integer.
+	 */
+	#[napi(js_name = "fromVector3f")]
+	pub fn from_vector3f(v: VectorLike) -> napi::Result<Vector3i> {
+		// Casting would silently turn NaN into 0 and clamp far positions to the edge of the range.
+		let block = |value: f64| -> napi::Result<i32> {
+			let floored = value.floor();
+			if floored >= i32::MIN as f64 && floored <= i32::MAX as f64 { return Ok(floored as i32) }
+			Err(Error::new(Status::InvalidArg, format!("Position must be a number within the range of a block position, got ({}, {}, {})", v.x, v.y, v.z)))
+		};
+
+		Ok(Vector3i::new(block(v.x)?, block(v.y)?, block(v.z)?))
+	}
+
+	/**
+	 * Returns the unit offset pointing out of a face.
+	 *
+	 * @param face - The face (BlockFace).
+	 */
+	#[napi]
+	pub fn from_face(face: BlockFace) -> Vector3i {
+		match face {
+			BlockFace::Down => Vector3i::new(0, -1, 0),
+			BlockFace::Up => Vector3i::new(0, 1, 0),
+			BlockFace::North => Vector3i::new(0, 0, -1),
+			BlockFace::South => Vector3i::new(0, 0, 1),
+			BlockFace::West => Vector3i::new(-1, 0, 0),
+			BlockFace::East => Vector3i::new(1, 0, 0)
+		}
+	}
+
+	/**
+	 * Parses a key created by key.
+	 *
+	 * @param key - The key, formatted as "x,y,z".
+	 */
+	#[napi]
+	pub fn from_key(key: String) -> napi::Result<Vector3i> {
+		let parts: Vec<Result<i32, _>> = key.split(',').map(|part| part.trim().parse::<i32>()).collect();
+
+		match parts.as_slice() {
+			[Ok(x), Ok(y), Ok(z)] => Ok(Vector3i::new(*x, *y, *z)),
+			_ => Err(Error::new(Status::InvalidArg, format!("Invalid block position key: {}", key)))
+		}
+	}
+
+	#[napi(js_name = "toVector3f")]
+	pub fn to_vector3f(&self) -> Vector3f {
+		Vector3f::new(self.x as f64, self.y as f64, self.z as f64)
+	}
+
+	/**
+	 * Returns the center of the block.
+	 */
+	#[napi]
+	pub fn center(&self) -> Vector3f {
+		Vector3f::new(self.x as f64 + 0.5, self.y as f64 + 0.5, self.z as f64 + 0.5)
+	}
+
+	#[napi]
+	pub fn add(&self, other: &Vector3i) -> Vector3i {
+		Vector3i::new(self.x.wrapping_add(other.x), self.y.wrapping_add(other.y), self.z.wrapping_add(other.z))
+	}
+
+	#[napi]
+	pub fn subtract(&self, other: &Vector3i) -> Vector3i {
+		Vector3i::new(self.x.wrapping_sub(other.x), self.y.wrapping_sub(other.y), self.z.wrapping_sub(other.z))
+	}
+
+	#[napi]
+	pub fn multiply(&self, scalar: i32) -> Vector3i {
+		Vector3i::new(self.x.wrapping_mul(scalar), self.y.wrapping_mul(scalar), self.z.wrapping_mul(scalar))
+	}
+
+	#[napi]
+	pub fn negate(&self) -> Vector3i {
+		Vector3i::new(self.x.wrapping_neg(), self.y.wrapping_neg(), self.z.wrapping_neg())
+	}
+
+	/**
+	 * Returns the position moved out of a face.
+	 *
+	 * @param face - The direction to move in (BlockFace).
+	 * @param distance - The number of blocks to move, defaults to 1.
+	 */
+	#[napi]
+	pub fn offset(&self, face: BlockFace, distance: Option<i32>) -> Vector3i {
+		self.add(&Vector3i::from_face(face).multiply(distance.unwrap_or(1)))
+	}
+
+	/**
+	 * Returns the six blocks sharing a face with this one, in BlockFace order.
+	 */
+	#[napi]
+	pub fn neighbors(&self) -> Vec<Vector3i> {
+		[BlockFace::Down, BlockFace::Up, BlockFace::North, BlockFace::South, BlockFace::West, BlockFace::East]
+			.into_iter()
+			.map(|face| self.offset(face, None))
+			.collect()
+	}
+
+	#[napi]
+	pub fn manhattan_distance(&self, other: &Vector3i) -> i64 {
+		let (dx, dy, dz) = (self.x as i64 - other.x as i64, self.y as i64 - other.y as i64, self.z as i64 - other.z as i64);
+		dx.abs() + dy.abs() + dz.abs()
+	}
+
+	#[napi]
+	pub fn distance_squared(&self, other: &Vector3i) -> f64 {
+		let (dx, dy, dz) = (self.x as f64 - other.x as f64, self.y as f64 - other.y as f64, self.z as f64 - other.z as f64);
+		dx * dx + dy * dy + dz * dz
+	}
+
+	#[napi]
+	pub fn equals(&self, other: &Vector3i) -> bool {
+		self == other
+	}
+
+	/**
+	 * Returns a string key identifying the position, for use in Map and Set.
+	 */
+	#[napi]
+	pub fn key(&self) -> String {
+		format!("{},{},{}", self.x, self.y, self.z)
+	}
+
+	/**
+	 * Returns a 32-bit hash of the position, using the same formula as Java's Vec3i.
+	 */
+	#[napi]
+	pub fn hash_code(&self) -> i32 {
+		self.y.wrapping_add(self.z.wrapping_mul(31)).wrapping_mul(31).wrapping_add(self.x)
+	}
+}